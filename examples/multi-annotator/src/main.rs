@@ -1,5 +1,6 @@
 #![allow(non_snake_case)] // charge_independent_Y needs the capital as it means the glycan fragmentation
 use std::{
+    borrow::Cow,
     collections::BTreeMap,
     fs::File,
     io::{BufReader, BufWriter},
@@ -11,8 +12,8 @@ use fragment::FragmentType;
 use itertools::Itertools;
 use rayon::prelude::*;
 use rustyms::{
-    spectrum::{Score, Scores},
-    system::{e, usize::Charge, Mass},
+    spectrum::Scores,
+    system::{e, mz, usize::Charge, Mass, MassOverCharge},
     *,
 };
 use spectrum::{AnnotatedPeak, PeakSpectrum};
@@ -25,7 +26,8 @@ struct Cli {
     /// The output path to output the resulting csv file
     #[arg(short, long)]
     out_path: String,
-    /// The tolerance for matching fragments, use `<x>ppm` or `<x>da` to control the unit, e.g. `10.0ppm` or `2.3da`
+    /// The tolerance for matching fragments, use `<x>ppm` or `<x>da` to control the unit, e.g. `10.0ppm` or `2.3da`.
+    /// Combine both with a pipe to use whichever is wider at the given mass, e.g. `5ppm|0.01da`
     #[arg(short, long, default_value_t = Tolerance::new_ppm(20.0), value_parser=mass_tolerance_parse)]
     pub tolerance: Tolerance<Mass>,
     /// Global model, will be overruled by line specific models (etd/td_etd/ethcd/etcad/hot eacid/eacid/ead/hcd/cid/all/none)
@@ -40,6 +42,9 @@ struct Cli {
     /// Turns on reporting of I/L coverage by satellite ions, returns a list with a 0 (not covered) or 1 (covered) for each I or L in the peptide
     #[arg(long)]
     report_IL_satellite_coverage: bool,
+    /// Only retain the given number of most intense peaks in every 100 m/z window before annotating the spectrum
+    #[arg(long)]
+    top_per_window: Option<usize>,
     /// To turn off loading the custom modifications database from the Annotator (if installed)
     #[arg(long)]
     no_custom_mods: bool,
@@ -108,6 +113,12 @@ fn main() {
                     .map_or_else(|_| model.clone(), |(text, _)| select_model(text, &model));
                 if let Some(spectrum) = file.iter().find(|s| s.raw_scan_number == Some(scan_number))
                 {
+                    let spectrum = match args.top_per_window {
+                        Some(top) => Cow::Owned(
+                            spectrum.filter_window(MassOverCharge::new::<mz>(100.0), top),
+                        ),
+                        None => Cow::Borrowed(spectrum),
+                    };
                     let fragments =
                         peptide.generate_theoretical_fragments(Charge::new::<e>(z), &model);
                     let annotated = spectrum.annotate(
@@ -125,25 +136,19 @@ fn main() {
                     if args.charge_independent_Y {
                         let unique_Y = fragments
                             .iter()
-                            .filter_map(|fragment| {
-                                if let FragmentType::Y(pos) = &fragment.ion {
-                                    Some(pos)
-                                } else {
-                                    None
-                                }
+                            .filter(|fragment| {
+                                matches!(fragment.ion, FragmentType::Y(_) | FragmentType::YCore(..))
                             })
+                            .map(|fragment| &fragment.ion)
                             .unique()
                             .count();
                         let unique_Y_found = annotated
                             .spectrum()
                             .flat_map(|peak| &peak.annotation)
-                            .filter_map(|fragment| {
-                                if let FragmentType::Y(pos) = &fragment.ion {
-                                    Some(pos)
-                                } else {
-                                    None
-                                }
+                            .filter(|fragment| {
+                                matches!(fragment.ion, FragmentType::Y(_) | FragmentType::YCore(..))
                             })
+                            .map(|fragment| &fragment.ion)
                             .unique()
                             .count();
                         row.insert(
@@ -151,34 +156,6 @@ fn main() {
                             format!("{}", (unique_Y_found as f64 / unique_Y as f64),),
                         );
                     }
-                    if args.report_intensity {
-                        row.insert(
-                            "intensity_combined".to_string(),
-                            match scores.score {
-                                Score::Position { intensity, .. }
-                                | Score::UniqueFormulas { intensity, .. } => {
-                                    intensity.fraction().to_string()
-                                }
-                            },
-                        );
-                        row.insert(
-                            "total_ion_current".to_string(),
-                            match scores.score {
-                                Score::Position { intensity, .. }
-                                | Score::UniqueFormulas { intensity, .. } => {
-                                    intensity.total.to_string()
-                                }
-                            },
-                        );
-                        for (ion, score) in &scores.ions {
-                            row.insert(format!("intensity_{ion}"), match score {
-                                Score::Position { intensity, .. }
-                                | Score::UniqueFormulas { intensity, .. } => {
-                                    intensity.fraction().to_string()
-                                }
-                            });
-                        }
-                    }
                     if args.report_IL_satellite_coverage {
                         row.insert(
                             "IL_satellite_coverage".to_string(),
@@ -209,31 +186,10 @@ fn main() {
                             ),
                         );
                     }
-                    row.insert(
-                        "ion_combined".to_string(),
-                        match scores.score {
-                            Score::Position {
-                                theoretical_positions,
-                                ..
-                            } => format!("{}", theoretical_positions.fraction(),),
-                            Score::UniqueFormulas {
-                                unique_formulas, ..
-                            } => format!("{}", unique_formulas.fraction(),),
-                        },
-                    );
-
-                    for (ion, score) in &scores.ions {
-                        let recovered = match score {
-                            Score::Position {
-                                theoretical_positions,
-                                ..
-                            } => theoretical_positions,
-                            Score::UniqueFormulas {
-                                unique_formulas, ..
-                            } => unique_formulas,
-                        };
-                        row.insert(format!("ion_{ion}"), format!("{}", recovered.fraction(),));
-                    }
+                    row.extend(scores.to_columns("").into_iter().filter(|(key, _)| {
+                        args.report_intensity
+                            || !(key.starts_with("intensity_") || key == "total_ion_current")
+                    }));
                     Some(row)
                 } else {
                     eprintln!("Could not find scan number {scan_number} for file {file_name}");
@@ -10,7 +10,7 @@ use rustyms::UnAmbiguous;
 use crate::imgt_gene::IMGTGene;
 use crate::structs::DataItem;
 
-use crate::shared::{AnnotatedSequence, Gene, Germline, Germlines, Species};
+use crate::shared::{AnnotatedSequence, Functionality, Gene, Germline, Germlines, Species};
 use crate::structs::SingleSeq;
 
 pub fn combine(
@@ -298,6 +298,7 @@ struct TemporarySequence {
     regions: HashMap<Vec<(Region, usize)>, Vec<usize>>,
     annotations: HashMap<Vec<(Annotation, usize)>, Vec<usize>>,
     dna: HashMap<String, Vec<usize>>,
+    functionality: Functionality,
 }
 
 impl TemporarySequence {
@@ -308,12 +309,14 @@ impl TemporarySequence {
             dna: [(single.dna, vec![0])].into(),
             regions: [(single.sequence.regions, vec![0])].into(),
             annotations: [(single.sequence.annotations, vec![0])].into(),
+            functionality: single.sequence.functionality,
         }
     }
 
     fn add_single(&mut self, single: SingleSeq) {
         let index = self.acc.len();
         self.acc.push(single.acc);
+        self.functionality = self.functionality.min(single.sequence.functionality);
         self.dna.entry(single.dna).or_default().push(index);
         self.regions
             .entry(single.sequence.regions)
@@ -330,6 +333,7 @@ impl TemporarySequence {
             sequence: self.sequence.clone(),
             regions: self.regions()[0].0.clone(),
             annotations: self.conserved()[0].0.clone(),
+            functionality: self.functionality,
         }
     }
 
@@ -7,7 +7,7 @@ use rustyms::{
     AminoAcid, CheckedAminoAcid,
 };
 
-use crate::shared::{AnnotatedSequence, Gene};
+use crate::shared::{AnnotatedSequence, Functionality, Gene};
 use crate::structs::{Location, SequenceRegion, SingleSeq};
 use crate::{find_possible_n_glycan_locations, fix_j};
 
@@ -17,6 +17,7 @@ pub struct IMGTGene {
     pub key: String,
     pub location: Location,
     pub allele: String,
+    pub functionality: Functionality,
     pub regions: HashMap<String, crate::structs::Region>,
 }
 
@@ -71,6 +72,7 @@ impl IMGTGene {
                     .collect(),
                 region_lengths,
                 conserved,
+                self.functionality,
             ),
             dna,
         })
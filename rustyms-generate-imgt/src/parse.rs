@@ -1,6 +1,6 @@
 use crate::{complement, imgt_gene::IMGTGene, translate};
 use crate::{
-    shared::Species,
+    shared::{Functionality, Species},
     structs::{AASequence, DataItem, Location, Region},
 };
 use itertools::Itertools;
@@ -72,6 +72,13 @@ impl DataItem {
         let mut result = Self {
             id: data.id[5..].split(';').next().unwrap().to_string(),
             species: data.os.ok_or("No species found")?,
+            functionality: if data.kw.contains(&"pseudogene".to_string()) {
+                Functionality::Pseudogene
+            } else if data.kw.contains(&"ORF".to_string()) {
+                Functionality::Orf
+            } else {
+                Functionality::Functional
+            },
             sequence: data.sq,
             genes: Vec::new(),
             regions: Vec::new(),
@@ -176,6 +183,7 @@ impl DataItem {
                 key: region.key,
                 location: region.location,
                 allele: region.allele,
+                functionality: self.functionality,
                 regions: HashMap::new(),
             });
         } else if ["V-REGION", "C-REGION", "J-REGION"].contains(&region.key.as_str()) // , "D-GENE"
@@ -191,6 +199,7 @@ impl DataItem {
                     key: region.key,
                     location: region.location,
                     allele: region.allele,
+                    functionality: self.functionality,
                     regions: HashMap::new(),
                 });
             }
@@ -3,7 +3,7 @@ use std::ops::RangeInclusive;
 use std::str::FromStr;
 
 use crate::imgt_gene::IMGTGene;
-use crate::shared::{AnnotatedSequence, Gene, Species};
+use crate::shared::{AnnotatedSequence, Functionality, Gene, Species};
 use rustyms::AminoAcid;
 
 #[derive(Debug)]
@@ -12,6 +12,7 @@ pub struct DataItem {
     pub genes: Vec<IMGTGene>,
     pub regions: Vec<Region>,
     pub species: Species,
+    pub functionality: Functionality,
     pub sequence: String,
 }
 
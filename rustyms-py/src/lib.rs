@@ -1,10 +1,15 @@
 //! Python bindings to the rustyms library.
 
+use std::collections::{BTreeMap, HashMap};
 use std::fmt::Debug;
 use std::num::NonZeroU16;
 
 use ordered_float::OrderedFloat;
-use pyo3::{exceptions::PyValueError, prelude::*, types::PyType};
+use pyo3::{
+    exceptions::PyValueError,
+    prelude::*,
+    types::{PyDict, PyType},
+};
 
 use rustyms::{AnnotatableSpectrum, Chemical, Linked, MultiChemical};
 
@@ -17,6 +22,177 @@ enum MassMode {
     MostAbundant,
 }
 
+/// Method to generate a decoy peptide.
+#[pyclass(eq, eq_int)]
+#[derive(PartialEq, Eq)]
+enum DecoyMethod {
+    Reverse,
+    PseudoReverse,
+}
+
+/// The consistency of the termini of a peptide with the cleavage specificity of a protease.
+#[pyclass(eq, eq_int)]
+#[derive(PartialEq, Eq)]
+enum TerminusStatus {
+    Specific,
+    SemiSpecificNTerm,
+    SemiSpecificCTerm,
+    NonSpecific,
+}
+
+/// The chemistry of the C terminus of a (synthetic) peptide, a free acid or an amide.
+#[pyclass(eq, eq_int)]
+#[derive(PartialEq, Eq)]
+enum CTermChemistry {
+    Acid,
+    Amide,
+}
+
+/// A tolerance for matching masses, either relative (ppm), absolute (Da), or the wider of both.
+///
+/// Use `Tolerance.ppm`, `Tolerance.absolute`, or `Tolerance.combined` to create a tolerance.
+///
+#[pyclass]
+#[derive(Debug, Clone, Copy)]
+pub struct Tolerance(rustyms::Tolerance<rustyms::system::Mass>);
+
+#[pymethods]
+impl Tolerance {
+    /// Create a relative tolerance.
+    ///
+    /// Parameters
+    /// ----------
+    /// value : float
+    ///     The tolerance in ppm.
+    ///
+    /// Returns
+    /// -------
+    /// Tolerance
+    ///
+    #[staticmethod]
+    fn ppm(value: f64) -> Self {
+        Tolerance(rustyms::Tolerance::new_ppm(value))
+    }
+
+    /// Create an absolute tolerance.
+    ///
+    /// Parameters
+    /// ----------
+    /// value : float
+    ///     The tolerance in Da.
+    ///
+    /// Returns
+    /// -------
+    /// Tolerance
+    ///
+    #[staticmethod]
+    fn absolute(value: f64) -> Self {
+        Tolerance(rustyms::Tolerance::new_absolute(rustyms::system::da(value)))
+    }
+
+    /// Create a combined tolerance, the window at any mass is the wider of the relative and
+    /// absolute tolerance.
+    ///
+    /// Parameters
+    /// ----------
+    /// ppm : float
+    ///     The relative tolerance in ppm.
+    /// absolute : float
+    ///     The absolute tolerance in Da.
+    ///
+    /// Returns
+    /// -------
+    /// Tolerance
+    ///
+    #[staticmethod]
+    fn combined(ppm: f64, absolute: f64) -> Self {
+        Tolerance(rustyms::Tolerance::new_combined(
+            ppm,
+            rustyms::system::da(absolute),
+        ))
+    }
+
+    fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Tolerance('{}')", self.0)
+    }
+
+    /// The bounds around the given reference (e.g. theoretical) mass for this tolerance, so the
+    /// range of experimental masses that are within the tolerance of this reference mass.
+    ///
+    /// Parameters
+    /// ----------
+    /// value : float
+    ///     The reference mass in Da.
+    ///
+    /// Returns
+    /// -------
+    /// tuple[float, float]
+    ///
+    fn bounds(&self, value: f64) -> (f64, f64) {
+        let (low, high) = self.0.bounds(rustyms::system::da(value));
+        (low.value, high.value)
+    }
+
+    /// The bounds around the given experimental mass for this tolerance, so the range of reference
+    /// (e.g. theoretical) masses for which this experimental mass is within the tolerance. This
+    /// only differs from `bounds` for asymmetric tolerances.
+    ///
+    /// Parameters
+    /// ----------
+    /// value : float
+    ///     The experimental mass in Da.
+    ///
+    /// Returns
+    /// -------
+    /// tuple[float, float]
+    ///
+    fn bounds_for_experimental(&self, value: f64) -> (f64, f64) {
+        let (low, high) = self.0.bounds_for_experimental(rustyms::system::da(value));
+        (low.value, high.value)
+    }
+
+    /// Scale this tolerance by the given factor.
+    ///
+    /// Parameters
+    /// ----------
+    /// factor : float
+    ///     The factor, for example 0.5 on a 10 ppm tolerance gives a 5 ppm tolerance.
+    ///
+    /// Returns
+    /// -------
+    /// Tolerance
+    ///
+    fn scale(&self, factor: f64) -> Self {
+        Tolerance(self.0.scale(factor))
+    }
+
+    /// Get the tolerance that gives the smallest window around the given reference mass.
+    ///
+    /// Parameters
+    /// ----------
+    /// a : Tolerance
+    /// b : Tolerance
+    /// reference : float
+    ///     The reference mass in Da.
+    ///
+    /// Returns
+    /// -------
+    /// Tolerance
+    ///
+    #[staticmethod]
+    fn tighter_of(a: Tolerance, b: Tolerance, reference: f64) -> Self {
+        Tolerance(rustyms::Tolerance::tighter_of(
+            a.0,
+            b.0,
+            rustyms::system::da(reference),
+        ))
+    }
+}
+
 /// Element.
 ///
 /// A chemical element, with its isotopes and their properties.
@@ -38,6 +214,35 @@ impl Element {
             .map_err(|_| PyValueError::new_err("Invalid element symbol."))
     }
 
+    /// Parse an element with an optional leading isotope number, eg `C`, `13C`, or `2H`. The
+    /// symbols `D` and `T` are accepted as shorthand for deuterium and tritium.
+    ///
+    /// Parameters
+    /// ----------
+    /// symbol : str
+    ///     The element symbol with optional isotope number.
+    ///
+    /// Returns
+    /// -------
+    /// tuple[Element, int | None]
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     If the element or isotope is not valid.
+    ///
+    /// Examples
+    /// --------
+    /// >>> Element.parse_with_isotope("13C")
+    /// (Element('C'), 13)
+    ///
+    #[classmethod]
+    fn parse_with_isotope(_cls: &Bound<'_, PyType>, symbol: &str) -> PyResult<(Self, Option<u16>)> {
+        rustyms::Element::parse_with_isotope(symbol)
+            .map(|(element, isotope)| (Self(element), isotope.map(NonZeroU16::get)))
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
     fn __repr__(&self) -> String {
         format!("Element('{}')", self.0)
     }
@@ -148,7 +353,8 @@ impl MolecularFormula {
         self.0.to_string()
     }
 
-    /// Create a new molecular formula from a ProForma formula notation string.
+    /// Create a new molecular formula from a ProForma formula notation string. Charged species
+    /// can be given in square brackets (`[Na+]`, `[SO4-2]`) or with a charge tag (`:z+1`).
     ///
     /// Parameters
     /// ----------
@@ -158,9 +364,16 @@ impl MolecularFormula {
     /// -------
     /// MolecularFormula
     ///
+    /// Examples
+    /// --------
+    /// >>> MolecularFormula.from_pro_forma("[Na+]").charge()
+    /// 1
+    /// >>> MolecularFormula.from_pro_forma("[SO4-2]").charge()
+    /// -2
+    ///
     #[classmethod]
     fn from_pro_forma(_cls: &Bound<'_, PyType>, proforma: &str) -> PyResult<Self> {
-        rustyms::MolecularFormula::from_pro_forma(proforma, .., false, false, true)
+        rustyms::MolecularFormula::from_pro_forma(proforma, .., true, false, true)
             .map(MolecularFormula)
             .map_err(|e| PyValueError::new_err(format!("Invalid ProForma string: {}", e)))
     }
@@ -182,6 +395,39 @@ impl MolecularFormula {
             .map_err(|e| PyValueError::new_err(format!("Invalid PSI-MOD string: {}", e)))
     }
 
+    /// Create a new molecular formula from a compact string as created by `to_compact_string`.
+    ///
+    /// Parameters
+    /// ----------
+    /// compact : str
+    ///
+    /// Returns
+    /// -------
+    /// MolecularFormula
+    ///
+    #[classmethod]
+    fn from_compact_string(_cls: &Bound<'_, PyType>, compact: &str) -> PyResult<Self> {
+        rustyms::MolecularFormula::from_compact_string(compact)
+            .map(MolecularFormula)
+            .map_err(|e| PyValueError::new_err(format!("Invalid compact formula: {}", e)))
+    }
+
+    /// Create a compact canonical string for this formula, containing all elements, isotopes,
+    /// additional mass, and charge. It can be parsed back with `from_compact_string`.
+    ///
+    /// Returns
+    /// -------
+    /// str
+    ///
+    /// Examples
+    /// --------
+    /// >>> MolecularFormula.from_pro_forma("C6H12O6").to_compact_string()
+    /// 'H12C6O6'
+    ///
+    fn to_compact_string(&self) -> String {
+        self.0.to_compact_string()
+    }
+
     /// Add the given element to this formula (while keeping it ordered and simplified)
     ///
     /// Parameters
@@ -285,6 +531,27 @@ impl MolecularFormula {
         self.0.most_abundant_mass().value
     }
 
+    /// The isotopic distribution of this formula as pairs of mass and relative intensity, normalised so that the
+    /// most abundant peak is 1.0. Every peak is placed at a whole Dalton offset from the monoisotopic mass.
+    ///
+    /// Parameters
+    /// ----------
+    /// min_abundance : float
+    ///     The minimal relative intensity of the returned peaks (default: 0.01).
+    ///
+    /// Returns
+    /// -------
+    /// list[tuple[float, float]]
+    ///
+    #[pyo3(signature = (min_abundance=0.01))]
+    fn isotopic_distribution(&self, min_abundance: f64) -> Vec<(f64, f64)> {
+        self.0
+            .isotopic_envelope(min_abundance)
+            .into_iter()
+            .map(|(mass, intensity)| (mass.value, intensity))
+            .collect()
+    }
+
     /// Get the mass in the given mode.
     ///
     /// Parameters
@@ -532,7 +799,7 @@ impl SimpleModification {
             &mut vec![],
             None,
         ) {
-            Ok((modification, _)) => Ok(SimpleModification(modification.defined().unwrap())),
+            Ok((modification, _, _)) => Ok(SimpleModification(modification.defined().unwrap())),
             Err(_) => Err(PyValueError::new_err("Invalid modification")),
         }
     }
@@ -545,6 +812,14 @@ impl SimpleModification {
         format!("Modification('{}')", self.0)
     }
 
+    fn __eq__(&self, other: &Self) -> bool {
+        simple_modification_identity(&self.0) == simple_modification_identity(&other.0)
+    }
+
+    fn __hash__(&self) -> u64 {
+        identity_hash(&simple_modification_identity(&self.0))
+    }
+
     /// Molecular formula of the modification.
     ///
     /// Returns
@@ -566,6 +841,43 @@ impl SimpleModification {
     }
 }
 
+/// The identity of a modification as used for equality and hashing. Modifications from an
+/// ontology are identified by their ontology and accession, all others by their definition (the
+/// formula or mass for offset modifications).
+fn simple_modification_identity(
+    modification: &rustyms::modification::SimpleModificationInner,
+) -> String {
+    match modification {
+        rustyms::modification::SimpleModificationInner::Database { id, .. }
+        | rustyms::modification::SimpleModificationInner::Linker { id, .. }
+        | rustyms::modification::SimpleModificationInner::Gno { id, .. } => id.id.map_or_else(
+            || format!("{}:{}", id.ontology, id.name),
+            |accession| format!("{}:{accession}", id.ontology),
+        ),
+        _ => modification.to_string(),
+    }
+}
+
+/// The identity of a modification as used for equality and hashing, see [`simple_modification_identity`].
+/// Cross-linked and ambiguous modifications are identified by their full definition.
+fn modification_identity(modification: &rustyms::Modification) -> String {
+    match modification {
+        rustyms::Modification::Simple {
+            modification: simple,
+            ..
+        } => simple_modification_identity(simple),
+        _ => modification.to_string(),
+    }
+}
+
+/// Hash the identity of a modification.
+fn identity_hash(identity: &str) -> u64 {
+    use std::hash::{Hash, Hasher};
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    identity.hash(&mut hasher);
+    hasher.finish()
+}
+
 /// Amino acid modification.
 ///
 /// Parameters
@@ -587,6 +899,14 @@ impl Modification {
         format!("Modification('{}')", self.0)
     }
 
+    fn __eq__(&self, other: &Self) -> bool {
+        modification_identity(&self.0) == modification_identity(&other.0)
+    }
+
+    fn __hash__(&self) -> u64 {
+        identity_hash(&modification_identity(&self.0))
+    }
+
     /// Molecular formula of the modification.
     ///
     /// Returns
@@ -606,6 +926,79 @@ impl Modification {
     fn monoisotopic_mass(&self) -> f64 {
         self.0.formula().monoisotopic_mass().value
     }
+
+    /// The free text info tags (ProForma `[INFO:...]`) of this modification.
+    ///
+    /// Returns
+    /// -------
+    /// list[str]
+    ///
+    #[getter]
+    fn info_tags(&self) -> Vec<String> {
+        self.0.info_tags().to_vec()
+    }
+}
+
+/// A glycan structure.
+///
+/// Parameters
+/// ----------
+/// structure : str
+///     The glycan structure in the textual representation, for example `hexnac(hexnac(hex(hex,hex)))`.
+///
+#[pyclass]
+#[derive(Debug, Clone)]
+pub struct GlycanStructure(rustyms::glycan::GlycanStructure);
+
+#[pymethods]
+impl GlycanStructure {
+    #[new]
+    fn new(structure: &str) -> Result<Self, CustomError> {
+        structure
+            .parse::<rustyms::glycan::GlycanStructure>()
+            .map(GlycanStructure)
+            .map_err(CustomError)
+    }
+
+    fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("GlycanStructure('{}')", self.0)
+    }
+
+    /// Molecular formula of the glycan.
+    ///
+    /// Returns
+    /// -------
+    /// MolecularFormula
+    ///
+    fn formula(&self) -> MolecularFormula {
+        MolecularFormula(self.0.formula())
+    }
+
+    /// Check if this glycan contains the conserved N-glycan core (Man3GlcNAc2).
+    ///
+    /// Returns
+    /// -------
+    /// bool
+    ///
+    fn is_valid_n_glycan(&self) -> bool {
+        self.0.is_valid_n_glycan()
+    }
+
+    /// A node/edge representation of this glycan as JSON, for use in (SNFG) visualisation. The
+    /// nodes are numbered in depth first order starting with the root and contain the name of the
+    /// monosaccharide. The edges connect a parent to a child node.
+    ///
+    /// Returns
+    /// -------
+    /// str
+    ///
+    fn to_json(&self) -> String {
+        self.0.to_json()
+    }
 }
 
 /// A theoretical fragment of a peptide.
@@ -753,11 +1146,22 @@ impl SequenceElement {
     fn ambiguous(&self) -> Option<std::num::NonZeroU32> {
         self.0.ambiguous
     }
+
+    /// The free text info tags (ProForma `[INFO:...]`) given on their own on this position.
+    ///
+    /// Returns
+    /// -------
+    /// list[str]
+    ///
+    #[getter]
+    fn info_tags(&self) -> Vec<String> {
+        self.0.info.to_vec()
+    }
 }
 
-/// Fragmentation model enum.
+/// Fragmentation model enum, see `CustomModel` for any other model.
 #[pyclass(eq, eq_int)]
-#[derive(PartialEq, Eq)]
+#[derive(Clone, PartialEq, Eq)]
 enum FragmentationModel {
     All,
     CidHcd,
@@ -765,13 +1169,108 @@ enum FragmentationModel {
     Ethcd,
 }
 
-/// Helper function to match a [`FragmentationModel`] to a rustyms Model.
-fn match_model(model: &FragmentationModel) -> PyResult<rustyms::Model> {
+#[pymethods]
+impl FragmentationModel {
+    /// Build a custom model, only the given ion series are generated.
+    ///
+    /// Parameters
+    /// ----------
+    /// series : dict[str, tuple[int, int]]
+    ///     The enabled ion series (a, b, c, d, v, w, x, y, or z) with their inclusive absolute
+    ///     charge range.
+    /// immonium : bool
+    ///     If immonium ions are generated.
+    /// precursor_losses : list[str]
+    ///     The neutral losses for the precursor, e.g. `["-H2O1"]`.
+    ///
+    /// Returns
+    /// -------
+    /// CustomModel
+    ///
+    /// Raises
+    /// ------
+    /// CustomError
+    ///     If any ion series or neutral loss is not recognised.
+    ///
+    #[classmethod]
+    #[pyo3(signature = (series, immonium=false, precursor_losses=Vec::new()))]
+    fn custom(
+        _cls: &Bound<'_, PyType>,
+        series: HashMap<String, (isize, isize)>,
+        immonium: bool,
+        precursor_losses: Vec<String>,
+    ) -> Result<CustomModel, CustomError> {
+        use rustyms::model::{ChargePoint, ChargeRange};
+        let mut builder = rustyms::Model::builder()
+            .immonium(immonium)
+            .precursor_losses(
+                precursor_losses
+                    .iter()
+                    .map(|l| l.parse::<rustyms::NeutralLoss>())
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(CustomError)?,
+            );
+        for (name, (start, end)) in series {
+            builder = builder
+                .with_series(
+                    &name,
+                    ChargeRange::new(ChargePoint::Absolute(start), ChargePoint::Absolute(end)),
+                )
+                .map_err(CustomError)?;
+        }
+        Ok(CustomModel(builder.build()))
+    }
+}
+
+/// A fragmentation model other than the predefined `FragmentationModel`s, loaded from an
+/// instrument method config file or built with `FragmentationModel.custom`. It can be used
+/// anywhere a `FragmentationModel` is accepted.
+#[pyclass(eq)]
+#[derive(Clone, PartialEq)]
+struct CustomModel(rustyms::Model);
+
+#[pymethods]
+impl CustomModel {
+    /// Load a model from an instrument method config file in JSON, containing the enabled
+    /// series, charges, neutral losses, glycan settings, m/z range, and tolerance.
+    ///
+    /// Parameters
+    /// ----------
+    /// path : str
+    ///     The path to the config file.
+    ///
+    /// Returns
+    /// -------
+    /// CustomModel
+    ///
+    /// Raises
+    /// ------
+    /// CustomError
+    ///     If the file could not be read or is not a valid config.
+    ///
+    #[staticmethod]
+    fn from_config_file(path: &str) -> Result<Self, CustomError> {
+        rustyms::Model::from_config_file(path)
+            .map(CustomModel)
+            .map_err(CustomError)
+    }
+}
+
+/// A fragmentation model given as argument, either a predefined or a custom model.
+#[derive(FromPyObject)]
+enum AnyFragmentationModel<'py> {
+    Predefined(FragmentationModel),
+    Custom(PyRef<'py, CustomModel>),
+}
+
+/// Helper function to match a [`FragmentationModel`] or [`CustomModel`] to a rustyms Model.
+fn match_model(model: &AnyFragmentationModel<'_>) -> rustyms::Model {
     match model {
-        FragmentationModel::All => Ok(rustyms::Model::all()),
-        FragmentationModel::CidHcd => Ok(rustyms::Model::cid_hcd()),
-        FragmentationModel::Etd => Ok(rustyms::Model::etd()),
-        FragmentationModel::Ethcd => Ok(rustyms::Model::ethcd()),
+        AnyFragmentationModel::Predefined(FragmentationModel::All) => rustyms::Model::all(),
+        AnyFragmentationModel::Predefined(FragmentationModel::CidHcd) => rustyms::Model::cid_hcd(),
+        AnyFragmentationModel::Predefined(FragmentationModel::Etd) => rustyms::Model::etd(),
+        AnyFragmentationModel::Predefined(FragmentationModel::Ethcd) => rustyms::Model::ethcd(),
+        AnyFragmentationModel::Custom(model) => model.0.clone(),
     }
 }
 
@@ -782,8 +1281,8 @@ fn match_model(model: &FragmentationModel) -> PyResult<rustyms::Model> {
 /// position : SequencePosition
 ///     The position
 ///
-#[pyclass]
-#[derive(Clone)]
+#[pyclass(eq, ord, hash, frozen)]
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct SequencePosition(rustyms::SequencePosition);
 
 #[pymethods]
@@ -826,13 +1325,51 @@ impl SequencePosition {
     fn is_c_term(&self) -> bool {
         matches!(self, SequencePosition(rustyms::SequencePosition::CTerm))
     }
-}
-/// A compound peptidoform with all data as provided by ProForma 2.0.
-///
-/// Parameters
+
+    /// Resolve this position to the index of the amino acid it is placed on, the N-terminus
+    /// resolves to the first and the C-terminus to the last amino acid. Positions can also be
+    /// compared and sorted, the N-terminus sorts first and the C-terminus last.
+    ///
+    /// Parameters
+    /// ----------
+    /// length : int
+    ///     The length of the peptide.
+    ///
+    /// Returns
+    /// -------
+    /// int
+    ///
+    fn as_index(&self, length: usize) -> usize {
+        self.0.as_index(length)
+    }
+}
+/// Create the custom database with the given custom (non-standard) residues.
+fn custom_residues_database(
+    custom_residues: Option<HashMap<char, MolecularFormula>>,
+) -> Result<Option<rustyms::ontologies::CustomDatabase>, CustomError> {
+    custom_residues
+        .map(|residues| {
+            let mut database = Vec::new();
+            for (symbol, formula) in residues {
+                rustyms::AminoAcid::register_custom(symbol, formula.0, &mut database)
+                    .map_err(CustomError)?;
+            }
+            Ok(database)
+        })
+        .transpose()
+}
+
+/// A compound peptidoform with all data as provided by ProForma 2.0.
+///
+/// Parameters
 /// ----------
 /// proforma : str
 ///     The ProForma string.
+/// custom_residues : dict[str, MolecularFormula] | None
+///     Custom (non-standard) residues that can be used in the ProForma string, with as key the
+///     symbol, a single ASCII punctuation character without a meaning in ProForma (e.g. `*`, `$`,
+///     `%`, or `~`), and as value the residue formula (the formula of the amino acid when part of
+///     a peptide chain). Selenocysteine (U) and pyrrolysine (O) are supported natively.
 ///
 #[pyclass]
 #[derive(Clone)]
@@ -842,12 +1379,81 @@ pub struct CompoundPeptidoform(rustyms::CompoundPeptidoformIon);
 impl CompoundPeptidoform {
     /// Create a new peptide from a ProForma string.
     #[new]
-    fn new(proforma: &str) -> Result<Self, CustomError> {
-        rustyms::CompoundPeptidoformIon::pro_forma(proforma, None)
+    #[pyo3(signature = (proforma, custom_residues=None))]
+    fn new(
+        proforma: &str,
+        custom_residues: Option<HashMap<char, MolecularFormula>>,
+    ) -> Result<Self, CustomError> {
+        let database = custom_residues_database(custom_residues)?;
+        rustyms::CompoundPeptidoformIon::pro_forma(proforma, database.as_ref())
+            .map(CompoundPeptidoform)
+            .map_err(CustomError)
+    }
+
+    /// Serialize this compound peptidoform into a compact binary format, see `from_bytes`.
+    ///
+    /// Returns
+    /// -------
+    /// bytes
+    ///
+    fn to_bytes(&self) -> Result<Vec<u8>, CustomError> {
+        self.0.to_bincode().map_err(CustomError)
+    }
+
+    /// Deserialize a compound peptidoform from the binary format as written by `to_bytes`.
+    ///
+    /// Parameters
+    /// ----------
+    /// bytes : bytes
+    ///     The binary representation.
+    ///
+    /// Returns
+    /// -------
+    /// CompoundPeptidoform
+    ///
+    #[staticmethod]
+    fn from_bytes(bytes: &[u8]) -> Result<Self, CustomError> {
+        rustyms::CompoundPeptidoformIon::from_bincode(bytes)
             .map(CompoundPeptidoform)
             .map_err(CustomError)
     }
 
+    /// Validate a ProForma string without creating the compound peptidoform.
+    ///
+    /// Parameters
+    /// ----------
+    /// proforma : str
+    ///     The ProForma string.
+    ///
+    /// Returns
+    /// -------
+    /// list[dict[str, str]]
+    ///     All errors, empty if the string is valid. Each error has the keys 'level',
+    ///     'short_description', 'long_description', and 'context'.
+    ///
+    #[staticmethod]
+    fn validate(proforma: &str) -> Vec<HashMap<String, String>> {
+        rustyms::CompoundPeptidoformIon::validate(proforma, None)
+            .err()
+            .unwrap_or_default()
+            .into_iter()
+            .map(|error| {
+                HashMap::from([
+                    ("level".to_string(), error.level().to_string()),
+                    (
+                        "short_description".to_string(),
+                        error.short_description().to_string(),
+                    ),
+                    (
+                        "long_description".to_string(),
+                        error.long_description().to_string(),
+                    ),
+                    ("context".to_string(), error.context().to_string()),
+                ])
+            })
+            .collect()
+    }
+
     /// Create a new peptide from a peptidoform.
     #[staticmethod]
     fn from_peptidoform(peptidoform: Peptidoform) -> Self {
@@ -882,7 +1488,7 @@ impl CompoundPeptidoform {
     /// ----------
     /// max_charge : int
     ///     The maximal charge of the fragments.
-    /// model : FragmentationModel
+    /// model : FragmentationModel | CustomModel
     ///     The model to use for the fragmentation.
     ///
     /// Returns
@@ -893,13 +1499,13 @@ impl CompoundPeptidoform {
     fn generate_theoretical_fragments(
         &self,
         max_charge: usize,
-        model: &FragmentationModel,
+        model: AnyFragmentationModel<'_>,
     ) -> PyResult<Vec<Fragment>> {
         Ok(self
             .0
             .generate_theoretical_fragments(
                 rustyms::system::usize::Charge::new::<rustyms::system::e>(max_charge),
-                &match_model(model)?,
+                &match_model(&model),
             )
             .iter()
             .map(|f| Fragment(f.clone()))
@@ -925,6 +1531,8 @@ impl CompoundPeptidoform {
 /// ----------
 /// proforma : str
 ///     The ProForma string.
+/// custom_residues : dict[str, MolecularFormula] | None
+///     Custom (non-standard) residues that can be used in the ProForma string, see `CompoundPeptidoform`.
 ///
 #[pyclass]
 #[derive(Clone)]
@@ -934,8 +1542,41 @@ pub struct Peptidoform(rustyms::PeptidoformIon);
 impl Peptidoform {
     /// Create a new peptidoform from a ProForma string. Panics
     #[new]
-    fn new(proforma: &str) -> Result<Self, CustomError> {
-        rustyms::PeptidoformIon::pro_forma(proforma, None)
+    #[pyo3(signature = (proforma, custom_residues=None))]
+    fn new(
+        proforma: &str,
+        custom_residues: Option<HashMap<char, MolecularFormula>>,
+    ) -> Result<Self, CustomError> {
+        let database = custom_residues_database(custom_residues)?;
+        rustyms::PeptidoformIon::pro_forma(proforma, database.as_ref())
+            .map(Peptidoform)
+            .map_err(CustomError)
+    }
+
+    /// Serialize this peptidoform into a compact binary format, see `from_bytes`.
+    ///
+    /// Returns
+    /// -------
+    /// bytes
+    ///
+    fn to_bytes(&self) -> Result<Vec<u8>, CustomError> {
+        self.0.to_bincode().map_err(CustomError)
+    }
+
+    /// Deserialize a peptidoform from the binary format as written by `to_bytes`.
+    ///
+    /// Parameters
+    /// ----------
+    /// bytes : bytes
+    ///     The binary representation.
+    ///
+    /// Returns
+    /// -------
+    /// Peptidoform
+    ///
+    #[staticmethod]
+    fn from_bytes(bytes: &[u8]) -> Result<Self, CustomError> {
+        rustyms::PeptidoformIon::from_bincode(bytes)
             .map(Peptidoform)
             .map_err(CustomError)
     }
@@ -961,6 +1602,59 @@ impl Peptidoform {
             .collect()
     }
 
+    /// Get a copy of this peptidoform with the given charge carriers (adduct ions) set on all
+    /// its peptides, replacing any charge carriers from the ProForma definition. This can be
+    /// used to set the precursor charge before fragmentation.
+    ///
+    /// Parameters
+    /// ----------
+    /// charge_carriers : MolecularCharge
+    ///     The charge carriers, e.g. `MolecularCharge.proton(2)` for `[M+2H]2+`.
+    ///
+    /// Returns
+    /// -------
+    /// Peptidoform
+    ///
+    fn with_charge_carriers(&self, charge_carriers: &MolecularCharge) -> Self {
+        let mut peptidoform = self.0.clone();
+        peptidoform.set_charge_carriers(Some(charge_carriers.0.clone()));
+        Peptidoform(peptidoform)
+    }
+
+    /// Get a copy of this peptidoform with the given global isotope modifications applied to all
+    /// its peptides, for example `[(Element("C"), 13)]` for full 13C labelling. Any existing
+    /// global isotope modification for the same element is replaced.
+    ///
+    /// Parameters
+    /// ----------
+    /// substitutions : list[tuple[Element, int | None]]
+    ///     The elements with the isotope to use for all atoms of that element.
+    ///
+    /// Returns
+    /// -------
+    /// Peptidoform
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     If any of the isotopes is not valid for its element.
+    ///
+    fn with_global_isotope_modifications(
+        &self,
+        substitutions: Vec<(Element, Option<u16>)>,
+    ) -> PyResult<Self> {
+        self.0
+            .with_global_isotope_modifications(
+                substitutions
+                    .iter()
+                    .map(|(e, i)| (e.0, (*i).and_then(NonZeroU16::new)))
+                    .collect::<Vec<_>>()
+                    .as_slice(),
+            )
+            .map(Peptidoform)
+            .ok_or_else(|| PyValueError::new_err("Invalid global isotope modifications"))
+    }
+
     /// Generate the theoretical fragments for this peptidoform, with the given maximal charge of the fragments,
     /// and the given model. With the global isotope modifications applied.
     ///
@@ -968,7 +1662,7 @@ impl Peptidoform {
     /// ----------
     /// max_charge : int
     ///     The maximal charge of the fragments.
-    /// model : FragmentationModel
+    /// model : FragmentationModel | CustomModel
     ///     The model to use for the fragmentation.
     ///
     /// Returns
@@ -979,13 +1673,13 @@ impl Peptidoform {
     fn generate_theoretical_fragments(
         &self,
         max_charge: usize,
-        model: &FragmentationModel,
+        model: AnyFragmentationModel<'_>,
     ) -> PyResult<Vec<Fragment>> {
         Ok(self
             .0
             .generate_theoretical_fragments(
                 rustyms::system::usize::Charge::new::<rustyms::system::e>(max_charge),
-                &match_model(model)?,
+                &match_model(&model),
             )
             .iter()
             .map(|f| Fragment(f.clone()))
@@ -1011,6 +1705,8 @@ impl Peptidoform {
 /// ----------
 /// proforma : str
 ///     The ProForma string.
+/// custom_residues : dict[str, MolecularFormula] | None
+///     Custom (non-standard) residues that can be used in the ProForma string, see `CompoundPeptidoform`.
 ///
 #[pyclass]
 #[derive(Clone)]
@@ -1020,12 +1716,73 @@ pub struct LinearPeptide(rustyms::Peptidoform<Linked>);
 impl LinearPeptide {
     /// Create a new peptide from a ProForma string.
     #[new]
-    fn new(proforma: &str) -> Result<Self, CustomError> {
-        rustyms::Peptidoform::pro_forma(proforma, None)
+    #[pyo3(signature = (proforma, custom_residues=None))]
+    fn new(
+        proforma: &str,
+        custom_residues: Option<HashMap<char, MolecularFormula>>,
+    ) -> Result<Self, CustomError> {
+        let database = custom_residues_database(custom_residues)?;
+        rustyms::Peptidoform::pro_forma(proforma, database.as_ref())
+            .map(LinearPeptide)
+            .map_err(CustomError)
+    }
+
+    /// Serialize this peptide into a compact binary format, see `from_bytes`.
+    ///
+    /// Returns
+    /// -------
+    /// bytes
+    ///
+    fn to_bytes(&self) -> Result<Vec<u8>, CustomError> {
+        self.0.to_bincode().map_err(CustomError)
+    }
+
+    /// Deserialize a peptide from the binary format as written by `to_bytes`.
+    ///
+    /// Parameters
+    /// ----------
+    /// bytes : bytes
+    ///     The binary representation.
+    ///
+    /// Returns
+    /// -------
+    /// LinearPeptide
+    ///
+    #[staticmethod]
+    fn from_bytes(bytes: &[u8]) -> Result<Self, CustomError> {
+        rustyms::Peptidoform::<Linked>::from_bincode(bytes)
             .map(LinearPeptide)
             .map_err(CustomError)
     }
 
+    /// Apply fixed modifications to every position that matches the placement rule.
+    ///
+    /// Parameters
+    /// ----------
+    /// modifications : list[tuple[SimpleModification, str]]
+    ///     The modifications with their placement rule, e.g. `"C@Anywhere"` or `"AnyNTerm"`.
+    ///
+    /// Returns
+    /// -------
+    /// LinearPeptide
+    ///
+    fn apply_fixed_modifications(
+        &self,
+        modifications: Vec<(SimpleModification, String)>,
+    ) -> Result<Self, CustomError> {
+        let modifications = modifications
+            .into_iter()
+            .map(|(modification, rule)| {
+                rule.parse::<rustyms::placement_rule::PlacementRule>()
+                    .map(|rule| (modification.0, rule))
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(CustomError)?;
+        Ok(LinearPeptide(
+            self.0.apply_fixed_modifications(&modifications),
+        ))
+    }
+
     fn __str__(&self) -> String {
         self.0.to_string()
     }
@@ -1053,7 +1810,8 @@ impl LinearPeptide {
             .collect()
     }
 
-    /// N-terminal modification.
+    /// N-terminal modifications. A terminus can only carry a single modification, so multiple
+    /// modifications are alternatives and all of them are enumerated for formulas and fragments.
     ///
     /// Returns
     /// -------
@@ -1068,7 +1826,8 @@ impl LinearPeptide {
             .collect()
     }
 
-    /// C-terminal modification.
+    /// C-terminal modifications. A terminus can only carry a single modification, so multiple
+    /// modifications are alternatives and all of them are enumerated for formulas and fragments.
     ///
     /// Returns
     /// -------
@@ -1083,6 +1842,28 @@ impl LinearPeptide {
             .collect()
     }
 
+    /// The free text info tags (ProForma `[INFO:...]`) given on their own on the N-terminus.
+    ///
+    /// Returns
+    /// -------
+    /// list[str]
+    ///
+    #[getter]
+    fn n_term_info_tags(&self) -> Vec<String> {
+        self.0.get_n_term_info().to_vec()
+    }
+
+    /// The free text info tags (ProForma `[INFO:...]`) given on their own on the C-terminus.
+    ///
+    /// Returns
+    /// -------
+    /// list[str]
+    ///
+    #[getter]
+    fn c_term_info_tags(&self) -> Vec<String> {
+        self.0.get_c_term_info().to_vec()
+    }
+
     /// Sequence of the peptide including modifications.
     ///
     /// Returns
@@ -1113,6 +1894,89 @@ impl LinearPeptide {
             .collect()
     }
 
+    /// The number of occurrences of each of the 20 canonical amino acids in this peptide, keyed by
+    /// the one letter code. Other amino acids (B/J/Z/U/O/X) are not counted.
+    ///
+    /// Returns
+    /// -------
+    /// dict[str, int]
+    ///
+    #[getter]
+    fn composition_counts(&self) -> HashMap<char, u32> {
+        rustyms::AminoAcid::CANONICAL_AMINO_ACIDS
+            .iter()
+            .zip(self.0.composition_counts())
+            .map(|(aa, count)| (aa.char(), count))
+            .collect()
+    }
+
+    /// The number of modifications on the termini and the amino acids of this peptide. Labile and
+    /// global isotope modifications are not counted.
+    ///
+    /// Returns
+    /// -------
+    /// int
+    ///
+    #[getter]
+    fn number_of_modifications(&self) -> usize {
+        self.0.number_of_modifications()
+    }
+
+    /// The number of modifications on this peptide per category. The keys are 'n_terminal',
+    /// 'c_terminal', 'side_chain', 'variable', 'ambiguous', 'glycan', 'cross_link', and 'labile'.
+    /// The location categories (terminal and side chain) count every modification with a defined
+    /// location, the kind categories (variable, glycan, and cross-link) overlap with these.
+    /// Modifications of unknown position are only counted as ambiguous.
+    ///
+    /// Returns
+    /// -------
+    /// dict[str, int]
+    ///
+    fn modification_summary(&self) -> HashMap<String, usize> {
+        let summary = self.0.modification_summary();
+        HashMap::from([
+            ("n_terminal".to_string(), summary.n_terminal),
+            ("c_terminal".to_string(), summary.c_terminal),
+            ("side_chain".to_string(), summary.side_chain),
+            ("variable".to_string(), summary.variable),
+            ("ambiguous".to_string(), summary.ambiguous),
+            ("glycan".to_string(), summary.glycan),
+            ("cross_link".to_string(), summary.cross_link),
+            ("labile".to_string(), summary.labile),
+        ])
+    }
+
+    /// Expand all ambiguous amino acid groups (`(?DN)` in ProForma) into all concrete orderings
+    /// of the amino acids in each group. If there are no ambiguous groups the result only
+    /// contains this peptide.
+    ///
+    /// Returns
+    /// -------
+    /// list[LinearPeptide]
+    ///
+    fn expand_ambiguous_sequences(&self) -> Vec<LinearPeptide> {
+        self.0
+            .expand_ambiguous_sequences()
+            .into_iter()
+            .map(LinearPeptide)
+            .collect()
+    }
+
+    /// All N-glycosylation sequons (N-X-S/T, where X is not P) in this peptide, with the glycan
+    /// structure if the asparagine is occupied by a glycan with known structure.
+    ///
+    /// Returns
+    /// -------
+    /// list[tuple[SequencePosition, GlycanStructure | None]]
+    ///
+    fn glycosylation_sites(&self) -> Vec<(SequencePosition, Option<GlycanStructure>)> {
+        self.0
+            .glycosylation_sites()
+            .into_iter()
+            .map(|(position, glycan)| (SequencePosition(position), glycan.map(GlycanStructure)))
+            .collect()
+    }
+
     /// Stripped sequence, meaning the sequence without any modifications.
     ///
     /// Returns
@@ -1162,6 +2026,44 @@ impl LinearPeptide {
         LinearPeptide(self.0.reverse())
     }
 
+    /// Generate a decoy of this peptide, the modifications stay on their amino acids. Only a
+    /// full reverse (`method='reverse'` without a fixed C terminus) swaps the terminal
+    /// modifications, just like `reverse`.
+    ///
+    /// Parameters
+    /// ----------
+    /// method : str
+    ///     The method to generate the decoy, either 'reverse' or 'shuffle'.
+    /// fixed_c_term : bool
+    ///     Keep the C-terminal amino acid in place, standard for tryptic decoys.
+    /// seed : int
+    ///     The seed for the shuffle, the shuffle is deterministic for a given seed.
+    ///
+    /// Returns
+    /// -------
+    /// LinearPeptide
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     If the method is not 'reverse' or 'shuffle'.
+    ///
+    #[pyo3(signature = (method="reverse", fixed_c_term=true, seed=0))]
+    fn decoy(&self, method: &str, fixed_c_term: bool, seed: u64) -> PyResult<LinearPeptide> {
+        let method = match (method, fixed_c_term) {
+            ("reverse", false) => rustyms::DecoyMethod::Reverse,
+            ("reverse", true) => rustyms::DecoyMethod::PseudoReverse,
+            ("shuffle", false) => rustyms::DecoyMethod::Shuffle(seed),
+            ("shuffle", true) => rustyms::DecoyMethod::PseudoShuffle(seed),
+            _ => {
+                return Err(PyValueError::new_err(format!(
+                    "Invalid decoy method '{method}', use 'reverse' or 'shuffle'"
+                )))
+            }
+        };
+        Ok(LinearPeptide(self.0.decoy(method)))
+    }
+
     /// Gives the formulas for the whole peptide. With the global isotope modifications applied. (Any B/Z will result in multiple possible formulas.)
     ///
     /// Returns
@@ -1177,70 +2079,249 @@ impl LinearPeptide {
         })
     }
 
-    /// Generate the theoretical fragments for this peptide, with the given maximal charge of the fragments, and the given model. With the global isotope modifications applied.
-    ///
-    /// Parameters
-    /// ----------
-    /// max_charge : int
-    ///     The maximal charge of the fragments.
-    /// model : FragmentationModel
-    ///     The model to use for the fragmentation.
+    /// Gives the formulas for the precursor, so the whole peptide including all labile
+    /// modifications. With the global isotope modifications applied. (Any B/Z will result in
+    /// multiple possible formulas.)
     ///
     /// Returns
     /// -------
-    /// list[Fragment] | None
-    ///   The theoretical fragments.
+    /// List[MolecularFormula] | None
     ///
-    fn generate_theoretical_fragments(
-        &self,
-        max_charge: usize,
-        model: &FragmentationModel,
-    ) -> Option<Vec<Fragment>> {
+    fn precursor_formula(&self) -> Option<Vec<MolecularFormula>> {
         self.0.clone().into_linear().map(|p| {
-            p.generate_theoretical_fragments(
-                rustyms::system::usize::Charge::new::<rustyms::system::e>(max_charge),
-                &match_model(model).unwrap(),
-            )
-            .iter()
-            .map(|f| Fragment(f.clone()))
-            .collect()
+            p.precursor_formula()
+                .iter()
+                .map(|f| MolecularFormula(f.clone()))
+                .collect()
         })
     }
-}
-
-#[pyclass]
-struct RawPeak(rustyms::spectrum::RawPeak);
-
-#[pymethods]
-impl RawPeak {
-    fn __repr__(&self) -> String {
-        format!("RawPeak(mz={}, intensity={})", self.mz(), self.intensity())
-    }
 
-    /// The m/z value of the peak.
+    /// Gives the formulas for the bare peptide backbone, so only the amino acids with the
+    /// terminal water, without any side chain, terminal, or labile modifications. With the global
+    /// isotope modifications applied. (Any B/Z will result in multiple possible formulas.)
     ///
     /// Returns
     /// -------
-    /// float
+    /// List[MolecularFormula] | None
     ///
-    #[getter]
-    fn mz(&self) -> f64 {
-        self.0.mz.value
+    fn fragment_backbone_formula(&self) -> Option<Vec<MolecularFormula>> {
+        self.0.clone().into_linear().map(|p| {
+            p.fragment_backbone_formula()
+                .iter()
+                .map(|f| MolecularFormula(f.clone()))
+                .collect()
+        })
     }
 
-    /// The intensity of the peak.
+    /// Gives the contribution of every residue (including its modifications) to the formula of
+    /// the whole peptide. The terminal modifications are listed as separate entries, but only if
+    /// any are present. The terminal water is not included. With the global isotope modifications
+    /// applied. For B/Z only the first possible formula is given.
     ///
     /// Returns
     /// -------
-    /// float
+    /// list[tuple[SequencePosition, MolecularFormula]] | None
     ///
-    #[getter]
-    fn intensity(&self) -> f64 {
-        self.0.intensity.into_inner()
+    fn residue_formulas(&self) -> Option<Vec<(SequencePosition, MolecularFormula)>> {
+        self.0.clone().into_linear().map(|p| {
+            p.residue_formulas()
+                .into_iter()
+                .map(|(position, formula)| (SequencePosition(position), MolecularFormula(formula)))
+                .collect()
+        })
     }
-}
 
-impl std::fmt::Display for RawPeak {
+    /// The difference between the average weight and the monoisotopic mass of this peptide in Da.
+    /// If the peptide has multiple formulas the first is used.
+    ///
+    /// Returns
+    /// -------
+    /// float | None
+    ///     The mass defect, or None if the peptide is not linear.
+    ///
+    fn mass_defect(&self) -> Option<f64> {
+        self.0.clone().into_linear().map(|p| p.mass_defect())
+    }
+
+    /// Find the first of the given target masses that matches the monoisotopic mass of this
+    /// peptide within the tolerance, allowing for isotope errors (the number of ¹³C atoms picked
+    /// instead of the monoisotopic peak).
+    ///
+    /// Parameters
+    /// ----------
+    /// masses : list[float]
+    ///     The target masses in Da.
+    /// tolerance : Tolerance
+    ///     The tolerance for matching.
+    /// isotope_errors : tuple[int, int]
+    ///     The inclusive range of isotope offsets to try.
+    ///
+    /// Returns
+    /// -------
+    /// tuple[int, int] | None
+    ///     The index of the matched target and the isotope offset, or None if there is no match
+    ///     or the peptide is not linear.
+    ///
+    #[pyo3(signature = (masses, tolerance, isotope_errors=(0, 0)))]
+    fn matches_any(
+        &self,
+        masses: Vec<f64>,
+        tolerance: &Tolerance,
+        isotope_errors: (i8, i8),
+    ) -> Option<(usize, i8)> {
+        let masses = masses
+            .into_iter()
+            .map(rustyms::system::da)
+            .collect::<Vec<_>>();
+        self.0
+            .clone()
+            .into_linear()
+            .and_then(|p| p.matches_any(&masses, tolerance.0, isotope_errors.0..=isotope_errors.1))
+    }
+
+    /// Determine if the termini of this peptide are consistent with the cleavage specificity of a
+    /// protease that cuts on the C terminal side of the given residues (like trypsin: `"KR"`). If
+    /// the protein is given the residues flanking this peptide in the protein are used, otherwise
+    /// only the residues of the peptide itself are used, so the N terminus cannot be verified.
+    ///
+    /// Parameters
+    /// ----------
+    /// cleave_after : str
+    ///     The residues on the C terminal side of which the protease cuts, e.g. `"KR"`.
+    /// protein : LinearPeptide | None
+    ///     The protein this peptide originates from.
+    ///
+    /// Returns
+    /// -------
+    /// TerminusStatus
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     If any of the residues is not a valid amino acid.
+    ///
+    #[pyo3(signature = (cleave_after, protein=None))]
+    fn enzymatic_terminus_status(
+        &self,
+        cleave_after: &str,
+        protein: Option<&LinearPeptide>,
+    ) -> PyResult<TerminusStatus> {
+        let residues = cleave_after
+            .chars()
+            .map(|c| {
+                rustyms::AminoAcid::try_from(c)
+                    .map_err(|()| PyValueError::new_err(format!("Invalid amino acid: {c}")))
+            })
+            .collect::<PyResult<Vec<_>>>()?;
+        Ok(
+            match self.0.enzymatic_terminus_status(
+                &rustyms::Protease::c_terminal_of(&residues),
+                protein.map(|p| &p.0),
+            ) {
+                rustyms::TerminusStatus::Specific => TerminusStatus::Specific,
+                rustyms::TerminusStatus::SemiSpecificNTerm => TerminusStatus::SemiSpecificNTerm,
+                rustyms::TerminusStatus::SemiSpecificCTerm => TerminusStatus::SemiSpecificCTerm,
+                rustyms::TerminusStatus::NonSpecific => TerminusStatus::NonSpecific,
+            },
+        )
+    }
+
+    /// The chemistry of the C terminus, an amide if the C terminus carries the Unimod amidation
+    /// and a free acid otherwise.
+    ///
+    /// Returns
+    /// -------
+    /// CTermChemistry
+    ///
+    #[getter]
+    fn c_terminal_chemistry(&self) -> CTermChemistry {
+        match self.0.c_terminal_chemistry() {
+            rustyms::CTermChemistry::Acid => CTermChemistry::Acid,
+            rustyms::CTermChemistry::Amide => CTermChemistry::Amide,
+        }
+    }
+
+    /// Set the chemistry of the C terminus. An amide is represented as the Unimod amidation on the
+    /// C terminus, so it is reflected in the ProForma output and the mass of this peptide. Any
+    /// other C terminal modifications are kept.
+    ///
+    /// Parameters
+    /// ----------
+    /// chemistry : CTermChemistry
+    ///     The chemistry of the C terminus.
+    ///
+    #[setter]
+    fn set_c_terminal_chemistry(&mut self, chemistry: &CTermChemistry) {
+        self.0.set_c_terminal_chemistry(match chemistry {
+            CTermChemistry::Acid => rustyms::CTermChemistry::Acid,
+            CTermChemistry::Amide => rustyms::CTermChemistry::Amide,
+        });
+    }
+
+    /// Generate the theoretical fragments for this peptide, with the given maximal charge of the fragments, and the given model. With the global isotope modifications applied.
+    ///
+    /// Parameters
+    /// ----------
+    /// max_charge : int
+    ///     The maximal charge of the fragments.
+    /// model : FragmentationModel | CustomModel
+    ///     The model to use for the fragmentation.
+    ///
+    /// Returns
+    /// -------
+    /// list[Fragment] | None
+    ///   The theoretical fragments.
+    ///
+    fn generate_theoretical_fragments(
+        &self,
+        max_charge: usize,
+        model: AnyFragmentationModel<'_>,
+    ) -> Option<Vec<Fragment>> {
+        self.0.clone().into_linear().map(|p| {
+            p.generate_theoretical_fragments(
+                rustyms::system::usize::Charge::new::<rustyms::system::e>(max_charge),
+                &match_model(&model),
+            )
+            .iter()
+            .map(|f| Fragment(f.clone()))
+            .collect()
+        })
+    }
+}
+
+#[pyclass]
+struct RawPeak(rustyms::spectrum::RawPeak);
+
+#[pymethods]
+impl RawPeak {
+    fn __repr__(&self) -> String {
+        format!("RawPeak(mz={}, intensity={})", self.mz(), self.intensity())
+    }
+
+    /// The m/z value of the peak.
+    ///
+    /// Returns
+    /// -------
+    /// float
+    ///
+    #[getter]
+    fn mz(&self) -> f64 {
+        self.0.mz.value
+    }
+
+    /// The intensity of the peak.
+    ///
+    /// Returns
+    /// -------
+    /// float
+    ///
+    #[getter]
+    fn intensity(&self) -> f64 {
+        self.0.intensity.into_inner()
+    }
+}
+
+impl std::fmt::Display for RawPeak {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         write!(f, "({}, {})", self.mz(), self.intensity())
     }
@@ -1297,6 +2378,18 @@ impl AnnotatedPeak {
             .map(|x| Fragment(x.clone()))
             .collect()
     }
+
+    /// The isotope (A+n) that was matched for the first annotation. Only set when the spectrum
+    /// was annotated with the most abundant mass mode.
+    ///
+    /// Returns
+    /// -------
+    /// int | None
+    ///
+    #[getter]
+    fn isotope_offset(&self) -> Option<i8> {
+        self.0.isotope_offset
+    }
 }
 
 impl std::fmt::Display for AnnotatedPeak {
@@ -1479,13 +2572,153 @@ impl RawSpectrum {
         self.0.clone().into_iter().map(RawPeak).collect()
     }
 
+    /// The total ion current (TIC), the sum of the intensities of all peaks.
+    ///
+    /// Returns
+    /// -------
+    /// float
+    ///
+    #[getter]
+    fn total_ion_current(&self) -> f64 {
+        self.0.total_ion_current()
+    }
+
+    /// The base peak, the most intense peak.
+    ///
+    /// Returns
+    /// -------
+    /// RawPeak | None
+    ///
+    #[getter]
+    fn base_peak(&self) -> Option<RawPeak> {
+        self.0.base_peak().map(RawPeak)
+    }
+
+    /// The intensity of the base peak.
+    ///
+    /// Returns
+    /// -------
+    /// float | None
+    ///
+    #[getter]
+    fn base_peak_intensity(&self) -> Option<f64> {
+        self.0.base_peak_intensity()
+    }
+
+    /// The m/z values of the peaks, without creating a peak object for every peak.
+    ///
+    /// Returns
+    /// -------
+    /// list[float]
+    ///
+    #[getter]
+    fn mz_array(&self) -> Vec<f64> {
+        rustyms::spectrum::PeakSpectrum::spectrum(&self.0)
+            .map(|p| p.mz.value)
+            .collect()
+    }
+
+    /// The intensities of the peaks, without creating a peak object for every peak.
+    ///
+    /// Returns
+    /// -------
+    /// list[float]
+    ///
+    #[getter]
+    fn intensity_array(&self) -> Vec<f64> {
+        rustyms::spectrum::PeakSpectrum::spectrum(&self.0)
+            .map(|p| *p.intensity)
+            .collect()
+    }
+
+    /// The m/z values and intensities of the peaks, these can be used to create a new spectrum.
+    ///
+    /// Returns
+    /// -------
+    /// tuple[list[float], list[float]]
+    ///
+    fn as_arrays(&self) -> (Vec<f64>, Vec<f64>) {
+        rustyms::spectrum::PeakSpectrum::spectrum(&self.0)
+            .map(|p| (p.mz.value, *p.intensity))
+            .unzip()
+    }
+
+    /// Sort the peaks on ascending m/z and merge all peaks that are within the given tolerance of
+    /// each other into a single peak, with the summed intensity and intensity weighted average m/z.
+    ///
+    /// Parameters
+    /// ----------
+    /// tolerance : float
+    ///     The tolerance in ppm for merging peaks.
+    ///
+    fn sort_and_dedup(&mut self, tolerance: f64) {
+        self.0
+            .sort_and_dedup(rustyms::Tolerance::new_ppm(tolerance));
+    }
+
+    /// Determine the precursor purity of this spectrum. This is the fraction of the total intensity
+    /// within the isolation window in the given MS1 spectrum that belongs to the isotopic envelope of
+    /// the precursor.
+    ///
+    /// Parameters
+    /// ----------
+    /// ms1 : RawSpectrum
+    ///     The MS1 spectrum that was used to select the precursor of this spectrum.
+    /// isolation_window : float
+    ///     The full width of the isolation window in m/z, centred on the precursor m/z.
+    /// tolerance : float
+    ///     The tolerance in ppm for matching the isotopic envelope of the precursor.
+    ///
+    /// Returns
+    /// -------
+    /// float
+    ///     The precursor purity, 0.0 if the precursor mass or charge is unknown.
+    ///
+    fn precursor_purity(&self, ms1: &RawSpectrum, isolation_window: f64, tolerance: f64) -> f64 {
+        self.0.precursor_purity(
+            &ms1.0,
+            rustyms::system::MassOverCharge::new::<rustyms::system::mz>(isolation_window),
+            rustyms::Tolerance::new_ppm(tolerance),
+        )
+    }
+
+    /// The spectral entropy of this spectrum, the Shannon entropy of the normalised peak intensities.
+    ///
+    /// Returns
+    /// -------
+    /// float
+    ///     The spectral entropy, 0.0 for an empty spectrum.
+    ///
+    fn spectral_entropy(&self) -> f64 {
+        self.0.spectral_entropy()
+    }
+
+    /// The (unweighted) spectral entropy similarity between this and the other spectrum.
+    ///
+    /// Parameters
+    /// ----------
+    /// other : RawSpectrum
+    ///     The spectrum to compare to.
+    /// tolerance : float
+    ///     The tolerance in ppm for matching peaks.
+    ///
+    /// Returns
+    /// -------
+    /// float
+    ///     The similarity, between 0.0 (no matching peaks) and 1.0 (identical spectra).
+    ///
+    fn spectral_entropy_similarity(&self, other: &RawSpectrum, tolerance: f64) -> f64 {
+        self.0
+            .spectral_entropy_similarity(&other.0, rustyms::Tolerance::new_ppm(tolerance))
+    }
+
     /// Annotate this spectrum with the given peptide
     ///
     /// Parameters
     /// ----------
     /// peptide : CompoundPeptide
     ///     The peptide to annotate the spectrum with.
-    /// model : FragmentationModel
+    /// model : FragmentationModel | CustomModel
     ///     The model to use for the fragmentation.
     /// mode : MassMode
     ///    The mode to use for the mass.
@@ -1495,19 +2728,14 @@ impl RawSpectrum {
     /// AnnotatedSpectrum
     ///     The annotated spectrum.
     ///
-    /// Raises
-    /// ------
-    /// ValueError
-    ///     If the model is not one of the valid models.
-    ///
     #[pyo3(signature = (peptide, model, mode=&MassMode::Monoisotopic))]
     fn annotate(
         &self,
         peptide: CompoundPeptidoform,
-        model: &FragmentationModel,
+        model: AnyFragmentationModel<'_>,
         mode: &MassMode,
     ) -> PyResult<AnnotatedSpectrum> {
-        let rusty_model = match_model(model)?;
+        let rusty_model = match_model(&model);
         let fragments = peptide.0.generate_theoretical_fragments(
             self.0
                 .charge
@@ -1525,6 +2753,208 @@ impl RawSpectrum {
             },
         )))
     }
+
+    /// Annotate this spectrum with the given peptide and its decoy
+    ///
+    /// Parameters
+    /// ----------
+    /// peptide : CompoundPeptide
+    ///     The peptide to annotate the spectrum with.
+    /// decoy_method : DecoyMethod
+    ///     The method to generate the decoy peptide.
+    /// model : FragmentationModel | CustomModel
+    ///     The model to use for the fragmentation.
+    /// mode : MassMode
+    ///    The mode to use for the mass.
+    ///
+    /// Returns
+    /// -------
+    /// tuple[AnnotatedSpectrum, AnnotatedSpectrum]
+    ///     The target and decoy annotated spectrum.
+    ///
+    #[pyo3(signature = (peptide, model, decoy_method=&DecoyMethod::PseudoReverse, mode=&MassMode::Monoisotopic))]
+    fn annotate_target_decoy(
+        &self,
+        peptide: CompoundPeptidoform,
+        model: AnyFragmentationModel<'_>,
+        decoy_method: &DecoyMethod,
+        mode: &MassMode,
+    ) -> PyResult<(AnnotatedSpectrum, AnnotatedSpectrum)> {
+        let rusty_model = match_model(&model);
+        let (target, decoy) = self.0.annotate_target_decoy(
+            peptide.0,
+            match decoy_method {
+                DecoyMethod::Reverse => rustyms::DecoyMethod::Reverse,
+                DecoyMethod::PseudoReverse => rustyms::DecoyMethod::PseudoReverse,
+            },
+            &rusty_model,
+            match mode {
+                MassMode::Monoisotopic => rustyms::MassMode::Monoisotopic,
+                MassMode::Average => rustyms::MassMode::Average,
+                MassMode::MostAbundant => rustyms::MassMode::MostAbundant,
+            },
+        );
+        Ok((AnnotatedSpectrum(target), AnnotatedSpectrum(decoy)))
+    }
+
+    /// Rank the given peptides on how well they explain this spectrum. The peptides are sorted on
+    /// the fraction of annotated intensity plus the average fraction of positions covered for all
+    /// individual peptides. This ranking score itself is not returned.
+    ///
+    /// Parameters
+    /// ----------
+    /// peptides : list[CompoundPeptidoform]
+    ///     The peptides to rank.
+    /// model : FragmentationModel | CustomModel
+    ///     The model to use for the fragmentation.
+    /// mode : MassMode
+    ///    The mode to use for the mass.
+    ///
+    /// Returns
+    /// -------
+    /// list[tuple[int, float, float]]
+    ///     The index of each peptide with its fraction of annotated intensity and fraction of
+    ///     annotated peaks, sorted from best to worst ranking.
+    ///
+    #[pyo3(signature = (peptides, model, mode=&MassMode::Monoisotopic))]
+    fn rank_peptides(
+        &self,
+        peptides: Vec<CompoundPeptidoform>,
+        model: AnyFragmentationModel<'_>,
+        mode: &MassMode,
+    ) -> PyResult<Vec<(usize, f64, f64)>> {
+        let rusty_model = match_model(&model);
+        let peptides = peptides.into_iter().map(|p| p.0).collect::<Vec<_>>();
+        Ok(self
+            .0
+            .rank_peptides(
+                &peptides,
+                &rusty_model,
+                match mode {
+                    MassMode::Monoisotopic => rustyms::MassMode::Monoisotopic,
+                    MassMode::Average => rustyms::MassMode::Average,
+                    MassMode::MostAbundant => rustyms::MassMode::MostAbundant,
+                },
+            )
+            .into_iter()
+            .map(|(index, scores)| match scores.score {
+                rustyms::spectrum::Score::Position {
+                    intensity, peaks, ..
+                }
+                | rustyms::spectrum::Score::UniqueFormulas {
+                    intensity, peaks, ..
+                } => (index, intensity.fraction(), peaks.fraction()),
+            })
+            .collect())
+    }
+
+    /// Determine the precursor charge of this spectrum for the given peptide, for spectra where the
+    /// charge is not known. The precursor mass of this spectrum is interpreted as the precursor m/z.
+    ///
+    /// Parameters
+    /// ----------
+    /// peptide : CompoundPeptidoform
+    ///     The peptide, without a charge state.
+    /// max_charge : int
+    ///     The highest charge to try, all charges from 1 up to this charge are tried.
+    /// tolerance : float
+    ///     The tolerance in ppm for matching the precursor mass.
+    /// max_isotope_error : int
+    ///     The highest isotope error to allow, all isotope errors from 0 up to this are tried.
+    ///
+    /// Returns
+    /// -------
+    /// int | None
+    ///     The charge that matches best, or None if no charge matches.
+    ///
+    #[pyo3(signature = (peptide, max_charge=6, tolerance=10.0, max_isotope_error=1))]
+    fn infer_charge(
+        &self,
+        peptide: CompoundPeptidoform,
+        max_charge: usize,
+        tolerance: f64,
+        max_isotope_error: isize,
+    ) -> Option<usize> {
+        self.0
+            .infer_charge(
+                &peptide.0,
+                1..=max_charge,
+                rustyms::Tolerance::new_ppm(tolerance),
+                0..=max_isotope_error,
+            )
+            .map(|c| c.value)
+    }
+}
+
+/// A spectrum prepared for repeated annotation with different peptides. The peak index is built
+/// once and reused for every annotation.
+///
+/// Parameters
+/// ----------
+/// spectrum : RawSpectrum
+///     The spectrum to annotate.
+///
+#[pyclass]
+pub struct AnnotationContext(rustyms::spectrum::AnnotationContext);
+
+#[pymethods]
+impl AnnotationContext {
+    #[new]
+    fn new(spectrum: &RawSpectrum) -> Self {
+        Self(rustyms::spectrum::AnnotationContext::new(
+            spectrum.0.clone(),
+        ))
+    }
+
+    fn __repr__(&self) -> String {
+        format!("AnnotationContext(title='{}')", self.0.spectrum().title)
+    }
+
+    /// The spectrum this context belongs to.
+    ///
+    /// Returns
+    /// -------
+    /// RawSpectrum
+    ///
+    #[getter]
+    fn spectrum(&self) -> RawSpectrum {
+        RawSpectrum(self.0.spectrum().clone())
+    }
+
+    /// Annotate the spectrum with the given peptide
+    ///
+    /// Parameters
+    /// ----------
+    /// peptide : CompoundPeptide
+    ///     The peptide to annotate the spectrum with.
+    /// model : FragmentationModel | CustomModel
+    ///     The model to use for the fragmentation.
+    /// mode : MassMode
+    ///    The mode to use for the mass.
+    ///
+    /// Returns
+    /// -------
+    /// AnnotatedSpectrum
+    ///     The annotated spectrum.
+    ///
+    #[pyo3(signature = (peptide, model, mode=&MassMode::Monoisotopic))]
+    fn annotate(
+        &self,
+        peptide: CompoundPeptidoform,
+        model: AnyFragmentationModel<'_>,
+        mode: &MassMode,
+    ) -> PyResult<AnnotatedSpectrum> {
+        let rusty_model = match_model(&model);
+        Ok(AnnotatedSpectrum(self.0.annotate(
+            peptide.0,
+            &rusty_model,
+            match mode {
+                MassMode::Monoisotopic => rustyms::MassMode::Monoisotopic,
+                MassMode::Average => rustyms::MassMode::Average,
+                MassMode::MostAbundant => rustyms::MassMode::MostAbundant,
+            },
+        )))
+    }
 }
 
 /// An annotated spectrum.
@@ -1613,6 +3043,403 @@ impl AnnotatedSpectrum {
     fn spectrum(&self) -> Vec<AnnotatedPeak> {
         self.0.clone().into_iter().map(AnnotatedPeak).collect()
     }
+
+    /// The completeness of the ladder of the given ion series, the fraction of the n−1 possible cleavage positions that is annotated.
+    ///
+    /// Parameters
+    /// ----------
+    /// series : str
+    ///     The ion series, one of a, b, c, d, v, w, x, y, or z.
+    ///
+    /// Returns
+    /// -------
+    /// float
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     If the ion series is not valid.
+    ///
+    fn series_completeness(&self, series: &str) -> PyResult<f64> {
+        use rustyms::fragment::FragmentKind;
+        let kind = match series {
+            "a" => FragmentKind::a,
+            "b" => FragmentKind::b,
+            "c" => FragmentKind::c,
+            "d" => FragmentKind::d,
+            "v" => FragmentKind::v,
+            "w" => FragmentKind::w,
+            "x" => FragmentKind::x,
+            "y" => FragmentKind::y,
+            "z" => FragmentKind::z,
+            _ => return Err(PyValueError::new_err("Invalid ion series")),
+        };
+        Ok(self.0.series_completeness(kind))
+    }
+
+    /// Render the peptide sequence with markers for the observed b (`┐` above) and y (`└` below)
+    /// ion cleavages, for quick textual QC.
+    ///
+    /// Returns
+    /// -------
+    /// str
+    ///
+    fn coverage_string(&self) -> String {
+        self.0.coverage_string()
+    }
+
+    /// A single score blending the fraction of annotated intensity and the fraction of annotated
+    /// peaks. The theoretical fragments are generated with the given model up to the charge of
+    /// the spectrum (or 1 if this is unknown).
+    ///
+    /// Parameters
+    /// ----------
+    /// model : FragmentationModel | CustomModel
+    ///     The model to use for the fragmentation.
+    /// intensity_weight : float
+    ///     The weight of the intensity fraction, 1.0 gives the intensity fraction and 0.0 gives the peaks fraction.
+    /// mode : MassMode
+    ///    The mode to use for the mass.
+    ///
+    /// Returns
+    /// -------
+    /// float
+    ///
+    #[pyo3(signature = (model, intensity_weight=1.0, mode=&MassMode::Monoisotopic))]
+    fn combined_score(
+        &self,
+        model: AnyFragmentationModel<'_>,
+        intensity_weight: f64,
+        mode: &MassMode,
+    ) -> PyResult<f64> {
+        let rusty_model = match_model(&model);
+        let mode = match mode {
+            MassMode::Monoisotopic => rustyms::MassMode::Monoisotopic,
+            MassMode::Average => rustyms::MassMode::Average,
+            MassMode::MostAbundant => rustyms::MassMode::MostAbundant,
+        };
+        let fragments = self.0.peptide.generate_theoretical_fragments(
+            self.0
+                .charge
+                .unwrap_or_else(|| rustyms::system::usize::Charge::new::<rustyms::system::e>(1)),
+            &rusty_model,
+        );
+        Ok(self
+            .0
+            .scores(&fragments, &rusty_model, mode)
+            .0
+            .combined(intensity_weight))
+    }
+
+    /// A compact quality control summary of this annotation. The theoretical fragments are
+    /// generated with the given model up to the charge of the spectrum (or 1 if this is unknown).
+    ///
+    /// The returned dict contains `matched_peaks` (the number of annotated peaks), `total_peaks`,
+    /// `annotated_tic_fraction` (the fraction of the total ion current that is annotated),
+    /// `series_coverage` (a dict with the ladder completeness for every ion series), and
+    /// `precursor_mass_error_ppm` (the signed precursor mass error, or None if the precursor
+    /// mass is unknown).
+    ///
+    /// Parameters
+    /// ----------
+    /// model : FragmentationModel | CustomModel
+    ///     The model to use for the fragmentation (default: FragmentationModel.All).
+    /// mode : MassMode
+    ///    The mode to use for the mass (default: MassMode.Monoisotopic).
+    ///
+    /// Returns
+    /// -------
+    /// dict
+    ///
+    #[pyo3(signature = (model=None, mode=&MassMode::Monoisotopic))]
+    fn summary<'py>(
+        &self,
+        py: Python<'py>,
+        model: Option<AnyFragmentationModel<'_>>,
+        mode: &MassMode,
+    ) -> PyResult<Bound<'py, PyDict>> {
+        let rusty_model = model.map_or_else(rustyms::Model::all, |model| match_model(&model));
+        let mode = match mode {
+            MassMode::Monoisotopic => rustyms::MassMode::Monoisotopic,
+            MassMode::Average => rustyms::MassMode::Average,
+            MassMode::MostAbundant => rustyms::MassMode::MostAbundant,
+        };
+        let fragments = self.0.peptide.generate_theoretical_fragments(
+            self.0
+                .charge
+                .unwrap_or_else(|| rustyms::system::usize::Charge::new::<rustyms::system::e>(1)),
+            &rusty_model,
+        );
+        let summary = self.0.summary(&fragments, &rusty_model, mode);
+        let dict = PyDict::new(py);
+        dict.set_item("matched_peaks", summary.matched_peaks.found)?;
+        dict.set_item("total_peaks", summary.matched_peaks.total)?;
+        dict.set_item("annotated_tic_fraction", summary.intensity.fraction())?;
+        dict.set_item(
+            "series_coverage",
+            summary
+                .series_coverage
+                .iter()
+                .map(|(kind, coverage)| (kind.to_string(), *coverage))
+                .collect::<HashMap<_, _>>(),
+        )?;
+        dict.set_item(
+            "precursor_mass_error_ppm",
+            summary
+                .precursor_mass_error
+                .map(|e| e.get::<rustyms::system::ratio::ppm>()),
+        )?;
+        Ok(dict)
+    }
+
+    /// Compare the annotations of this spectrum with the annotations of another annotated
+    /// spectrum of the same underlying spectrum, for example to compare two fragmentation models.
+    ///
+    /// Parameters
+    /// ----------
+    /// other : AnnotatedSpectrum
+    ///     The other annotated spectrum.
+    ///
+    /// Returns
+    /// -------
+    /// AnnotationDiff
+    ///
+    fn diff(&self, other: &Self) -> AnnotationDiff {
+        AnnotationDiff(self.0.diff(&other.0))
+    }
+}
+
+/// The differences between the annotations of two annotated spectra.
+#[pyclass]
+pub struct AnnotationDiff(rustyms::spectrum::AnnotationDiff);
+
+#[pymethods]
+impl AnnotationDiff {
+    fn __repr__(&self) -> String {
+        format!(
+            "AnnotationDiff(only_self={}, only_other={}, changed={})",
+            self.0.only_self.len(),
+            self.0.only_other.len(),
+            self.0.changed.len()
+        )
+    }
+
+    /// The peaks that are only annotated in the first spectrum.
+    ///
+    /// Returns
+    /// -------
+    /// list[AnnotatedPeak]
+    ///
+    #[getter]
+    fn only_self(&self) -> Vec<AnnotatedPeak> {
+        self.0
+            .only_self
+            .iter()
+            .cloned()
+            .map(AnnotatedPeak)
+            .collect()
+    }
+
+    /// The peaks that are only annotated in the second spectrum.
+    ///
+    /// Returns
+    /// -------
+    /// list[AnnotatedPeak]
+    ///
+    #[getter]
+    fn only_other(&self) -> Vec<AnnotatedPeak> {
+        self.0
+            .only_other
+            .iter()
+            .cloned()
+            .map(AnnotatedPeak)
+            .collect()
+    }
+
+    /// The peaks that are annotated in both spectra but with different annotations, as the peak from the first and the peak from the second spectrum.
+    ///
+    /// Returns
+    /// -------
+    /// list[tuple[AnnotatedPeak, AnnotatedPeak]]
+    ///
+    #[getter]
+    fn changed(&self) -> Vec<(AnnotatedPeak, AnnotatedPeak)> {
+        self.0
+            .changed
+            .iter()
+            .map(|(a, b)| (AnnotatedPeak(a.clone()), AnnotatedPeak(b.clone())))
+            .collect()
+    }
+
+    /// Check if both spectra have identical annotations.
+    ///
+    /// Returns
+    /// -------
+    /// bool
+    ///
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+/// Enumerate all glycan compositions that match the given mass within the tolerance.
+///
+/// Parameters
+/// ----------
+/// mass : float
+///     The mass (e.g. a mass delta on a peptide) in Da.
+/// tolerance : Tolerance
+///     The tolerance for matching the mass.
+/// bounds : str
+///     The monosaccharides to use with their maximal counts as a glycan composition, e.g. `"HexNAc4Hex9Fuc2NeuAc2"`.
+///
+/// Returns
+/// -------
+/// list[list[tuple[str, int]]]
+///     The compositions as monosaccharides with their counts, sorted on absolute mass error.
+///
+/// Raises
+/// ------
+/// ValueError
+///     If the bounds are not a valid glycan composition.
+///
+#[pyfunction]
+fn glycan_compositions_within(
+    mass: f64,
+    tolerance: Tolerance,
+    bounds: &str,
+) -> PyResult<Vec<Vec<(String, u16)>>> {
+    let composition = rustyms::glycan::MonoSaccharide::from_composition(bounds)
+        .map_err(|e| PyValueError::new_err(format!("Invalid glycan composition: {}", e)))?;
+    let (monosaccharides, bounds): (Vec<_>, Vec<_>) = composition
+        .into_iter()
+        .map(|(sugar, count)| (sugar, u16::try_from(count).unwrap_or_default()))
+        .unzip();
+    Ok(rustyms::glycan::compositions_within(
+        rustyms::system::da(mass),
+        tolerance.0,
+        &monosaccharides,
+        &bounds,
+    )
+    .into_iter()
+    .map(|composition| {
+        composition
+            .into_iter()
+            .map(|(sugar, count)| (sugar.to_string(), count))
+            .collect()
+    })
+    .collect())
+}
+
+/// All known monosaccharides with their residue formula and monoisotopic residue mass.
+///
+/// Returns
+/// -------
+/// list[tuple[str, MolecularFormula, float]]
+///
+#[pyfunction]
+fn monosaccharide_table() -> Vec<(String, MolecularFormula, f64)> {
+    rustyms::glycan::monosaccharide_table()
+        .into_iter()
+        .map(|(sugar, formula, mass)| (sugar.to_string(), MolecularFormula(formula), mass.value))
+        .collect()
+}
+
+/// Find all isobaric sets with a mass within the given range. The masses are the monoisotopic
+/// masses of the residues, so without the terminal water.
+///
+/// Parameters
+/// ----------
+/// min_mass : float
+///     The minimal mass in Da (inclusive).
+/// max_mass : float
+///     The maximal mass in Da (inclusive).
+/// amino_acids : str | None
+///     The amino acids to build the sets from as one letter codes, e.g. `"ACDEFG"`. Defaults to all amino acids with a unique mass.
+/// fixed : list[SimpleModification]
+///     The fixed modifications, placed on any location allowed by their placement rules.
+/// variable : list[SimpleModification]
+///     The variable modifications, placed on any location allowed by their placement rules.
+///
+/// Returns
+/// -------
+/// list[LinearPeptide]
+///
+/// Raises
+/// ------
+/// ValueError
+///     If any of the amino acids is invalid or if the minimal mass is not positive.
+///
+#[pyfunction]
+#[pyo3(signature = (min_mass, max_mass, amino_acids=None, fixed=Vec::new(), variable=Vec::new()))]
+fn find_isobaric_sets_in_range(
+    min_mass: f64,
+    max_mass: f64,
+    amino_acids: Option<&str>,
+    fixed: Vec<SimpleModification>,
+    variable: Vec<SimpleModification>,
+) -> PyResult<Vec<LinearPeptide>> {
+    if min_mass <= 0.0 {
+        return Err(PyValueError::new_err("The minimal mass has to be positive"));
+    }
+    let amino_acids = amino_acids.map_or_else(
+        || Ok(rustyms::AminoAcid::UNIQUE_MASS_AMINO_ACIDS.to_vec()),
+        |aas| {
+            aas.chars()
+                .map(|c| {
+                    rustyms::AminoAcid::try_from(c)
+                        .map_err(|()| PyValueError::new_err(format!("Invalid amino acid: {c}")))
+                })
+                .collect::<PyResult<Vec<_>>>()
+        },
+    )?;
+    let fixed = fixed.into_iter().map(|m| (m.0, None)).collect::<Vec<_>>();
+    let variable = variable
+        .into_iter()
+        .map(|m| (m.0, None))
+        .collect::<Vec<_>>();
+    Ok(rustyms::find_isobaric_sets_in_range(
+        rustyms::system::da(min_mass),
+        rustyms::system::da(max_mass),
+        &amino_acids,
+        &fixed,
+        &variable,
+        None,
+    )
+    .map(|p| LinearPeptide(p.into()))
+    .collect())
+}
+
+/// Group fragments by the backbone bond that was broken to generate them, for use in ladder plots.
+/// A bond is identified by the residue on its N terminal side, so N terminal ions (a, b, c, d) are
+/// found at their own sequence index while C terminal ions (v, w, x, y, z) are found one position
+/// N terminal of their sequence index. Complementary ions, like b2 and y(n-2), are therefore
+/// grouped together at index 1. Fragments that do not result from a single backbone cleavage
+/// (precursor, immonium, glycan, internal, and diagnostic ions among others) are not included.
+///
+/// Parameters
+/// ----------
+/// fragments : list[Fragment]
+///     The fragments to group.
+///
+/// Returns
+/// -------
+/// dict[SequencePosition, list[Fragment]]
+///
+#[pyfunction]
+fn group_fragments_by_position(
+    fragments: Vec<PyRef<Fragment>>,
+) -> BTreeMap<SequencePosition, Vec<Fragment>> {
+    let fragments = fragments.iter().map(|f| f.0.clone()).collect::<Vec<_>>();
+    rustyms::group_fragments_by_position(&fragments)
+        .into_iter()
+        .map(|(position, fragments)| {
+            (
+                SequencePosition(position),
+                fragments.into_iter().map(Fragment).collect(),
+            )
+        })
+        .collect()
 }
 
 /// Python bindings to the rustyms library.
@@ -1622,12 +3449,18 @@ fn rustyms_py03(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<AminoAcid>()?;
     m.add_class::<AnnotatedPeak>()?;
     m.add_class::<AnnotatedSpectrum>()?;
+    m.add_class::<AnnotationContext>()?;
+    m.add_class::<AnnotationDiff>()?;
+    m.add_class::<CTermChemistry>()?;
     m.add_class::<CompoundPeptidoform>()?;
     m.add_class::<CustomError>()?;
+    m.add_class::<DecoyMethod>()?;
     m.add_class::<Element>()?;
     m.add_class::<Fragment>()?;
     m.add_class::<FragmentationModel>()?;
+    m.add_class::<CustomModel>()?;
     m.add_class::<FragmentType>()?;
+    m.add_class::<GlycanStructure>()?;
     m.add_class::<LinearPeptide>()?;
     m.add_class::<MassMode>()?;
     m.add_class::<Modification>()?;
@@ -1637,7 +3470,14 @@ fn rustyms_py03(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<RawPeak>()?;
     m.add_class::<RawSpectrum>()?;
     m.add_class::<SequenceElement>()?;
+    m.add_class::<SequencePosition>()?;
     m.add_class::<SimpleModification>()?;
+    m.add_class::<TerminusStatus>()?;
+    m.add_class::<Tolerance>()?;
+    m.add_function(wrap_pyfunction!(find_isobaric_sets_in_range, m)?)?;
+    m.add_function(wrap_pyfunction!(glycan_compositions_within, m)?)?;
+    m.add_function(wrap_pyfunction!(group_fragments_by_position, m)?)?;
+    m.add_function(wrap_pyfunction!(monosaccharide_table, m)?)?;
     Ok(())
 }
 
@@ -1654,6 +3494,47 @@ impl std::fmt::Display for CustomError {
     }
 }
 
+#[pymethods]
+impl CustomError {
+    /// The short description of the error.
+    ///
+    /// Returns
+    /// -------
+    /// str
+    ///
+    #[getter]
+    fn short_description(&self) -> String {
+        self.0.short_description().to_string()
+    }
+
+    /// The long description of the error.
+    ///
+    /// Returns
+    /// -------
+    /// str
+    ///
+    #[getter]
+    fn long_description(&self) -> String {
+        self.0.long_description().to_string()
+    }
+
+    /// Suggestions to fix the error, for example the closest matching names for an unknown
+    /// modification.
+    ///
+    /// Returns
+    /// -------
+    /// list[str]
+    ///
+    #[getter]
+    fn suggestions(&self) -> Vec<String> {
+        self.0.suggestions().to_vec()
+    }
+
+    fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+}
+
 impl std::convert::From<CustomError> for PyErr {
     fn from(value: CustomError) -> Self {
         PyValueError::new_err(value)
@@ -123,6 +123,7 @@ pub fn consecutive_align<const STEPS: u16, A: AtMax<SimpleLinear> + AtMax<Linear
                 chains: use_chains,
                 allele,
                 genes: Some([gene.0].into()),
+                functionality: None,
             }
             .germlines()
             .map(|seq| {
@@ -190,6 +191,7 @@ pub fn par_consecutive_align<
                 chains: use_chains,
                 allele,
                 genes: Some([gene.0].into()),
+                functionality: None,
             }
             .par_germlines()
             .map(|seq| {
@@ -0,0 +1,79 @@
+use crate::{peptidoform::AtMax, Peptidoform, SequenceElement};
+
+use super::Alignment;
+
+/// Build a per column majority consensus from a set of pairwise alignments that all share the
+/// same first sequence (the reference). For every residue in the reference the residue (including
+/// its modifications) that is most often aligned to it in the second sequences is chosen. If most
+/// alignments have a deletion at a position this position is left out of the consensus. Ties are
+/// resolved in favour of the reference residue, and otherwise in favour of the option seen first.
+/// The termini and charge carriers are kept from the reference.
+///
+/// This is intended as a rough consensus for small sets of closely related sequences (eg a
+/// germline family) and has the following limitations:
+/// * Insertions in the second sequences (relative to the reference) are never part of the consensus.
+/// * Steps that span more than one residue on either sequence (eg `N` aligned to `GG` in a mass
+///   based alignment) do not vote for the positions they cover.
+/// * Reference positions outside of the aligned region of an alignment (in local alignments) do
+///   not get a vote from that alignment, if a position gets no votes at all the reference residue
+///   is used.
+/// * The reference itself does not vote, except to break ties.
+///
+/// Returns `None` if no alignments are given, or if the alignments do not share the same reference.
+pub fn consensus<A, B: AtMax<A>>(alignments: &[Alignment<'_, A, B>]) -> Option<Peptidoform<A>> {
+    let reference = alignments.first()?.seq_a();
+    if alignments
+        .iter()
+        .any(|alignment| alignment.seq_a().sequence() != reference.sequence())
+    {
+        return None;
+    }
+
+    // For every reference position the options (`None` for a deletion) with their votes
+    let mut votes: Vec<Vec<(Option<SequenceElement<A>>, usize)>> =
+        vec![Vec::new(); reference.len()];
+    for alignment in alignments {
+        let mut index_a = alignment.start_a();
+        let mut index_b = alignment.start_b();
+        for piece in alignment.path() {
+            let vote = match (piece.step_a, piece.step_b) {
+                (1, 1) => Some(Some(alignment.seq_b()[index_b].clone().mark::<A>())),
+                (1, 0) => Some(None),
+                _ => None,
+            };
+            if let Some(vote) = vote {
+                let options = &mut votes[index_a];
+                if let Some(option) = options.iter_mut().find(|(option, _)| *option == vote) {
+                    option.1 += 1;
+                } else {
+                    options.push((vote, 1));
+                }
+            }
+            index_a += piece.step_a as usize;
+            index_b += piece.step_b as usize;
+        }
+    }
+
+    let mut result = reference.clone();
+    *result.sequence_mut() = votes
+        .into_iter()
+        .zip(reference.sequence())
+        .filter_map(|(options, reference_element)| {
+            options
+                .into_iter()
+                .enumerate()
+                .max_by_key(|(index, (option, count))| {
+                    (
+                        *count,
+                        option.as_ref() == Some(reference_element),
+                        std::cmp::Reverse(*index),
+                    )
+                })
+                .map_or_else(
+                    || Some(reference_element.clone()),
+                    |(_, (option, _))| option,
+                )
+        })
+        .collect();
+    Some(result)
+}
@@ -1,4 +1,8 @@
-use std::fmt::Debug;
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashSet},
+    fmt::Debug,
+};
 
 use crate::{
     peptidoform::{AtMax, SimpleLinear},
@@ -19,13 +23,142 @@ use super::{
 /// The [`AlignType`] controls the alignment behaviour, global/local or anything in between.
 /// # Panics
 /// It panics when the length of `seq_a` or `seq_b` is bigger than [`isize::MAX`].
-#[allow(clippy::too_many_lines)]
 pub fn align<'lifetime, const STEPS: u16, A: AtMax<SimpleLinear>, B: AtMax<SimpleLinear>>(
     seq_a: &'lifetime Peptidoform<A>,
     seq_b: &'lifetime Peptidoform<B>,
     scoring: AlignScoring<'lifetime>,
     align_type: AlignType,
 ) -> Alignment<'lifetime, A, B> {
+    let (matrix, global_highest) =
+        fill_matrix::<STEPS, A, B>(seq_a, seq_b, scoring, align_type, None);
+    let (start_a, start_b, path) = matrix.trace_path(align_type, global_highest);
+
+    Alignment {
+        seq_a: std::borrow::Cow::Borrowed(seq_a),
+        seq_b: std::borrow::Cow::Borrowed(seq_b),
+        score: determine_final_score(seq_a, seq_b, start_a, start_b, &path, scoring),
+        path,
+        start_a,
+        start_b,
+        align_type,
+        maximal_step: STEPS,
+    }
+}
+
+/// Create the `n` best alignments of two peptides, see [`align`] for the details of the
+/// alignment itself. Next to the best path all other paths through the alignment matrix are
+/// considered, and the best `n` distinct alignments are returned. The alignments are sorted by
+/// descending score and alignments with an identical path ([`Alignment::short`]) are only
+/// returned once. Fewer than `n` alignments are returned if not enough distinct paths exist.
+/// # Panics
+/// It panics when the length of `seq_a` or `seq_b` is bigger than [`isize::MAX`].
+pub fn align_n<'lifetime, const STEPS: u16, A: AtMax<SimpleLinear>, B: AtMax<SimpleLinear>>(
+    seq_a: &'lifetime Peptidoform<A>,
+    seq_b: &'lifetime Peptidoform<B>,
+    scoring: AlignScoring<'lifetime>,
+    align_type: AlignType,
+    n: usize,
+) -> Vec<Alignment<'lifetime, A, B>> {
+    let mut candidates = vec![vec![Vec::new(); seq_b.len() + 1]; seq_a.len() + 1];
+    let (matrix, _) =
+        fill_matrix::<STEPS, A, B>(seq_a, seq_b, scoring, align_type, Some(&mut candidates));
+
+    // Best first search from the end to the start of the matrix. The score stored in every cell
+    // is the best score for any path up to that cell, so the sum of the pieces already taken and
+    // the score of the current cell is the best possible score for any path through this state.
+    // This guarantees that the paths are completed in order of descending score.
+    let mut states: Vec<(Vec<Piece>, usize, usize)> = Vec::new();
+    let mut queue = BinaryHeap::new();
+    for (index_a, index_b) in matrix.end_cells(align_type) {
+        let score = matrix.value[index_a][index_b].score;
+        queue.push((score, Reverse(states.len())));
+        states.push((Vec::new(), index_a, index_b));
+    }
+
+    let mut seen = HashSet::new();
+    let mut alignments = Vec::new();
+    while let Some((_, Reverse(state))) = queue.pop() {
+        if alignments.len() >= n {
+            break;
+        }
+        let (suffix, index_a, index_b) = std::mem::take(&mut states[state]);
+        let cell = &matrix.value[index_a][index_b];
+        if cell.step_a == 0 && cell.step_b == 0 {
+            if suffix.is_empty() {
+                continue;
+            }
+            // Reached the start, rebuild the scores along this path, the affine gap scores
+            // depend on the actual previous piece which is not necessarily the best one
+            let mut path: Vec<Piece> = Vec::with_capacity(suffix.len());
+            for mut piece in suffix.into_iter().rev() {
+                let previous = path.last();
+                if piece.match_type == MatchType::Gap {
+                    let is_gap_start = previous.map_or(true, |p| {
+                        !(p.step_a == 0 && piece.step_a == 0 || p.step_b == 0 && piece.step_b == 0)
+                    });
+                    piece.local_score = scoring.gap_extend as isize
+                        + scoring.gap_start as isize * isize::from(is_gap_start);
+                }
+                piece.score = previous.map_or(0, |p| p.score) + piece.local_score;
+                path.push(piece);
+            }
+            let alignment = Alignment {
+                seq_a: std::borrow::Cow::Borrowed(seq_a),
+                seq_b: std::borrow::Cow::Borrowed(seq_b),
+                score: determine_final_score(seq_a, seq_b, index_a, index_b, &path, scoring),
+                path,
+                start_a: index_a,
+                start_b: index_b,
+                align_type,
+                maximal_step: STEPS,
+            };
+            if seen.insert(
+                alignment
+                    .path
+                    .iter()
+                    .map(|p| (p.match_type, p.step_a, p.step_b))
+                    .collect::<Vec<_>>(),
+            ) {
+                alignments.push(alignment);
+            }
+            continue;
+        }
+        let suffix_score = suffix.iter().map(|p| p.local_score).sum::<isize>();
+        let options = if candidates[index_a][index_b].is_empty() {
+            std::slice::from_ref(cell)
+        } else {
+            candidates[index_a][index_b].as_slice()
+        };
+        for piece in options {
+            if !align_type.left.global() && piece.score <= 0 {
+                continue;
+            }
+            let mut new_suffix = suffix.clone();
+            new_suffix.push(piece.clone());
+            queue.push((suffix_score + piece.score, Reverse(states.len())));
+            states.push((
+                new_suffix,
+                index_a - piece.step_a as usize,
+                index_b - piece.step_b as usize,
+            ));
+        }
+    }
+    alignments.sort_by_key(|a| Reverse(a.score.absolute));
+    alignments
+}
+
+/// Fill the alignment matrix, if `candidates` is given all possible pieces for every cell are
+/// stored in there as well. Returns the matrix and the highest scoring cell.
+/// # Panics
+/// It panics when the length of `seq_a` or `seq_b` is bigger than [`isize::MAX`].
+#[allow(clippy::too_many_lines)]
+fn fill_matrix<const STEPS: u16, A: AtMax<SimpleLinear>, B: AtMax<SimpleLinear>>(
+    seq_a: &Peptidoform<A>,
+    seq_b: &Peptidoform<B>,
+    scoring: AlignScoring<'_>,
+    align_type: AlignType,
+    mut candidates: Option<&mut Vec<Vec<Vec<Piece>>>>,
+) -> (Matrix, (isize, usize, usize)) {
     assert!(isize::try_from(seq_a.len()).is_ok());
     assert!(isize::try_from(seq_b.len()).is_ok());
 
@@ -116,6 +249,9 @@ pub fn align<'lifetime, const STEPS: u16, A: AtMax<SimpleLinear>, B: AtMax<Simpl
                         )
                     };
                     if let Some(p) = piece {
+                        if let Some(candidates) = candidates.as_deref_mut() {
+                            candidates[index_a][index_b].push(p.clone());
+                        }
                         if highest.is_none()
                             || highest.as_ref().is_some_and(|h: &Piece| h.score < p.score)
                         {
@@ -151,18 +287,7 @@ pub fn align<'lifetime, const STEPS: u16, A: AtMax<SimpleLinear>, B: AtMax<Simpl
             }
         }
     }
-    let (start_a, start_b, path) = matrix.trace_path(align_type, global_highest);
-
-    Alignment {
-        seq_a: std::borrow::Cow::Borrowed(seq_a),
-        seq_b: std::borrow::Cow::Borrowed(seq_b),
-        score: determine_final_score(seq_a, seq_b, start_a, start_b, &path, scoring),
-        path,
-        start_a,
-        start_b,
-        align_type,
-        maximal_step: STEPS,
-    }
+    (matrix, global_highest)
 }
 
 pub(super) fn determine_final_score<A, B>(
@@ -209,10 +334,9 @@ pub(super) fn score_pair<A: AtMax<SimpleLinear>, B: AtMax<SimpleLinear>>(
     scoring: AlignScoring<'_>,
     score: isize,
 ) -> Piece {
-    match (
-        a.0.aminoacid.aminoacid() == b.0.aminoacid.aminoacid(),
-        scoring.tolerance.within(a.1, b.1),
-    ) {
+    let identical = a.0.aminoacid.aminoacid() == b.0.aminoacid.aminoacid()
+        && (!scoring.require_modification_identity || a.0.modifications == b.0.modifications);
+    match (identical, scoring.tolerance.within(a.1, b.1)) {
         (true, true) => {
             let local = scoring.matrix[a.0.aminoacid.aminoacid() as usize]
                 [b.0.aminoacid.aminoacid() as usize] as isize;
@@ -408,6 +532,21 @@ impl Matrix {
         (high.1, high.2, path.into_iter().rev().collect())
     }
 
+    /// All cells where an alignment of the given type could end
+    fn end_cells(&self, ty: AlignType) -> Vec<(usize, usize)> {
+        if ty.right.global_a() && ty.right.global_b() {
+            vec![(self.a, self.b)]
+        } else if ty.right.global_b() {
+            (0..=self.a).map(|v| (v, self.b)).collect()
+        } else if ty.right.global_a() {
+            (0..=self.b).map(|v| (self.a, v)).collect()
+        } else {
+            (0..=self.a)
+                .flat_map(|a| (0..=self.b).map(move |b| (a, b)))
+                .collect()
+        }
+    }
+
     fn find_end(&self, ty: AlignType, high: (isize, usize, usize)) -> (isize, usize, usize) {
         if ty.right.global_a() && ty.right.global_a() {
             (self.value[self.a][self.b].score, self.a, self.b)
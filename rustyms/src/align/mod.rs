@@ -29,6 +29,7 @@ mod align_type;
 mod alignment;
 #[cfg(test)]
 mod bad_alignments;
+mod consensus;
 mod diagonal_array;
 mod mass_alignment;
 mod multi_alignment;
@@ -44,7 +45,8 @@ pub use consecutive::*;
 
 pub use align_type::{AlignType, Side};
 pub use alignment::{Alignment, Score, Stats};
-pub use mass_alignment::align;
+pub use consensus::consensus;
+pub use mass_alignment::{align, align_n};
 pub use piece::Piece;
 pub use scoring::{AlignScoring, MatchType};
 
@@ -62,7 +64,7 @@ pub mod matrix {
 mod tests {
     use crate::{peptidoform::SimpleLinear, Peptidoform};
 
-    use super::{scoring::AlignScoring, AlignType, Alignment};
+    use super::{consensus, scoring::AlignScoring, AlignType, Alignment};
 
     fn align<'a, const STEPS: u16>(
         a: &'a Peptidoform<SimpleLinear>,
@@ -148,4 +150,24 @@ mod tests {
             c
         );
     }
+
+    #[test]
+    fn consensus_majority() {
+        let reference = linear("PEPTIDEK");
+        let others = ["PEPTIDEK", "PEPSIDEK", "PEPSLDEK", "PEPSIDEK", "PEPTLDEK"].map(linear);
+        let alignments = others
+            .iter()
+            .map(|other| align::<1>(&reference, other))
+            .collect::<Vec<_>>();
+        assert_eq!(consensus(&alignments), Some(linear("PEPSIDEK")));
+        // Ties are resolved in favour of the reference
+        assert_eq!(consensus(&alignments[..2]), Some(linear("PEPTIDEK")));
+        assert_eq!(consensus::<SimpleLinear, SimpleLinear>(&[]), None);
+        let other_reference = linear("PEPTIDER");
+        let mixed = vec![
+            align::<1>(&reference, &others[0]),
+            align::<1>(&other_reference, &others[0]),
+        ];
+        assert_eq!(consensus(&mixed), None);
+    }
 }
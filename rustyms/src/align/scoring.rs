@@ -78,6 +78,13 @@ pub struct AlignScoring<'a> {
     ///
     /// Default: Monoisotopic.
     pub mass_mode: MassMode,
+    /// Require the modifications to be identical for two identical amino acids to be scored as
+    /// an identity (or mass mismatch). If the modifications differ the step is scored as a
+    /// mismatch instead. This prevents modifications with an identical mass, or
+    /// a modification and its absence, from being scored as an identical residue.
+    ///
+    /// Default: false.
+    pub require_modification_identity: bool,
 }
 
 impl Default for AlignScoring<'static> {
@@ -93,6 +100,7 @@ impl Default for AlignScoring<'static> {
             matrix: matrices::BLOSUM62,
             tolerance: crate::Tolerance::new_ppm(10.0),
             mass_mode: MassMode::Monoisotopic,
+            require_modification_identity: false,
         }
     }
 }
@@ -1,7 +1,7 @@
 #![allow(clippy::missing_panics_doc)]
 
 use crate::{
-    align::{align, scoring::AlignScoring, AlignType, Alignment},
+    align::{align, align_n, scoring::AlignScoring, AlignType, Alignment},
     Peptidoform, SimpleLinear,
 };
 
@@ -118,6 +118,27 @@ fn normal_2() {
     );
 }
 
+#[test]
+fn modification_identity() {
+    test_alignment(
+        "PEPS[Phospho]IDE",
+        "PEPSIDE",
+        AlignScoring::default(),
+        AlignType::GLOBAL,
+        "3=1m3=",
+    );
+    test_alignment(
+        "PEPS[Phospho]IDE",
+        "PEPSIDE",
+        AlignScoring {
+            require_modification_identity: true,
+            ..Default::default()
+        },
+        AlignType::GLOBAL,
+        "3=1X3=",
+    );
+}
+
 #[test]
 fn overextended_rotation() {
     test_alignment(
@@ -154,6 +175,62 @@ fn no_detected_rotation_2() {
 /// Test if the given alignment is as expected and can be recreated
 /// # Errors
 /// When the alignment is not identical to path and when the alignment cannot be recreated from the path.
+#[test]
+fn top_n() {
+    const MAXIMAL_STEP: u16 = 4;
+    for (seq_a, seq_b, align_type) in [
+        ("ANA", "AGGA", AlignType::GLOBAL),
+        ("AAASSS", "ASSS", AlignType::GLOBAL_A),
+        ("HHHHHHAA", "AAHHHHHHH", AlignType::EITHER_GLOBAL),
+        ("WGGDGFYAMDY", "WGGDGFYAMDY", AlignType::LOCAL),
+    ] {
+        let first_peptide = Peptidoform::pro_forma(seq_a, None)
+            .unwrap()
+            .into_simple_linear()
+            .unwrap();
+        let second_peptide = Peptidoform::pro_forma(seq_b, None)
+            .unwrap()
+            .into_simple_linear()
+            .unwrap();
+        let scoring = AlignScoring::default();
+        let best = align::<MAXIMAL_STEP, SimpleLinear, SimpleLinear>(
+            &first_peptide,
+            &second_peptide,
+            scoring,
+            align_type,
+        );
+        let alignments = align_n::<MAXIMAL_STEP, SimpleLinear, SimpleLinear>(
+            &first_peptide,
+            &second_peptide,
+            scoring,
+            align_type,
+            5,
+        );
+        assert_eq!(alignments.len(), 5, "{seq_a} vs {seq_b}");
+        assert_eq!(alignments[0].short(), best.short(), "{seq_a} vs {seq_b}");
+        assert_eq!(alignments[0].score, best.score, "{seq_a} vs {seq_b}");
+        for window in alignments.windows(2) {
+            assert!(window[0].score.absolute >= window[1].score.absolute);
+            assert_ne!(window[0].short(), window[1].short());
+        }
+        for alignment in &alignments {
+            let recreated = Alignment::create_from_path(
+                &first_peptide,
+                &second_peptide,
+                alignment.start_a,
+                alignment.start_b,
+                &alignment.short(),
+                scoring,
+                align_type,
+                MAXIMAL_STEP,
+            )
+            .unwrap();
+            assert_eq!(recreated.path, alignment.path, "{}", alignment.short());
+            assert_eq!(&recreated, alignment);
+        }
+    }
+}
+
 fn test_alignment(
     seq_a: &str,
     seq_b: &str,
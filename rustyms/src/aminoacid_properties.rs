@@ -208,6 +208,97 @@ impl crate::AminoAcid {
     }
 }
 
+impl crate::AminoAcid {
+    /// The hydrophobicity of this amino acid on the given scale. For the ambiguous amino acids
+    /// (B, J, Z) the average of the options is given, selenocysteine uses the value for cysteine,
+    /// pyrrolysine the value for lysine, and unknown (X) is given as 0.0.
+    pub fn hydrophobicity(self, scale: HydrophobicityScale) -> f64 {
+        match self {
+            Self::AmbiguousAsparagine => {
+                (Self::Asparagine.hydrophobicity(scale) + Self::AsparticAcid.hydrophobicity(scale))
+                    / 2.0
+            }
+            Self::AmbiguousLeucine => {
+                (Self::Leucine.hydrophobicity(scale) + Self::Isoleucine.hydrophobicity(scale)) / 2.0
+            }
+            Self::AmbiguousGlutamine => {
+                (Self::Glutamine.hydrophobicity(scale) + Self::GlutamicAcid.hydrophobicity(scale))
+                    / 2.0
+            }
+            Self::Selenocysteine => Self::Cysteine.hydrophobicity(scale),
+            Self::Pyrrolysine => Self::Lysine.hydrophobicity(scale),
+            Self::Unknown => 0.0,
+            _ => {
+                let (kyte_doolittle, hopp_woods, eisenberg) = match self {
+                    Self::Alanine => (1.8, -0.5, 0.62),
+                    Self::Arginine => (-4.5, 3.0, -2.53),
+                    Self::Asparagine => (-3.5, 0.2, -0.78),
+                    Self::AsparticAcid => (-3.5, 3.0, -0.90),
+                    Self::Cysteine => (2.5, -1.0, 0.29),
+                    Self::Glutamine => (-3.5, 0.2, -0.85),
+                    Self::GlutamicAcid => (-3.5, 3.0, -0.74),
+                    Self::Glycine => (-0.4, 0.0, 0.48),
+                    Self::Histidine => (-3.2, -0.5, -0.40),
+                    Self::Isoleucine => (4.5, -1.8, 1.38),
+                    Self::Leucine => (3.8, -1.8, 1.06),
+                    Self::Lysine => (-3.9, 3.0, -1.50),
+                    Self::Methionine => (1.9, -1.3, 0.64),
+                    Self::Phenylalanine => (2.8, -2.5, 1.19),
+                    Self::Proline => (-1.6, 0.0, 0.12),
+                    Self::Serine => (-0.8, 0.3, -0.18),
+                    Self::Threonine => (-0.7, -0.4, -0.05),
+                    Self::Tryptophan => (-0.9, -3.4, 0.81),
+                    Self::Tyrosine => (-1.3, -2.3, 0.26),
+                    _ => (4.2, -1.5, 1.08), // Valine, all others are handled above
+                };
+                match scale {
+                    HydrophobicityScale::KyteDoolittle => kyte_doolittle,
+                    HydrophobicityScale::HoppWoods => hopp_woods,
+                    HydrophobicityScale::Eisenberg => eisenberg,
+                }
+            }
+        }
+    }
+
+    /// The pKa of the ionisable side chain of this amino acid, with a flag indicating if the
+    /// side chain is positively charged when protonated (basic) or neutral when protonated
+    /// (acidic). Returns None if the side chain is not ionisable or unknown. The values are taken
+    /// from EMBOSS.
+    pub const fn side_chain_pka(self) -> Option<(f64, bool)> {
+        match self {
+            Self::Cysteine => Some((8.5, false)),
+            Self::Selenocysteine => Some((5.2, false)),
+            Self::AsparticAcid => Some((3.9, false)),
+            Self::GlutamicAcid => Some((4.1, false)),
+            Self::Tyrosine => Some((10.1, false)),
+            Self::Histidine => Some((6.5, true)),
+            Self::Lysine => Some((10.8, true)),
+            Self::Arginine => Some((12.5, true)),
+            _ => None,
+        }
+    }
+}
+
+/// The pKa of the free N terminal amine of a peptide, from EMBOSS.
+pub const N_TERMINAL_PKA: f64 = 8.6;
+/// The pKa of the free C terminal carboxyl of a peptide, from EMBOSS.
+pub const C_TERMINAL_PKA: f64 = 3.6;
+
+/// A hydrophobicity scale, higher values are more hydrophobic for all scales except for Hopp-Woods
+/// which is a hydrophilicity scale.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Serialize, Deserialize,
+)]
+pub enum HydrophobicityScale {
+    /// Kyte & Doolittle (1982) <https://doi.org/10.1016/0022-2836(82)90515-0>
+    #[default]
+    KyteDoolittle,
+    /// Hopp & Woods (1981) <https://doi.org/10.1073/pnas.78.6.3824>
+    HoppWoods,
+    /// Eisenberg consensus scale (1984) <https://doi.org/10.1016/0022-2836(84)90309-7>
+    Eisenberg,
+}
+
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Serialize, Deserialize)]
 pub enum PhysiochemicalClass {
     Aliphatic,
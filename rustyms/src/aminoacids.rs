@@ -1,11 +1,16 @@
+use std::sync::Arc;
+
 use serde::{Deserialize, Serialize};
 
 use crate::{
+    error::{Context, CustomError},
     formula::MolecularFormula,
     fragment::{Fragment, FragmentType, PeptidePosition},
     model::*,
+    modification::{ModificationId, Ontology, SimpleModification, SimpleModificationInner},
     molecular_charge::CachedCharge,
-    Multi, MultiChemical, NeutralLoss, SequencePosition,
+    ontologies::CustomDatabase,
+    Chemical, Multi, MultiChemical, NeutralLoss, SequencePosition,
 };
 
 include!("shared/aminoacid.rs");
@@ -548,8 +553,124 @@ impl AminoAcid {
             _ => false,
         }
     }
+
+    /// Register a custom (non-standard) residue with the given symbol and residue formula (the
+    /// formula of the amino acid when part of a peptide chain, so without the water) in the given
+    /// custom database. ProForma parsing with this custom database then accepts this symbol, which
+    /// is stored as an unknown amino acid (X) with the custom residue as modification, so all
+    /// formula and fragment calculations take it into account. When displaying a peptidoform such
+    /// a residue is written with its custom symbol again. Registering an already registered symbol
+    /// replaces its formula. Selenocysteine (U) and pyrrolysine (O) are supported natively and do
+    /// not need to be registered.
+    ///
+    /// # Errors
+    /// If the symbol is already in use for a standard amino acid, or if it is not an ASCII
+    /// punctuation character that has no meaning in ProForma (for example `*`, `$`, `%`, or `~`).
+    pub fn register_custom(
+        symbol: char,
+        formula: MolecularFormula,
+        custom_database: &mut CustomDatabase,
+    ) -> Result<(), CustomError> {
+        if Self::try_from(symbol).is_ok() {
+            return Err(CustomError::error(
+                "Invalid custom amino acid",
+                format!("The symbol '{symbol}' is already used for a standard amino acid"),
+                Context::none(),
+            ));
+        }
+        if !Self::is_custom_symbol(symbol) {
+            return Err(CustomError::error(
+                "Invalid custom amino acid",
+                format!(
+                    "The symbol '{symbol}' cannot be used, use an ASCII punctuation character without a meaning in ProForma"
+                ),
+                Context::none(),
+            ));
+        }
+        let modification = Arc::new(SimpleModificationInner::Database {
+            specificities: Vec::new(),
+            formula,
+            id: ModificationId {
+                ontology: Ontology::Custom,
+                name: symbol.to_string(),
+                ..ModificationId::default()
+            },
+        });
+        if let Some(existing) = custom_database
+            .iter_mut()
+            .find(|(_, _, m)| Self::custom_symbol(m) == Some(symbol))
+        {
+            existing.2 = modification;
+        } else {
+            custom_database.push((None, symbol.to_string(), modification));
+        }
+        Ok(())
+    }
+
+    /// Get the custom residue with the given symbol from the custom database, see
+    /// [`Self::register_custom`].
+    pub fn custom(
+        symbol: char,
+        custom_database: Option<&CustomDatabase>,
+    ) -> Option<SimpleModification> {
+        custom_database?
+            .iter()
+            .find(|(_, _, m)| Self::custom_symbol(m) == Some(symbol))
+            .map(|(_, _, m)| m.clone())
+    }
+
+    /// Get the symbol if the given modification is a custom residue, see [`Self::register_custom`].
+    pub(crate) fn custom_symbol(modification: &SimpleModification) -> Option<char> {
+        match &**modification {
+            SimpleModificationInner::Database {
+                specificities,
+                id:
+                    ModificationId {
+                        ontology: Ontology::Custom,
+                        name,
+                        ..
+                    },
+                ..
+            } if specificities.is_empty() => {
+                let mut chars = name.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(symbol), None)
+                        if Self::try_from(symbol).is_err() && Self::is_custom_symbol(symbol) =>
+                    {
+                        Some(symbol)
+                    }
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Remove a custom residue from the custom database, returning its residue formula if it was
+    /// registered, see [`Self::register_custom`].
+    pub fn unregister_custom(
+        symbol: char,
+        custom_database: &mut CustomDatabase,
+    ) -> Option<MolecularFormula> {
+        let index = custom_database
+            .iter()
+            .position(|(_, _, m)| Self::custom_symbol(m) == Some(symbol))?;
+        Some(custom_database.remove(index).2.formula())
+    }
+
+    /// Check if this symbol could be used for a custom residue, any ASCII punctuation character
+    /// without a meaning in ProForma
+    fn is_custom_symbol(symbol: char) -> bool {
+        symbol.is_ascii_punctuation() && !PRO_FORMA_RESERVED.contains(&symbol)
+    }
 }
 
+/// All characters with a meaning in ProForma sequences, these cannot be used for custom amino acids
+const PRO_FORMA_RESERVED: &[char] = &[
+    '[', ']', '{', '}', '(', ')', '<', '>', '-', '+', '/', ':', '@', '^', '?', '#', ',', '.', '|',
+    '=', '\'', '"', '!', '&', ';', '\\',
+];
+
 impl std::fmt::Display for AminoAcid {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.char())
@@ -640,4 +761,79 @@ mod tests {
         assert_eq!(AminoAcid::try_from('c'), Ok(AminoAcid::Cysteine));
         assert_eq!(AminoAcid::try_from('🦀'), Err(()));
     }
+
+    #[test]
+    fn custom_residue() {
+        use crate::{system::e, system::usize::Charge, Peptidoform};
+
+        // Hydroxyproline
+        let mut database = Vec::new();
+        AminoAcid::register_custom('$', molecular_formula!(C 5 H 7 N 1 O 2), &mut database)
+            .unwrap();
+        assert_eq!(
+            AminoAcid::custom('$', Some(&database)).map(|m| m.formula()),
+            Some(molecular_formula!(C 5 H 7 N 1 O 2))
+        );
+        assert_eq!(AminoAcid::custom('$', None), None);
+        assert!(
+            AminoAcid::register_custom('P', MolecularFormula::default(), &mut database).is_err()
+        );
+        assert!(
+            AminoAcid::register_custom('[', MolecularFormula::default(), &mut database).is_err()
+        );
+        assert!(
+            AminoAcid::register_custom('7', MolecularFormula::default(), &mut database).is_err()
+        );
+
+        let custom = Peptidoform::pro_forma("GP$GPP", Some(&database))
+            .unwrap()
+            .into_linear()
+            .unwrap();
+        let explicit = Peptidoform::pro_forma("GPX[Formula:C5H7N1O2]GPP", None)
+            .unwrap()
+            .into_linear()
+            .unwrap();
+        let proline = Peptidoform::pro_forma("GPPGPP", None)
+            .unwrap()
+            .into_linear()
+            .unwrap();
+        assert_eq!(custom.to_string(), "GP$GPP");
+        assert_eq!(
+            Peptidoform::pro_forma(&custom.to_string(), Some(&database))
+                .unwrap()
+                .into_linear()
+                .unwrap(),
+            custom
+        );
+        assert_eq!(custom.formulas(), explicit.formulas());
+        assert_eq!(
+            custom.formulas(),
+            proline.formulas() + molecular_formula!(O 1)
+        );
+        let model = Model::all();
+        let fragments = custom
+            .generate_theoretical_fragments(Charge::new::<e>(1), &model)
+            .into_iter()
+            .filter_map(|f| f.formula)
+            .collect::<Vec<_>>();
+        assert!(!fragments.is_empty());
+        assert_eq!(
+            fragments,
+            explicit
+                .generate_theoretical_fragments(Charge::new::<e>(1), &model)
+                .into_iter()
+                .filter_map(|f| f.formula)
+                .collect::<Vec<_>>()
+        );
+        assert!(Peptidoform::pro_forma("GP$GPP", None).is_err());
+        assert!(Peptidoform::pro_forma("GP%GPP", Some(&database)).is_err());
+
+        assert_eq!(
+            AminoAcid::unregister_custom('$', &mut database),
+            Some(molecular_formula!(C 5 H 7 N 1 O 2))
+        );
+        assert_eq!(AminoAcid::custom('$', Some(&database)), None);
+        assert_eq!(AminoAcid::unregister_custom('$', &mut database), None);
+        assert!(Peptidoform::pro_forma("GP$GPP", Some(&database)).is_err());
+    }
 }
@@ -1,11 +1,71 @@
 use std::num::NonZeroU16;
 use std::sync::OnceLock;
 
-use crate::system::{da, fraction, Ratio};
+use crate::{
+    error::{Context, CustomError},
+    helper_functions::explain_number_error,
+    system::{da, fraction, Ratio},
+};
 
 include!("shared/element.rs");
 
 impl Element {
+    /// Parse an element with an optional leading isotope number, eg `C`, `13C`, or `2H`. The
+    /// symbols `D` and `T` are accepted as shorthand for deuterium (`2H`) and tritium (`3H`).
+    /// Surrounding whitespace is ignored.
+    /// # Errors
+    /// If the symbol is not a valid element, if the isotope number is not a valid number, or if
+    /// the isotope does not have a defined mass.
+    pub fn parse_with_isotope(value: &str) -> Result<(Self, Option<NonZeroU16>), CustomError> {
+        let offset = value.len() - value.trim_start().len();
+        let trimmed = value.trim();
+        let split = trimmed
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(trimmed.len());
+        let (number, symbol) = trimmed.split_at(split);
+        let (element, isotope) = match symbol {
+            "D" | "T" if !number.is_empty() => {
+                return Err(CustomError::error(
+                    "Invalid isotope",
+                    "The deuterium and tritium shorthands cannot have an isotope number",
+                    Context::line(None, value, offset, trimmed.len()),
+                ))
+            }
+            "D" => (Self::H, NonZeroU16::new(2)),
+            "T" => (Self::H, NonZeroU16::new(3)),
+            _ => {
+                let element = Self::try_from(symbol).map_err(|()| {
+                    CustomError::error(
+                        "Invalid element",
+                        "This is not a valid element symbol",
+                        Context::line(None, value, offset + split, symbol.len()),
+                    )
+                })?;
+                let isotope = if number.is_empty() {
+                    None
+                } else {
+                    Some(number.parse::<NonZeroU16>().map_err(|err| {
+                        CustomError::error(
+                            "Invalid isotope",
+                            format!("The isotope number {}", explain_number_error(&err)),
+                            Context::line(None, value, offset, split),
+                        )
+                    })?)
+                };
+                (element, isotope)
+            }
+        };
+        if element.is_valid(isotope) {
+            Ok((element, isotope))
+        } else {
+            Err(CustomError::error(
+                "Invalid isotope",
+                format!("The isotope {trimmed} does not have a defined mass"),
+                Context::line(None, value, offset, trimmed.len()),
+            ))
+        }
+    }
+
     /// Validate this isotope to have a defined mass
     pub fn is_valid(self, isotope: Option<NonZeroU16>) -> bool {
         if self == Self::Electron {
@@ -104,6 +164,10 @@ static ELEMENTAL_DATA_CELL: OnceLock<ElementalData> = OnceLock::new();
 #[cfg(test)]
 #[allow(clippy::missing_panics_doc)]
 mod test {
+    use std::num::NonZeroU16;
+
+    use crate::Element;
+
     #[test]
     fn hill_notation() {
         assert_eq!(
@@ -111,4 +175,35 @@ mod test {
             "C6H10O5".to_string()
         );
     }
+
+    #[test]
+    fn parse_with_isotope() {
+        assert_eq!(Element::parse_with_isotope("C"), Ok((Element::C, None)));
+        assert_eq!(
+            Element::parse_with_isotope("13C"),
+            Ok((Element::C, NonZeroU16::new(13)))
+        );
+        assert_eq!(
+            Element::parse_with_isotope(" 2H "),
+            Ok((Element::H, NonZeroU16::new(2)))
+        );
+        assert_eq!(
+            Element::parse_with_isotope("D"),
+            Ok((Element::H, NonZeroU16::new(2)))
+        );
+        assert_eq!(
+            Element::parse_with_isotope("T"),
+            Ok((Element::H, NonZeroU16::new(3)))
+        );
+        assert_eq!(
+            Element::parse_with_isotope("18O"),
+            Ok((Element::O, NonZeroU16::new(18)))
+        );
+        assert!(Element::parse_with_isotope("2D").is_err());
+        assert!(Element::parse_with_isotope("0C").is_err());
+        assert!(Element::parse_with_isotope("200C").is_err());
+        assert!(Element::parse_with_isotope("13").is_err());
+        assert!(Element::parse_with_isotope("13Xx").is_err());
+        assert!(Element::parse_with_isotope("").is_err());
+    }
 }
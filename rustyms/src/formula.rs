@@ -1,6 +1,8 @@
 use crate::{
-    system::{da, fraction, Mass, OrderedMass, Ratio},
-    MassMode,
+    error::{Context, CustomError},
+    helper_functions::explain_number_error,
+    system::{da, fraction, Mass, MassOverCharge, OrderedMass, Ratio},
+    Element, MassMode, MolecularCharge,
 };
 use std::fmt::Write;
 
@@ -57,7 +59,8 @@ impl MolecularFormula {
             .iter()
             .enumerate()
             .max_by_key(|s| ordered_float::OrderedFloat(*s.1));
-        self.monoisotopic_mass() + da(max.map_or(0, |f| f.0) as f64)
+        self.monoisotopic_mass()
+            + da(max.map_or(0, |f| f.0) as f64 + f64::from(self.isotopic_distribution_start()))
     }
 
     /// Get the mass in the given mode
@@ -70,6 +73,15 @@ impl MolecularFormula {
         }
     }
 
+    /// Create a mass only formula for the neutral monoisotopic mass implied by the given m/z and
+    /// charge, assuming the charge is carried by protons. The mass of a proton (the mass of a
+    /// hydrogen minus the mass of an electron) is subtracted once for every charge.
+    pub fn from_mz_and_charge(mz: MassOverCharge, charge: crate::system::usize::Charge) -> Self {
+        let z = crate::system::f64::Charge::new::<crate::system::charge::e>(charge.value as f64);
+        let proton = MolecularCharge::proton(1).formula().monoisotopic_mass();
+        Self::with_additional_mass((mz * z - proton * charge.value as f64).value)
+    }
+
     /// Create a [Hill notation](https://en.wikipedia.org/wiki/Chemical_formula#Hill_system) from this collections of elements merged with the ProForma notation for specific isotopes
     pub fn hill_notation(&self) -> String {
         self.hill_notation_generic(|element, buffer| {
@@ -122,6 +134,79 @@ impl MolecularFormula {
             }
         })
     }
+
+    /// Create a compact canonical string for this formula, for example to be used as a key in a
+    /// cache. All elements are written in the internal (element, isotope) order with an explicit
+    /// count, isotopes are written as `[13C2]`, followed by the additional mass (if any) and the
+    /// charge tag (if any): `H12C6O6`, `H6C1[13C2]O1+0.5:z+1`. This string can be parsed back
+    /// into the exact same formula with [`Self::from_compact_string`]. The ambiguous labels are
+    /// not part of this string.
+    pub fn to_compact_string(&self) -> String {
+        let mut buffer = String::new();
+        for (element, isotope, number) in &self.elements {
+            if *element == Element::Electron {
+                continue;
+            }
+            if let Some(isotope) = isotope {
+                write!(&mut buffer, "[{isotope}{element}{number}]").unwrap();
+            } else {
+                write!(&mut buffer, "{element}{number}").unwrap();
+            }
+        }
+        if self.additional_mass != 0.0 {
+            write!(&mut buffer, "{:+}", self.additional_mass).unwrap();
+        }
+        if self.charge().value != 0 {
+            write!(&mut buffer, ":z{:+}", self.charge().value).unwrap();
+        }
+        buffer
+    }
+
+    /// Parse a compact string as created by [`Self::to_compact_string`].
+    /// # Errors
+    /// If the elements, additional mass, or charge are not valid.
+    pub fn from_compact_string(value: &str) -> Result<Self, CustomError> {
+        let (rest, charge) = match value.rsplit_once(":z") {
+            Some((rest, charge)) => (
+                rest,
+                charge.parse::<i32>().map_err(|err| {
+                    CustomError::error(
+                        "Invalid compact molecular formula",
+                        format!("The charge number is {}", explain_number_error(&err)),
+                        Context::line(None, value, rest.len() + 2, charge.len()),
+                    )
+                })?,
+            ),
+            None => (value, 0),
+        };
+        // The additional mass starts at the first sign that does not directly follow an element
+        let bytes = rest.as_bytes();
+        let mass_start = (0..bytes.len())
+            .find(|i| {
+                (bytes[*i] == b'+' || bytes[*i] == b'-')
+                    && (*i == 0 || bytes[*i - 1].is_ascii_digit() || bytes[*i - 1] == b']')
+            })
+            .unwrap_or(bytes.len());
+        let mut result = if mass_start == 0 {
+            Self::default()
+        } else {
+            Self::from_pro_forma(rest, ..mass_start, false, false, true)?
+        };
+        if mass_start < bytes.len() {
+            let mass = rest[mass_start..].parse::<f64>().map_err(|_| {
+                CustomError::error(
+                    "Invalid compact molecular formula",
+                    "The additional mass is not a valid number",
+                    Context::line(None, value, mass_start, rest.len() - mass_start),
+                )
+            })?;
+            result.add_mass(mass.into());
+        }
+        if charge != 0 {
+            let _ = result.add((Element::Electron, None, -charge));
+        }
+        Ok(result)
+    }
 }
 
 impl std::fmt::Display for AmbiguousLabel {
@@ -142,6 +227,7 @@ impl std::fmt::Display for AmbiguousLabel {
             Self::CrossLinkBroken(name, formula) => {
                 write!(f, "broken{name}@{}", formula.hill_notation())
             }
+            Self::NeutralLoss(loss) => write!(f, "loss{loss}"),
         }
     }
 }
@@ -189,8 +275,8 @@ impl std::fmt::Display for MolecularFormula {
 #[allow(clippy::missing_panics_doc)]
 mod tests {
     use crate::{
-        model::ChargeRange, molecular_formula, AminoAcid, Fragment, MolecularCharge,
-        MolecularFormula, MultiChemical,
+        model::ChargeRange, AminoAcid, Chemical, Fragment, MolecularCharge, MolecularFormula,
+        MultiChemical,
     };
 
     #[test]
@@ -275,6 +361,42 @@ mod tests {
         );
     }
 
+    #[test]
+    fn pro_forma_charged_species() {
+        let sodium = MolecularFormula::from_pro_forma("[Na+]", .., true, false, true).unwrap();
+        assert_eq!(sodium, molecular_formula!(Na 1 Electron -1));
+        assert_eq!(sodium.charge().value, 1);
+        let sulfate = MolecularFormula::from_pro_forma("[SO4-2]", .., true, false, true).unwrap();
+        assert_eq!(sulfate, molecular_formula!(S 1 O 4 Electron 2));
+        assert_eq!(sulfate.charge().value, -2);
+        assert_eq!(
+            MolecularFormula::from_pro_forma("H2O[Na+][13C1]", .., true, false, true).unwrap(),
+            molecular_formula!(H 2 O 1 Na 1 Electron -1 [13 C 1])
+        );
+        assert!(MolecularFormula::from_pro_forma("[Na+]", .., false, false, true).is_err());
+    }
+
+    #[test]
+    fn compact_string_round_trip() {
+        let formulas = [
+            MolecularFormula::default(),
+            molecular_formula!(C 6 H 12 O 6),
+            molecular_formula!(C 8 [13 C 2] H 6 [2 H 4] N -1 [15 N 1] Co 1 O 1),
+            molecular_formula!(Na 1 Electron -1),
+            molecular_formula!(S 1 O 4 Electron 2) + MolecularFormula::with_additional_mass(-0.1),
+            MolecularFormula::with_additional_mass(12.345_678_9),
+        ];
+        assert_eq!(formulas[1].to_compact_string(), "H12C6O6");
+        for formula in formulas {
+            let compact = formula.to_compact_string();
+            assert_eq!(
+                MolecularFormula::from_compact_string(&compact),
+                Ok(formula),
+                "{compact}"
+            );
+        }
+    }
+
     #[test]
     fn unimod() {
         assert_eq!(
@@ -357,4 +479,23 @@ mod tests {
         assert!(!all_fragments_labelled(&fragment_u));
         assert!(all_fragments_labelled(&fragment_ul));
     }
+
+    #[test]
+    fn from_mz_and_charge() {
+        let peptide = molecular_formula!(C 40 H 62 N 10 O 15);
+        for z in 1..=4 {
+            let charged = peptide.clone() + MolecularCharge::proton(z).formula();
+            let charge = crate::system::usize::Charge::new::<crate::system::e>(z as usize);
+            let mz = charged.monoisotopic_mass()
+                / crate::system::f64::Charge::new::<crate::system::e>(z as f64);
+            let formula = MolecularFormula::from_mz_and_charge(mz, charge);
+            assert!(formula.elements().is_empty());
+            assert!(
+                (formula.monoisotopic_mass() - peptide.monoisotopic_mass())
+                    .value
+                    .abs()
+                    < 1e-9
+            );
+        }
+    }
 }
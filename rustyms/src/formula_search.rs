@@ -0,0 +1,166 @@
+//! Search for molecular formulas that fit a given mass.
+
+use std::num::NonZeroU16;
+
+use crate::{
+    system::{da, Mass},
+    Element, MolecularFormula, Tolerance,
+};
+
+/// Find all molecular formulas that fit the given mass within the tolerance. The formulas are
+/// built from the given elements (with an optional isotope), where each element can occur from
+/// zero up to the given maximal number of times. See [`find_formulas_iter`] for the order of the
+/// results.
+/// # Panics
+/// If any of the given elements or isotopes does not have a defined mass.
+pub fn find_formulas(
+    mass: Mass,
+    tolerance: Tolerance<Mass>,
+    elements: &[(Element, Option<NonZeroU16>, i32)],
+) -> Vec<MolecularFormula> {
+    find_formulas_iter(mass, tolerance, elements).collect()
+}
+
+/// Lazily find all molecular formulas that fit the given mass within the tolerance, see
+/// [`find_formulas`]. The formulas are generated on demand, so the search can be stopped as soon
+/// as an acceptable formula is found. The results are ordered as if each element is a nested
+/// loop, with the first element as the outermost loop, and all numbers counting up from zero.
+/// # Panics
+/// If any of the given elements or isotopes does not have a defined mass.
+pub fn find_formulas_iter(
+    mass: Mass,
+    tolerance: Tolerance<Mass>,
+    elements: &[(Element, Option<NonZeroU16>, i32)],
+) -> impl Iterator<Item = MolecularFormula> {
+    FormulaSearchIterator::new(tolerance.bounds_for_experimental(mass), elements)
+}
+
+/// Iteratively generate molecular formulas within the given bounds.
+#[derive(Debug)]
+struct FormulaSearchIterator {
+    elements: Vec<(Element, Option<NonZeroU16>, i32, Mass)>,
+    bounds: (Mass, Mass),
+    state: Vec<i32>,
+    done: bool,
+}
+
+impl FormulaSearchIterator {
+    /// Create the iterator, any element with a maximal number below one is ignored.
+    /// # Panics
+    /// If any of the given elements or isotopes does not have a defined mass.
+    fn new(bounds: (Mass, Mass), elements: &[(Element, Option<NonZeroU16>, i32)]) -> Self {
+        let elements: Vec<_> = elements
+            .iter()
+            .filter(|(_, _, max)| *max > 0)
+            .map(|(element, isotope, max)| {
+                (
+                    *element,
+                    *isotope,
+                    *max,
+                    element
+                        .mass(*isotope)
+                        .expect("Element or isotope does not have a defined mass"),
+                )
+            })
+            .collect();
+        Self {
+            state: vec![0; elements.len()],
+            done: elements.is_empty(),
+            elements,
+            bounds,
+        }
+    }
+
+    /// The mass of the current state
+    fn mass(&self) -> Mass {
+        self.elements
+            .iter()
+            .zip(&self.state)
+            .map(|((_, _, _, mass), n)| *mass * f64::from(*n))
+            .fold(da(0.0), |acc, m| acc + m)
+    }
+
+    /// The formula of the current state
+    fn formula(&self) -> MolecularFormula {
+        let mut formula = MolecularFormula::default();
+        for ((element, isotope, _, _), n) in self.elements.iter().zip(&self.state) {
+            if *n > 0 {
+                let _ = formula.add((*element, *isotope, *n));
+            }
+        }
+        formula
+    }
+}
+
+impl Iterator for FormulaSearchIterator {
+    type Item = MolecularFormula;
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.done {
+            // Add one at the innermost level, if that is full or too heavy reset this level and
+            // add one at the level below, the masses are all positive so heavier states are never
+            // useful on the same level.
+            let mut level = self.state.len() - 1;
+            loop {
+                self.state[level] += 1;
+                if self.state[level] <= self.elements[level].2 && self.mass() <= self.bounds.1 {
+                    break;
+                }
+                self.state[level] = 0;
+                if level == 0 {
+                    self.done = true;
+                    return None;
+                }
+                level -= 1;
+            }
+            if self.mass() >= self.bounds.0 {
+                return Some(self.formula());
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::missing_panics_doc)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn water() {
+        let elements = [
+            (Element::C, None, 10),
+            (Element::H, None, 20),
+            (Element::O, None, 10),
+        ];
+        let water = molecular_formula!(H 2 O 1);
+        let found = find_formulas(
+            water.monoisotopic_mass(),
+            Tolerance::new_ppm(10.0),
+            &elements,
+        );
+        assert_eq!(found, vec![water]);
+    }
+
+    #[test]
+    fn lazy_order() {
+        let elements = [
+            (Element::C, None, 20),
+            (Element::H, None, 40),
+            (Element::N, None, 10),
+            (Element::O, None, 10),
+        ];
+        let mass = da(200.0);
+        let tolerance = Tolerance::new_absolute(da(0.5));
+        let all = find_formulas(mass, tolerance, &elements);
+        assert!(all.len() > 3);
+        assert!(all
+            .iter()
+            .all(|f| (f.monoisotopic_mass() - mass).value.abs() <= 0.5));
+        assert_eq!(
+            find_formulas_iter(mass, tolerance, &elements)
+                .take(3)
+                .collect::<Vec<_>>(),
+            all[..3]
+        );
+    }
+}
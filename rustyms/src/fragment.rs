@@ -2,6 +2,7 @@
 
 use std::{
     borrow::Cow,
+    collections::BTreeMap,
     fmt::{Debug, Display},
 };
 
@@ -43,6 +44,9 @@ pub struct Fragment {
     pub confidence: Option<OrderedFloat<f64>>,
     /// If this is an auxiliary fragment (from mzPAF)
     pub auxiliary: bool,
+    /// The relative weight of the ion series of this fragment if that is set up as a secondary
+    /// series in the model, see [`crate::model::PrimaryIonSeries::secondary`]
+    pub secondary_weight: Option<OrderedFloat<f64>>,
 }
 
 impl Fragment {
@@ -82,6 +86,7 @@ impl Fragment {
             deviation: None,
             confidence: None,
             auxiliary: false,
+            secondary_weight: None,
         }
     }
 
@@ -105,20 +110,35 @@ impl Fragment {
             .cartesian_product(theoretical_mass.iter())
             .cartesian_product(charge_carriers.range(charge_range))
             .cartesian_product(std::iter::once(None).chain(neutral_losses.iter().map(Some)))
-            .map(|(((term, mass), charge), loss)| Self {
-                formula: Some(
-                    term + mass
-                        + charge.formula_inner(SequencePosition::default(), peptidoform_index)
-                        + loss.unwrap_or(&NeutralLoss::Gain(MolecularFormula::default())),
-                ),
-                charge: Charge::new::<crate::system::e>(charge.charge().value.try_into().unwrap()),
-                ion: annotation.clone(),
-                peptidoform_ion_index: Some(peptidoform_ion_index),
-                peptidoform_index: Some(peptidoform_index),
-                neutral_loss: loss.map(|l| vec![l.clone()]).unwrap_or_default(),
-                deviation: None,
-                confidence: None,
-                auxiliary: false,
+            .map(|(((term, mass), charge), loss)| {
+                let formula = term
+                    + mass
+                    + charge.formula_inner(SequencePosition::default(), peptidoform_index)
+                    + loss.unwrap_or(&NeutralLoss::Gain(MolecularFormula::default()));
+                // Modification specific neutral losses are tracked as labels on the formula
+                let neutral_loss = formula
+                    .labels()
+                    .iter()
+                    .filter_map(|label| match label {
+                        AmbiguousLabel::NeutralLoss(loss) => Some(loss.clone()),
+                        _ => None,
+                    })
+                    .chain(loss.cloned())
+                    .collect();
+                Self {
+                    formula: Some(formula),
+                    charge: Charge::new::<crate::system::e>(
+                        charge.charge().value.try_into().unwrap(),
+                    ),
+                    ion: annotation.clone(),
+                    peptidoform_ion_index: Some(peptidoform_ion_index),
+                    peptidoform_index: Some(peptidoform_index),
+                    neutral_loss,
+                    deviation: None,
+                    confidence: None,
+                    auxiliary: false,
+                    secondary_weight: None,
+                }
             })
             .collect()
     }
@@ -357,18 +377,24 @@ pub enum FragmentType {
     z(PeptidePosition),
     /// z·
     z·(PeptidePosition),
-    // glycan A fragment (Never generated)
-    //A(GlycanPosition),
+    /// glycan cross-ring A fragment, the ring of the monosaccharide at this position broken at the
+    /// given ring bonds (eg 2,4A), retaining the non-reducing part
+    A(GlycanPosition, (u8, u8)),
     /// glycan B fragment
     B(GlycanPosition),
-    // glycan C fragment (Never generated)
-    //C(GlycanPosition),
-    // glycan X fragment (Never generated)
-    //X(GlycanPosition),
+    /// glycan C fragment, the B fragment retaining the glycosidic oxygen (B + H2O)
+    C(GlycanPosition),
+    /// glycan cross-ring X fragment, the ring of the monosaccharide at this position broken at the
+    /// given ring bonds (eg 0,2X), retaining the reducing part (including the peptide)
+    X(GlycanPosition, (u8, u8)),
     /// glycan Y fragment, generated by one or more branches broken
     Y(Vec<GlycanPosition>),
-    // glycan Z fragment (Never generated)
-    // Z(GlycanPosition),
+    /// glycan Z fragment, the Y fragment losing the glycosidic oxygen (Y − H2O)
+    Z(Vec<GlycanPosition>),
+    /// glycan Y fragment retaining only the peptide and the given number of core monosaccharides,
+    /// so Y0 for the bare peptide and Y1 for the peptide with the first monosaccharide of the
+    /// glycan (eg the core `HexNAc` of an N-glycan), also saves the attachment (AA + sequence index)
+    YCore(usize, Option<(AminoAcid, usize)>),
     /// Internal glycan fragment, meaning both a B and Y breakages (and potentially multiple of both), resulting in a set of monosaccharides
     Oxonium(Vec<GlycanBreakPos>),
     /// A B or internal glycan fragment for a glycan where only the composition is known, also saves the attachment (AA + sequence index)
@@ -418,7 +444,11 @@ impl FragmentType {
     /// Get the glycan position of this ion (or None not applicable)
     pub const fn glycan_position(&self) -> Option<&GlycanPosition> {
         match self {
-            Self::B(n) | Self::Diagnostic(DiagnosticPosition::Glycan(n, _)) => Some(n),
+            Self::A(n, _)
+            | Self::B(n)
+            | Self::C(n)
+            | Self::X(n, _)
+            | Self::Diagnostic(DiagnosticPosition::Glycan(n, _)) => Some(n),
             _ => None,
         }
     }
@@ -439,8 +469,15 @@ impl FragmentType {
             | Self::Diagnostic(DiagnosticPosition::Peptide(n, _))
             | Self::Immonium(n, _)
             | Self::PrecursorSideChainLoss(n, _) => Some(n.series_number.to_string()),
-            Self::B(n) | Self::Diagnostic(DiagnosticPosition::Glycan(n, _)) => Some(n.label()),
-            Self::Y(bonds) => Some(bonds.iter().map(GlycanPosition::label).join("")),
+            Self::A(n, _)
+            | Self::B(n)
+            | Self::C(n)
+            | Self::X(n, _)
+            | Self::Diagnostic(DiagnosticPosition::Glycan(n, _)) => Some(n.label()),
+            Self::Y(bonds) | Self::Z(bonds) => {
+                Some(bonds.iter().map(GlycanPosition::label).join(""))
+            }
+            Self::YCore(retained, _) => Some(retained.to_string()),
             Self::Oxonium(breakages) => Some(
                 breakages
                     .iter()
@@ -479,8 +516,12 @@ impl FragmentType {
             Self::y(_) => Cow::Borrowed("y"),
             Self::z(_) => Cow::Borrowed("z"),
             Self::z·(_) => Cow::Borrowed("z·"),
+            Self::A(_, (first, second)) => Cow::Owned(format!("{first},{second}A")),
             Self::B(_) => Cow::Borrowed("B"),
-            Self::Y(_) | Self::YComposition(_, _) => Cow::Borrowed("Y"),
+            Self::C(_) => Cow::Borrowed("C"),
+            Self::X(_, (first, second)) => Cow::Owned(format!("{first},{second}X")),
+            Self::Y(_) | Self::YComposition(_, _) | Self::YCore(_, _) => Cow::Borrowed("Y"),
+            Self::Z(_) => Cow::Borrowed("Z"),
             Self::Diagnostic(DiagnosticPosition::Peptide(_, aa)) => {
                 Cow::Owned(format!("d{}", aa.char()))
             }
@@ -517,11 +558,17 @@ impl FragmentType {
             Self::x(_) => FragmentKind::x,
             Self::y(_) => FragmentKind::y,
             Self::z(_) | Self::z·(_) => FragmentKind::z,
-            Self::Y(_) | Self::YComposition(_, _) => FragmentKind::Y,
+            Self::Y(_)
+            | Self::Z(_)
+            | Self::X(_, _)
+            | Self::YComposition(_, _)
+            | Self::YCore(_, _) => FragmentKind::Y,
             Self::Diagnostic(
                 DiagnosticPosition::Glycan(_, _) | DiagnosticPosition::GlycanCompositional(_, _),
             )
+            | Self::A(_, _)
             | Self::B(_)
+            | Self::C(_)
             | Self::Oxonium(_)
             | Self::OxoniumComposition(_, _) => FragmentKind::Oxonium,
             Self::Diagnostic(_) => FragmentKind::diagnostic,
@@ -619,9 +666,9 @@ pub enum FragmentKind {
     y,
     /// z and z·
     z,
-    /// glycan Y fragment, generated by one or more branches broken
+    /// glycan Y or Z fragment, generated by one or more branches broken, or a glycan cross-ring X fragment
     Y,
-    /// B or glycan diagnostic ion or Internal glycan fragment, meaning both a B and Y breakages (and potentially multiple of both), resulting in a set of monosaccharides
+    /// B, C, or glycan diagnostic ion or Internal glycan fragment, meaning both a B and Y breakages (and potentially multiple of both), resulting in a set of monosaccharides, or a glycan cross-ring A fragment
     Oxonium,
     /// Immonium ion
     immonium,
@@ -700,6 +747,43 @@ impl std::fmt::Display for GlycanBreakPos {
     }
 }
 
+/// Group fragments by the backbone bond that was broken to generate them, for use in ladder plots.
+/// A bond is identified by the residue on its N terminal side, so the bond between the residues
+/// at index `i` and `i + 1` is found at `SequencePosition::Index(i)`. This means that N terminal
+/// ions (a, b, c, d) are found at their own sequence index while C terminal ions (v, w, x, y, z)
+/// are found one position N terminal of their sequence index. Complementary ions, like b2 and
+/// y(n-2), are therefore grouped together at `SequencePosition::Index(1)`. A C terminal ion
+/// spanning the whole peptide is found at `SequencePosition::NTerm`.
+///
+/// Fragments that do not result from a single backbone cleavage (precursor, immonium, glycan,
+/// internal, and diagnostic ions among others) are not included. The fragments are cloned and keep
+/// the order in which they were given.
+pub fn group_fragments_by_position(
+    fragments: &[Fragment],
+) -> BTreeMap<SequencePosition, Vec<Fragment>> {
+    let mut groups: BTreeMap<SequencePosition, Vec<Fragment>> = BTreeMap::new();
+    for fragment in fragments {
+        let position = match &fragment.ion {
+            FragmentType::a(n) | FragmentType::b(n) | FragmentType::c(n) | FragmentType::d(n) => {
+                n.sequence_index
+            }
+            FragmentType::v(n)
+            | FragmentType::w(n)
+            | FragmentType::x(n)
+            | FragmentType::y(n)
+            | FragmentType::z(n)
+            | FragmentType::z·(n) => match n.sequence_index {
+                SequencePosition::Index(0) => SequencePosition::NTerm,
+                SequencePosition::Index(i) => SequencePosition::Index(i - 1),
+                other => other,
+            },
+            _ => continue,
+        };
+        groups.entry(position).or_default().push(fragment.clone());
+    }
+    groups
+}
+
 #[cfg(test)]
 #[allow(clippy::missing_panics_doc)]
 mod tests {
@@ -738,4 +822,71 @@ mod tests {
         assert_eq!(n1.flip_terminal(), c1);
         assert_eq!(n2.flip_terminal(), c2);
     }
+
+    #[test]
+    fn group_by_position() {
+        let peptide = crate::Peptidoform::pro_forma("AVLGEK", None)
+            .unwrap()
+            .into_linear()
+            .unwrap();
+        let model = crate::Model::none()
+            .b(crate::model::PrimaryIonSeries::default())
+            .y(crate::model::PrimaryIonSeries::default());
+        let fragments =
+            peptide.generate_theoretical_fragments(Charge::new::<crate::system::e>(1), &model);
+        let groups = group_fragments_by_position(&fragments);
+        let labels = |position| {
+            groups[&position]
+                .iter()
+                .map(|f| f.ion.to_string())
+                .sorted()
+                .collect_vec()
+        };
+        assert_eq!(labels(SequencePosition::Index(1)), ["b2", "y4"]);
+        assert_eq!(labels(SequencePosition::Index(4)), ["b5", "y1"]);
+        assert_eq!(
+            groups.keys().copied().collect_vec(),
+            (0..5).map(SequencePosition::Index).collect_vec()
+        );
+        assert!(groups
+            .values()
+            .flatten()
+            .all(|f| f.ion.position().is_some()));
+        assert!(!groups
+            .values()
+            .flatten()
+            .any(|f| f.ion == FragmentType::Precursor));
+    }
+
+    #[test]
+    fn metal_charge_carriers() {
+        let model = crate::Model::none().y(crate::model::PrimaryIonSeries::default());
+        let y3 = |proforma: &str, charge: usize| {
+            crate::Peptidoform::pro_forma(proforma, None)
+                .unwrap()
+                .into_linear()
+                .unwrap()
+                .generate_theoretical_fragments(Charge::new::<crate::system::e>(2), &model)
+                .into_iter()
+                .filter(|f| {
+                    matches!(&f.ion, FragmentType::y(p) if p.series_number == 3)
+                        && f.charge.value == charge
+                })
+                .filter_map(|f| f.mz(MassMode::Monoisotopic))
+                .sorted_by(|a, b| a.value.total_cmp(&b.value))
+                .collect_vec()
+        };
+        let protons = y3("PEPTIDE/2", 2);
+        let mixed = y3("PEPTIDE/2[+H+,+Na+]", 2);
+        assert_eq!(protons.len(), 1);
+        assert_eq!(mixed.len(), 1);
+        let sodium = (molecular_formula!(Na 1).monoisotopic_mass()
+            - molecular_formula!(H 1).monoisotopic_mass())
+        .value;
+        assert!(((mixed[0] - protons[0]).value - sodium / 2.0).abs() < 1e-6);
+        // A singly charged fragment can carry either the proton or the sodium
+        let single = y3("PEPTIDE/2[+H+,+Na+]", 1);
+        assert_eq!(single.len(), 2);
+        assert!(((single[1] - single[0]).value - sodium).abs() < 1e-6);
+    }
 }
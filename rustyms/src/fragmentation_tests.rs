@@ -1,8 +1,9 @@
 #![allow(clippy::missing_panics_doc)]
 use crate::{
+    fragment::{FragmentKind, FragmentType},
     model::*,
-    modification::ModificationId,
-    system::{ratio::ppm, usize::Charge, MassOverCharge, Ratio},
+    modification::{ModificationId, Ontology},
+    system::{ratio::ppm, usize::Charge, Mass, MassOverCharge, Ratio},
     *,
 };
 
@@ -14,6 +15,7 @@ use self::{
 };
 
 use itertools::Itertools;
+use ordered_float::OrderedFloat;
 
 #[test]
 fn triple_a() {
@@ -188,6 +190,101 @@ fn higher_charges() {
     );
 }
 
+#[test]
+fn mz_range() {
+    let model = Model::all().mz_range(
+        MassOverCharge::new::<crate::system::mz>(50.0),
+        MassOverCharge::new::<crate::system::mz>(300.0),
+    );
+    let fragments = Peptidoform::pro_forma("PEPTIDEK", None)
+        .unwrap()
+        .into_linear()
+        .unwrap()
+        .generate_theoretical_fragments(Charge::new::<crate::system::e>(3), &model);
+    assert!(!fragments.is_empty());
+    assert!(fragments.iter().all(|f| f
+        .mz(MassMode::Monoisotopic)
+        .is_some_and(|mz| (50.0..=300.0).contains(&mz.value))));
+}
+
+#[test]
+fn min_fragment_mass() {
+    let peptide = Peptidoform::pro_forma("PEPTIDEK", None)
+        .unwrap()
+        .into_linear()
+        .unwrap();
+    let model = Model::none()
+        .b(PrimaryIonSeries::default())
+        .immonium((true, ChargeRange::ONE));
+    let charge = Charge::new::<crate::system::e>(1);
+    let all = peptide.generate_theoretical_fragments(charge, &model);
+    let b1 = |f: &Fragment| matches!(f.ion, FragmentType::b(p) if p.sequence_index == SequencePosition::Index(0));
+    assert!(all.iter().any(b1));
+    assert!(all
+        .iter()
+        .any(|f| f.formula.as_ref().unwrap().monoisotopic_mass().value < 150.0));
+    let filtered = peptide.generate_theoretical_fragments(
+        charge,
+        &model.min_fragment_mass(Mass::new::<crate::system::dalton>(150.0)),
+    );
+    assert!(!filtered.is_empty());
+    assert!(filtered.len() < all.len());
+    assert!(!filtered.iter().any(b1));
+    assert!(filtered
+        .iter()
+        .all(|f| f.formula.as_ref().unwrap().monoisotopic_mass().value >= 150.0));
+}
+
+#[test]
+fn b_y_complementarity() {
+    let peptide = Peptidoform::pro_forma("PEPTIDEK", None)
+        .unwrap()
+        .into_linear()
+        .unwrap();
+    let proton = MolecularCharge::proton(1).formula().monoisotopic_mass();
+    for water_loss in [false, true] {
+        let model = Model::none()
+            .b(PrimaryIonSeries::default())
+            .y(PrimaryIonSeries::default())
+            .c_terminal_water_loss(water_loss);
+        let fragments =
+            peptide.generate_theoretical_fragments(Charge::new::<crate::system::e>(1), &model);
+        let mass = |f: &Fragment| f.formula.as_ref().unwrap().monoisotopic_mass();
+        let precursor = fragments
+            .iter()
+            .find(|f| f.ion == fragment::FragmentType::Precursor)
+            .map(mass)
+            .unwrap();
+        if water_loss {
+            let normal = peptide
+                .generate_theoretical_fragments(Charge::new::<crate::system::e>(1), &Model::none());
+            assert!(
+                (mass(&normal[0]) - precursor - molecular_formula!(H 2 O 1).monoisotopic_mass())
+                    .value
+                    .abs()
+                    < 1e-6
+            );
+        }
+        for b in &fragments {
+            let fragment::FragmentType::b(b_position) = b.ion else {
+                continue;
+            };
+            let y = fragments
+                .iter()
+                .find(|y| {
+                    matches!(y.ion, fragment::FragmentType::y(y_position) if y_position.series_number + b_position.series_number == peptide.len())
+                })
+                .unwrap();
+            assert!(
+                (mass(b) + mass(y) - precursor - proton).value.abs() < 1e-6,
+                "b{} + y{} != precursor + proton",
+                b_position.series_number,
+                peptide.len() - b_position.series_number
+            );
+        }
+    }
+}
+
 #[test]
 fn all_aminoacids() {
     // Compare rustyms with https://proteomicsresource.washington.edu/cgi-bin/fragment.cgi
@@ -596,6 +693,34 @@ fn intra_link() {
     assert_eq!(doubly_annotated.len(), 0);
 }
 
+#[test]
+fn inter_link_cleavage() {
+    let peptide =
+        CompoundPeptidoformIon::pro_forma("VK[C:DSSO#XL1]LR//AK[#XL1]GR", Some(&custom_database()))
+            .unwrap();
+    let labels = |cleavable: bool| {
+        let model = Model::none()
+            .b(PrimaryIonSeries::default())
+            .y(PrimaryIonSeries::default())
+            .allow_cross_link_cleavage(cleavable);
+        let fragments =
+            peptide.generate_theoretical_fragments(Charge::new::<crate::system::e>(2), &model);
+        let has_label = |test: fn(&AmbiguousLabel) -> bool| {
+            fragments
+                .iter()
+                .any(|f| f.formula.as_ref().unwrap().labels().iter().any(test))
+        };
+        (
+            has_label(|l| matches!(l, AmbiguousLabel::CrossLinkBound(_))),
+            has_label(|l| matches!(l, AmbiguousLabel::CrossLinkBroken(_, _))),
+        )
+    };
+    // Intact bridged fragments are always generated, the cleaved variants with the linker stubs
+    // only if cross-link cleavage is allowed
+    assert_eq!(labels(true), (true, true));
+    assert_eq!(labels(false), (true, false));
+}
+
 #[test]
 fn ensure_no_double_xl_labels_breaking() {
     let peptide =
@@ -768,3 +893,270 @@ fn test(
         );
     }
 }
+
+#[test]
+fn secondary_ion_series() {
+    let peptide = Peptidoform::pro_forma("PEPTIDEK", None)
+        .unwrap()
+        .into_linear()
+        .unwrap();
+    let charge = Charge::new::<crate::system::e>(2);
+    let count = |fragments: &[Fragment], kind: FragmentKind| {
+        fragments.iter().filter(|f| f.ion.kind() == kind).count()
+    };
+    let cid = peptide.generate_theoretical_fragments(charge, &Model::cid_hcd());
+    let secondary = peptide.generate_theoretical_fragments(
+        charge,
+        &Model::cid_hcd().c(PrimaryIonSeries::default().secondary(Some(0.1))),
+    );
+    assert_eq!(count(&cid, FragmentKind::c), 0);
+    assert!(count(&secondary, FragmentKind::c) > 0);
+    assert_eq!(
+        count(&cid, FragmentKind::b),
+        count(&secondary, FragmentKind::b)
+    );
+    assert_eq!(
+        count(&cid, FragmentKind::y),
+        count(&secondary, FragmentKind::y)
+    );
+    assert!(secondary
+        .iter()
+        .all(|f| (f.ion.kind() == FragmentKind::c)
+            == (f.secondary_weight == Some(OrderedFloat(0.1)))));
+    assert!(secondary
+        .iter()
+        .all(|f| !f.auxiliary && f.confidence.is_none()));
+}
+
+#[test]
+fn custom_modification_neutral_losses() {
+    let peptide = Peptidoform::pro_forma("PEPT[Phospho]IDEK", None)
+        .unwrap()
+        .into_linear()
+        .unwrap();
+    let phospho = Ontology::Unimod.find_id(21, None).unwrap();
+    let custom = NeutralLoss::Loss(MolecularFormula::with_additional_mass(274.08));
+    let charge = Charge::new::<crate::system::e>(2);
+    let has_custom_loss = |fragments: &[Fragment]| {
+        fragments
+            .iter()
+            .any(|f| f.formula.as_ref().unwrap().additional_mass() == OrderedFloat(-274.08))
+    };
+    let default = peptide.generate_theoretical_fragments(charge, &Model::cid_hcd());
+    let custom_model =
+        Model::cid_hcd().modification_neutral_losses(vec![(phospho, vec![custom.clone()])]);
+    let fragments = peptide.generate_theoretical_fragments(charge, &custom_model);
+    assert!(!has_custom_loss(&default));
+    assert!(has_custom_loss(&fragments));
+    assert!(fragments
+        .iter()
+        .any(|f| f.ion == FragmentType::Precursor && f.neutral_loss.contains(&custom)));
+    assert!(!fragments.iter().any(|f| f.ion == FragmentType::Precursor
+        && f.neutral_loss
+            .contains(&NeutralLoss::Loss(molecular_formula!(H 3 O 4 P 1)))));
+    assert!(default.iter().any(|f| f.ion == FragmentType::Precursor
+        && f.neutral_loss
+            .contains(&NeutralLoss::Loss(molecular_formula!(H 3 O 4 P 1)))));
+}
+
+#[test]
+fn modification_neutral_losses_on_fragments() {
+    let peptide = Peptidoform::pro_forma("PEPT[Phospho]IDEK", None)
+        .unwrap()
+        .into_linear()
+        .unwrap();
+    let phosphate = NeutralLoss::Loss(molecular_formula!(H 3 O 4 P 1));
+    let fragments = peptide
+        .generate_theoretical_fragments(Charge::new::<crate::system::e>(1), &Model::cid_hcd());
+    let lossy = |fragment: &Fragment| fragment.neutral_loss.contains(&phosphate);
+    // Only fragments that contain the phosphorylated threonine can lose phosphoric acid
+    let series = |fragment: &&Fragment| match &fragment.ion {
+        FragmentType::b(position) => Some(('b', position.series_number)),
+        FragmentType::y(position) => Some(('y', position.series_number)),
+        _ => None,
+    };
+    let b = |n: usize| move |f: &&Fragment| series(f) == Some(('b', n));
+    let y = |n: usize| move |f: &&Fragment| series(f) == Some(('y', n));
+    assert!(fragments.iter().filter(b(4)).any(lossy));
+    assert!(fragments.iter().filter(y(5)).any(lossy));
+    assert!(!fragments.iter().filter(b(3)).any(lossy));
+    assert!(!fragments.iter().filter(y(4)).any(lossy));
+    // The lossy fragment has the mass of the intact fragment minus the loss
+    let intact = fragments
+        .iter()
+        .filter(b(4))
+        .find(|f| f.neutral_loss.is_empty())
+        .unwrap();
+    let lost = fragments.iter().filter(b(4)).find(|f| lossy(f)).unwrap();
+    assert_eq!(
+        lost.formula.as_ref().unwrap().monoisotopic_mass(),
+        (intact.formula.as_ref().unwrap() + &phosphate).monoisotopic_mass()
+    );
+}
+
+#[test]
+fn glycan_cross_ring_fragments() {
+    // Man5GlcNAc2 high mannose glycan
+    let glycan: glycan::GlycanStructure = "hexnac(hexnac(hex(hex(hex),hex(hex,hex))))"
+        .parse()
+        .unwrap();
+    let mut peptide = Peptidoform::pro_forma("NATK", None)
+        .unwrap()
+        .into_linear()
+        .unwrap();
+    let bare = peptide.formulas()[0].monoisotopic_mass();
+    peptide.add_simple_modification(
+        SequencePosition::Index(0),
+        Arc::new(SimpleModificationInner::GlycanStructure(glycan)),
+    );
+    let charge = Charge::new::<crate::system::e>(1);
+    let proton = MolecularCharge::proton(1).formula().monoisotopic_mass();
+    // The peptide + 83 Da ion from the core GlcNAc
+    let expected = bare + molecular_formula!(C 4 H 5 N 1 O 1).monoisotopic_mass() + proton;
+    let is_expected = |f: &Fragment| {
+        matches!(&f.ion, FragmentType::X(position, (0, 2)) if position.inner_depth == 0)
+            && f.neutral_loss.is_empty()
+            && (f.mz(MassMode::Monoisotopic).unwrap().value - expected.value).abs() < 1e-6
+    };
+
+    let model = Model::none().glycan(GlycanModel::DISALLOW.allow_structural(true));
+    let fragments = peptide.generate_theoretical_fragments(charge, &model);
+    assert!(!fragments
+        .iter()
+        .any(|f| matches!(f.ion, FragmentType::X(_, _) | FragmentType::A(_, _))));
+
+    let model = model.glycan(
+        GlycanModel::DISALLOW
+            .allow_structural(true)
+            .cross_ring_fragments(true),
+    );
+    let fragments = peptide.generate_theoretical_fragments(charge, &model);
+    assert!(fragments.iter().any(is_expected), "{fragments:?}");
+    assert!(fragments
+        .iter()
+        .any(|f| matches!(f.ion, FragmentType::A(_, (2, 4)))));
+    assert_eq!(
+        fragments
+            .iter()
+            .find(|f| is_expected(f))
+            .unwrap()
+            .ion
+            .to_string(),
+        "0,2X5"
+    );
+    // Cross-ring A fragments show the broken ring bonds as prefix and the branch as suffix
+    let a_labels = fragments
+        .iter()
+        .filter(|f| matches!(f.ion, FragmentType::A(_, _)))
+        .map(|f| f.ion.to_string())
+        .collect::<Vec<_>>();
+    for label in ["2,4A5", "2,4A2α", "2,4A1α'", "2,4A1β"] {
+        assert!(a_labels.iter().any(|l| l == label), "{label} {a_labels:?}");
+    }
+}
+
+#[test]
+fn glycan_oxonium_ions() {
+    let charge = Charge::new::<crate::system::e>(1);
+    let has_mz = |fragments: &[Fragment], mz: f64| {
+        fragments.iter().any(|f| {
+            f.ion.kind() == FragmentKind::Oxonium
+                && (f.mz(MassMode::Monoisotopic).unwrap().value - mz).abs() < 0.01
+        })
+    };
+    let mut structure = Peptidoform::pro_forma("NATK", None)
+        .unwrap()
+        .into_linear()
+        .unwrap();
+    structure.add_simple_modification(
+        SequencePosition::Index(0),
+        Arc::new(SimpleModificationInner::GlycanStructure(
+            "hexnac(hexnac(hex(hex(hex),hex(hex,hex))))"
+                .parse()
+                .unwrap(),
+        )),
+    );
+    let composition = Peptidoform::pro_forma("N[Glycan:HexNAc2Hex5]ATK", None)
+        .unwrap()
+        .into_linear()
+        .unwrap();
+    for peptide in [structure, composition] {
+        let fragments = peptide.generate_theoretical_fragments(charge, &Model::none());
+        assert!(!has_mz(&fragments, 204.087));
+        let model = Model::none().glycan(GlycanModel::DISALLOW.oxonium(true));
+        let fragments = peptide.generate_theoretical_fragments(charge, &model);
+        assert!(has_mz(&fragments, 163.060), "Hex");
+        assert!(has_mz(&fragments, 204.087), "HexNAc");
+        assert!(has_mz(&fragments, 366.140), "HexHexNAc");
+        assert!(fragments
+            .iter()
+            .any(|f| matches!(f.ion, FragmentType::Diagnostic(_))
+                && (f.mz(MassMode::Monoisotopic).unwrap().value - 138.055).abs() < 0.01));
+        // No doubly generated oxonium ions if the compositional B ions are also generated
+        let model = Model::none().glycan(
+            GlycanModel::DISALLOW
+                .compositional_range(1..=10)
+                .oxonium(true),
+        );
+        let fragments = peptide.generate_theoretical_fragments(charge, &model);
+        assert_eq!(
+            fragments
+                .iter()
+                .filter(|f| f.neutral_loss.is_empty()
+                    && f.charge.value == 1
+                    && f.ion.kind() == FragmentKind::Oxonium
+                    && (f.mz(MassMode::Monoisotopic).unwrap().value - 204.087).abs() < 0.01)
+                .count(),
+            1
+        );
+    }
+}
+
+#[test]
+fn glycan_core_y_ions() {
+    let charge = Charge::new::<crate::system::e>(1);
+    // NATK + H+ and NATK + HexNAc + H+
+    let (y0, y1) = (433.2405, 636.3199);
+    let has_core = |fragments: &[Fragment], retained: usize, mz: f64| {
+        fragments.iter().any(|f| {
+            matches!(f.ion, FragmentType::YCore(r, Some((AminoAcid::Asparagine, 0))) if r == retained)
+                && f.neutral_loss.is_empty()
+                && f.charge.value == 1
+                && (f.mz(MassMode::Monoisotopic).unwrap().value - mz).abs() < 0.01
+        })
+    };
+    let mut structure = Peptidoform::pro_forma("NATK", None)
+        .unwrap()
+        .into_linear()
+        .unwrap();
+    structure.add_simple_modification(
+        SequencePosition::Index(0),
+        Arc::new(SimpleModificationInner::GlycanStructure(
+            "hexnac(hexnac(hex(hex(hex),hex(hex,hex))))"
+                .parse()
+                .unwrap(),
+        )),
+    );
+    let composition = Peptidoform::pro_forma("N[Glycan:HexNAc2Hex5]ATK", None)
+        .unwrap()
+        .into_linear()
+        .unwrap();
+    for (peptide, model) in [
+        (structure, GlycanModel::DISALLOW.allow_structural(true)),
+        (
+            composition,
+            GlycanModel::DISALLOW.compositional_range(1..=3),
+        ),
+    ] {
+        let fragments = peptide.generate_theoretical_fragments(charge, &Model::none());
+        assert!(!has_core(&fragments, 0, y0));
+        let fragments =
+            peptide.generate_theoretical_fragments(charge, &Model::none().glycan(model));
+        assert!(has_core(&fragments, 0, y0), "Y0");
+        assert!(has_core(&fragments, 1, y1), "Y1");
+        assert!(fragments
+            .iter()
+            .filter(|f| matches!(f.ion, FragmentType::YCore(..)))
+            .all(|f| f.ion.label() == "Y"));
+    }
+}
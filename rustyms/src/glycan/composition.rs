@@ -0,0 +1,113 @@
+//! Search for glycan compositions matching a mass
+
+use crate::{system::Mass, Chemical, Tolerance, WithinTolerance};
+
+use super::MonoSaccharide;
+
+/// Enumerate all glycan compositions that match the given mass (e.g. a mass delta on a peptide)
+/// within the tolerance. The composition is built out of the given monosaccharides, where
+/// `bounds[i]` caps the number of times `monosaccharides[i]` can occur. If fewer bounds than
+/// monosaccharides are given the remaining monosaccharides are only limited by the mass. The
+/// monoisotopic mass of the monosaccharide residues is used, and only monosaccharides with a
+/// positive mass are considered. The compositions are sorted on absolute mass error, and only
+/// contain the monosaccharides that occur at least once.
+pub fn compositions_within(
+    mass: Mass,
+    tolerance: Tolerance<Mass>,
+    monosaccharides: &[MonoSaccharide],
+    bounds: &[u16],
+) -> Vec<Vec<(MonoSaccharide, u16)>> {
+    let options = monosaccharides
+        .iter()
+        .enumerate()
+        .map(|(index, sugar)| {
+            (
+                sugar,
+                sugar.formula().monoisotopic_mass(),
+                bounds.get(index).copied().unwrap_or(u16::MAX),
+            )
+        })
+        .filter(|(_, mass, _)| mass.value > 0.0)
+        .collect::<Vec<_>>();
+    let (_, upper) = tolerance.bounds_for_experimental(mass);
+
+    let mut found = Vec::new();
+    let mut counts = vec![0_u16; options.len()];
+    enumerate(
+        &options,
+        0,
+        Mass::default(),
+        upper,
+        &mut counts,
+        &mut |counts, total| {
+            if counts.iter().any(|c| *c > 0) && tolerance.within(&mass, &total) {
+                found.push(((total - mass).value.abs(), counts.to_vec()));
+            }
+        },
+    );
+
+    found.sort_by(|a, b| a.0.total_cmp(&b.0));
+    found
+        .into_iter()
+        .map(|(_, counts)| {
+            options
+                .iter()
+                .zip(counts)
+                .filter(|(_, count)| *count > 0)
+                .map(|((sugar, _, _), count)| ((*sugar).clone(), count))
+                .collect()
+        })
+        .collect()
+}
+
+/// Recursively try all counts for the monosaccharide at `index` and all following ones
+fn enumerate(
+    options: &[(&MonoSaccharide, Mass, u16)],
+    index: usize,
+    total: Mass,
+    upper: Mass,
+    counts: &mut [u16],
+    report: &mut impl FnMut(&[u16], Mass),
+) {
+    if index == options.len() {
+        report(counts, total);
+        return;
+    }
+    let (_, mass, bound) = options[index];
+    let mut current = total;
+    let mut count = 0;
+    loop {
+        counts[index] = count;
+        enumerate(options, index + 1, current, upper, counts, report);
+        current += mass;
+        if count == bound || current > upper {
+            break;
+        }
+        count += 1;
+    }
+    counts[index] = 0;
+}
+
+#[cfg(test)]
+#[allow(clippy::missing_panics_doc)]
+mod tests {
+    use super::*;
+    use crate::system::da;
+
+    #[test]
+    fn man5() {
+        let sugars = ["HexNAc", "Hex", "Fuc", "NeuAc"]
+            .iter()
+            .map(|name| MonoSaccharide::from_composition(name).unwrap()[0].0.clone())
+            .collect::<Vec<_>>();
+        let man5 = vec![(sugars[0].clone(), 2), (sugars[1].clone(), 5)];
+        let tolerance = Tolerance::new_absolute(da(0.02));
+
+        let found = compositions_within(da(1216.42), tolerance, &sugars, &[4, 9, 2, 2]);
+        assert!(found.contains(&man5), "{found:?}");
+        let found = compositions_within(da(1216.42), tolerance, &sugars, &[1, 9, 2, 2]);
+        assert!(!found.contains(&man5), "{found:?}");
+        assert!(found.iter().all(|c| c[0].0 != sugars[0] || c[0].1 <= 1));
+        assert!(compositions_within(da(10.0), tolerance, &sugars, &[]).is_empty());
+    }
+}
@@ -5,10 +5,18 @@ use std::{fmt::Display, hash::Hash};
 use itertools::Itertools;
 use serde::{Deserialize, Serialize};
 
-use super::{glycan_parse_list, BaseSugar, MonoSaccharide, PositionedGlycanStructure};
+use super::{
+    glycan_parse_list, BaseSugar, GlycanSubstituent, HexoseIsomer, MonoSaccharide,
+    PositionedGlycanStructure,
+};
 use crate::{
     error::{Context, CustomError},
     formula::{Chemical, MolecularFormula},
+    fragment::{Fragment, FragmentType, GlycanBreakPos},
+    model::GlycanModel,
+    molecular_charge::{CachedCharge, MolecularCharge},
+    system::usize::Charge,
+    Model, Multi,
 };
 
 include!("../shared/glycan_structure.rs");
@@ -172,6 +180,134 @@ impl GlycanStructure {
         output.extend(self.branches.iter().flat_map(Self::composition_inner));
         output
     }
+
+    /// Get a node/edge representation of this glycan as JSON, for use in (SNFG) visualisation.
+    /// The nodes are numbered in depth first order starting with the root (the monosaccharide
+    /// attached to the peptide) and contain the name of the monosaccharide. The edges connect a
+    /// parent to a child node. The linkage positions are not stored for glycan structures so are not
+    /// present in the output.
+    /// ```json
+    /// {"edges":[{"child":1,"parent":0}],"nodes":[{"id":0,"name":"HexNAc"},{"id":1,"name":"Hex"}]}
+    /// ```
+    pub fn to_json(&self) -> String {
+        fn add(
+            glycan: &GlycanStructure,
+            parent: Option<usize>,
+            nodes: &mut Vec<serde_json::Value>,
+            edges: &mut Vec<serde_json::Value>,
+        ) {
+            let id = nodes.len();
+            nodes.push(serde_json::json!({"id": id, "name": glycan.sugar.to_string()}));
+            if let Some(parent) = parent {
+                edges.push(serde_json::json!({"parent": parent, "child": id}));
+            }
+            for branch in &glycan.branches {
+                add(branch, Some(id), nodes, edges);
+            }
+        }
+        let mut nodes = Vec::new();
+        let mut edges = Vec::new();
+        add(self, None, &mut nodes, &mut edges);
+        serde_json::json!({"nodes": nodes, "edges": edges}).to_string()
+    }
+
+    /// Check if this glycan contains the conserved N-glycan core (`Man3GlcNAc2`), so a `GlcNAc`
+    /// followed by a `GlcNAc` followed by a `Man` that has at least two `Man` branches. Any other
+    /// monosaccharides can be attached to this core (for example a core fucose). Monosaccharides
+    /// without a defined isomer (`Hex`/`HexNAc`) are allowed to stand in for `Man`/`GlcNAc`.
+    pub fn is_valid_n_glycan(&self) -> bool {
+        fn is(
+            sugar: &MonoSaccharide,
+            isomer: HexoseIsomer,
+            substituents: &[GlycanSubstituent],
+        ) -> bool {
+            (sugar.base_sugar == BaseSugar::Hexose(None)
+                || sugar.base_sugar == BaseSugar::Hexose(Some(isomer)))
+                && sugar.substituents == substituents
+                && !sugar.furanose
+        }
+        let glcnac = |glycan: &Self| {
+            is(
+                &glycan.sugar,
+                HexoseIsomer::Glucose,
+                &[GlycanSubstituent::NAcetyl],
+            )
+        };
+        let man = |glycan: &Self| is(&glycan.sugar, HexoseIsomer::Mannose, &[]);
+
+        glcnac(self)
+            && self.branches.iter().any(|second| {
+                glcnac(second)
+                    && second.branches.iter().any(|third| {
+                        man(third) && third.branches.iter().filter(|arm| man(arm)).count() >= 2
+                    })
+            })
+    }
+    /// Generate the theoretical fragments for this glycan as a released glycan, so with a free
+    /// reducing end. This generates the B, C, Y, and Z glycosidic fragments, the internal
+    /// fragments, and the diagnostic ions, with the neutral losses and charge ranges from the
+    /// model. If turned on in the model the cross-ring fragments are generated as well. The
+    /// structural fragments are generated regardless of [`GlycanModel::allow_structural`].
+    ///
+    /// Breaking the bond at the reducing end does not result in a fragment, so no Y/Z fragment is
+    /// generated for this position and no B/C or internal fragment is generated that contains
+    /// the reducing end monosaccharide, as those would be the same species as the Y/Z fragments
+    /// or the full glycan.
+    /// # Panics
+    /// Panics if the `max_charge` is bigger than [`isize::MAX`].
+    pub fn fragments(&self, max_charge: Charge, model: &GlycanModel) -> Vec<Fragment> {
+        let mut charge_carriers: CachedCharge = MolecularCharge::proton(
+            isize::try_from(max_charge.value)
+                .expect("Charge of the precursor cannot be higher then isize::MAX"),
+        )
+        .into();
+        let water = molecular_formula!(H 2 O 1);
+        let positioned = self.clone().determine_positions();
+        let full_formula = Multi::from(positioned.formula() + &water);
+        let model = Model::none().glycan(model.clone().allow_structural(true));
+
+        let mut fragments = Vec::new();
+        for fragment in positioned.generate_theoretical_fragments(
+            &model,
+            0,
+            0,
+            &mut charge_carriers,
+            &full_formula,
+            None,
+        ) {
+            let reducing_end = match &fragment.ion {
+                FragmentType::B(position) => position.inner_depth == 0,
+                FragmentType::Y(positions) => positions.iter().any(|p| p.inner_depth == 0),
+                FragmentType::Oxonium(breakages) => breakages
+                    .iter()
+                    .any(|b| matches!(b, GlycanBreakPos::B(p) if p.inner_depth == 0)),
+                _ => false,
+            };
+            if reducing_end {
+                continue;
+            }
+            match &fragment.ion {
+                FragmentType::B(position) => {
+                    fragments.push(Fragment {
+                        formula: fragment.formula.as_ref().map(|f| f + &water),
+                        ion: FragmentType::C(position.clone()),
+                        ..fragment.clone()
+                    });
+                    fragments.push(fragment);
+                }
+                FragmentType::Y(positions) => {
+                    fragments.push(Fragment {
+                        formula: fragment.formula.as_ref().map(|f| f - &water),
+                        ion: FragmentType::Z(positions.clone()),
+                        ..fragment.clone()
+                    });
+                    fragments.push(fragment);
+                }
+                _ => fragments.push(fragment),
+            }
+        }
+        fragments
+    }
 }
 
 #[cfg(test)]
@@ -353,4 +489,85 @@ mod test {
             "HexNAc(HexNAc(Hex(Hex(HexNAc,HexNAc),Hex(Hex))))"
         );
     }
+
+    #[test]
+    fn n_glycan_core() {
+        // Man9GlcNAc2 high mannose glycan
+        let high_mannose = GlycanStructure::from_short_iupac(
+            "Man(a1-2)Man(a1-2)Man(a1-3)[Man(a1-2)Man(a1-3)[Man(a1-2)Man(a1-6)]Man(a1-6)]Man(b1-4)GlcNAc(b1-4)GlcNAc(b1-",
+            0..107,
+            0,
+        )
+        .unwrap();
+        assert!(high_mannose.is_valid_n_glycan());
+        // Core fucosylated, with unspecified isomers
+        assert!(
+            GlycanStructure::from_str("hexnac(fuc,hexnac(hex(hex,hex)))")
+                .unwrap()
+                .is_valid_n_glycan()
+        );
+        // Missing one of the core mannoses
+        assert!(!GlycanStructure::from_str("hexnac(hexnac(hex(hex(hex))))")
+            .unwrap()
+            .is_valid_n_glycan());
+        // Missing the core GlcNAc
+        assert!(!GlycanStructure::from_str("hex(hex,hex)")
+            .unwrap()
+            .is_valid_n_glycan());
+    }
+
+    #[test]
+    fn to_json() {
+        let glycan = GlycanStructure::from_str("hexnac(fuc,hexnac(hex(hex,hex)))").unwrap();
+        let json: serde_json::Value = serde_json::from_str(&glycan.to_json()).unwrap();
+        let nodes = json["nodes"].as_array().unwrap();
+        let edges = json["edges"].as_array().unwrap();
+        assert_eq!(nodes.len(), 6);
+        assert_eq!(edges.len(), 5);
+        assert_eq!(nodes[0]["id"], 0);
+        assert_eq!(nodes[0]["name"], "HexNAc");
+        assert_eq!(nodes[1]["name"], "Fuc");
+        assert_eq!(edges[0]["parent"], 0);
+        assert_eq!(edges[0]["child"], 1);
+        assert!(edges
+            .iter()
+            .all(|e| e["parent"].as_u64() < e["child"].as_u64()));
+        assert_eq!(
+            GlycanStructure::from_str("hexnac(hex)").unwrap().to_json(),
+            r#"{"edges":[{"child":1,"parent":0}],"nodes":[{"id":0,"name":"HexNAc"},{"id":1,"name":"Hex"}]}"#
+        );
+    }
+
+    #[test]
+    fn released_fragments() {
+        let glycan = GlycanStructure::from_str("hexnac(fuc,hexnac(hex(hex,hex)))").unwrap();
+        let fragments = glycan.fragments(Charge::new::<crate::system::e>(1), &GlycanModel::ALLOW);
+        let water = molecular_formula!(H 2 O 1).monoisotopic_mass();
+        // One single breakage Y fragment for every glycosidic bond except the reducing end
+        assert_eq!(
+            fragments
+                .iter()
+                .filter(|f| matches!(&f.ion, FragmentType::Y(p) if p.len() == 1))
+                .count(),
+            5
+        );
+        assert!(fragments.iter().all(|f| match &f.ion {
+            FragmentType::B(p) | FragmentType::C(p) => p.inner_depth != 0,
+            FragmentType::Y(p) | FragmentType::Z(p) => p.iter().all(|p| p.inner_depth != 0),
+            _ => true,
+        }));
+        // Every B has a C and every Y a Z fragment, differing by one water
+        for fragment in &fragments {
+            let partner = match &fragment.ion {
+                FragmentType::B(p) => FragmentType::C(p.clone()),
+                FragmentType::Y(p) => FragmentType::Z(p.clone()),
+                _ => continue,
+            };
+            let partner = fragments.iter().find(|f| f.ion == partner).unwrap();
+            let difference = (partner.formula.as_ref().unwrap().monoisotopic_mass()
+                - fragment.formula.as_ref().unwrap().monoisotopic_mass())
+            .abs();
+            assert!((difference - water).value.abs() < 1e-6);
+        }
+    }
 }
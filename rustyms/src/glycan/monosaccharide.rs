@@ -3,13 +3,32 @@
 use crate::{
     fragment::{DiagnosticPosition, Fragment, FragmentType},
     molecular_charge::CachedCharge,
-    system::usize::Charge,
+    system::{usize::Charge, Mass},
     AminoAcid, Model, Multi, NeutralLoss,
 };
 
 include!("../shared/glycan.rs");
 include!("../shared/glycan_lists.rs");
 
+/// The supported glycan cross-ring fragments
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CrossRingFragment {
+    /// 0,2X
+    X02,
+    /// 2,4A
+    A24,
+}
+
+impl CrossRingFragment {
+    /// The broken ring bonds
+    pub(crate) const fn bonds(self) -> (u8, u8) {
+        match self {
+            Self::X02 => (0, 2),
+            Self::A24 => (2, 4),
+        }
+    }
+}
+
 impl MonoSaccharide {
     /// Generate the composition used for searching on glycans
     pub(crate) fn search_composition(
@@ -59,6 +78,45 @@ impl MonoSaccharide {
         let compositions =
             Self::composition_options(composition, model.glycan.compositional_range.clone());
 
+        // If attached to a peptide the Y fragments that retain nothing or only a single HexNAc
+        // are always generated (as Y0 and Y1) as these are diagnostic for glycopeptides
+        let total: MolecularFormula = composition
+            .iter()
+            .map(|s| {
+                s.0.formula_inner(SequencePosition::default(), peptidoform_index) * s.1 as i32
+            })
+            .sum();
+        let mut core = Vec::new();
+        if attachment.is_some() && !compositions.is_empty() {
+            core.push((0, total.clone()));
+            if composition.iter().map(|(_, n)| n).sum::<isize>() > 1 {
+                if let Some((hexnac, _)) = composition.iter().find(|(sugar, n)| {
+                    *n > 0
+                        && matches!(sugar.base_sugar, BaseSugar::Hexose(_))
+                        && sugar.substituents == [GlycanSubstituent::NAcetyl]
+                }) {
+                    core.push((
+                        1,
+                        &total
+                            - hexnac.formula_inner(SequencePosition::default(), peptidoform_index),
+                    ));
+                }
+            }
+        }
+        for (retained, lost) in &core {
+            fragments.extend(full_formula.iter().flat_map(|base| {
+                Fragment::new(
+                    base - lost,
+                    Charge::default(),
+                    peptidoform_ion_index,
+                    peptidoform_index,
+                    FragmentType::YCore(*retained, attachment),
+                )
+                .with_charge_range(charge_carriers, model.glycan.other_charge_range)
+                .flat_map(|o| o.with_neutral_losses(&model.glycan.neutral_losses))
+            }));
+        }
+
         // Generate compositional B and Y ions
         for composition in compositions {
             let formula: MolecularFormula = composition
@@ -67,6 +125,7 @@ impl MonoSaccharide {
                     s.0.formula_inner(SequencePosition::default(), peptidoform_index) * s.1 as i32
                 })
                 .sum();
+            let is_core = core.iter().any(|(_, lost)| *lost == formula);
             fragments.extend(
                 Fragment::new(
                     formula.clone(),
@@ -78,22 +137,118 @@ impl MonoSaccharide {
                 .with_charge_range(charge_carriers, model.glycan.oxonium_charge_range)
                 .flat_map(|o| o.with_neutral_losses(&model.glycan.neutral_losses)),
             );
-            fragments.extend(full_formula.to_vec().iter().flat_map(|base| {
+            if !is_core {
+                fragments.extend(full_formula.to_vec().iter().flat_map(|base| {
+                    Fragment::new(
+                        base - &formula,
+                        Charge::default(),
+                        peptidoform_ion_index,
+                        peptidoform_index,
+                        FragmentType::YComposition(composition.clone(), attachment),
+                    )
+                    .with_charge_range(charge_carriers, model.glycan.other_charge_range)
+                    .flat_map(|o| o.with_neutral_losses(&model.glycan.neutral_losses))
+                }));
+            }
+        }
+
+        // Generate the common oxonium ions that are not already generated as compositional B ions
+        if model.glycan.oxonium {
+            fragments.extend(Self::common_oxonium_ions(
+                composition,
+                &model.glycan.compositional_range,
+                model,
+                peptidoform_ion_index,
+                peptidoform_index,
+                charge_carriers,
+                attachment,
+            ));
+        }
+
+        // Generate compositional diagnostic ions
+        fragments.extend(Self::compositional_diagnostic_ions(
+            composition,
+            model,
+            peptidoform_ion_index,
+            peptidoform_index,
+            charge_carriers,
+            attachment,
+        ));
+
+        fragments
+    }
+
+    /// Generate the common oxonium ions for this composition, these are the single monosaccharide
+    /// oxonium ions (eg `HexNAc` 204.087) and `HexHexNAc` (366.140). Any oxonium ion with a
+    /// number of monosaccharides within the `skip` range is not generated, so this can be used to
+    /// skip any ions that are already generated as compositional B ions.
+    pub(crate) fn common_oxonium_ions(
+        composition: &[(Self, isize)],
+        skip: &std::ops::RangeInclusive<usize>,
+        model: &Model,
+        peptidoform_ion_index: usize,
+        peptidoform_index: usize,
+        charge_carriers: &mut CachedCharge,
+        attachment: Option<(AminoAcid, usize)>,
+    ) -> Vec<Fragment> {
+        let present = composition.iter().filter(|(_, n)| *n > 0).collect_vec();
+        let mut options = Vec::new();
+        if !skip.contains(&1) {
+            options.extend(
+                present
+                    .iter()
+                    .map(|(sugar, _)| sugar)
+                    .unique()
+                    .map(|sugar| vec![(sugar.clone(), 1)]),
+            );
+        }
+        if !skip.contains(&2) {
+            let hex = present.iter().find(|(sugar, _)| {
+                matches!(sugar.base_sugar, BaseSugar::Hexose(_)) && sugar.substituents.is_empty()
+            });
+            let hexnac = present.iter().find(|(sugar, _)| {
+                matches!(sugar.base_sugar, BaseSugar::Hexose(_))
+                    && sugar.substituents == [GlycanSubstituent::NAcetyl]
+            });
+            if let (Some((hex, _)), Some((hexnac, _))) = (hex, hexnac) {
+                options.push(vec![(hex.clone(), 1), (hexnac.clone(), 1)]);
+            }
+        }
+        options
+            .into_iter()
+            .flat_map(|option| {
+                let formula: MolecularFormula = option
+                    .iter()
+                    .map(|s| {
+                        s.0.formula_inner(SequencePosition::default(), peptidoform_index)
+                    })
+                    .sum();
                 Fragment::new(
-                    base - &formula,
+                    formula,
                     Charge::default(),
                     peptidoform_ion_index,
                     peptidoform_index,
-                    FragmentType::YComposition(composition.clone(), attachment),
+                    FragmentType::OxoniumComposition(option, attachment),
                 )
-                .with_charge_range(charge_carriers, model.glycan.other_charge_range)
+                .with_charge_range(charge_carriers, model.glycan.oxonium_charge_range)
                 .flat_map(|o| o.with_neutral_losses(&model.glycan.neutral_losses))
-            }));
-        }
+                .collect_vec()
+            })
+            .collect()
+    }
 
-        // Generate compositional diagnostic ions
-        for (sugar, _) in composition {
-            fragments.extend(
+    /// Generate the diagnostic ions for all monosaccharides in this composition
+    pub(crate) fn compositional_diagnostic_ions(
+        composition: &[(Self, isize)],
+        model: &Model,
+        peptidoform_ion_index: usize,
+        peptidoform_index: usize,
+        charge_carriers: &mut CachedCharge,
+        attachment: Option<(AminoAcid, usize)>,
+    ) -> Vec<Fragment> {
+        composition
+            .iter()
+            .flat_map(|(sugar, _)| {
                 sugar
                     .diagnostic_ions(
                         peptidoform_ion_index,
@@ -104,11 +259,10 @@ impl MonoSaccharide {
                     .into_iter()
                     .flat_map(|d| {
                         d.with_charge_range(charge_carriers, model.glycan.oxonium_charge_range)
-                    }),
-            );
-        }
-
-        fragments
+                    })
+                    .collect_vec()
+            })
+            .collect()
     }
 
     /// Get all unique combinations of monosaccharides within the given range of number of monosaccharides used
@@ -158,6 +312,29 @@ impl MonoSaccharide {
         result
     }
 
+    /// The part of this monosaccharide retained in a cross-ring fragment. The 0,2X fragment retains
+    /// C1 and C2 (including the substituent on C2) on the reducing side, the 2,4A fragment retains
+    /// C3 and C4 on the non-reducing side (which assumes the non-reducing branches are linked
+    /// through O3 or O4). Both include the usual hydrogen transfer from the X to the A fragment.
+    /// Only pyranose hexoses have cross-ring fragments defined.
+    pub(crate) fn cross_ring_part(&self, fragment: CrossRingFragment) -> Option<MolecularFormula> {
+        if !matches!(self.base_sugar, BaseSugar::Hexose(_)) || self.furanose {
+            return None;
+        }
+        Some(match fragment {
+            CrossRingFragment::X02 => {
+                if self.substituents.contains(&GlycanSubstituent::NAcetyl) {
+                    molecular_formula!(C 4 H 5 N 1 O 1)
+                } else if self.substituents.contains(&GlycanSubstituent::Amino) {
+                    molecular_formula!(C 2 H 3 N 1)
+                } else {
+                    molecular_formula!(C 2 H 2 O 1)
+                }
+            }
+            CrossRingFragment::A24 => molecular_formula!(C 2 H 4 O 2),
+        })
+    }
+
     /// Generate all uncharged diagnostic ions for this monosaccharide.
     /// According to: <https://doi.org/10.1016/j.trac.2018.09.007>.
     pub(crate) fn diagnostic_ions(
@@ -219,11 +396,39 @@ impl MonoSaccharide {
     }
 }
 
+/// All known monosaccharides (as recognised when parsing glycans) with their residue formula and
+/// the monoisotopic mass of that formula. Monosaccharides that are known under multiple names
+/// are only listed once.
+pub fn monosaccharide_table() -> Vec<(MonoSaccharide, MolecularFormula, Mass)> {
+    glycan_parse_list()
+        .iter()
+        .map(|(_, sugar)| sugar)
+        .unique()
+        .map(|sugar| {
+            let formula = sugar.formula();
+            let mass = formula.monoisotopic_mass();
+            (sugar.clone(), formula, mass)
+        })
+        .collect()
+}
+
 #[cfg(test)]
 #[allow(clippy::missing_panics_doc)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn monosaccharide_table() {
+        let table = super::monosaccharide_table();
+        let hexnac = table
+            .iter()
+            .find(|(sugar, _, _)| sugar.to_string() == "HexNAc")
+            .unwrap();
+        assert_eq!(hexnac.1, molecular_formula!(H 13 C 8 N 1 O 5));
+        assert!((hexnac.2.value - 203.0794).abs() < 1e-4);
+        assert!(table.len() < glycan_parse_list().len());
+    }
+
     #[test]
     fn pro_forma_compliance() {
         let cases = &[
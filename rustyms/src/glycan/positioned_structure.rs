@@ -4,7 +4,7 @@ use std::hash::Hash;
 use itertools::Itertools;
 use serde::{Deserialize, Serialize};
 
-use super::MonoSaccharide;
+use super::{CrossRingFragment, MonoSaccharide};
 use crate::{
     formula::{Chemical, MolecularFormula},
     fragment::{Fragment, FragmentType, GlycanBreakPos, GlycanPosition},
@@ -65,7 +65,11 @@ impl PositionedGlycanStructure {
                     })
                     .flat_map(|f| f.with_neutral_losses(&model.glycan.neutral_losses))
                     .collect_vec();
-                // Generate all Y fragments
+                // Generate all Y fragments, if attached to a peptide the fragments that retain
+                // nothing or only the first monosaccharide are labelled as Y0 and Y1
+                let sugar = self
+                    .sugar
+                    .formula_inner(SequencePosition::default(), peptidoform_index);
                 base_fragments.extend(
                     self.internal_break_points(peptidoform_index, attachment)
                         .iter()
@@ -74,6 +78,20 @@ impl PositionedGlycanStructure {
                                 && !bonds.iter().all(|b| matches!(b, GlycanBreakPos::End(_)))
                         })
                         .flat_map(move |(f, bonds)| {
+                            let ion = if attachment.is_some() && f.is_empty() {
+                                FragmentType::YCore(0, attachment)
+                            } else if attachment.is_some() && *f == sugar {
+                                FragmentType::YCore(1, attachment)
+                            } else {
+                                FragmentType::Y(
+                                    bonds
+                                        .iter()
+                                        .filter(|b| !matches!(b, GlycanBreakPos::End(_)))
+                                        .map(GlycanBreakPos::position)
+                                        .cloned()
+                                        .collect(),
+                                )
+                            };
                             full_formula.iter().map(move |full| {
                                 Fragment::new(
                                     full - self.formula_inner(
@@ -83,14 +101,7 @@ impl PositionedGlycanStructure {
                                     Charge::zero(),
                                     peptidoform_ion_index,
                                     peptidoform_index,
-                                    FragmentType::Y(
-                                        bonds
-                                            .iter()
-                                            .filter(|b| !matches!(b, GlycanBreakPos::End(_)))
-                                            .map(GlycanBreakPos::position)
-                                            .cloned()
-                                            .collect(),
-                                    ),
+                                    ion.clone(),
                                 )
                             })
                         })
@@ -99,6 +110,27 @@ impl PositionedGlycanStructure {
                         })
                         .flat_map(|f| f.with_neutral_losses(&model.glycan.neutral_losses)),
                 );
+                // Generate all cross-ring fragments
+                if model.glycan.cross_ring_fragments {
+                    base_fragments.extend(
+                        self.cross_ring_fragments(
+                            peptidoform_ion_index,
+                            peptidoform_index,
+                            full_formula,
+                            attachment,
+                        )
+                        .into_iter()
+                        .flat_map(|f| {
+                            let range = if matches!(f.ion, FragmentType::A(_, _)) {
+                                model.glycan.oxonium_charge_range
+                            } else {
+                                model.glycan.other_charge_range
+                            };
+                            f.with_charge_range(charge_carriers, range)
+                        })
+                        .flat_map(|f| f.with_neutral_losses(&model.glycan.neutral_losses)),
+                    );
+                }
                 // Generate all diagnostic ions
                 base_fragments.extend(
                     self.diagnostic_ions(peptidoform_ion_index, peptidoform_index, attachment)
@@ -137,6 +169,53 @@ impl PositionedGlycanStructure {
         output
     }
 
+    /// Generate the 0,2X and 2,4A cross-ring fragments for all positions, without charge and
+    /// neutral loss options. The X fragments retain the peptide and the glycan except for this
+    /// position and everything attached to it, the A fragments retain everything attached to this
+    /// position.
+    fn cross_ring_fragments(
+        &self,
+        peptidoform_ion_index: usize,
+        peptidoform_index: usize,
+        full_formula: &Multi<MolecularFormula>,
+        attachment: Option<(AminoAcid, usize)>,
+    ) -> Vec<Fragment> {
+        let own = self.formula_inner(SequencePosition::default(), peptidoform_index);
+        let sugar = self
+            .sugar
+            .formula_inner(SequencePosition::default(), peptidoform_index);
+        let mut fragments = Vec::new();
+        if let Some(part) = self.sugar.cross_ring_part(CrossRingFragment::X02) {
+            fragments.extend(full_formula.iter().map(|full| {
+                Fragment::new(
+                    full - &own + &part,
+                    Charge::zero(),
+                    peptidoform_ion_index,
+                    peptidoform_index,
+                    FragmentType::X(self.position(attachment), CrossRingFragment::X02.bonds()),
+                )
+            }));
+        }
+        if let Some(part) = self.sugar.cross_ring_part(CrossRingFragment::A24) {
+            fragments.push(Fragment::new(
+                &own - &sugar + &part,
+                Charge::zero(),
+                peptidoform_ion_index,
+                peptidoform_index,
+                FragmentType::A(self.position(attachment), CrossRingFragment::A24.bonds()),
+            ));
+        }
+        fragments.extend(self.branches.iter().flat_map(|b| {
+            b.cross_ring_fragments(
+                peptidoform_ion_index,
+                peptidoform_index,
+                full_formula,
+                attachment,
+            )
+        }));
+        fragments
+    }
+
     /// Generate all fragments without charge and neutral loss options
     fn oxonium_fragments(
         &self,
@@ -371,8 +371,8 @@ pub fn next_number<const ALLOW_SIGN: bool, const FLOATING_POINT: bool, Number: F
     };
     let end = match range.end_bound() {
         Bound::Unbounded => line.chars().count(),
-        Bound::Excluded(n) => n - 1,
-        Bound::Included(n) => *n,
+        Bound::Excluded(n) => *n,
+        Bound::Included(n) => n + 1,
     };
     let mut positive = true;
     let mut sign_set = false;
@@ -402,7 +402,7 @@ pub fn next_number<const ALLOW_SIGN: bool, const FLOATING_POINT: bool, Number: F
         .take_while(|(_, c)| {
             if c.is_ascii_digit() || (FLOATING_POINT && ".eE+-".contains(*c)) {
                 consumed += 1;
-                consumed < end - start
+                consumed <= end.saturating_sub(start)
             } else {
                 false
             }
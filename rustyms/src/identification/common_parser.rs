@@ -35,6 +35,38 @@ macro_rules! format_family {
             pub version: $version
         }
 
+        impl $format {
+            /// Check if all columns required for this version are present in the given (lowercase) header
+            #[allow(dead_code)] // Not used for formats with a provided header
+            fn has_columns(&self, header: &[String]) -> bool {
+                let present = |column: &str| header.iter().any(|h| h == column);
+                let optional: &[crate::identification::common_parser::OptionalColumn] = &[$(self.$oname,)*];
+                $(present(self.$rname) &&)* optional.iter().all(|column| match column {
+                    crate::identification::common_parser::OptionalColumn::Required(c) => present(c),
+                    _ => true,
+                })
+            }
+        }
+
+        impl $data {
+            /// Detect the version of this format based on the header line of a file. The separator
+            /// can be overridden (as done with `sep=` lines), otherwise the default separator for
+            /// this format is used. Formats without header line cannot be detected.
+            #[allow(dead_code)] // Not used for formats with a provided header
+            pub(crate) fn detect_version(header_line: &str, separator: Option<u8>) -> Option<&'static $format> {
+                let provided_header: Option<Vec<String>> = $header;
+                if provided_header.is_some() {
+                    return None;
+                }
+                let header = crate::csv::csv_separate(header_line, separator.unwrap_or($separator))
+                    .ok()?
+                    .into_iter()
+                    .map(|r| header_line[r].to_lowercase())
+                    .collect::<Vec<_>>();
+                $versions.into_iter().find(|format| format.has_columns(&header))
+            }
+        }
+
         impl IdentifiedPeptideSource for $data {
             type Source = CsvLine;
             type Format = $format;
@@ -71,7 +71,7 @@ fn pgpointnovo() {
     }
 }
 
-const DEEPNOVO_V0_0_1: &str = "scan	predicted_sequence	predicted_score	predicted_position_score
+pub(super) const DEEPNOVO_V0_0_1: &str = "scan	predicted_sequence	predicted_score	predicted_position_score
 14	V,A,P,E,I,P,V,Y,I,N,E,V,A,I,V,I,I,P,A,I,A,A,R	-1.53	-2.72,-0.55,-1.77,-0.85,-1.36,-0.46,-1.95,-0.89,-2.23,-1.98,-0.38,-1.17,-2.06,-1.45,-1.43,-2.26,-1.98,-1.47,-2.14,-1.91,-2.26,-1.89
 15	I,I,P,K,P,I,V,I,I,G,A,Nmod,G,T,T,V,I,V,G,M,P,A,G,A,K	-1.05	-3.45,-4.20,-2.48,-0.37,-3.40,-1.80,-1.82,-1.67,-0.16,-0.39,-1.86,-0.00,-0.01,-0.00,-0.00,-0.03,-0.12,-0.12,-0.31,-1.24,-0.55,-0.68,-0.45,-1.16
 2535	E,E,E,T,H,I,K,P,S,N,T,K,V,D,K	-0.42	-0.96,-0.10,-2.23,-0.04,-0.31,-0.06,-0.00,-0.03,-0.32,-1.52,-0.09,-0.30,-0.30,-0.06
@@ -92,7 +92,7 @@ const DEEPNOVO_V0_0_1: &str = "scan	predicted_sequence	predicted_score	predicted
 2559	I,S,H,V,S,S,G,G,G,A,S,E,V,I,I,V,G,A,D	-0.39	-0.03,-1.84,-0.93,-1.22,-0.48,-0.84,-0.00,-0.00,-0.01,-0.00,-0.00,-1.15,-0.40,-0.11,-0.00,-0.14,-0.14,-0.03
 2560	I,I,G,G,I,M,T,Y,G,D,F,N,Q,T,P,K	-0.59	-0.26,-1.40,-1.23,-0.65,-0.37,-0.11,-0.04,-0.49,-0.22,-1.55,-0.72,-0.66,-0.97,-0.57,-0.27";
 
-const PGPOINTNOVO_V1_0_6: &str = "feature_id	feature_area	predicted_sequence	predicted_score	predicted_position_score	precursor_mz	precursor_charge	protein_access_id	scan_list_middle	scan_list_original	predicted_score_max
+pub(super) const PGPOINTNOVO_V1_0_6: &str = "feature_id	feature_area	predicted_sequence	predicted_score	predicted_position_score	precursor_mz	precursor_charge	protein_access_id	scan_list_middle	scan_list_original	predicted_score_max
 F1:15	1.0	S,A,Q,E,S,S,S,S,S,S,S,L,L,L,V,L,L,L,L,L,K,K,K	-2.65	-2.29,-2.19,-2.25,-2.31,-2.31,-2.46,-2.45,-2.45,-2.44,-2.45,-2.45,-3.09,-3.13,-3.09,-2.20,-3.06,-3.09,-3.09,-3.09,-3.11,-3.93,-4.07,-0.07	1216.224487304688	2.0	DENOVO	F1:15	F1:15	-2.65
 F1:2538	1.0	T,K,P,E,S,A,G,P,G,N,T,P,Y,G,E,E,G,E,E,S,E,D,T,S,T,Y,R	-1.47	-2.49,-0.85,-0.44,-2.59,-1.56,-1.82,-2.12,-1.74,-1.68,-1.79,-0.75,-1.07,-1.94,-2.38,-2.27,-0.74,-2.58,-1.07,-0.83,-1.86,-1.83,-1.09,-0.23,-1.49,-0.40,-2.00,-0.07	722.81494140625	4.0	DENOVO	F1:2538	F1:2538	-1.47
 F1:2542	1.0	S,G,G,S,D,A,S,E,S,D,G,Y,T,E,P,V,K,T,Y,T,H,G,P,S,G,T,S,Q,P,A	-1.39	-2.43,-1.00,-1.54,-2.41,-0.66,-1.09,-2.54,-1.19,-2.21,-0.43,-0.32,-1.73,-0.88,-0.85,-2.35,-2.54,-2.14,-1.07,-0.43,-0.39,-0.16,-0.89,-1.83,-2.40,-1.98,-2.38,-1.40,-1.70,-0.51,-0.16	743.328918457031	4.0	DENOVO	F1:2542	F1:2542	-1.39
@@ -114,7 +114,7 @@ F1:14039	1.0				419.316162109375	2.0		F1:14039	F1:14039
 F1:14051	1.0	P,K,K,P,L,L,T,Q,L,K	-2.2	-0.00,-3.37,-4.03,-0.94,-2.76,-2.46,-2.31,-2.42,-2.55,-1.16	583.386840820313	2.0	DENOVO	F1:14051	F1:14051	-2.2
 F1:14056	1.0	L,T,P,A,S,G,G,A,T,T,Y,L,S,Q,E,Y,S,K	-1.76	-0.50,-0.94,-1.32,-1.77,-2.15,-2.37,-2.56,-1.95,-2.29,-2.81,-1.06,-1.10,-2.52,-2.12,-2.35,-1.03,-0.77,-2.10	937.463256835938	2.0	DENOVO	F1:14056	F1:14056	-1.76";
 
-const POINTNOVO_V0_0_1: &str = "feature_id	feature_area	predicted_sequence	predicted_score	predicted_position_score	precursor_mz	precursor_charge	protein_access_id	scan_list_middle	scan_list_original	predicted_score_max
+pub(super) const POINTNOVO_V0_0_1: &str = "feature_id	feature_area	predicted_sequence	predicted_score	predicted_position_score	precursor_mz	precursor_charge	protein_access_id	scan_list_middle	scan_list_original	predicted_score_max
 F1:14	1.0	K,K,K,K,A,L,L,G,L,P,A,L,S,G,L,G,G,E,E,E,H,K,K	-2.16	-0.00,-0.32,-2.52,-3.19,-3.02,-2.87,-2.69,-2.87,-2.25,-2.30,-3.06,-2.20,-2.60,-1.99,-2.69,-0.31,-2.15,-2.69,-2.62,-1.17,-1.68,-2.85,-1.69	1216.224487304688	2.0	DENOVO	F1:14	F1:14	-2.16
 F1:15	1.0	K,K,K,K,K,K,K,R,L,L,L,S,G,G,S,E,D,G,E,S,S,K	-2.54	-0.00,-3.10,-3.20,-3.22,-3.22,-3.22,-3.22,-2.96,-2.84,-2.84,-2.84,-2.68,-2.73,-2.74,-2.68,-2.44,-1.39,-2.75,-2.48,-0.93,-2.74,-1.69	1216.224487304688	2.0	DENOVO	F1:15	F1:15	-2.54
 F1:2535	1.0	C(Carbamidomethylation),D,L,V,N,H,K,P,S,N,T,K,V,D,K	-0.28	-1.53,-0.30,-0.95,-0.08,-0.10,-0.05,-0.01,-0.01,-0.16,-0.40,-0.09,-0.09,-0.11,-0.39,-0.00	439.477081298828	4.0	DENOVO	F1:2535	F1:2535	-0.28
@@ -136,7 +136,7 @@ F1:2559	1.0	L,C(Carbamidomethylation),D,V,N,H,K,P,S,N,T,K,V,D,K	-0.59	-0.71,-1.2
 F1:2560	1.0	G,T,D,N,V,N,H,K,R,G,K,S,A,Q,D,K	-1.11	-0.01,-0.75,-2.93,-1.00,-1.65,-1.23,-0.54,-1.03,-1.09,-0.81,-0.60,-0.89,-1.61,-2.78,-0.69,-0.22	585.632873535156	3.0	DENOVO	F1:2560	F1:2560	-1.11
 F1:2659	1.0	H,G,G,G,G,Y,S,D,P,D,E,E,T,T,G,M(Oxidation),C(Carbamidomethylation),D,K,N,P,A,C(Carbamidomethylation),Y,W	-1.79	-0.00,-1.47,-2.56,-2.58,-2.81,-1.59,-2.01,-1.74,-1.34,-2.20,-2.61,-2.40,-0.89,-2.46,-0.40,-1.85,-1.51,-1.83,-2.75,-0.88,-2.13,-2.09,-0.60,-1.31,-2.61	705.77099609375	4.0	DENOVO	F1:2659	F1:2659	-1.79";
 
-const BIATNOVO_V0_1: &str = "feature_id	feature_area	predicted_sequence	predicted_score	predicted_position_score	precursor_mz	precursor_charge	protein_access_id	scan_list_middle	scan_list_original	predicted_score_max
+pub(super) const BIATNOVO_V0_1: &str = "feature_id	feature_area	predicted_sequence	predicted_score	predicted_position_score	precursor_mz	precursor_charge	protein_access_id	scan_list_middle	scan_list_original	predicted_score_max
 F1:14	1.0	Q(Deamidation),W,W,W,Q,L,G,L,V,G,L,V,L,V,L,V,L,L,Y,E	-5.75	-6.29,-5.10,-5.09,-5.82,-5.88,-4.68,-6.96,-5.82,-4.32,-7.10,-6.12,-4.90,-7.30,-5.04,-7.19,-5.08,-5.17,-5.51,-6.77,-4.75	1216.224487304688	2.0	DENOVO	F1:14	F1:14	-5.75
 F1:15	1.0	K,W,W,W,W,W,R,E,K,N,P,E,N,P,E,K,E	-5.15	-5.84,-5.48,-4.69,-4.79,-4.85,-5.54,-5.78,-2.96,-6.02,-5.10,-5.64,-4.75,-5.65,-5.56,-3.40,-6.13,-5.45	1216.224487304688	2.0	DENOVO	F1:15	F1:15	-5.15
 F1:2535	1.0	V,G,N,P,E,K,E,K,N,P,E,K,E,K,E	-5.34	-5.97,-5.65,-4.93,-6.26,-3.66,-5.94,-4.91,-6.10,-5.47,-5.29,-3.16,-6.08,-4.76,-6.27,-5.72	439.477081298828	4.0	DENOVO	F1:2535	F1:2535	-5.34
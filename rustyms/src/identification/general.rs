@@ -1,20 +1,30 @@
-use std::path::Path;
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    fs::File,
+    io::{BufRead, BufReader},
+    path::Path,
+};
+
+use flate2::bufread::GzDecoder;
 
 use super::{
     error::{Context, CustomError},
     ontologies::CustomDatabase,
-    DeepNovoFamilyData, FastaData, IdentifiedPeptide, IdentifiedPeptideIter,
-    IdentifiedPeptideSource, InstaNovoData, MSFraggerData, MZTabData, MaxQuantData, NovoBData,
-    NovorData, OpairData, PLGSData, PLinkData, PeaksData, PepNetData, PowerNovoData, SageData,
-    SpectrumSequenceListData,
+    CompoundPeptidoformIon, DeepNovoFamilyData, FastaData, GeneralCsvColumns, GeneralCsvData,
+    IdentifiedPeptide, IdentifiedPeptideIter, IdentifiedPeptideSource, InstaNovoData,
+    MSFraggerData, MZTabData, MaxQuantData, NovoBData, NovorData, OpairData, PLGSData, PLinkData,
+    PeaksData, PepNetData, PowerNovoData, SageData, SpectrumIds, SpectrumSequenceListData,
 };
+use crate::helper_functions::check_extension;
 
 // TODO:
 // * Merge multiple annotations for the same spectrum (e.g. all candidates peaks export, take care not to lose info on chimeric spectra)
 // * Merge identical (or similar?) peptide sequences (for faster processing)
 
 /// Open the selected path and automatically determine the file type. It will uncompress gzipped
-/// files automatically.
+/// files automatically. CSV and TSV files that are not recognised as any of the known formats
+/// are read as a best effort with automatically detected columns, see [`GeneralCsvData`], in
+/// which case any row that could not be read is returned as an error.
 ///
 /// # Errors
 /// It errors if the file type could not be determined or if opening the file errors.
@@ -58,13 +68,20 @@ pub fn open_identified_peptides_file<'a>(
                 PLGSData::parse_file(path, custom_database)
                     .map(IdentifiedPeptideIter::into_box)
                     .map_err(|ple| (pe, ne, ie, le, pne, ple))
-            }).map_err(|(pe, ne, ie, le, pne, ple)| {
+            }).or_else(|(pe, ne, ie, le, pne, ple)| {
+                GeneralCsvData::parse_file(path, b',', custom_database)
+                    .map(|peptides| {
+                        Box::new(peptides.map(|p| p.map(Into::into)))
+                            as Box<dyn Iterator<Item = Result<IdentifiedPeptide, CustomError>> + 'a>
+                    })
+                    .map_err(|ge| (pe, ne, ie, le, pne, ple, ge))
+            }).map_err(|(pe, ne, ie, le, pne, ple, ge)| {
                 CustomError::error(
                     "Unknown file format",
-                    "Could not be recognised as either a Peaks, Novor, InstaNovo, pLink, PowerNovo, or PLGS file",
+                    "Could not be recognised as either a Peaks, Novor, InstaNovo, pLink, PowerNovo, or PLGS file, nor could the columns be detected",
                     Context::show(path.to_string_lossy()),
                 )
-                .with_underlying_errors(vec![pe, ne, ie, le, pne, ple])
+                .with_underlying_errors(vec![pe, ne, ie, le, pne, ple, ge])
             }),
         Some("tsv") => MSFraggerData::parse_file(path, custom_database)
             .map(IdentifiedPeptideIter::into_box)
@@ -78,13 +95,21 @@ pub fn open_identified_peptides_file<'a>(
                     .map(IdentifiedPeptideIter::into_box)
                     .map_err(|pe| (me, se, pe))
             })
-            .map_err(|(me, se, pe)| {
+            .or_else(|(me, se, pe)| {
+                GeneralCsvData::parse_file(path, b'\t', custom_database)
+                    .map(|peptides| {
+                        Box::new(peptides.map(|p| p.map(Into::into)))
+                            as Box<dyn Iterator<Item = Result<IdentifiedPeptide, CustomError>> + 'a>
+                    })
+                    .map_err(|ge| (me, se, pe, ge))
+            })
+            .map_err(|(me, se, pe, ge)| {
                 CustomError::error(
                     "Unknown file format",
-                    "Could not be recognised a MSFragger, PepNet or Sage file",
+                    "Could not be recognised a MSFragger, PepNet or Sage file, nor could the columns be detected",
                     Context::show(path.to_string_lossy()),
                 )
-                .with_underlying_errors(vec![me, se, pe])
+                .with_underlying_errors(vec![me, se, pe, ge])
             }),
         Some("psmtsv") => {
             OpairData::parse_file(path, custom_database).map(IdentifiedPeptideIter::into_box)
@@ -128,14 +153,414 @@ pub fn open_identified_peptides_file<'a>(
     }
 }
 
+/// An iterator adapter for iterators over identified peptides, as returned by
+/// [`open_identified_peptides_file`], to filter the peptides on the fly.
+pub trait IdentifiedPeptideIterExt:
+    Iterator<Item = Result<IdentifiedPeptide, CustomError>> + Sized
+{
+    /// Only keep the identified peptides with a (normalised) score of at least the given
+    /// minimum, see [`IdentifiedPeptide::score`]. Peptides without a score are dropped. Errors
+    /// are passed through so they can still be handled by the caller.
+    fn filter_by_score(self, min_normalized: f64) -> FilterByScore<Self> {
+        FilterByScore {
+            iter: self,
+            min_normalized,
+        }
+    }
+}
+
+impl<I: Iterator<Item = Result<IdentifiedPeptide, CustomError>>> IdentifiedPeptideIterExt for I {}
+
+/// An iterator that only returns identified peptides above a score threshold, see
+/// [`IdentifiedPeptideIterExt::filter_by_score`].
+#[derive(Clone, Debug)]
+pub struct FilterByScore<I> {
+    iter: I,
+    min_normalized: f64,
+}
+
+impl<I: Iterator<Item = Result<IdentifiedPeptide, CustomError>>> Iterator for FilterByScore<I> {
+    type Item = Result<IdentifiedPeptide, CustomError>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.find(|peptide| {
+            peptide.as_ref().map_or(true, |p| {
+                p.score.is_some_and(|score| score >= self.min_normalized)
+            })
+        })
+    }
+}
+
+/// All supported identified peptide file formats, see [`detect_identification_format`].
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum IdentificationFormat {
+    /// DeepNovo, PointNovo, PGPointNovo, or BiatNovo
+    DeepNovoFamily,
+    /// Fasta
+    Fasta,
+    /// A CSV/TSV file of unknown format with automatically detected columns
+    GeneralCsv,
+    /// MaxQuant
+    MaxQuant,
+    /// InstaNovo
+    InstaNovo,
+    /// MSFragger
+    MSFragger,
+    /// mzTab
+    MZTab,
+    /// NovoB
+    NovoB,
+    /// Novor
+    Novor,
+    /// OPair
+    Opair,
+    /// Peaks
+    Peaks,
+    /// PepNet
+    PepNet,
+    /// PLGS
+    PLGS,
+    /// pLink
+    PLink,
+    /// PowerNovo
+    PowerNovo,
+    /// Sage
+    Sage,
+    /// SpectrumSequenceList
+    SpectrumSequenceList,
+}
+
+/// Open the selected path and detect the file format based on its contents, see
+/// [`detect_identification_format`]. It will uncompress gzipped files automatically.
+///
+/// # Errors
+/// If the file could not be opened.
+pub fn detect_identification_format_file(
+    path: impl AsRef<Path>,
+) -> Result<Option<IdentificationFormat>, CustomError> {
+    let path = path.as_ref();
+    let file = File::open(path).map_err(|e| {
+        CustomError::error(
+            "Could not open file",
+            e,
+            Context::show(path.to_string_lossy()),
+        )
+    })?;
+    if check_extension(path, "gz") {
+        Ok(detect_identification_format(GzDecoder::new(
+            BufReader::new(file),
+        )))
+    } else {
+        Ok(detect_identification_format(file))
+    }
+}
+
+/// Detect the format of an identified peptides file based on its contents, regardless of the
+/// extension. This looks at the header line for the CSV/TSV based formats, at the line prefixes
+/// for mzTab and Fasta files, and tries to parse the first line for formats without a header
+/// line (NovoB). As a last resort any header line with a recognisable peptide sequence column is
+/// detected as [`IdentificationFormat::GeneralCsv`]. Returns `None` if the format could not be
+/// recognised.
+pub fn detect_identification_format(reader: impl std::io::Read) -> Option<IdentificationFormat> {
+    type Detector = fn(&str, Option<u8>) -> bool;
+    let mut lines = BufReader::new(reader)
+        .lines()
+        .map_while(Result::ok)
+        .filter(|line| !line.trim().is_empty());
+    let mut header = lines.next()?;
+    let mut separator = None;
+    if let Some(sep) = header.strip_prefix("sep=") {
+        separator = Some(u8::try_from(sep.chars().next()?).ok()?);
+        header = lines.next()?;
+    }
+
+    if header.starts_with('>') {
+        return Some(IdentificationFormat::Fasta);
+    }
+    if ["MTD\t", "COM\t", "PRH\t", "PEH\t", "PSH\t", "SMH\t"]
+        .iter()
+        .any(|prefix| header.starts_with(prefix))
+    {
+        return Some(IdentificationFormat::MZTab);
+    }
+
+    let header = header.as_str();
+    // Only check the formats until the first one matches
+    let detectors: [(Detector, IdentificationFormat); 13] = [
+        (
+            |h, s| PeaksData::detect_version(h, s).is_some(),
+            IdentificationFormat::Peaks,
+        ),
+        (
+            |h, s| NovorData::detect_version(h, s).is_some(),
+            IdentificationFormat::Novor,
+        ),
+        (
+            |h, s| InstaNovoData::detect_version(h, s).is_some(),
+            IdentificationFormat::InstaNovo,
+        ),
+        (
+            |h, s| PLinkData::detect_version(h, s).is_some(),
+            IdentificationFormat::PLink,
+        ),
+        (
+            |h, s| PowerNovoData::detect_version(h, s).is_some(),
+            IdentificationFormat::PowerNovo,
+        ),
+        (
+            |h, s| PLGSData::detect_version(h, s).is_some(),
+            IdentificationFormat::PLGS,
+        ),
+        (
+            |h, s| MSFraggerData::detect_version(h, s).is_some(),
+            IdentificationFormat::MSFragger,
+        ),
+        (
+            |h, s| SageData::detect_version(h, s).is_some(),
+            IdentificationFormat::Sage,
+        ),
+        (
+            |h, s| PepNetData::detect_version(h, s).is_some(),
+            IdentificationFormat::PepNet,
+        ),
+        (
+            |h, s| OpairData::detect_version(h, s).is_some(),
+            IdentificationFormat::Opair,
+        ),
+        (
+            |h, s| MaxQuantData::detect_version(h, s).is_some(),
+            IdentificationFormat::MaxQuant,
+        ),
+        (
+            |h, s| DeepNovoFamilyData::detect_version(h, s).is_some(),
+            IdentificationFormat::DeepNovoFamily,
+        ),
+        (
+            |h, s| SpectrumSequenceListData::detect_version(h, s).is_some(),
+            IdentificationFormat::SpectrumSequenceList,
+        ),
+    ];
+    detectors
+        .into_iter()
+        .find_map(|(detect, format)| detect(header, separator).then_some(format))
+        .or_else(|| {
+            NovoBData::parse_reader(header.as_bytes(), None)
+                .ok()?
+                .next()?
+                .ok()
+                .map(|_| IdentificationFormat::NovoB)
+        })
+        .or_else(|| {
+            let separator =
+                separator.unwrap_or_else(|| if header.contains('\t') { b'\t' } else { b',' });
+            let columns = crate::csv::csv_separate(header, separator).ok()?;
+            GeneralCsvColumns::detect(&columns.into_iter().map(|c| &header[c]).collect::<Vec<_>>())
+                .map(|_| IdentificationFormat::GeneralCsv)
+        })
+}
+
+/// The key that determines if two identified peptides are duplicates, see [`merge_identifications`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum DedupKey {
+    /// Identified peptides are duplicates if they are identified from the same spectra
+    Scan,
+    /// Identified peptides are duplicates if they are identified from the same spectra with the same peptidoform
+    #[default]
+    ScanAndSequence,
+}
+
+/// Merge the identified peptides from multiple sources into a single list, removing duplicates
+/// as defined by the given key. Of each group of duplicates only the peptide with the highest
+/// score is kept (peptides without a score are ranked below any peptide with a score). Peptides
+/// without any spectrum reference are never seen as duplicates. The order of first occurrence
+/// is retained.
+pub fn merge_identifications(
+    files: &[Vec<IdentifiedPeptide>],
+    dedup_key: DedupKey,
+) -> Vec<IdentifiedPeptide> {
+    let mut output: Vec<IdentifiedPeptide> = Vec::new();
+    let mut seen: HashMap<(SpectrumIds, Option<CompoundPeptidoformIon>), usize> = HashMap::new();
+    for peptide in files.iter().flatten() {
+        let scans = peptide.scans();
+        if scans == SpectrumIds::None {
+            output.push(peptide.clone());
+            continue;
+        }
+        let sequence = match dedup_key {
+            DedupKey::Scan => None,
+            DedupKey::ScanAndSequence => peptide
+                .peptide()
+                .map(|p| p.compound_peptidoform().into_owned()),
+        };
+        match seen.entry((scans, sequence)) {
+            Entry::Occupied(entry) => {
+                let existing = &mut output[*entry.get()];
+                if peptide.score.unwrap_or(f64::NEG_INFINITY)
+                    > existing.score.unwrap_or(f64::NEG_INFINITY)
+                {
+                    *existing = peptide.clone();
+                }
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(output.len());
+                output.push(peptide.clone());
+            }
+        }
+    }
+    output
+}
+
 #[allow(clippy::missing_panics_doc)]
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::identification::{test_format, MSFraggerVersion, SageVersion};
+    use crate::identification::{test_format, MSFraggerVersion, MetaData, SageVersion};
+    use crate::Peptidoform;
     use std::fs::File;
     use std::io::BufReader;
 
+    #[test]
+    fn detect_format() {
+        assert_eq!(
+            detect_identification_format(&b">sp|P01857|IGHG1_HUMAN\nASTKGPSVFPLAPSSK"[..]),
+            Some(IdentificationFormat::Fasta)
+        );
+        assert_eq!(detect_identification_format(&b""[..]), None);
+        assert_eq!(
+            detect_identification_format(&b"unknown,header\n1,2"[..]),
+            None
+        );
+        assert_eq!(
+            detect_identification_format_file("src/identification/test_files/sage_v0_14.tsv")
+                .unwrap(),
+            Some(IdentificationFormat::Sage)
+        );
+        assert_eq!(
+            detect_identification_format_file("src/identification/test_files/msfragger_v21.tsv")
+                .unwrap(),
+            Some(IdentificationFormat::MSFragger)
+        );
+    }
+
+    #[test]
+    fn detect_format_examples() {
+        use crate::identification::{
+            deepnovofamily_tests, general_csv_tests, instanovo_tests, maxquant_tests,
+            msfragger_tests, mztab_test, novob_tests, novor_tests, opair_tests, peaks_tests,
+            pepnet_tests, plgs_tests, plink_tests, powernovo_tests, sage_tests, ssl_tests,
+        };
+        let examples: &[(IdentificationFormat, &[&str])] = &[
+            (
+                IdentificationFormat::DeepNovoFamily,
+                &[
+                    deepnovofamily_tests::DEEPNOVO_V0_0_1,
+                    deepnovofamily_tests::PGPOINTNOVO_V1_0_6,
+                    deepnovofamily_tests::POINTNOVO_V0_0_1,
+                    deepnovofamily_tests::BIATNOVO_V0_1,
+                ],
+            ),
+            (
+                IdentificationFormat::GeneralCsv,
+                &[general_csv_tests::GENERAL_CSV],
+            ),
+            (
+                IdentificationFormat::InstaNovo,
+                &[instanovo_tests::INSTANOVO_V1_0_0],
+            ),
+            (
+                IdentificationFormat::MaxQuant,
+                &[
+                    maxquant_tests::MAXQUANT_MSMS,
+                    maxquant_tests::MAXQUANT_MSMS_SCANS,
+                    maxquant_tests::MAXQUANT_NOVO_MSMS_SCANS,
+                    maxquant_tests::MAXQUANT_NOVO_MSMS_SCANS_NEW,
+                    maxquant_tests::MAXQUANT_SILAC,
+                ],
+            ),
+            (
+                IdentificationFormat::MSFragger,
+                &[
+                    msfragger_tests::DATA_V21_MANUAL,
+                    msfragger_tests::DATA_V21,
+                    msfragger_tests::DATA_V22,
+                ],
+            ),
+            (
+                IdentificationFormat::MZTab,
+                &[
+                    mztab_test::PRIDE_EXP_EXCERPT_AC_1643,
+                    mztab_test::PRIDE_EXP_EXCERPT_AC_16649,
+                    mztab_test::SILAC_CQI,
+                    mztab_test::ITRAQ_CQI,
+                    mztab_test::ITRAQ_SQI,
+                    mztab_test::LABELFREE_CQI,
+                    mztab_test::LABELFREE_SQI,
+                    mztab_test::CASANOVO_V3_2_0_A,
+                    mztab_test::CASANOVO_V3_2_0_B,
+                    mztab_test::CASANOVO_V4_2_1,
+                    mztab_test::CONTRANOVO_V1_0_0,
+                ],
+            ),
+            (IdentificationFormat::NovoB, &[novob_tests::NOVOB_V0_0_1]),
+            (
+                IdentificationFormat::Novor,
+                &[
+                    novor_tests::DATA_OLD_DENOVO,
+                    novor_tests::DATA_NEW_DENOVO,
+                    novor_tests::DATA_NEW_PSM,
+                ],
+            ),
+            (IdentificationFormat::Opair, &[opair_tests::DATA]),
+            (
+                IdentificationFormat::Peaks,
+                &[
+                    peaks_tests::DATA_AB,
+                    peaks_tests::DATA_X,
+                    peaks_tests::DATA_X_PATCHED_SEP,
+                    peaks_tests::DATA_X_PATCHED,
+                    peaks_tests::DATA_XPLUS,
+                    peaks_tests::DATA_11,
+                    peaks_tests::DATA_11_FEATURES,
+                    peaks_tests::DATA_11_ALL_CANDIDATES,
+                    peaks_tests::DATA_11_CUSTOM_MODIFICATION,
+                    peaks_tests::DATA_12,
+                    peaks_tests::DATA_X_PLUS_SEP,
+                    peaks_tests::DATA_DB_PEPTIDE,
+                    peaks_tests::DATA_DB_PSM,
+                    peaks_tests::DATA_DB_PROTEIN_PEPTIDE,
+                ],
+            ),
+            (IdentificationFormat::PepNet, &[pepnet_tests::PEPNET_V1_0]),
+            (
+                IdentificationFormat::PLGS,
+                &[plgs_tests::DATA_FRAGMENT, plgs_tests::DATA_PEPTIDE],
+            ),
+            (IdentificationFormat::PLink, &[plink_tests::DATA_V2_3]),
+            (
+                IdentificationFormat::PowerNovo,
+                &[powernovo_tests::POWERNOVO_V1_0_1],
+            ),
+            (IdentificationFormat::Sage, &[sage_tests::DATA]),
+            (
+                IdentificationFormat::SpectrumSequenceList,
+                &[
+                    ssl_tests::CASCADIA_V0_0_5,
+                    ssl_tests::SMALL_MOLECULES_EXAMPLE,
+                    ssl_tests::PEPTIDE_EXAMPLE,
+                ],
+            ),
+        ];
+        for (format, data) in examples {
+            for (index, data) in data.iter().enumerate() {
+                assert_eq!(
+                    detect_identification_format(data.as_bytes()),
+                    Some(*format),
+                    "example {index} of {format:?}"
+                );
+            }
+        }
+    }
+
     #[test]
     fn open_sage() {
         match test_format::<SageData>(
@@ -169,4 +594,63 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn filter_by_score() {
+        let path = "src/identification/test_files/sage_v0_14.tsv";
+        let scores = open_identified_peptides_file(path, None)
+            .unwrap()
+            .map(|p| p.unwrap().score.unwrap())
+            .collect::<Vec<_>>();
+        let threshold = (scores.iter().copied().fold(f64::INFINITY, f64::min)
+            + scores.iter().copied().fold(f64::NEG_INFINITY, f64::max))
+            / 2.0;
+        let expected = scores.iter().filter(|s| **s >= threshold).count();
+        let filtered = open_identified_peptides_file(path, None)
+            .unwrap()
+            .filter_by_score(threshold)
+            .map(Result::unwrap)
+            .collect::<Vec<_>>();
+        assert!(expected > 0 && expected < scores.len());
+        assert_eq!(filtered.len(), expected);
+        assert!(filtered.iter().all(|p| p.score.unwrap() >= threshold));
+        assert_eq!(
+            open_identified_peptides_file(path, None)
+                .unwrap()
+                .filter_by_score(-1.0)
+                .count(),
+            scores.len()
+        );
+    }
+
+    #[test]
+    fn merge() {
+        let read = || {
+            SageData::parse_reader(
+                BufReader::new(File::open("src/identification/test_files/sage_v0_14.tsv").unwrap()),
+                None,
+            )
+            .unwrap()
+            .map(|p| IdentifiedPeptide::from(p.unwrap()))
+            .collect::<Vec<_>>()
+        };
+        let first = read();
+        let mut second = read()[..10].to_vec();
+        second[0].score = Some(1.0);
+        if let MetaData::Sage(SageData { peptide, .. }) = &mut second[1].metadata {
+            *peptide = Peptidoform::pro_forma("PEPTIDE", None)
+                .unwrap()
+                .into_semi_ambiguous()
+                .unwrap();
+        }
+
+        let merged = merge_identifications(&[first.clone(), second.clone()], DedupKey::Scan);
+        assert_eq!(merged.len(), first.len());
+        assert_eq!(merged[0].score, Some(1.0));
+
+        let merged = merge_identifications(&[first.clone(), second], DedupKey::ScanAndSequence);
+        assert_eq!(merged.len(), first.len() + 1);
+        assert_eq!(merged[0].score, Some(1.0));
+        assert_eq!(merged[1], first[1]);
+    }
 }
@@ -0,0 +1,239 @@
+use std::{fs::File, io::BufReader, path::Path};
+
+use flate2::bufread::GzDecoder;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    csv::{parse_csv_raw, CsvLine},
+    error::{Context, CustomError},
+    helper_functions::check_extension,
+    identification::{IdentifiedPeptide, MetaData, SpectrumId},
+    ontologies::CustomDatabase,
+    peptidoform::{SemiAmbiguous, SloppyParsingParameters},
+    system::usize::Charge,
+    Peptidoform,
+};
+
+/// The (normalised) column names recognised as the peptide column, in order of preference
+const SEQUENCE_COLUMNS: &[&str] = &[
+    "proforma",
+    "modifiedsequence",
+    "modifiedpeptide",
+    "peptidesequence",
+    "sequence",
+    "peptide",
+    "denovo",
+];
+/// The (normalised) column names recognised as the scan column, in order of preference
+const SCAN_COLUMNS: &[&str] = &[
+    "scan",
+    "scans",
+    "scannumber",
+    "scanid",
+    "scanindex",
+    "spectrum",
+    "spectrumid",
+];
+/// The (normalised) column names recognised as the charge column, in order of preference
+const CHARGE_COLUMNS: &[&str] = &["charge", "z", "precursorcharge"];
+/// The (normalised) column names recognised as the score column, in order of preference
+const SCORE_COLUMNS: &[&str] = &["score"];
+
+/// The columns as detected in a general CSV file, see [`GeneralCsvData`]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default, Serialize, Deserialize)]
+pub struct GeneralCsvColumns {
+    /// The index of the peptide sequence column
+    pub sequence: usize,
+    /// The index of the scan column, if present
+    pub scan: Option<usize>,
+    /// The index of the charge column, if present
+    pub charge: Option<usize>,
+    /// The index of the score column, if present
+    pub score: Option<usize>,
+}
+
+impl GeneralCsvColumns {
+    /// Detect the columns based on the headers of a file. Headers are matched case insensitively
+    /// while ignoring any non alphanumeric characters, so `Peptide Sequence`, `peptide_sequence`,
+    /// and `PeptideSequence` are all recognised. If there is no exact match, the first header
+    /// containing any of the names is used. Returns `None` if no sequence column could be found.
+    pub fn detect(headers: &[impl AsRef<str>]) -> Option<Self> {
+        let normalised: Vec<String> = headers
+            .iter()
+            .map(|h| {
+                h.as_ref()
+                    .chars()
+                    .filter(char::is_ascii_alphanumeric)
+                    .map(|c| c.to_ascii_lowercase())
+                    .collect()
+            })
+            .collect();
+        let find = |names: &[&str]| {
+            names
+                .iter()
+                .find_map(|name| normalised.iter().position(|h| h == name))
+                .or_else(|| {
+                    names
+                        .iter()
+                        .filter(|name| name.len() > 1)
+                        .find_map(|name| normalised.iter().position(|h| h.contains(name)))
+                })
+        };
+        Some(Self {
+            sequence: find(SEQUENCE_COLUMNS)?,
+            scan: find(SCAN_COLUMNS),
+            charge: find(CHARGE_COLUMNS),
+            score: find(SCORE_COLUMNS),
+        })
+    }
+}
+
+/// The data from a CSV/TSV file in an unknown format, where the columns were detected based on
+/// the headers. This is used as a best effort fallback for files that could not be recognised
+/// as any of the known formats, see [`GeneralCsvColumns::detect`].
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct GeneralCsvData {
+    /// The peptide
+    pub peptide: Peptidoform<SemiAmbiguous>,
+    /// The scan, as spectrum index if it is a number otherwise as native id
+    pub scan: Option<SpectrumId>,
+    /// The charge
+    pub z: Option<Charge>,
+    /// The score, as found in the file
+    pub score: Option<f64>,
+    /// The line index in the original file (0 based)
+    pub line_index: usize,
+}
+
+impl GeneralCsvData {
+    /// Parse a CSV/TSV file with automatic column detection, gzipped files are uncompressed
+    /// automatically. Any row that could not be parsed is returned as an error, while the
+    /// other rows can still be used.
+    /// # Errors
+    /// If the file could not be opened or if no sequence column could be detected in the header.
+    pub fn parse_file<'a>(
+        path: impl AsRef<Path>,
+        separator: u8,
+        custom_database: Option<&'a CustomDatabase>,
+    ) -> Result<Box<dyn Iterator<Item = Result<Self, CustomError>> + 'a>, CustomError> {
+        let path = path.as_ref();
+        let file = File::open(path).map_err(|e| {
+            CustomError::error(
+                "Could not open file",
+                e,
+                Context::show(path.to_string_lossy()),
+            )
+        })?;
+        if check_extension(path, "gz") {
+            Self::parse_reader(
+                GzDecoder::new(BufReader::new(file)),
+                separator,
+                custom_database,
+            )
+        } else {
+            Self::parse_reader(file, separator, custom_database)
+        }
+    }
+
+    /// Parse a CSV/TSV reader with automatic column detection. Any row that could not be parsed
+    /// is returned as an error, while the other rows can still be used.
+    /// # Errors
+    /// If the header could not be read or if no sequence column could be detected in the header.
+    pub fn parse_reader<'a>(
+        reader: impl std::io::Read + 'a,
+        separator: u8,
+        custom_database: Option<&'a CustomDatabase>,
+    ) -> Result<Box<dyn Iterator<Item = Result<Self, CustomError>> + 'a>, CustomError> {
+        let lines = parse_csv_raw(reader, separator, None)?;
+        let columns = GeneralCsvColumns::detect(lines.headers()).ok_or_else(|| {
+            CustomError::error(
+                "Could not detect sequence column",
+                format!(
+                    "None of the columns ({}) could be recognised as a peptide sequence column",
+                    lines.headers().join(", ")
+                ),
+                Context::none(),
+            )
+        })?;
+        Ok(Box::new(lines.map(move |line| {
+            line.and_then(|line| Self::parse_line(&line, columns, custom_database))
+        })))
+    }
+
+    /// Parse a single line with the given columns
+    /// # Errors
+    /// If any of the detected columns contains invalid data.
+    pub fn parse_line(
+        line: &CsvLine,
+        columns: GeneralCsvColumns,
+        custom_database: Option<&CustomDatabase>,
+    ) -> Result<Self, CustomError> {
+        let peptide = Peptidoform::sloppy_pro_forma(
+            line.line(),
+            line.range(columns.sequence).clone(),
+            custom_database,
+            &SloppyParsingParameters::default(),
+        )?;
+        let scan = columns
+            .scan
+            .map(|c| line[c].trim())
+            .filter(|s| !s.is_empty() && *s != "-")
+            .map(|s| {
+                s.parse::<usize>()
+                    .map_or_else(|_| SpectrumId::Native(s.to_string()), SpectrumId::Index)
+            });
+        let z = columns
+            .charge
+            .filter(|c| {
+                let text = line[*c].trim();
+                !text.is_empty() && text != "-"
+            })
+            .map(|c| {
+                line[c]
+                    .trim()
+                    .trim_end_matches('+')
+                    .parse::<usize>()
+                    .map(Charge::new::<crate::system::charge::e>)
+                    .map_err(|_| {
+                        CustomError::error(
+                            "Invalid charge",
+                            "The charge should be a positive integer, optionally followed by '+'",
+                            line.column_context(c),
+                        )
+                    })
+            })
+            .transpose()?;
+        let score = columns
+            .score
+            .map(|c| {
+                line.parse_column_or_empty::<f64>(
+                    c,
+                    &CustomError::error(
+                        "Invalid score",
+                        "The score should be a number",
+                        Context::none(),
+                    ),
+                )
+            })
+            .transpose()?
+            .flatten();
+        Ok(Self {
+            peptide,
+            scan,
+            z,
+            score,
+            line_index: line.line_index(),
+        })
+    }
+}
+
+impl From<GeneralCsvData> for IdentifiedPeptide {
+    fn from(value: GeneralCsvData) -> Self {
+        Self {
+            // The scale of the score is unknown, so only use it if it already fits in the normalised range
+            score: value.score.filter(|s| (-1.0..=1.0).contains(s)),
+            local_confidence: None,
+            metadata: MetaData::GeneralCsv(value),
+        }
+    }
+}
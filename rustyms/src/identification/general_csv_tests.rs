@@ -0,0 +1,63 @@
+#![allow(clippy::missing_panics_doc)]
+use crate::identification::{
+    test_identified_peptide, GeneralCsvColumns, GeneralCsvData, IdentifiedPeptide, SpectrumId,
+    SpectrumIds,
+};
+
+#[test]
+fn detect_columns() {
+    assert_eq!(
+        GeneralCsvColumns::detect(&["Scan Nr", "Peptide_Sequence", "Z", "Best score"]),
+        Some(GeneralCsvColumns {
+            sequence: 1,
+            scan: Some(0),
+            charge: Some(2),
+            score: Some(3),
+        })
+    );
+    assert_eq!(
+        GeneralCsvColumns::detect(&["peptide", "Modified sequence"]),
+        Some(GeneralCsvColumns {
+            sequence: 1,
+            scan: None,
+            charge: None,
+            score: None,
+        })
+    );
+    assert_eq!(GeneralCsvColumns::detect(&["mz", "rt"]), None);
+}
+
+#[test]
+fn general_csv() {
+    let peptides: Vec<_> = GeneralCsvData::parse_reader(GENERAL_CSV.as_bytes(), b',', None)
+        .unwrap()
+        .collect();
+    assert_eq!(peptides.len(), 4);
+    assert!(peptides[2].is_err());
+    let peptides: Vec<IdentifiedPeptide> = peptides
+        .into_iter()
+        .filter_map(Result::ok)
+        .map(Into::into)
+        .collect();
+    assert_eq!(peptides.len(), 3);
+    for peptide in &peptides {
+        test_identified_peptide(peptide, true, false).unwrap();
+        assert_eq!(peptide.format_name(), "General CSV");
+    }
+    assert_eq!(
+        peptides[0].scans(),
+        SpectrumIds::FileNotKnown(vec![SpectrumId::Index(12)])
+    );
+    assert_eq!(peptides[0].charge().map(|c| c.value), Some(2));
+    assert_eq!(peptides[0].score, Some(0.9));
+    assert_eq!(peptides[1].score, None);
+    assert_eq!(peptides[2].scans(), SpectrumIds::None);
+    assert_eq!(peptides[2].charge(), None);
+}
+
+pub(super) const GENERAL_CSV: &str = r"Scan Nr,Peptide_Sequence,Z,Best score
+12,PEPTIDE,2+,0.9
+13,AC[+57.021]DEFGHK,3,85.2
+14,PEPTIDE,two,0.5
+,WRQLLK,,
+";
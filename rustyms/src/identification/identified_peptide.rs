@@ -1,5 +1,6 @@
 use std::{
     borrow::Cow,
+    collections::HashMap,
     fmt::Display,
     ops::{Range, RangeInclusive},
     path::PathBuf,
@@ -13,16 +14,16 @@ use crate::{
     formula::MultiChemical,
     identification::{
         deepnovofamily::DeepNovoFamilyData, fasta::FastaData, fasta::FastaIdentifier,
-        instanovo::InstaNovoData, novob::NovoBData, novor::NovorData, opair::OpairData,
-        peaks::PeaksData, pepnet::PepNetData, plink::PLinkData, powernovo::PowerNovoData,
-        system::MassOverCharge, MSFraggerData, MZTabData, MaxQuantData, PLGSData, SageData,
-        SpectrumSequenceListData,
+        general_csv::GeneralCsvData, instanovo::InstaNovoData, novob::NovoBData, novor::NovorData,
+        opair::OpairData, peaks::PeaksData, pepnet::PepNetData, plink::PLinkData,
+        powernovo::PowerNovoData, system::MassOverCharge, MSFraggerData, MZTabData, MaxQuantData,
+        PLGSData, SageData, SpectrumSequenceListData,
     },
     ontologies::CustomDatabase,
     peptidoform::{SemiAmbiguous, SimpleLinear},
     system::usize::Charge,
     system::{OrderedTime, Time},
-    Peptidoform, PeptidoformIon,
+    Peptidoform, PeptidoformIon, RawSpectrum,
 };
 
 use super::CompoundPeptidoformIon;
@@ -46,6 +47,8 @@ pub enum MetaData {
     DeepNovoFamily(DeepNovoFamilyData),
     /// Fasta metadata
     Fasta(FastaData),
+    /// Metadata from a CSV/TSV file with automatically detected columns
+    GeneralCsv(GeneralCsvData),
     /// MaxQuant metadata
     MaxQuant(MaxQuantData),
     /// InstaNovo metadata
@@ -174,6 +177,7 @@ impl IdentifiedPeptide {
             | MetaData::Opair(OpairData { peptide, .. })
             | MetaData::PepNet(PepNetData { peptide, .. })
             | MetaData::PowerNovo(PowerNovoData { peptide, .. })
+            | MetaData::GeneralCsv(GeneralCsvData { peptide, .. })
             | MetaData::Sage(SageData { peptide, .. }) => {
                 Some(ReturnedPeptide::LinearSemiAmbiguous(peptide))
             }
@@ -226,6 +230,7 @@ impl IdentifiedPeptide {
             MetaData::SpectrumSequenceList(_) => "SpectrumSequenceList",
             MetaData::DeepNovoFamily(_) => "DeepNovo Family",
             MetaData::Fasta(_) => "Fasta",
+            MetaData::GeneralCsv(_) => "General CSV",
             MetaData::InstaNovo(_) => "InstaNovo",
             MetaData::MaxQuant(_) => "MaxQuant",
             MetaData::MSFragger(_) => "MSFragger",
@@ -250,6 +255,7 @@ impl IdentifiedPeptide {
             }
             MetaData::DeepNovoFamily(DeepNovoFamilyData { version, .. }) => version.to_string(),
             MetaData::Fasta(_) => "Fasta".to_string(),
+            MetaData::GeneralCsv(_) => "Detected columns".to_string(),
             MetaData::InstaNovo(InstaNovoData { version, .. }) => version.to_string(),
             MetaData::MaxQuant(MaxQuantData { version, .. }) => version.to_string(),
             MetaData::MSFragger(MSFraggerData { version, .. }) => version.to_string(),
@@ -298,6 +304,11 @@ impl IdentifiedPeptide {
             MetaData::PowerNovo(PowerNovoData { scan, .. }) => {
                 scan.as_ref().map_or("-".to_string(), ToString::to_string)
             }
+            MetaData::GeneralCsv(GeneralCsvData {
+                scan, line_index, ..
+            }) => scan
+                .as_ref()
+                .map_or_else(|| line_index.to_string(), ToString::to_string),
             MetaData::PepNet(_) => "-".to_string(),
             MetaData::PLGS(PLGSData {
                 peptide_component_id,
@@ -349,6 +360,7 @@ impl IdentifiedPeptide {
             | MetaData::InstaNovo(InstaNovoData { z, .. })
             | MetaData::MZTab(MZTabData { z, .. }) => Some(*z),
             MetaData::Peaks(PeaksData { z, .. })
+            | MetaData::GeneralCsv(GeneralCsvData { z, .. })
             | MetaData::DeepNovoFamily(DeepNovoFamilyData { z, .. }) => *z,
             MetaData::SpectrumSequenceList(SpectrumSequenceListData { z, .. }) => {
                 (z.value >= 0).then_some(Charge::new::<crate::system::charge::e>(z.value as usize))
@@ -386,6 +398,7 @@ impl IdentifiedPeptide {
             | MetaData::NovoB(_)
             | MetaData::PowerNovo(_)
             | MetaData::PepNet(_)
+            | MetaData::GeneralCsv(_)
             | MetaData::PLink(_) => None,
         }
     }
@@ -492,6 +505,11 @@ impl IdentifiedPeptide {
                 OrderedTime::from(*precursor_lift_off_rt)
                     ..=OrderedTime::from(*precursor_touch_down_rt),
             )]),
+            MetaData::GeneralCsv(GeneralCsvData { scan, .. }) => {
+                scan.as_ref().map_or(SpectrumIds::None, |scan| {
+                    SpectrumIds::FileNotKnown(vec![scan.clone()])
+                })
+            }
             MetaData::Fasta(_) | MetaData::PepNet(_) => SpectrumIds::None,
         }
     }
@@ -521,6 +539,7 @@ impl IdentifiedPeptide {
             | MetaData::Fasta(_)
             | MetaData::SpectrumSequenceList(_)
             | MetaData::PowerNovo(_)
+            | MetaData::GeneralCsv(_)
             | MetaData::PepNet(_) => None,
         }
     }
@@ -550,6 +569,7 @@ impl IdentifiedPeptide {
             MetaData::Fasta(_)
             | MetaData::PowerNovo(_)
             | MetaData::SpectrumSequenceList(_)
+            | MetaData::GeneralCsv(_)
             | MetaData::PepNet(_) => None,
         }
     }
@@ -611,6 +631,7 @@ impl IdentifiedPeptide {
             | MetaData::InstaNovo(_)
             | MetaData::PowerNovo(_)
             | MetaData::SpectrumSequenceList(_)
+            | MetaData::GeneralCsv(_)
             | MetaData::PepNet(_) => None,
         }
     }
@@ -633,6 +654,7 @@ impl IdentifiedPeptide {
             | MetaData::DeepNovoFamily(_)
             | MetaData::SpectrumSequenceList(_)
             | MetaData::InstaNovo(_)
+            | MetaData::GeneralCsv(_)
             | MetaData::PepNet(_) => None,
         }
     }
@@ -669,6 +691,7 @@ impl IdentifiedPeptide {
             | MetaData::Fasta(_)
             | MetaData::PowerNovo(_)
             | MetaData::SpectrumSequenceList(_)
+            | MetaData::GeneralCsv(_)
             | MetaData::PepNet(_) => None,
         }
     }
@@ -684,7 +707,7 @@ impl IdentifiedPeptide {
 }
 
 /// Multiple spectrum identifiers
-#[derive(Clone, Default, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[derive(Clone, Default, PartialEq, Eq, Hash, Debug, Serialize, Deserialize)]
 pub enum SpectrumIds {
     /// When no spectra references are known at all
     #[default]
@@ -695,8 +718,50 @@ pub enum SpectrumIds {
     FileKnown(Vec<(PathBuf, Vec<SpectrumId>)>),
 }
 
+impl SpectrumIds {
+    /// Find the spectra referenced by these spectrum identifiers in the given loaded raw files.
+    /// A file is matched on its full path, or if no file with the same path is loaded on its
+    /// file stem, to allow identifications on a raw file to be resolved against an exported
+    /// (e.g. mgf) version of that file. If the file is not known all loaded files are searched,
+    /// sorted by path. An index is matched against [`RawSpectrum::raw_scan_number`], a native id
+    /// against [`RawSpectrum::title`], and a retention time range against [`RawSpectrum::rt`].
+    pub fn resolve<'a>(
+        &self,
+        files: &'a HashMap<PathBuf, Vec<RawSpectrum>>,
+    ) -> Vec<&'a RawSpectrum> {
+        match self {
+            Self::None => Vec::new(),
+            Self::FileNotKnown(ids) => files
+                .iter()
+                .sorted_by(|a, b| a.0.cmp(b.0))
+                .flat_map(|(_, spectra)| spectra)
+                .filter(|spectrum| ids.iter().any(|id| id.matches(spectrum)))
+                .collect(),
+            Self::FileKnown(references) => references
+                .iter()
+                .filter_map(|(path, ids)| {
+                    files
+                        .get(path)
+                        .or_else(|| {
+                            files
+                                .iter()
+                                .find(|(p, _)| p.file_stem() == path.file_stem())
+                                .map(|(_, spectra)| spectra)
+                        })
+                        .map(|spectra| (spectra, ids))
+                })
+                .flat_map(|(spectra, ids)| {
+                    spectra
+                        .iter()
+                        .filter(|spectrum| ids.iter().any(|id| id.matches(spectrum)))
+                })
+                .collect(),
+        }
+    }
+}
+
 /// A spectrum identifier
-#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Serialize, Deserialize)]
 pub enum SpectrumId {
     /// A native id, the format differs between vendors
     Native(String),
@@ -725,6 +790,17 @@ impl std::fmt::Display for SpectrumId {
 }
 
 impl SpectrumId {
+    /// Check if the given spectrum is identified by this identifier, see [`SpectrumIds::resolve`]
+    pub fn matches(&self, spectrum: &RawSpectrum) -> bool {
+        match self {
+            Self::Index(i) => spectrum.raw_scan_number == Some(*i),
+            Self::Native(n) => spectrum.title == *n,
+            Self::RetentionTime(range) => spectrum
+                .rt
+                .is_some_and(|rt| range.contains(&OrderedTime::from(rt))),
+        }
+    }
+
     /// Get the index if this is an index
     pub const fn index(&self) -> Option<usize> {
         match self {
@@ -1089,3 +1165,68 @@ impl std::str::FromStr for PeaksFamilyId {
         }
     }
 }
+
+#[cfg(test)]
+#[allow(clippy::missing_panics_doc)]
+mod tests {
+    use super::*;
+    use crate::system::time::min;
+
+    #[test]
+    fn resolve_spectra() {
+        let spectrum = |title: &str, scan: usize, rt: f64| {
+            let mut spectrum = RawSpectrum::default();
+            spectrum.title = title.to_string();
+            spectrum.raw_scan_number = Some(scan);
+            spectrum.rt = Some(Time::new::<min>(rt));
+            spectrum
+        };
+        let files = HashMap::from([
+            (
+                PathBuf::from("a.mgf"),
+                vec![spectrum("a1", 1, 1.0), spectrum("a2", 2, 2.0)],
+            ),
+            (
+                PathBuf::from("b.mgf"),
+                vec![spectrum("b1", 1, 3.0), spectrum("b2", 2, 4.0)],
+            ),
+        ]);
+        let titles = |ids: SpectrumIds| {
+            ids.resolve(&files)
+                .into_iter()
+                .map(|s| s.title.as_str())
+                .collect_vec()
+        };
+
+        assert_eq!(
+            titles(SpectrumIds::FileKnown(vec![(
+                PathBuf::from("b.mgf"),
+                vec![SpectrumId::Index(2)]
+            )])),
+            ["b2"]
+        );
+        assert_eq!(
+            titles(SpectrumIds::FileKnown(vec![(
+                PathBuf::from("a.raw"),
+                vec![SpectrumId::Native("a1".to_string())]
+            )])),
+            ["a1"]
+        );
+        assert_eq!(
+            titles(SpectrumIds::FileNotKnown(vec![SpectrumId::Index(1)])),
+            ["a1", "b1"]
+        );
+        assert_eq!(
+            titles(SpectrumIds::FileNotKnown(vec![SpectrumId::RetentionTime(
+                OrderedTime::from(Time::new::<min>(1.5))..=OrderedTime::from(Time::new::<min>(3.0))
+            )])),
+            ["a2", "b1"]
+        );
+        assert!(titles(SpectrumIds::None).is_empty());
+        assert!(titles(SpectrumIds::FileKnown(vec![(
+            PathBuf::from("c.mgf"),
+            vec![SpectrumId::Index(1)]
+        )]))
+        .is_empty());
+    }
+}
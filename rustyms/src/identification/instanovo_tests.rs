@@ -19,7 +19,7 @@ fn instanovo() {
     }
 }
 
-const INSTANOVO_V1_0_0: &str = r#"scan_number,precursor_mz,precursor_charge,experiment_name,spectrum_id,preds,preds_tokenised,log_probs,token_log_probs
+pub(super) const INSTANOVO_V1_0_0: &str = r#"scan_number,precursor_mz,precursor_charge,experiment_name,spectrum_id,preds,preds_tokenised,log_probs,token_log_probs
 0,1353.116333007813,4,20230408_F1_UM4_Peng0013_SA_EXT00_her_01_tryp,20230408_F1_UM4_Peng0013_SA_EXT00_her_01_tryp:0,LKVKVILEAEPS(+79.97)EEEEEEEEEEEEEEEEEEEEEEEEKEEK,"L, K, V, K, V, I, L, E, A, E, P, S(+79.97), E, E, E, E, E, E, E, E, E, E, E, E, E, E, E, E, E, E, E, E, E, E, E, E, K, E, E, K",-47.14482498168945,"[-1.1898047924041748, -1.2532058954238892, -1.4706779718399048, -1.578391671180725, -1.910727858543396, -0.4288635551929474, -0.10262472927570343, -0.2335159033536911, -0.3816143870353699, -0.1399289071559906, -0.2679944634437561, -0.37487441301345825, -0.28003591299057007, -0.29957395792007446, -0.6062297224998474, -1.0798466205596924, -1.3055310249328613, -1.1969765424728394, -0.8466325402259827, -0.7559331655502319, -0.8520379066467285, -1.1635522842407227, -1.5230286121368408, -1.5223480463027954, -1.3874539136886597, -1.3555835485458374, -1.3308098316192627, -1.461938738822937, -1.292738437652588, -1.7667877674102783, -1.8383617401123047, -1.924727439880371, -1.5695301294326782, -1.4049240350723267, -1.2322568893432617, -1.1730256080627441, -0.09055394679307938, -3.6145036220550537, -1.8250231742858887, -3.1126556396484375]"
 1,1353.116333007813,4,20230408_F1_UM4_Peng0013_SA_EXT00_her_01_tryp,20230408_F1_UM4_Peng0013_SA_EXT00_her_01_tryp:1,SSSSTSGS(+79.97)DC(+57.02)DGVHVEPEEEDMES(+79.97)EDEDEDEDLVTSTTSK,"S, S, S, S, T, S, G, S(+79.97), D, C(+57.02), D, G, V, H, V, E, P, E, E, E, D, M, E, S(+79.97), E, D, E, D, E, D, E, D, L, V, T, S, T, T, S, K",-33.68059539794922,"[-0.07847003638744354, -0.5382011532783508, -0.38900521397590637, -0.3034709692001343, -0.14283983409404755, -0.004337664693593979, -0.005992896854877472, -0.443778932094574, -2.0242862701416016, -1.3575999736785889, -1.3120659589767456, -0.8160025477409363, -0.9171149730682373, -0.1492014229297638, -0.28191035985946655, -1.03749680519104, -0.7952876687049866, -0.11219097673892975, -0.6492378115653992, -0.0394880585372448, -0.35166993737220764, -0.031147046014666557, -0.1014118641614914, -0.8919384479522705, -0.5123100876808167, -1.5009464025497437, -0.7995803952217102, -0.8618601560592651, -0.74873948097229, -1.0185350179672241, -1.0649776458740234, -1.9072270393371582, -1.3915926218032837, -1.454406499862671, -0.8135812878608704, -0.7613984942436218, -1.0519371032714844, -2.232295274734497, -2.410634994506836, -2.3764281272888184]"
 2,1216.224487304688,2,20230408_F1_UM4_Peng0013_SA_EXT00_her_01_tryp,20230408_F1_UM4_Peng0013_SA_EXT00_her_01_tryp:2,LSPS(+79.97)PPLLPPPPPPVPLPPLPPK,"L, S, P, S(+79.97), P, P, L, L, P, P, P, P, P, P, V, P, L, P, P, L, P, P, K",-234.313232421875,"[-0.48280513286590576, -1.9982898235321045, -1.145535945892334, -1.2481436729431152, -1.242499589920044, -0.4877724349498749, -0.8774506449699402, -0.7018945217132568, -0.9126332998275757, -1.0259754657745361, -1.1820683479309082, -0.46859246492385864, -1.7760021686553955, -0.9172687530517578, -0.9521878361701965, -0.9288878440856934, -0.02773796021938324, -0.20492245256900787, -1.173280954360962, -0.6005244255065918, -0.05487779155373573, -0.6778227686882019, -2.4466471672058105]"
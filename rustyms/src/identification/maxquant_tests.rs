@@ -88,7 +88,7 @@ fn maxquant_silac() {
     }
 }
 
-const MAXQUANT_MSMS: &str = "Raw file	Scan number	Scan index	Sequence	Length	Missed cleavages	Modifications	Modified sequence	Oxidation (M) Probabilities	oxidation(w) Probabilities	Oxidation (M) Score diffs	oxidation(w) Score diffs	Gln->pyro-Glu	Glu->pyro-Glu	Oxidation (M)	oxidation(w)	Proteins	Charge	Fragmentation	Mass analyzer	Type	Scan event number	Isotope index	m/z	Mass	Mass error [ppm]	Mass error [Da]	Simple mass error [ppm]	Retention time	PEP	Score	Delta score	Score diff	Localization prob	Combinatorics	PIF	Fraction of total spectrum	Base peak fraction	Precursor full scan number	Precursor Intensity	Precursor apex fraction	Precursor apex offset	Precursor apex offset time	Matches	Intensities	Mass deviations [Da]	Mass deviations [ppm]	Masses	Number of matches	Intensity coverage	Peak coverage	Unfragmented precursor intensity	Unfragmented precursor fraction	Neutral loss level	ETD identification type	Reverse	All scores	All sequences	All modified sequences	MS3 scan numbers	Reporter PIF	Reporter fraction	id	Protein group IDs	Peptide ID	Mod. peptide ID	Evidence ID	Oxidation (M) site IDs	oxidation(w) site IDs	Mass deficit
+pub(super) const MAXQUANT_MSMS: &str = "Raw file	Scan number	Scan index	Sequence	Length	Missed cleavages	Modifications	Modified sequence	Oxidation (M) Probabilities	oxidation(w) Probabilities	Oxidation (M) Score diffs	oxidation(w) Score diffs	Gln->pyro-Glu	Glu->pyro-Glu	Oxidation (M)	oxidation(w)	Proteins	Charge	Fragmentation	Mass analyzer	Type	Scan event number	Isotope index	m/z	Mass	Mass error [ppm]	Mass error [Da]	Simple mass error [ppm]	Retention time	PEP	Score	Delta score	Score diff	Localization prob	Combinatorics	PIF	Fraction of total spectrum	Base peak fraction	Precursor full scan number	Precursor Intensity	Precursor apex fraction	Precursor apex offset	Precursor apex offset time	Matches	Intensities	Mass deviations [Da]	Mass deviations [ppm]	Masses	Number of matches	Intensity coverage	Peak coverage	Unfragmented precursor intensity	Unfragmented precursor fraction	Neutral loss level	ETD identification type	Reverse	All scores	All sequences	All modified sequences	MS3 scan numbers	Reporter PIF	Reporter fraction	id	Protein group IDs	Peptide ID	Mod. peptide ID	Evidence ID	Oxidation (M) site IDs	oxidation(w) site IDs	Mass deficit
 20220225_L1_UM8_Peng0013_SA_mab_A_1_tryp	13268	9541	AAPSVTLFPPSSEELQANK	19	0	Unmodified	_AAPSVTLFPPSSEELQANK_					0	0	0	0	IGLC3;IGLC2;IGLC6;IGLC7	3	ETHCD	FTMS	MSMS	6		662.67747	1985.0106	NaN	NaN	1.8636318	58.734	0.017559	57.352	54.205	NaN	NaN	1	0	0	0	13262	6123579.5	0.4944330476421861	6	-0.2669410705566406	y7;y10;y11;y13;y14;c3;c5;c6;c11;c12;c14;c15;c16;c17;c18;z°1;z°2;z°3;z°7;z°8;z°12;z°13;z°15;z°16;z'2;z'18	2770.971435546875;10292.0849609375;54295.921875;4390.515625;1351.7666015625;6203.7548828125;3477.310302734375;6202.57666015625;12855.75390625;14654.42578125;21939.880859375;31883.1640625;30842.6640625;14824.419921875;24749.46875;3728.290771484375;1238.7689208984375;9095.4375;2436.718994140625;6729.138671875;21537.138671875;19962.28515625;9211.7431640625;5796.3505859375;2133.27783203125;3119.4990234375	0.007465081987561462;0.0016557981921323517;-0.0006528410613100277;0.0011892476247794548;0.0022059764876303234;-0.0001424394082505387;-0.0017716840594061978;-0.001725394306618;-0.0014198162411958037;-0.0010817414868142805;-0.0017515587519483233;0.010050551253925732;-0.00041923705339286244;-0.003696086472928073;0.002471265092935937;-0.00011240368280596158;-0.0005504349984164492;-0.0009356147507446622;-0.0018595733498614209;-0.0010790430047791233;-0.0011147231489303522;0.0005530898861252354;-0.0023915377585126407;-0.002475787596495138;-0.00038531500780436545;0.018494814423092976	8.978786860325311;1.5018089527057223;-0.5442197465989401;0.8146973879207076;1.4133733688608707;-0.5538920393026521;-3.9969136505380667;-3.16987048628711;-1.3078626833153697;-0.9224901002266982;-1.2242516190414676;6.51030901412731;-0.2507609741134921;-2.1206536468428028;1.3308291526678353;-0.8574268673402518;-2.2454127508838386;-2.959166860812196;-2.280555143675931;-1.1957015599504723;-0.837733916839391;0.3830996046548967;-1.4548460483594012;-1.4303679683157235;-1.565397089972868;9.734362155893091	831.4132080078125;1102.535837963408;1199.5909104546615;1459.7415462625754;1560.7882080078125;257.1609594351082;443.2630310058594;544.3106631902067;1085.6003916226412;1172.6320819577868;1430.7179379674521;1543.7901998378463;1671.8592471375537;1742.8996377747733;1856.9363978704075;131.0941925048828;245.13755798339844;316.17505695095076;815.40380859375;902.4350564733049;1330.641062183549;1443.723458350914;1643.8424953688584;1730.8746080285964;246.1452178955078;1899.951340098477	26	0.03580576304537366	0.08176100628930817	0	0	None	Unknown		57.35223277771986;3.1467750414827766;1.7776555042975895	AAPSVTLFPPSSEELQANK;DIALERGQEADAVAAELSK;ANAQLEESSPPFLTVSPAK	_AAPSVTLFPPSSEELQANK_;_DIALERGQEADAVAAELSK_;_ANAQLEESSPPFLTVSPAK_				0	22	0	0	0			0.05746640861548258
 20220225_L1_UM8_Peng0013_SA_mab_A_1_tryp	13447	9704	AAPSVTLFPPSSEELQANK	19	0	Unmodified	_AAPSVTLFPPSSEELQANK_					0	0	0	0	IGLC3;IGLC2;IGLC6;IGLC7	3	ETHCD	FTMS	MSMS	9		662.67747	1985.0106	NaN	NaN	2.3170299	59.502	0.00023293	125.28	113.2	NaN	NaN	1	0	0	0	13438	731249.625	0.059042914471177474	20	-1.0261764526367188	y1;y2;y10;y11;y12;y13;c3;c4;c5;c6;c11;c12;c13;c14;c15;c16;c17;c18;z°1;z°3;z°7;z°8;z°12;z°13;z°14;z°15;z°16;z'2;z'18	2392.554931640625;1138.020751953125;5723.35302734375;28788.640625;3949.9716796875;3274.597412109375;1994.0433349609375;1733.2158203125;2244.16796875;3484.68896484375;7114.0322265625;11712.7099609375;17419.87890625;18966.828125;8295.1748046875;22702.578125;15332.8994140625;13395.9970703125;691.71630859375;4937.03955078125;1239.6055908203125;4233.3212890625;16935.47265625;13415.6025390625;18506.494140625;9812.1220703125;3624.2197265625;1528.7862548828125;2496.37646484375	0.00024008368592376428;-0.0003352767312208016;-0.002703682795527129;-0.0007559904013305641;0.003873260041245885;-0.0013349320995530434;-1.0641018775459088E-05;-0.0009717330719354322;-0.00015425241878119778;-0.00011052589650262234;0.003858371944488681;0.0036041633063632617;0.0034277112094969198;0.0026071805975789175;0.003863670350256143;0.003938819510040048;0.007346484198251346;0.0030827725192921207;0.0004369127234440384;-7.299973748331468E-05;-0.0019206085061114209;-0.000940937171776568;0.002071167940584928;0.0003968143205383967;-0.013600823359183778;0.0036192893871884735;-0.006934674250715034;0.00014874260938313455;0.0045724411361334205	1.6319726830532497;-1.2838175088469388;-2.4522305869609653;-0.6302067894450941;2.8762085474484027;-0.91449736058771;-0.04137884791104829;-2.823214780595352;-0.3479942400972605;-0.2030572463261335;3.554153751007683;3.073579312998058;2.63331779438421;1.8222938786850273;2.502707211582579;2.35595776628987;4.2151197835975465;1.660139538443642;3.332829211374332;-0.23088455285427847;-2.355407642332502;-1.042664853304599;1.556522610310627;0.2748547689587114;-8.804345458703771;2.201733214479358;-4.006446425557574;0.6042893434216744;2.406591965889362	147.11256408691406;261.15606689453125;1102.5401974443957;1199.5910136040015;1346.6547982697589;1459.7440704422997;257.16082763671875;344.1938171386719;443.26141357421875;544.3090483217966;1085.5951134344555;1172.6273960529936;1301.6701656012906;1430.7135792281026;1543.79638671875;1671.8548890809902;1742.888595204102;1856.9357863629812;131.09364318847656;316.1741943359375;815.4038696289062;902.4349183674719;1330.6378762924594;1443.7236146264795;1544.7852907382592;1643.8364845417127;1730.8790669152506;246.14468383789062;1899.965262471764	29	0.2438005750924478	0.13302752293577982	0	0	None	Unknown		125.2790195118964;12.080040696317239;4.44412852167444	AAPSVTLFPPSSEELQANK;DIALERGQEADAVAAELSK;ANAQLEESSPPFLTVSPAK	_AAPSVTLFPPSSEELQANK_;_DIALERGQEADAVAAELSK_;_ANAQLEESSPPFLTVSPAK_				1	22	0	0	1			0.05746640861548258
 20220225_L1_UM8_Peng0013_SA_mab_A_1_tryp	13448	9705	AAPSVTLFPPSSEELQANK	19	0	Unmodified	_AAPSVTLFPPSSEELQANK_					0	0	0	0	IGLC3;IGLC2;IGLC6;IGLC7	3	CID	FTMS	MSMS	10		662.67747	1985.0106	NaN	NaN	2.3170299	59.508	0.017189	54.355	52.994	NaN	NaN	1	0	0	0	13438	731249.625	0.059042914471177474	20	-1.0261764526367188	y1;y2;y3;y4;y5;y6;y7;y8;y9;y10;y11;y12;y8-H2O;y9-H2O;y10-H2O;y11-H2O;y1-NH3;y2-NH3;y4-NH3;y7-NH3;b2;b3;b7;b8;b7-H2O;b8-H2O	11437.2421875;5517.70068359375;5879.0009765625;4730.2265625;2970.7822265625;4980.73828125;2117.119384765625;6560.4169921875;10804.98828125;44862.828125;120878.375;930.1884765625;1072.8460693359375;1674.8443603515625;6898.1044921875;20218.55078125;8163.56689453125;2836.39013671875;2594.78662109375;2090.646728515625;5731.69873046875;3415.752685546875;4531.10107421875;5592.41357421875;4047.81640625;4588.69140625	0.0004537067327987643;-0.0007320052468458016;-0.0001172408843217454;-0.0015067704999296438;0.000770100525073758;0.0007212024694354113;-4.2242231188538426E-05;0.0017054358547738957;0.0010810405162828829;9.211668566422304E-05;0.0008874129032392375;0.0004684048001308838;0.00012021183761135035;-0.0003831165436167794;0.00577044710712471;0.0048527144460877025;0.000409121834366033;0.0003983366125339671;-0.0013529911405498751;0.01241012111256623;0.0004188371218845077;0.00034150259844523134;0.002035469266729706;-0.0006268376466778136;0.0005910298140179293;-4.296207168863475E-05	3.0840832286899045;-2.802937506085921;-0.3529300662714872;-3.2737879603562816;1.3431953490906334;1.0268019895696536;-0.05080728734509438;1.8568610215273191;1.075144799717809;0.08354971498181459;0.739763893615337;0.34782753266116284;0.13350314111846137;-0.38797611975000307;5.32073262122772;4.106988640020054;3.145014212629123;1.631665900735315;-3.052597203685159;15.238703056869129;2.9272710734908705;1.4221339049290416;3.1786108222755844;-0.7960495201307741;0.9496662387323807;-0.05583664199769213	147.1123504638672;261.1564636230469;332.1929626464844;460.2529296875;573.334716796875;702.3773587911306;831.4207153320312;918.4509960638453;1005.4836488690838;1102.5374016449146;1199.589370200697;1346.658203125;900.4420166015625;987.4745483398438;1084.521158628193;1181.574840212854;130.08584594726562;244.1287841796875;443.2262268066406;814.3817138671875;143.08108520507812;240.13392639160156;640.3644172055334;787.4354934286469;622.3552969586862;769.4243448667719	26	0.2383018669423918	0.06860158311345646	0	0	None	Unknown		54.35492206311417;1.3606151709937753;0.08398074589203781	AAPSVTLFPPSSEELQANK;ANAQLEESSPPFLTVSPAK;DIALERGQEADAVAAELSK	_AAPSVTLFPPSSEELQANK_;_ANAQLEESSPPFLTVSPAK_;_DIALERGQEADAVAAELSK_				2	22	0	0	2			0.05746640861548258
@@ -109,7 +109,7 @@ const MAXQUANT_MSMS: &str = "Raw file	Scan number	Scan index	Sequence	Length	Mis
 20220225_L1_UM8_Peng0013_SA_mab_A_1_tryp	6499	3388	AMKPWIQPK	9	1	Unmodified	_AMKPWIQPK_					0	0	0	0	CON__P02663	3	CID	FTMS	MULTI-MSMS	5	0	366.8758	1097.6056	-0.70904	-0.00026013	1.0926832	28.274	0.00059441	88.37	86.334	NaN	NaN	1	0	0	0	6494	2753523	1	0	0	y1;y2;y3;y4;y5;y6;y7;y8;y1-NH3;y2-NH3;y3-NH3;y5-NH3;y6-NH3;y7-NH3;a2;b2;b4;b5;b6;b7(2+)	15274.078125;36969.87890625;7032.0341796875;13885.4453125;17214.212890625;129998.203125;22643.21875;4551.54931640625;13168.87890625;1289.09716796875;29024.859375;1013.5811767578125;2346.6923828125;3783.072998046875;12648.0966796875;1824.768310546875;4553.384765625;4629.75537109375;713.0250854492188;4553.384765625	0.00010275458436126428;4.06788499844879E-05;-0.00022764139640685244;6.14274542840576E-05;0.0005343120318457295;0.0005686528247679234;0.0005743620316707165;0.0025009040712120623;0.000134463631241033;2.6611529676756618E-05;8.521558771690252E-05;-0.007312766412610472;-0.0013628792563622483;-0.005177456190040175;0.00011648849999801314;2.0734623461748924E-05;0.0032926482687685166;0.00032599034841496177;0.004377101700015373;0.0016072390687895677	0.6984752735298337;0.1666035759717133;-0.6115703667249786;0.12657412517428518;0.7958331651814565;0.7400096737507406;0.6406467565645118;2.433796434940195;1.0336508661738062;0.11715967129275393;0.2399104301855961;-11.175306883876477;-1.8137501577824617;-5.886724838981539;0.6653070075517338;0.1020983251113691;7.68898391337754;0.5306596105541396;6.017531259108548;3.7532206103195196	147.11270141601562;244.16552734375;372.2243731753964;485.3081480869457;671.386988155868;768.4397176670751;896.5346749755682;1027.5732330397288;130.08612060546875;227.1389923095703;355.1975112169123;654.3682861328125;751.4151000976562;879.51387769229;175.08984375;203.08485412597656;428.22930908203125;614.3115886934517;727.3916015625;428.22930908203125	20	0.2678539567186284	0.06884057971014493	0	0	None	Unknown		88.36965876740499;2.0357826168845694	AMKPWIQPK;QESLELIRK	_AMKPWIQPK_;_(Gln->pyro-Glu)QESLELIRK_				17	3	6	7	9			0.06067270221819854
 20220225_L1_UM8_Peng0013_SA_mab_A_1_tryp	4423	1501	AQYEEIANR	9	0	Unmodified	_AQYEEIANR_					0	0	0	0	CON__P13647	2	ETHCD	FTMS	MULTI-MSMS	8	0	547.26727	1092.52	0.38385	0.00021007	2.9840251	18.151	0.0030574	101.64	54.457	NaN	NaN	1	0	0	0	4415	6947319	0.2999219556621128	1	-0.055812835693359375	y1;y3;y4;y5;y6;y7;c8;z°3;z°4;z°5;z°6;z°7;z°8;z'2	951.2175903320312;1620.69384765625;2248.073486328125;3184.73095703125;5610.0224609375;29416.81640625;4918.42724609375;7124.21240234375;15928.6630859375;29674.12109375;15365.568359375;6655.4052734375;12492.44921875;2971.8740234375	9.92025780988115E-06;-0.0010493086093106285;3.7376868817773357E-05;-0.0009031414699620655;-0.000267138044478088;-0.0001872590358971138;0.0012115104174199587;-0.001802254228948641;-0.000927774521471747;-0.001022136310893984;-0.0006786258413740143;-0.0011767274172598263;-0.0005790530335616495;-0.0002645262874807486	0.05664868506977645;-2.913127387141585;0.07897361037002955;-1.4994216418204749;-0.3652577880507904;-0.20936089749947268;1.2937392895638113;-5.236339651760932;-2.0289634931741807;-1.7433437725093983;-0.9486624077739183;-1.3396045418875049;-0.5753294983888272;-0.9648917967440759	175.1189422607422;360.2000427246094;473.28302001953125;602.3265536340699;731.3685107268444;894.4317593861358;936.4409253029826;344.182071600829;457.2652611015215;586.3079485595109;715.3501981452414;878.4140247851172;1006.4720046221336;274.1512451171875	14	0.2324769183073939	0.15730337078651685	0	0	None	Unknown		101.64353485380936;47.18689753183167;6.303046881789662	AQYEEIANR;AQYEDIAQR;AFDICPLEK	_AQYEEIANR_;_AQYEDIAQR_;_AFDICPLEK_				18	12	7	8	10			-0.022572234301378558";
 
-const MAXQUANT_MSMS_SCANS: &str = "Raw file                                             	Scan number 	Retention time 	Ion injection time 	Total ion current  	Collision energy    	Summations 	Base peak intensity 	Elapsed time 	Identified 	Matched 	Reverse 	MS/MS IDs 	Sequence                                                                                                       	Length 	Filtered peaks 	m/z                 	Mass               	Charge 	MS2 m/z             	Type  	Fragmentation 	Mass analyzer 	Parent intensity fraction 	Fraction of total spectrum 	Base peak fraction 	Precursor full scan number 	Precursor intensity 	Precursor apex fraction 	Precursor apex offset 	Precursor apex offset time 	Scan event number 	Modifications                  	Modified sequence                                                                                                                                                	Proteins                                                                                                                                                                              	Score                  	PEP                     	Experiment        	MS3 scan numbers 	Reporter PIF 	Reporter fraction 	Intens Comp Factor 	CTCD Comp 	RawOvFtT   	AGC Fill 	Scan index 	MS scan index 	MS scan number
+pub(super) const MAXQUANT_MSMS_SCANS: &str = "Raw file                                             	Scan number 	Retention time 	Ion injection time 	Total ion current  	Collision energy    	Summations 	Base peak intensity 	Elapsed time 	Identified 	Matched 	Reverse 	MS/MS IDs 	Sequence                                                                                                       	Length 	Filtered peaks 	m/z                 	Mass               	Charge 	MS2 m/z             	Type  	Fragmentation 	Mass analyzer 	Parent intensity fraction 	Fraction of total spectrum 	Base peak fraction 	Precursor full scan number 	Precursor intensity 	Precursor apex fraction 	Precursor apex offset 	Precursor apex offset time 	Scan event number 	Modifications                  	Modified sequence                                                                                                                                                	Proteins                                                                                                                                                                              	Score                  	PEP                     	Experiment        	MS3 scan numbers 	Reporter PIF 	Reporter fraction 	Intens Comp Factor 	CTCD Comp 	RawOvFtT   	AGC Fill 	Scan index 	MS scan index 	MS scan number
 20230418_L1_UM1_Peng0013_SA_EXT00_mix3_lysN_4h_test2 	          3 	     0.0056511 	NaN                	    109530         	 35                 	         0 	             9009.1 	       0.341 	           	        	        	-1        	                                                                                                               	     0 	            87 	1349.6195068359375  	NaN                	     0 	1349.6195068359375  	PEAK  	CID           	FTMS          	                        0 	                         0 	                 0 	-1                         	NaN                 	NaN                     	0                     	NaN                        	                1 	                               	                                                                                                                                                                 	                                                                                                                                                                                      	NaN                    	NaN                     	LysN_sp3_L1_test2 	                 	             	                  	NaN                	NaN       	  230239.9 	NaN      	         0 	            1 	             2
 20230418_L1_UM1_Peng0013_SA_EXT00_mix3_lysN_4h_test2 	          4 	     0.010648  	NaN                	     60049         	 25.448551177978516 	         0 	             8063.8 	       0.408 	           	        	        	-1        	                                                                                                               	     0 	            34 	1349.6195068359375  	NaN                	     0 	1349.6195068359375  	PEAK  	ETHCD         	FTMS          	                        0 	                         0 	                 0 	-1                         	NaN                 	NaN                     	0                     	NaN                        	                2 	                               	                                                                                                                                                                 	                                                                                                                                                                                      	NaN                    	NaN                     	LysN_sp3_L1_test2 	                 	             	                  	NaN                	NaN       	  220183.5 	NaN      	         1 	            1 	             2
 20230418_L1_UM1_Peng0013_SA_EXT00_mix3_lysN_4h_test2 	          6 	     0.018001  	NaN                	    115910         	 35                 	         0 	            15149   	       0.341 	           	        	+       	-1        	KSSEDFVAFMLVHKQEFKMQTGDETLT                                                                                    	    27 	            79 	1573.7630615234375  	NaN                	     0 	1573.7630615234375  	PEAK  	CID           	FTMS          	                        0 	                         0 	                 0 	-1                         	NaN                 	NaN                     	0                     	NaN                        	                1 	Unmodified                     	_KSSEDFVAFMLVHKQEFKMQTGDETLT_                                                                                                                                    	REV__CON__Q28107                                                                                                                                                                      	0                      	0.03366231396707156     	LysN_sp3_L1_test2 	                 	             	                  	NaN                	NaN       	  234690.4 	NaN      	         2 	            2 	             5
@@ -130,7 +130,7 @@ const MAXQUANT_MSMS_SCANS: &str = "Raw file
 20230418_L1_UM1_Peng0013_SA_EXT00_mix3_lysN_4h_test2 	         26 	     0.10998   	NaN                	     99046         	 35                 	         0 	             8872.6 	       0.338 	           	        	        	-1        	                                                                                                               	     0 	            74 	1764.98681640625    	NaN                	     0 	1764.98681640625    	PEAK  	CID           	FTMS          	                        0 	                         0 	                 0 	-1                         	NaN                 	NaN                     	0                     	NaN                        	                7 	                               	                                                                                                                                                                 	                                                                                                                                                                                      	NaN                    	NaN                     	LysN_sp3_L1_test2 	                 	             	                  	NaN                	NaN       	  238263.3 	NaN      	        17 	            7 	            19
 20230418_L1_UM1_Peng0013_SA_EXT00_mix3_lysN_4h_test2 	         28 	     0.11617   	NaN                	    147010         	 35                 	         0 	             6579.6 	       0.341 	           	        	        	-1        	                                                                                                               	     0 	           101 	1766.4884580021653  	7061.9247261422615 	     4 	1767.747314453125   	MULTI 	CID           	FTMS          	                        0 	                         0 	                 0 	27                         	165744.078125       	0.49159822680931803     	2                     	-0.02665609121322632       	                1 	                               	                                                                                                                                                                 	                                                                                                                                                                                      	NaN                    	NaN                     	LysN_sp3_L1_test2 	                 	             	                  	NaN                	NaN       	  246445.1 	NaN      	        18 	            8 	            27";
 
-const MAXQUANT_NOVO_MSMS_SCANS: &str = "Raw file                                           	Scan number 	Retention time 	Ion injection time 	Total ion current 	Collision energy    	Summations 	Base peak intensity 	Elapsed time 	Identified 	Matched 	Reverse 	MS/MS IDs 	Sequence                                 	Length 	Filtered peaks 	m/z                 	Mass               	Charge 	MS2 m/z             	Type  	Fragmentation 	Mass analyzer 	Parent intensity fraction 	Fraction of total spectrum 	Base peak fraction 	Precursor full scan number 	Precursor intensity 	Precursor apex fraction 	Precursor apex offset 	Precursor apex offset time 	Scan event number 	Modifications                 	Modified sequence                                                                           	Proteins                                                                                                                          	Score                  	PEP                     	Experiment  	MS3 scan numbers 	Reporter PIF 	Reporter fraction 	DN sequence                                                                                                                                                                            	DN length 	DN min levenshtein distance 	DN extension sequence                                                      	DN extended 	DN complete 	DN raw score 	DN extension score 	DN extension norm. score 	DN normalized score 	DN complete score 	DN combined score 	DN nterm mass      	DN cterm mass      	DN missing mass    	DN nterm delta score  	DN cterm delta score  	DN term delta score   	DN full length delta score 	DN protease score 	DN complement score  	DN A2 score           	DN water loss score   	DN ammonia loss score 	DN agrees with andromeda 	DN agrees with andromeda complete 	DN all sequences                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                            	DN all scores                                                             	DN all agrees       	DN any agrees 	DN number of steps 	DN is dominantly y 	Intens Comp Factor 	CTCD Comp 	RawOvFtT  	AGC Fill 	Scan index 	MS scan index 	MS scan number
+pub(super) const MAXQUANT_NOVO_MSMS_SCANS: &str = "Raw file                                           	Scan number 	Retention time 	Ion injection time 	Total ion current 	Collision energy    	Summations 	Base peak intensity 	Elapsed time 	Identified 	Matched 	Reverse 	MS/MS IDs 	Sequence                                 	Length 	Filtered peaks 	m/z                 	Mass               	Charge 	MS2 m/z             	Type  	Fragmentation 	Mass analyzer 	Parent intensity fraction 	Fraction of total spectrum 	Base peak fraction 	Precursor full scan number 	Precursor intensity 	Precursor apex fraction 	Precursor apex offset 	Precursor apex offset time 	Scan event number 	Modifications                 	Modified sequence                                                                           	Proteins                                                                                                                          	Score                  	PEP                     	Experiment  	MS3 scan numbers 	Reporter PIF 	Reporter fraction 	DN sequence                                                                                                                                                                            	DN length 	DN min levenshtein distance 	DN extension sequence                                                      	DN extended 	DN complete 	DN raw score 	DN extension score 	DN extension norm. score 	DN normalized score 	DN complete score 	DN combined score 	DN nterm mass      	DN cterm mass      	DN missing mass    	DN nterm delta score  	DN cterm delta score  	DN term delta score   	DN full length delta score 	DN protease score 	DN complement score  	DN A2 score           	DN water loss score   	DN ammonia loss score 	DN agrees with andromeda 	DN agrees with andromeda complete 	DN all sequences                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                            	DN all scores                                                             	DN all agrees       	DN any agrees 	DN number of steps 	DN is dominantly y 	Intens Comp Factor 	CTCD Comp 	RawOvFtT  	AGC Fill 	Scan index 	MS scan index 	MS scan number
 20221121_F1_UM4_Peng0013_SA_EXT00_MIX3_SP3_LysN_1h 	        142 	       0.72822 	NaN                	           288190 	35                  	         0 	            40942   	       0.345 	           	        	        	-1        	                                         	     0 	            44 	 612.1839599609375  	NaN                	     0 	 612.1839599609375  	PEAK  	CID           	FTMS          	                        0 	                         0 	                 0 	-1                         	NaN                 	NaN                     	0                     	NaN                        	                1 	                              	                                                                                            	                                                                                                                                  	NaN                    	NaN                     	sp3_lysn_1h 	                 	             	                  	A                                                                                                                                                                                      	1         	-1                          	                                                                           	            	            	    2.4743   	0                  	0                        	          0.20308   	           0      	            0     	980.2683953351578  	164.02427087955314 	1144.292666214711  	0                     	0                     	0                     	     0                     	                0 	0                    	0                     	 0                    	 0                    	                         	                                  	A                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                           	0.20308                                                                   	-                   	              	                 3 	+                  	NaN                	NaN       	 102970.2 	NaN      	         0 	          140 	           141
 20221121_F1_UM4_Peng0013_SA_EXT00_MIX3_SP3_LysN_1h 	        143 	       0.73405 	NaN                	           206420 	86.43135070800781   	         0 	            28810   	       0.499 	           	        	        	-1        	                                         	     0 	            71 	 612.1839599609375  	NaN                	     0 	 612.1839599609375  	PEAK  	ETHCD         	FTMS          	                        0 	                         0 	                 0 	-1                         	NaN                 	NaN                     	0                     	NaN                        	                2 	                              	                                                                                            	                                                                                                                                  	NaN                    	NaN                     	sp3_lysn_1h 	                 	             	                  	                                                                                                                                                                                       	-1        	-1                          	                                                                           	            	            	    0        	0                  	0                        	          0         	           0      	            0     	NaN                	NaN                	NaN                	0                     	0                     	0                     	     0                     	                0 	0                    	0                     	 0                    	 0                    	                         	                                  	                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                            	0                                                                         	-                   	              	                 0 	                   	NaN                	NaN       	  76652.4 	NaN      	         1 	          140 	           141
 20221121_F1_UM4_Peng0013_SA_EXT00_MIX3_SP3_LysN_1h 	       1591 	       8.0177  	NaN                	           154180 	35                  	         0 	            23985   	       0.346 	           	        	        	-1        	                                         	     0 	            62 	 368.68696927490356 	NaN                	     0 	 368.687255859375   	PEAK  	CID           	FTMS          	                        0 	                         0 	                 0 	1590                       	157140.3125         	0.768202274775432       	-108                  	0.5617332458496094         	                1 	                              	                                                                                            	                                                                                                                                  	NaN                    	NaN                     	sp3_lysn_1h 	                 	             	                  	C                                                                                                                                                                                      	1         	-1                          	                                                                           	            	            	    7.4743   	0                  	0                        	          1.0164    	           0      	            0     	446.25032907539463 	125.07152734927969 	571.3218564246744  	0                     	0                     	0                     	     0                     	                0 	0.5                  	0                     	 0                    	 0                    	                         	                                  	C                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                           	1.0164                                                                    	-                   	              	                10 	+                  	NaN                	NaN       	  67962.1 	NaN      	         2 	         1587 	          1590
@@ -151,7 +151,7 @@ const MAXQUANT_NOVO_MSMS_SCANS: &str = "Raw file
 20221121_F1_UM4_Peng0013_SA_EXT00_MIX3_SP3_LysN_1h 	       2359 	      12.003   	NaN                	          1809400 	38.41393280029297   	         0 	            97234   	       0.451 	           	        	        	-1        	                                         	     0 	           157 	 364.5184437217449  	1090.5335017654347 	     3 	 364.51898193359375 	MULTI 	ETHCD         	FTMS          	                        0 	                         0 	                 0 	2357                       	846420.375          	0.04209900124712305     	-1                    	0.005328178405761719       	                2 	                              	                                                                                            	                                                                                                                                  	NaN                    	NaN                     	sp3_lysn_1h 	                 	             	                  	KKVEPKSCD                                                                                                                                                                              	9         	-1                          	                                                                           	            	+           	   45.635    	0                  	0                        	          4.1847    	           4.1847 	           92.641 	0                  	0                  	0                  	0.8303360952406962    	3.0102999566398125    	0.8303360952406962    	     3.0102999566398125    	                2 	1.6622367467574017   	0                     	 0.2302046884880938   	 0                    	                         	                                  	KKVEPKSCD;KKVEPKSDC;[VS]K{[VA]|[{L|I}G]}PKSCD;[VS]K{[VA]|[{L|I}G]}PKSDC;KKVEPKS[CD];KKVE{[QG]|[NA]}C{[QN]|[E{L|I}]|[KN]}                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                    	4.1847;3.9087;1.8956;1.6196;1.3996;1.0014                                 	-;-;-;-;-;-         	              	              1105 	+                  	NaN                	NaN       	 482125.9 	NaN      	        17 	         2340 	          2357
 20221121_F1_UM4_Peng0013_SA_EXT00_MIX3_SP3_LysN_1h 	       2360 	      12.01    	NaN                	          5302500 	35                  	         0 	           856390   	       0.343 	           	        	        	-1        	                                         	     0 	           217 	 482.2263171798785  	962.438081426557   	     2 	 482.22735595703125 	MULTI 	CID           	FTMS          	                        0 	                         0 	                 0 	2357                       	1782684.375         	0.03033970926655378     	-1                    	0.005328178405761719       	                3 	                              	                                                                                            	                                                                                                                                  	NaN                    	NaN                     	sp3_lysn_1h 	                 	             	                  	KVEPKSCD                                                                                                                                                                               	8         	-1                          	                                                                           	            	+           	  114.3      	0                  	0                        	         11.876     	          11.876  	           98.747 	0                  	0                  	0                  	19.87283262043249     	12.653262676180148    	12.653262676180148    	     7.875344171398339     	                2 	2.8001648626366045   	0.769551078621726     	 1.7530834337684316   	 0                    	                         	                                  	KVEPKSCD;KD{L|I}PKSCD;KVEPKSDC;KD{L|I}PKSDC;DK{L|I}PKSCD;{[QV]|[N{L|I}]|[RA]|[KV]}EPKSCD;{NN|[MP]|[D{L|I}]|[EV]}KPKSCD;DK{L|I}PKSDC;{[EN]|[QD]|[WG]|[KD]|[RS]}{L|I}PKSCD;{[QV]|[N{L|I}]|[RA]|[KV]}EPSKCD                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                    	11.876;11.058;10.561;9.743;9.6231;8.6085;8.3789;8.3084;7.926;7.8546       	-;-;-;-;-;-;-;-;-;- 	              	              3138 	+                  	NaN                	NaN       	1362959   	NaN      	        18 	         2340 	          2357";
 
-const MAXQUANT_NOVO_MSMS_SCANS_NEW: &str = "Raw file	Scan number	Retention time	Ion injection time	Total ion current	Collision energy	Summations	Base peak intensity	Elapsed time	Identified	Matched	Reverse	MS/MS IDs	Sequence	Length	Filtered peaks	m/z	Mass	Charge	MS2 m/z	Type	Fragmentation	Mass analyzer	Parent intensity fraction	Fraction of total spectrum	Base peak fraction	Precursor full scan number	Precursor intensity	Precursor apex fraction	Precursor apex offset	Precursor apex offset time	Scan event number	Modifications	Modified sequence	Proteins	Score	PEP	MS3 scan numbers	Reporter PIF	Reporter fraction	DN sequence	DN length	DN min levenshtein distance	DN extension sequence	DN extended	DN complete	DN raw score	DN extension score	DN extension norm. score	DN normalized score	DN complete score	DN combined score	DN nterm mass	DN cterm mass	DN missing mass	DN nterm delta score	DN cterm delta score	DN term delta score	DN full length delta score	DN protease score	DN complement score	DN A2 score	DN water loss score	DN ammonia loss score	DN agrees with andromeda	DN agrees with andromeda complete	DN all sequences	DN all scores	DN all agrees	DN any agrees	DN number of steps	DN is dominantly y	Intens Comp Factor	CTCD Comp	RawOvFtT	AGC Fill	Scan index	MS scan index	MS scan number
+pub(super) const MAXQUANT_NOVO_MSMS_SCANS_NEW: &str = "Raw file	Scan number	Retention time	Ion injection time	Total ion current	Collision energy	Summations	Base peak intensity	Elapsed time	Identified	Matched	Reverse	MS/MS IDs	Sequence	Length	Filtered peaks	m/z	Mass	Charge	MS2 m/z	Type	Fragmentation	Mass analyzer	Parent intensity fraction	Fraction of total spectrum	Base peak fraction	Precursor full scan number	Precursor intensity	Precursor apex fraction	Precursor apex offset	Precursor apex offset time	Scan event number	Modifications	Modified sequence	Proteins	Score	PEP	MS3 scan numbers	Reporter PIF	Reporter fraction	DN sequence	DN length	DN min levenshtein distance	DN extension sequence	DN extended	DN complete	DN raw score	DN extension score	DN extension norm. score	DN normalized score	DN complete score	DN combined score	DN nterm mass	DN cterm mass	DN missing mass	DN nterm delta score	DN cterm delta score	DN term delta score	DN full length delta score	DN protease score	DN complement score	DN A2 score	DN water loss score	DN ammonia loss score	DN agrees with andromeda	DN agrees with andromeda complete	DN all sequences	DN all scores	DN all agrees	DN any agrees	DN number of steps	DN is dominantly y	Intens Comp Factor	CTCD Comp	RawOvFtT	AGC Fill	Scan index	MS scan index	MS scan number
 Peng2021_Herceptin_tryp	1082	5.5518	NaN	85826	150	0	9484.6	0.563				-1	ADPQCPQPATR	11	53	414.8530882002953	NaN	0	414.85345458984375	PEAK	ETHCD	FTMS	0	0	0	1081	39746.4609375	0.15245426474242343	1	-0.01165151596069336	1	2 Deamidation (NQ)	_ADPQ(Deamidation (NQ))CPQ(Deamidation (NQ))PATR_	CON__Q2UVX4	0	2.494881019055721E+68					-1	-1				0	0	0	0	0	0	NaN	NaN	NaN	0	0	0	0	0	0	0	0	0				0	-		0		NaN	NaN	44672.9	NaN	0	1080	1081
 Peng2021_Herceptin_tryp	2873	14.911	NaN	911790	150	0	152240	0.563				-1		0	119	635.7412429518962	1269.4679329705923	2	635.741455078125	MULTI	ETHCD	FTMS	0	0	0	2872	774866.1875	0.7452108926331117	-1	0.0049343109130859375	1				NaN	NaN				{M(Oxidation (M))|F}{I|L}R	3	-1				34.481	0	0	2.7161	0	0	835.2041037417856	0	835.2041037417856	0	2.8165482042847323	0	0	2	0	0	0	0			{M(Oxidation (M))|F}{I|L}R	2.7161	-		256	+	NaN	NaN	248436.8	NaN	1	2870	2872
 Peng2021_Herceptin_tryp	2875	14.922	NaN	500140	150	0	53154	0.564				-1		0	91	476.197299664117	475.190023197517	1	476.19769287109375	MULTI	ETHCD	FTMS	0	0	0	2874	472027.5625	0.9825358978034996	-92	0.47070789337158203	1				NaN	NaN				{M(Oxidation (M))|F}	1	-1				3.7176	0	0	0.78234	0	0	107.07632653484377	218.05457483463127	325.13090136947505	0	0	0	0	0	0	0	0	0			{M(Oxidation (M))|F}	0.78234	-		5	+	NaN	NaN	146767.3	NaN	2	2871	2874
@@ -172,7 +172,7 @@ Peng2021_Herceptin_tryp	2892	15.013	NaN	272060	150	0	10832	0.563				-1		0	85	102
 Peng2021_Herceptin_tryp	3223	16.722	NaN	17198000	150	0	1491600	0.564				-1		0	197	382.2204605038279	1143.6395521116838	3	382.2206115722656	MULTI	ETHCD	FTMS	0	0	0	3222	190723056	0.41476852216237636	-1	0.0050983428955078125	1				NaN	NaN				YQQKPGKAPK	10	-1			+	113.26	0	0	9.9039	9.9039	98.033	0	0	0	40.86721247813779	20.036134084268156	20.036134084268156	9.179751216704588	2	2.486346736360778	1.0457574905606752	0	1.4604093769761874			YQQKPGKAPK;YQ[AG]KPGKAPK;YQQKP[KQ]PK;YQQKK[PG]APK;YQQKK[QP]PK;Y[AG]QKPGKAPK;YQ[AG]KP[KQ]PK;YQQK[{I|L}V][HT]K;YQ[AG]KK[PG]APK;YQQKPGKK[PA]	9.9039;9.1012;8.8051;8.7154;8.2748;8.1868;7.9986;7.9645;7.9127;7.904	-;-;-;-;-;-;-;-;-;-		20178	+	NaN	NaN	4338578	NaN	17	3204	3222
 Peng2021_Herceptin_tryp	3224	16.731	NaN	13449000	35	0	807970	0.363				-1	ALVFVDNHDNQR	12	501	476.90101572458514	1427.6812177739555	3	476.90185546875	MULTI	CID	FTMS	0	0	0	3222	7877181.5	0.32541819426270824	-1	0.0050983428955078125	2	Deamidation (NQ)	_ALVFVDNHDN(Deamidation (NQ))QR_	CON__Q3MHH8	0.26538908928332583	23.155714642610686				{I|L}{D|N(Deamidation (NQ))}NVNHKPSNTK	12	-1				146.43	0	0	10.256	0	0	61.99602626060216	0	61.99602626060216	0	80.95983742624236	0	0	2	0	0	2.2376954943324545	3.05902924611682			{I|L}{D|N(Deamidation (NQ))}NVNHKPSNTK	10.256	-		167870	+	NaN	NaN	3435191	NaN	18	3204	3222";
 
-const MAXQUANT_SILAC: &str = "Sequence	Length	K Count	R Count	Modifications	Modified sequence	Carbamidomethyl (C) Probabilities	NEM Probabilities	Oxidation (M) Probabilities	Carbamidomethyl (C) Score Diffs	NEM Score Diffs	Oxidation (M) Score Diffs	Acetyl (Protein N-term)	Carbamidomethyl (C)	NEM	Oxidation (M)	Missed cleavages	Proteins	Leading proteins	Leading razor protein	Gene names	Protein names	Type	Labeling State	Raw file	Experiment	MS/MS m/z	Charge	m/z	Mass	Uncalibrated - Calibrated m/z [ppm]	Uncalibrated - Calibrated m/z [Da]	Mass error [ppm]	Mass error [Da]	Uncalibrated mass error [ppm]	Uncalibrated mass error [Da]	Max intensity m/z 0	Max intensity m/z 1	Retention time	Retention length	Calibrated retention time	Calibrated retention time start	Calibrated retention time finish	Retention time calibration	Match time difference	Match m/z difference	Match q-value	Match score	Number of data points	Number of scans	Number of isotopic peaks	PIF	Fraction of total spectrum	Base peak fraction	PEP	MS/MS count	MS/MS scan number	MS/MS scan numbers	MS3 scan numbers	Score	Delta score	Combinatorics	Ratio H/L	Ratio H/L normalized	Ratio H/L shift	Intensity	Intensity L	Intensity H	Reverse	Potential contaminant	id	Protein group IDs	Peptide ID	Mod. peptide ID	MS/MS IDs	Best MS/MS	Carbamidomethyl (C) site IDs	NEM site IDs	Oxidation (M) site IDs	Taxonomy IDs	Taxonomy names	Mass deficit	Theor. isotope correlation	Isotope correlation	Time correlation
+pub(super) const MAXQUANT_SILAC: &str = "Sequence	Length	K Count	R Count	Modifications	Modified sequence	Carbamidomethyl (C) Probabilities	NEM Probabilities	Oxidation (M) Probabilities	Carbamidomethyl (C) Score Diffs	NEM Score Diffs	Oxidation (M) Score Diffs	Acetyl (Protein N-term)	Carbamidomethyl (C)	NEM	Oxidation (M)	Missed cleavages	Proteins	Leading proteins	Leading razor protein	Gene names	Protein names	Type	Labeling State	Raw file	Experiment	MS/MS m/z	Charge	m/z	Mass	Uncalibrated - Calibrated m/z [ppm]	Uncalibrated - Calibrated m/z [Da]	Mass error [ppm]	Mass error [Da]	Uncalibrated mass error [ppm]	Uncalibrated mass error [Da]	Max intensity m/z 0	Max intensity m/z 1	Retention time	Retention length	Calibrated retention time	Calibrated retention time start	Calibrated retention time finish	Retention time calibration	Match time difference	Match m/z difference	Match q-value	Match score	Number of data points	Number of scans	Number of isotopic peaks	PIF	Fraction of total spectrum	Base peak fraction	PEP	MS/MS count	MS/MS scan number	MS/MS scan numbers	MS3 scan numbers	Score	Delta score	Combinatorics	Ratio H/L	Ratio H/L normalized	Ratio H/L shift	Intensity	Intensity L	Intensity H	Reverse	Potential contaminant	id	Protein group IDs	Peptide ID	Mod. peptide ID	MS/MS IDs	Best MS/MS	Carbamidomethyl (C) site IDs	NEM site IDs	Oxidation (M) site IDs	Taxonomy IDs	Taxonomy names	Mass deficit	Theor. isotope correlation	Isotope correlation	Time correlation
 AAAAAAVGPGAGGAGSAVPGGAGPCATVSVFPGAR	35	0	1	Acetyl (Protein N-term),Carbamidomethyl (C)	_(Acetyl (Protein N-term))AAAAAAVGPGAGGAGSAVPGGAGPC(Carbamidomethyl (C))ATVSVFPGAR_	AAAAAAVGPGAGGAGSAVPGGAGPC(1)ATVSVFPGAR			AAAAAAVGPGAGGAGSAVPGGAGPC(52)ATVSVFPGAR			1	1	0	0	0	Q86X55	Q86X55	Q86X55	CARM1	Histone-arginine methyltransferase CARM1	ISO-MSMS	0	20240503_EX1_UM3_Neder016_SA_EXT00_ssABE_LPS_2H	2H	984.4912109375	3	983.826039	2948.45629	NaN	NaN	-1.1278	-0.0011095	NaN	NaN	NaN	NaN	37.209	0.15495	37.246	37.137	37.292	0.037453								0	0	0	1.252E-05	1	42275	42275		51.9	40.645	1	1.2986	1.1129	0	41203000	30702000	10501000			0	1723	0	0	0	0	4677					0.0599979585881556			
 AAAAAAVGPGAGGAGSAVPGGAGPCATVSVFPGAR	35	0	1	Acetyl (Protein N-term),Carbamidomethyl (C)	_(Acetyl (Protein N-term))AAAAAAVGPGAGGAGSAVPGGAGPC(Carbamidomethyl (C))ATVSVFPGAR_	AAAAAAVGPGAGGAGSAVPGGAGPC(1)ATVSVFPGAR			AAAAAAVGPGAGGAGSAVPGGAGPC(46)ATVSVFPGAR			1	1	0	0	0	Q86X55	Q86X55	Q86X55	CARM1	Histone-arginine methyltransferase CARM1	ISO-MSMS	0	20240503_EX1_UM3_Neder016_SA_EXT00_ssABE_LPS_3L	3L	983.826904296875	3	983.826039	2948.45629	NaN	NaN	-0.043596	-4.2891E-05	NaN	NaN	NaN	NaN	37.18	0.16776	37.187	37.086	37.254	0.00774								0	0	0	0.0010182	2	41582	41509;41582		46.149	38.652	1	0.63379	0.71963	0	33916000	31452000	2463500			1	1723	0	0	1;2	2	4677					0.0599979585881556			
 AAAAECDVVMAATEPELLDDQEAK	24	1	0	Acetyl (Protein N-term),Carbamidomethyl (C)	_(Acetyl (Protein N-term))AAAAEC(Carbamidomethyl (C))DVVMAATEPELLDDQEAK_	AAAAEC(1)DVVMAATEPELLDDQEAK			AAAAEC(150)DVVMAATEPELLDDQEAK			1	1	0	0	0	Q99615	Q99615	Q99615	DNAJC7	DnaJ homolog subfamily C member 7	MULTI-MSMS		20240503_EX1_UM3_Neder016_SA_EXT00_ssABE_LPS_1H	1H	864.3967895507812	3	863.728009	2588.1622	-0.63499	-0.00054846	-0.18674	-0.00016129	-0.82173	-0.00070975	864.397299440235	866.3973555350774	43.135	0.25949	43.135	43	43.26	0					423	47	14	0	0	0	4.3535000000000003E-29	4	49980	49820;49822;49980;49994		146.6	146.6	1	0.81248	0.84241	0	895110000	413870000	481240000			2	2091	1	1	3;4;5;6	5	5431					-0.06835578745904058	0.9792858	0.95722574	0.98833406
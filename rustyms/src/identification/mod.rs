@@ -6,6 +6,7 @@ mod common_parser;
 mod deepnovofamily;
 mod fasta;
 mod general;
+mod general_csv;
 mod identified_peptide;
 mod instanovo;
 mod maxquant;
@@ -26,6 +27,7 @@ use crate::*;
 pub use deepnovofamily::*;
 pub use fasta::*;
 pub use general::*;
+pub use general_csv::*;
 pub use identified_peptide::*;
 pub use instanovo::*;
 pub use maxquant::*;
@@ -45,6 +47,8 @@ pub use ssl::*;
 #[cfg(test)]
 mod deepnovofamily_tests;
 #[cfg(test)]
+mod general_csv_tests;
+#[cfg(test)]
 mod instanovo_tests;
 #[cfg(test)]
 mod maxquant_tests;
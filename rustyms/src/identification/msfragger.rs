@@ -1,14 +1,18 @@
-use std::path::{Path, PathBuf};
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 
 //Spectrum	Spectrum.File	Peptide	Modified sequence	Extended.Peptide	Prev.AA	Next.AA	Peptide.Length	Charge	Retention	Observed.Mass	Calibrated.Observed.Mass	Observed.M.Z	Calibrated.Observed.M.Z	Calculated.Peptide.Mass	Calculated.M.Z	Delta.Mass	Expectation	Hyperscore	Nextscore	PeptideProphet.Probability	Number.of.Enzymatic.Termini	Number.of.Missed.Cleavages	Protein.Start	Protein.End	Intensity	Assigned.Modifications	Observed.Modifications	Purity	Is.Unique	Protein	Protein.ID	Entry.Name	Gene	Protein.Description	Mapped.Genes	Mapped.Proteins	condition	group
 use crate::{
     error::{Context, CustomError},
     helper_functions::explain_number_error,
     identification::SpectrumId,
+    modification::{Modification, SimpleModification, SimpleModificationInner},
     ontologies::CustomDatabase,
     peptidoform::{SemiAmbiguous, SloppyParsingParameters},
     system::{usize::Charge, Mass, MassOverCharge, Time},
-    Peptidoform,
+    AminoAcid, Peptidoform,
 };
 use itertools::Itertools;
 use serde::{Deserialize, Serialize};
@@ -106,7 +110,12 @@ format_family!(
                     SpectrumId::Index(m.get(2).unwrap().as_str().parse::<usize>().unwrap());
             }
         }
-        if parsed.peptide.is_none() {
+        if let Some(peptide) = parsed.extended_peptide[1]
+            .as_ref()
+            .and_then(|p| parse_assigned_modifications(p, &parsed.assigned_modifications))
+        {
+            parsed.peptide = Some(peptide);
+        } else if parsed.peptide.is_none() {
             parsed.peptide.clone_from(&parsed.extended_peptide[1]);
         }
         Ok(parsed)
@@ -116,6 +125,41 @@ format_family!(
 /// The Regex to match against MSFragger scan fields
 static IDENTIFER_REGEX: std::sync::OnceLock<regex::Regex> = std::sync::OnceLock::new();
 
+/// Place the modifications from the assigned modifications column (eg `8M(15.9949), N-term(42.0106)`)
+/// as mass modifications on the unmodified peptide. The positions are 1-based. Returns None if
+/// there are no assigned modifications or if any of them cannot be interpreted as a mass on a
+/// valid location, in which case the modified peptide column should be used instead.
+fn parse_assigned_modifications(
+    peptide: &Peptidoform<SemiAmbiguous>,
+    assigned: &str,
+) -> Option<Peptidoform<SemiAmbiguous>> {
+    if assigned.trim().is_empty() {
+        return None;
+    }
+    let mut peptide = peptide.clone();
+    for modification in assigned.split(',') {
+        let (location, mass) = modification.trim().split_once('(')?;
+        let mass: SimpleModification = Arc::new(SimpleModificationInner::Mass(
+            Mass::new::<crate::system::dalton>(mass.strip_suffix(')')?.trim().parse().ok()?).into(),
+        ));
+        match location.trim() {
+            "N-term" | "n" => peptide.add_simple_n_term(mass),
+            "C-term" | "c" => peptide.add_simple_c_term(mass),
+            location => {
+                let (split, aa) = location.char_indices().last()?;
+                let index = location[..split].parse::<usize>().ok()?.checked_sub(1)?;
+                let aa = AminoAcid::try_from(aa).ok()?;
+                let element = peptide.sequence_mut().get_mut(index)?;
+                if element.aminoacid.aminoacid() != aa {
+                    return None;
+                }
+                element.modifications.push(Modification::from(mass));
+            }
+        }
+    }
+    Some(peptide)
+}
+
 impl From<MSFraggerData> for IdentifiedPeptide {
     fn from(value: MSFraggerData) -> Self {
         Self {
@@ -1,7 +1,12 @@
 #![allow(clippy::missing_panics_doc)]
 use std::io::BufReader;
 
-use crate::identification::{test_format, MSFraggerData, MSFraggerVersion};
+use crate::{
+    identification::{test_format, IdentifiedPeptideSource, MSFraggerData, MSFraggerVersion},
+    modification::{Modification, SimpleModificationInner},
+    system::{dalton, Mass},
+    AminoAcid,
+};
 
 #[test]
 fn msfragger_v21() {
@@ -54,7 +59,31 @@ fn msfragger_v22() {
     }
 }
 
-const DATA_V21_MANUAL: &str = r"Spectrum	Spectrum File	Peptide	Modified Peptide	Extended Peptide	Prev AA	Next AA	Peptide Length	Charge	Retention	Observed Mass	Calibrated Observed Mass	Observed M/Z	Calibrated Observed M/Z	Calculated Peptide Mass	Calculated M/Z	Delta Mass	Expectation	Hyperscore	Nextscore	PeptideProphet Probability	Number of Enzymatic Termini	Number of Missed Cleavages	Protein Start	Protein End	Intensity	Assigned Modifications	Observed Modifications	Purity	Is Unique	Protein	Protein ID	Entry Name	Gene	Protein Description	Mapped Genes	Mapped Proteins	condition	group
+#[test]
+fn assigned_modifications() {
+    let data = MSFraggerData::parse_reader(DATA_V21.as_bytes(), None)
+        .unwrap()
+        .map(Result::unwrap)
+        .find(|p| p.assigned_modifications == "8M(15.9949)")
+        .unwrap();
+    let peptide = data.peptide.unwrap();
+    assert_eq!(peptide.sequence().len(), 10);
+    for (index, element) in peptide.sequence().iter().enumerate() {
+        if index == 7 {
+            assert_eq!(element.aminoacid.aminoacid(), AminoAcid::Methionine);
+            assert_eq!(
+                element.modifications.as_slice(),
+                &[Modification::from(SimpleModificationInner::Mass(
+                    Mass::new::<dalton>(15.9949).into()
+                ))]
+            );
+        } else {
+            assert!(element.modifications.is_empty());
+        }
+    }
+}
+
+pub(super) const DATA_V21_MANUAL: &str = r"Spectrum	Spectrum File	Peptide	Modified Peptide	Extended Peptide	Prev AA	Next AA	Peptide Length	Charge	Retention	Observed Mass	Calibrated Observed Mass	Observed M/Z	Calibrated Observed M/Z	Calculated Peptide Mass	Calculated M/Z	Delta Mass	Expectation	Hyperscore	Nextscore	PeptideProphet Probability	Number of Enzymatic Termini	Number of Missed Cleavages	Protein Start	Protein End	Intensity	Assigned Modifications	Observed Modifications	Purity	Is Unique	Protein	Protein ID	Entry Name	Gene	Protein Description	Mapped Genes	Mapped Proteins	condition	group
 20240621_EX2_UM2_6579035_SA_EXT00_SS25_1A_Alk14_R1.21346.21346.2	D:\06-June\6579035\SS25\MSFragger_4-24\1_alk14_1\interact.pep.xml	GAQLSGGR	n[621]GAQLSGGR	.GAQLSGGR.GAPEPAQT	M	G	8	2	1811.7999	1364.8169	1364.8121	683.4157	683.4133	1364.814	683.4143	-0.0018	1.449549e-05	24.446	10.854	1	2	0	2	9	478745568	N-term(DB14 (N-term))		0	true	sp|A0A0U1RRL7|MMPOS_HUMAN	A0A0U1RRL7	MMPOS_HUMAN	MMP24OS	Protein MMP24OS			1_alk14_1	1_alk14
 20240621_EX2_UM2_6579035_SA_EXT00_SS25_1B_Alk14_R2.20911.20911.2	D:\06-June\6579035\SS25\MSFragger_4-24\1_alk14_2\interact.pep.xml	GAQLSGGR	n[621]GAQLSGGR	.GAQLSGGR.GAPEPAQT	M	G	8	2	1812.5955	1364.8169	1364.8116	683.4157	683.4131	1364.814	683.4143	-0.0023	1.071478e-05	24.441	10.843	1	2	0	2	9	405120480	N-term(DB14 (N-term))		0	true	sp|A0A0U1RRL7|MMPOS_HUMAN	A0A0U1RRL7	MMPOS_HUMAN	MMP24OS	Protein MMP24OS			1_alk14_2	1_alk14
 20240621_EX2_UM2_6579035_SA_EXT00_SS25_1C_Alk14_R3.20941.20941.2	D:\06-June\6579035\SS25\MSFragger_4-24\1_alk14_3\interact.pep.xml	GAQLSGGR	n[621]GAQLSGGR	.GAQLSGGR.GAPEPAQT	M	G	8	2	1815.257	1364.8164	1364.8118	683.4155	683.4132	1364.814	683.4143	-0.0022	0.0001124486	22.515	11.357	1	2	0	2	9	336166400	N-term(DB14 (N-term))		0	true	sp|A0A0U1RRL7|MMPOS_HUMAN	A0A0U1RRL7	MMPOS_HUMAN	MMP24OS	Protein MMP24OS			1_alk14_3	1_alk14
@@ -76,7 +105,7 @@ const DATA_V21_MANUAL: &str = r"Spectrum	Spectrum File	Peptide	Modified Peptide
 20240621_EX2_UM2_6579035_SA_EXT00_SS25_5A_Alk16_Pal_R1.15538.15538.4	D:\06-June\6579035\SS25\MSFragger_4-24\5_alk16_Pal_1\interact.pep.xml	GSRVSREDFEWVYTDQPHADR	n[621]GSRVSREDFEWVYTDQPHADR	.GSRVSREDFEWVYTDQPHADR.RREIIAKY	M	R	21	4	1399.581	3169.5972	3169.6016	793.4066	793.4077	3169.5945	793.4059	0.007	2.785583e-08	29.282	12.917	1	2	2	2	22	23077696	N-term(DB14 (N-term))		0	true	sp|O15121|DEGS1_HUMAN	O15121	DEGS1_HUMAN	DEGS1	Sphingolipid delta(4)-desaturase DES1			5_alk16_Pal_1	5_alk16_Pal
 ";
 
-const DATA_V21: &str = r"Spectrum	Spectrum File	Peptide	Modified Peptide	Extended Peptide	Prev AA	Next AA	Peptide Length	Charge	Retention	Observed Mass	Calibrated Observed Mass	Observed M/Z	Calibrated Observed M/Z	Calculated Peptide Mass	Calculated M/Z	Delta Mass	Expectation	Hyperscore	Nextscore	PeptideProphet Probability	Number of Enzymatic Termini	Number of Missed Cleavages	Protein Start	Protein End	Intensity	Assigned Modifications	Observed Modifications	Purity	Is Unique	Protein	Protein ID	Entry Name	Gene	Protein Description	Mapped Genes	Mapped Proteins
+pub(super) const DATA_V21: &str = r"Spectrum	Spectrum File	Peptide	Modified Peptide	Extended Peptide	Prev AA	Next AA	Peptide Length	Charge	Retention	Observed Mass	Calibrated Observed Mass	Observed M/Z	Calibrated Observed M/Z	Calculated Peptide Mass	Calculated M/Z	Delta Mass	Expectation	Hyperscore	Nextscore	PeptideProphet Probability	Number of Enzymatic Termini	Number of Missed Cleavages	Protein Start	Protein End	Intensity	Assigned Modifications	Observed Modifications	Purity	Is Unique	Protein	Protein ID	Entry Name	Gene	Protein Description	Mapped Genes	Mapped Proteins
 20240206_EX1_UM3_6579035_SA_EXT00_SS16_pH7p5-single_res30k.03832.03832.3	D:\02-February\6579035\SS16\MSFragger3\4_res30k_1\interact.pep.xml	QVHPDTGISSK		VYVYKVIK.QVHPDTGISSK.AMGIMNSF	K	A	11	3	503.3970	1167.5969	1167.5906	390.2062	390.2041	1167.5884	390.2034	0.0022	0.00026471960000	21.3860	11.0140	0.9999	2	0	48	58	1.1243405E7			0.00	false	sp|O60814|H2B1K_HUMAN	O60814	H2B1K_HUMAN	H2BC12	Histone H2B type 1-K	H2BC1, H2BC11, H2BC12L, H2BC13, H2BC14, H2BC15, H2BC17, H2BC18, H2BC21, H2BC26, H2BC3, H2BC4, H2BC5, H2BC9	sp|P06899|H2B1J_HUMAN, sp|P23527|H2B1O_HUMAN, sp|P33778|H2B1B_HUMAN, sp|P57053|H2BFS_HUMAN, sp|P58876|H2B1D_HUMAN, sp|P62807|H2B1C_HUMAN, sp|Q16778|H2B2E_HUMAN, sp|Q5QNW6|H2B2F_HUMAN, sp|Q8N257|H2B3B_HUMAN, sp|Q93079|H2B1H_HUMAN, sp|Q96A08|H2B1A_HUMAN, sp|Q99877|H2B1N_HUMAN, sp|Q99879|H2B1M_HUMAN, sp|Q99880|H2B1L_HUMAN
 20240206_EX1_UM3_6579035_SA_EXT00_SS16_pH7p5-single_res30k.03833.03833.4	D:\02-February\6579035\SS16\MSFragger3\4_res30k_1\interact.pep.xml	LAQHITYVHQHSR		PDRDNDIR.LAQHITYVHQHSR.QPPSQFEP	R	Q	13	4	503.4661	1588.8329	1588.8269	398.2155	398.2140	1588.8221	398.2128	0.0047	0.00001598673000	26.5640	11.3860	0.9924	2	0	533	545	3841888.2			0.00	true	sp|P33993|MCM7_HUMAN	P33993	MCM7_HUMAN	MCM7	DNA replication licensing factor MCM7		
 20240206_EX1_UM3_6579035_SA_EXT00_SS16_pH7p5-single_res30k.03835.03835.3	D:\02-February\6579035\SS16\MSFragger3\4_res30k_1\interact.pep.xml	RVHPVSTMIK	RVHPVSTM[147]IK	ESIMKNIR.RVHPVSTMIK.GIYGIKDD	R	G	10	3	503.6042	1182.6621	1182.6564	395.2280	395.2261	1182.6542	395.2253	0.0022	0.05410656000000	15.5670	9.7440	0.9549	2	0	269	278	2613044.5	8M(15.9949)		0.00	true	sp|P00338|LDHA_HUMAN	P00338	LDHA_HUMAN	LDHA	L-lactate dehydrogenase A chain		
@@ -98,7 +127,7 @@ const DATA_V21: &str = r"Spectrum	Spectrum File	Peptide	Modified Peptide	Extende
 20240206_EX1_UM3_6579035_SA_EXT00_SS16_pH7p5-single_res30k.03926.03926.3	D:\02-February\6579035\SS16\MSFragger3\4_res30k_1\interact.pep.xml	EDSQRPGAHLTVK		EPKRAVSR.EDSQRPGAHLTVK.KIFVGGIK	R	K	13	3	510.5855	1436.7423	1436.7379	479.9214	479.9199	1436.7370	479.9196	0.0008	0.03654860000000	15.5510	11.6570	0.9056	2	1	93	105	655305.7			0.00	false	sp|P09651|ROA1_HUMAN	P09651	ROA1_HUMAN	HNRNPA1	Heterogeneous nuclear ribonucleoprotein A1	HNRNPA1L2	sp|Q32P51|RA1L2_HUMAN
 ";
 
-const DATA_V22: &str = r"Spectrum	Spectrum File	Peptide	Modified Peptide	Extended Peptide	Prev AA	Next AA	Peptide Length	Charge	Retention	Observed Mass	Calibrated Observed Mass	Observed M/Z	Calibrated Observed M/Z	Calculated Peptide Mass	Calculated M/Z	Delta Mass	Expectation	Hyperscore	Nextscore	Probability	Number of Enzymatic Termini	Number of Missed Cleavages	Protein Start	Protein End	Intensity	Assigned Modifications	Observed Modifications	Purity	Is Unique	Protein	Protein ID	Entry Name	Gene	Protein Description	Mapped Genes	Mapped Proteins
+pub(super) const DATA_V22: &str = r"Spectrum	Spectrum File	Peptide	Modified Peptide	Extended Peptide	Prev AA	Next AA	Peptide Length	Charge	Retention	Observed Mass	Calibrated Observed Mass	Observed M/Z	Calibrated Observed M/Z	Calculated Peptide Mass	Calculated M/Z	Delta Mass	Expectation	Hyperscore	Nextscore	Probability	Number of Enzymatic Termini	Number of Missed Cleavages	Protein Start	Protein End	Intensity	Assigned Modifications	Observed Modifications	Purity	Is Unique	Protein	Protein ID	Entry Name	Gene	Protein Description	Mapped Genes	Mapped Proteins
 20240621_EX2_UM2_6579035_SA_EXT00_SS25_1A_Alk14_R1.04252.04252.2	D:\06-June\6579035\SS25\MSFragger_prenylation_saturation_2\alk14_1\interact.pep.xml	HAVSEGTK		IIPGEIAK.HAVSEGTK.AVTKYTSS	K	A	8	2	484.2465	827.4148	827.4143	414.7147	414.7144	827.4137	414.7141	0.0006	0.02229718000000	12.1070	0.0000	0.9783	2	0	110	117	321365.75			0.00	false	sp|P58876|H2B1D_HUMAN	P58876	H2B1D_HUMAN	H2BC5	Histone H2B type 1-D	H2BC1, H2BC11, H2BC12, H2BC12L, H2BC13, H2BC14, H2BC15, H2BC17, H2BC18, H2BC21, H2BC26, H2BC3, H2BC4, H2BC9, H2BK1	sp|A0A2R8Y619|H2BK1_HUMAN, sp|O60814|H2B1K_HUMAN, sp|P06899|H2B1J_HUMAN, sp|P23527|H2B1O_HUMAN, sp|P33778|H2B1B_HUMAN, sp|P57053|H2BFS_HUMAN, sp|P62807|H2B1C_HUMAN, sp|Q16778|H2B2E_HUMAN, sp|Q5QNW6|H2B2F_HUMAN, sp|Q8N257|H2B3B_HUMAN, sp|Q93079|H2B1H_HUMAN, sp|Q96A08|H2B1A_HUMAN, sp|Q99877|H2B1N_HUMAN, sp|Q99879|H2B1M_HUMAN, sp|Q99880|H2B1L_HUMAN
 20240621_EX2_UM2_6579035_SA_EXT00_SS25_1A_Alk14_R1.04340.04340.4	D:\06-June\6579035\SS25\MSFragger_prenylation_saturation_2\alk14_1\interact.pep.xml	HAVSEGTKAVTKYTSSK		IIPGEIAK.HAVSEGTKAVTKYTSSK.	K	-	17	4	494.4629	1792.9319	1792.9276	449.2403	449.2392	1792.9319	449.2403	-0.0042	0.00000000000000	44.7880	9.8410	1.0000	2	2	110	126	0			0.00	false	sp|P58876|H2B1D_HUMAN	P58876	H2B1D_HUMAN	H2BC5	Histone H2B type 1-D	H2BC1, H2BC13, H2BC14, H2BC15, H2BC17, H2BC18, H2BC21, H2BC26, H2BC3, H2BC4, H2BC9, H2BK1	sp|A0A2R8Y619|H2BK1_HUMAN, sp|P23527|H2B1O_HUMAN, sp|P33778|H2B1B_HUMAN, sp|P62807|H2B1C_HUMAN, sp|Q16778|H2B2E_HUMAN, sp|Q5QNW6|H2B2F_HUMAN, sp|Q8N257|H2B3B_HUMAN, sp|Q93079|H2B1H_HUMAN, sp|Q96A08|H2B1A_HUMAN, sp|Q99877|H2B1N_HUMAN, sp|Q99879|H2B1M_HUMAN, sp|Q99880|H2B1L_HUMAN
 20240621_EX2_UM2_6579035_SA_EXT00_SS25_1A_Alk14_R1.04491.04491.3	D:\06-June\6579035\SS25\MSFragger_prenylation_saturation_2\alk14_1\interact.pep.xml	RMHVQLSTSR		DNTEFQGK.RMHVQLSTSR.LRTAPGMG	K	L	10	3	506.0050	1213.6370	1213.6338	405.5529	405.5519	1213.6349	405.5522	-0.0011	0.00000000032271	22.2990	9.9590	1.0000	2	0	140	149	9287616.0			0.00	false	sp|Q9BWF3|RBM4_HUMAN	Q9BWF3	RBM4_HUMAN	RBM4	RNA-binding protein 4	RBM4B	sp|Q9BQ04|RBM4B_HUMAN
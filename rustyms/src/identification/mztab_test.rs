@@ -95,7 +95,7 @@ fn open_file(reader: impl BufRead) -> Result<usize, CustomError> {
     Ok(peptides)
 }
 
-const PRIDE_EXP_EXCERPT_AC_1643: &str = r"MTD	mzTab-version	1.0 rc5
+pub(super) const PRIDE_EXP_EXCERPT_AC_1643: &str = r"MTD	mzTab-version	1.0 rc5
 MTD	mzTab-mode	Complete
 MTD	mzTab-type	Identification
 MTD	mzTab-ID	1643
@@ -163,7 +163,7 @@ PSM	QGVNDNEEGFFSAR	15077	IPI00298497	null	IPI human	2.31	null	null	null	null	nul
 PSM	QGVNDNEEGFFSAR	14371	IPI00298497	null	IPI human	2.31	null	null	null	null	null	null	null	ms_run[1]:spectrum=14371	null	null	31	44
 PSM	EAPSLRPAPPPISGGGYR	14059	IPI00298497	null	IPI human	2.31	null	null	null	null	null	null	null	ms_run[1]:spectrum=14059	null	null	55	72";
 
-const PRIDE_EXP_EXCERPT_AC_16649: &str = r"MTD	mzTab-version	1.0 rc5
+pub(super) const PRIDE_EXP_EXCERPT_AC_16649: &str = r"MTD	mzTab-version	1.0 rc5
 MTD	mzTab-mode	Summary
 MTD	mzTab-type	Quantification
 MTD	mzTab-ID	16649
@@ -252,7 +252,7 @@ PSM	QGQIDNQTR	7979	223462890	null	NCBInr_2010_10	nr_101020.fasta	[MS, MS:1001207
 PSM	EFSMMFK	8068	223462890	null	NCBInr_2010_10	nr_101020.fasta	[MS, MS:1001207, Mascot, ]	42.5	0-MOD:01499,7-MOD:01499	null	1	1207.596069	1207.609896	ms_run[1]:spectrum=8068	K	H	2332	2338
 PSM	SLQQLAEER	8285	223462890	null	NCBInr_2010_10	nr_101020.fasta	[MS, MS:1001207, Mascot, ]	73.16	0-MOD:01499	null	1	1217.661011	1217.661163	ms_run[1]:spectrum=8285	R	S	1217	1225";
 
-const SILAC_CQI: &str = r#"COM	This	line	serves	as	a	size	and	separator	hint	for	spreadsheet	applications.	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-
+pub(super) const SILAC_CQI: &str = r#"COM	This	line	serves	as	a	size	and	separator	hint	for	spreadsheet	applications.	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-
 COM	Report of a "Complete Quantification report" SILAC experiment, quantification on 2 study variables (control/treatment), 3+3 assays (replicates) reported, identifications reported.
 MTD	mzTab-version	1.0.0
 MTD	mzTab-mode	Complete
@@ -350,7 +350,7 @@ PSM	LGLGIDEDDPTVDDTSAAVTEEMPPLEGDDDTSR	24	P07901	1	UniProtKB	2013_08	[MS,MS:1001
 PSM	TLTIVDTGIGMTK	25	P07901	1	UniProtKB	2013_08	[MS,MS:1001207,Mascot,]	40	null	ms_run[3]:scan=858	1132.62	3	450.5823742	450.583	R	A	88	100
 PSM	MPEETQTQDQPMEEEEVETFAFQAEIAQLMSLIINTFYSNK	26	P07901	1	UniProtKB	2013_08	[MS,MS:1001207,Mascot,]	6	12-UNIMOD:35	ms_run[3]:scan=2923	3184.08	2	2405.596441	2405.6084	-	E	1	41"#;
 
-const ITRAQ_CQI: &str = r#"COM	This	line	serves	as	a	size	and	separator	hint	for	spreadsheet	applications.	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-
+pub(super) const ITRAQ_CQI: &str = r#"COM	This	line	serves	as	a	size	and	separator	hint	for	spreadsheet	applications.	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-
 COM	Report of a minimal "Complete Quantification report" iTRAQ experiment, quantification on 4 study variables (t=0, t=1, t=2, t=3), 4*4 assays (4 replicate experiments) reported, identifications reported.
 MTD	mzTab-version	1.0.0
 MTD	mzTab-mode	Complete
@@ -466,7 +466,7 @@ PSM	MIKLGLGIDEDDPTVDDTSAAVTEEMPPLEGDDDTSR	32	P07901	1	UniProtKB	2013_08	[MS,MS:1
 PSM	LGLGIDEDDPTVDDTSAAVTEEMPPLEGDDDTSR	33	P07901	1	UniProtKB	2013_08	[MS,MS:1001207,Mascot,]	0	23-UNIMOD:35	ms_run[4]:scan=4019	2611.08	2	1788.289062	1788.2886	K	M	695	728
 PSM	MPEETQTQDQPMEEEEVETFAFQAEIAQLMSLIINTFYSNK	34	P07901	1	UniProtKB	2013_08	[MS,MS:1001207,Mascot,]	11	0-UNIMOD:35	ms_run[4]:scan=4592	3184.08	2	2405.57421	2405.6084	-	E	1	41"#;
 
-const ITRAQ_SQI: &str = r#"COM	This	line	serves	as	a	size	and	separator	hint	for	spreadsheet	applications.	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-
+pub(super) const ITRAQ_SQI: &str = r#"COM	This	line	serves	as	a	size	and	separator	hint	for	spreadsheet	applications.	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-
 COM	Report of a minimal Summary Quantification report" iTRAQ experiment, quantification on 4 study variables (t=0, t=1, t=2, t=3) reported, identifications reported.
 MTD	mzTab-version	1.0.0
 MTD	mzTab-mode	Summary
@@ -525,7 +525,7 @@ PSM	LGLGIDEDDPTVDDTSAAVTEEMPPLEGDDDTSR	24	P07901	1	UniProtKB	2013_08	[MS,MS:1001
 PSM	TLTIVDTGIGMTK	25	P07901	1	UniProtKB	2013_08	[MS,MS:1001207,Mascot,]	26	0-UNIMOD:214,13-UNIMOD:214	ms_run[3]:scan=858	1132.62	3	450.599	450.583	R	A	88	100
 PSM	MPEETQTQDQPMEEEEVETFAFQAEIAQLMSLIINTFYSNK	26	P07901	1	UniProtKB	2013_08	[MS,MS:1001207,Mascot,]	-7	0-UNIMOD:214,41-UNIMOD:214	ms_run[3]:scan=2923	3184.08	2	2405.62	2405.61	-	E	1	41"#;
 
-const LABELFREE_CQI: &str = r#"COM	This	line	serves	as	a	size	and	separator	hint	for	spreadsheet	applications.	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-
+pub(super) const LABELFREE_CQI: &str = r#"COM	This	line	serves	as	a	size	and	separator	hint	for	spreadsheet	applications.	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-
 COM	Report of a minimal "Complete Quantification report" label free experiment, quantification on 2 study variables (control/treatment), 3+3 assays (replicates) reported,identifications reported.
 MTD	mzTab-version	1.0.0
 MTD	mzTab-mode	Complete
@@ -630,7 +630,7 @@ PSM	LGLGIDEDDPTVDDTSAAVTEEMPPLEGDDDTSR	48	P07901	1	UniProtKB	2013_08	[MS,MS:1001
 PSM	TLTIVDTGIGMTK	49	P07901	1	UniProtKB	2013_08	[MS,MS:1001207,Mascot,]	39	11-UNIMOD:35	ms_run[6]:scan=1123	1132.62	3	450.6038036	450.583	R	A	88	100
 PSM	MPEETQTQDQPMEEEEVETFAFQAEIAQLMSLIINTFYSNK	50	P07901	1	UniProtKB	2013_08	[MS,MS:1001207,Mascot,]	33	0-UNIMOD:35	ms_run[6]:scan=3188	3184.08	2	2405.605739	2405.6084	-	E	1	41"#;
 
-const LABELFREE_SQI: &str = r#"COM	This	line	serves	as	a	size	and	separator	hint	for	spreadsheet	applications.	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-
+pub(super) const LABELFREE_SQI: &str = r#"COM	This	line	serves	as	a	size	and	separator	hint	for	spreadsheet	applications.	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-	-
 COM	Report of a minimal "Summary Quantification report" label free experiment, quantification on 2 study variables (control/treatment) reported,identifications reported.
 MTD	mzTab-version	1.0.0
 MTD	mzTab-mode	Summary
@@ -719,7 +719,7 @@ PSM	LGLGIDEDDPTVDDTSAAVTEEMPPLEGDDDTSR	48	P07901	1	UniProtKB	2013_08	[MS,MS:1001
 PSM	TLTIVDTGIGMTK	49	P07901	1	UniProtKB	2013_08	[MS,MS:1001207,Mascot,]	36	11-UNIMOD:35	ms_run[6]:scan=1123	1132.62	3	450.5838508	450.583	R	A	88	100
 PSM	MPEETQTQDQPMEEEEVETFAFQAEIAQLMSLIINTFYSNK	50	P07901	1	UniProtKB	2013_08	[MS,MS:1001207,Mascot,]	11	0-UNIMOD:35	ms_run[6]:scan=3188	3184.08	2	2405.619464	2405.6084	-	E	1	41"#;
 
-const CASANOVO_V3_2_0_A: &str = r"MTD	mzTab-version	1.0.0
+pub(super) const CASANOVO_V3_2_0_A: &str = r"MTD	mzTab-version	1.0.0
 MTD	mzTab-mode	Summary
 MTD	mzTab-type	Identification
 MTD	description	Casanovo identification file MM_peptides_casanovo_AspN
@@ -820,7 +820,7 @@ PSM	VKDDDYR	38	null	null	null	null	[MS, MS:1003281, Casanovo, 3.2.0]	-0.60386642
 PSM	GLSSHGR	39	null	null	null	null	[MS, MS:1003281, Casanovo, 3.2.0]	-0.5621655200208937	null	null	1	667.176513671875	713.36891216688	ms_run[1]:index=39	null	null	null	null	0.49872,0.23509,0.96360,0.66934,0.21727,0.12017,0.36065
 PSM	LYRPK	40	null	null	null	null	[MS, MS:1003281, Casanovo, 3.2.0]	-0.7804442211985588	null	null	1	607.20947265625	676.41407216688	ms_run[1]:index=40	null	null	null	null	0.18311,0.12588,0.11594,0.19753,0.47532";
 
-const CASANOVO_V3_2_0_B: &str = r"MTD	mzTab-version	1.0.0
+pub(super) const CASANOVO_V3_2_0_B: &str = r"MTD	mzTab-version	1.0.0
 MTD	mzTab-mode	Summary
 MTD	mzTab-type	Identification
 MTD	description	Casanovo identification file EH3420
@@ -921,7 +921,7 @@ PSM	GRALRRK	38	null	null	null	null	[MS, MS:1003281, Casanovo, 3.2.0]	-0.55639706
 PSM	SLDSERGR	39	null	null	null	null	[MS, MS:1003281, Casanovo, 3.2.0]	-0.5517001785337925	null	null	2	456.0073547363281	460.23322981688005	ms_run[1]:index=39	null	null	null	null	0.86180,0.33256,0.34989,0.51239,0.29720,0.32683,0.18481,0.72092
 PSM	GRPLSGSGSGLL	40	null	null	null	null	[MS, MS:1003281, Casanovo, 3.2.0]	-0.5062050955990951	null	null	2	556.092529296875	550.80656231688	ms_run[1]:index=40	null	null	null	null	0.99992,0.72701,0.68849,0.10854,0.25012,0.46942,0.37918,0.22213,0.23691,0.27216,0.78839,0.78325";
 
-const CASANOVO_V4_2_1: &str = r"MTD	mzTab-version	1.0.0
+pub(super) const CASANOVO_V4_2_1: &str = r"MTD	mzTab-version	1.0.0
 MTD	mzTab-mode	Summary
 MTD	mzTab-type	Identification
 MTD	description	Casanovo identification file 20191211_F1_Ag5_peng0013_SA_her_tryp_c421
@@ -1022,7 +1022,7 @@ PSM	ATKPAEPAAPA	37	null	null	null	null	[MS, MS:1003281, Casanovo, 4.2.1]	-0.2947
 PSM	TPTTFTLSSLK	38	null	null	null	null	[MS, MS:1003281, Casanovo, 4.2.1]	-0.6585477354625862	null	null	2.0	601.83105	598.33206131688	ms_run[1]:index=37	null	null	null	null	0.49606,0.24439,0.25802,0.25530,0.24457,0.25336,0.24752,0.42064,0.27782,0.35260,0.38190
 PSM	LNGGNNHTGEK	39	null	null	null	null	[MS, MS:1003281, Casanovo, 4.2.1]	-0.5278554943700631	null	null	2.0	579.2828	570.77324631688	ms_run[1]:index=38	null	null	null	null	0.59246,0.34809,0.47594,0.72999,0.35148,0.35545,0.30616,0.42239,0.51386,0.50807,0.34118";
 
-const CONTRANOVO_V1_0_0: &str = r"MTD	mzTab-version	1.0.0
+pub(super) const CONTRANOVO_V1_0_0: &str = r"MTD	mzTab-version	1.0.0
 MTD	mzTab-mode	Summary
 MTD	mzTab-type	Identification
 MTD	description	Casanovo identification file 20230408_F1_UM4_Peng0013_SA_EXT00_her_01_tryp_formatted4_pointnovo
@@ -20,7 +20,7 @@ fn novob() {
     }
 }
 
-const NOVOB_V0_0_1: &str = "1	2	2412.424	['VATTAYDYYSYSVITDAIYNF']	-8.679	0.000000	['FGVGAENYGDDEEGDIEEIIINIIEK']	-449.920	0.000000
+pub(super) const NOVOB_V0_0_1: &str = "1	2	2412.424	['VATTAYDYYSYSVITDAIYNF']	-8.679	0.000000	['FGVGAENYGDDEEGDIEEIIINIIEK']	-449.920	0.000000
 2	2	2412.424	['STSGDTISEINSEIIEEQVGVNK']	-17.753	0.000000	['mAITmASTQIEDITDEEIIEIIIEK']	-449.991	0.000000
 3	4	1735.869	['HKDGSTEIKDnKTNR']	11.023	0.000013	['CDVNHKIPSNTKVDK']	0.000	0.077978
 4	4	2869.220	['RPPPPPPSPPPPPEEGPTAPPRPQTYH']	-2.230	0.000000	['TREPQEPMEHQGGSGPESGQENSTYR']	-0.014	0.000000
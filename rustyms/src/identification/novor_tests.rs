@@ -54,7 +54,7 @@ fn novor_new_psm() {
     }
 }
 
-const DATA_OLD_DENOVO: &str = r"Fraction,Scan #,m/z,z,Score,Peptide Mass,Error (ppm),Length,De Novo Peptide,DB Sequence
+pub(super) const DATA_OLD_DENOVO: &str = r"Fraction,Scan #,m/z,z,Score,Peptide Mass,Error (ppm),Length,De Novo Peptide,DB Sequence
 F1,18017,553.821533203125,2,97.9,1105.6284,0.1,9,LLLFWASTR,
 F1,16407,561.81787109375,2,97.8,1121.6233,-1.9,9,LLLYWASTR,LLIYWASTR
 F1,15003,454.247955322266,2,97.5,906.4811,0.3,8,DGFLQSLK,N(Deamidated)GFIQSLK
@@ -76,7 +76,7 @@ F1,13719,451.720458984375,2,95.7,901.4269,-0.6,6,WM(O)HWVK,
 F1,9345,496.262512207031,2,95.6,990.5094,1.1,9,SSQSLLNSR,
 F1,9309,409.229461669922,2,95.6,816.4454,-1.2,7,QSLLNSR,";
 
-const DATA_NEW_DENOVO: &str = r"# id, scanNum, RT, mz(data), z, pepMass(denovo), err(data-denovo), ppm(1e6*err/(mz*z)), score, peptide, aaScore 
+pub(super) const DATA_NEW_DENOVO: &str = r"# id, scanNum, RT, mz(data), z, pepMass(denovo), err(data-denovo), ppm(1e6*err/(mz*z)), score, peptide, aaScore 
 0, 10234, 3058.1, 706.3292, 4, 2821.2907, -0.0032, -1.1, 24.8, MM(O)EKGPHTLNSPGRGMFM(O)TTLLDPE, 11-15-39-35-2-4-3-1-5-44-2-1-6-3-2-34-46-6-1-3-37-57-68-79-91
 1, 8236, 2472.1, 543.2781, 3, 1626.8154, -0.0029, -1.8, 73.1, DLAPGTYLHWVREA, 25-53-25-3-43-52-94-96-99-94-98-97-90-90
 2, 1846, 587.9, 446.7351, 2, 891.4491, 0.0065, 7.3, 40.9, FLWANEL, 69-42-63-79-8-24-1
@@ -98,7 +98,7 @@ const DATA_NEW_DENOVO: &str = r"# id, scanNum, RT, mz(data), z, pepMass(denovo),
 18, 7042, 2125.7, 497.7650, 4, 1987.0064, 0.0245, 12.3, 46.8, SFFLQHLNKAGPASRWE, 40-86-98-93-22-1-1-11-1-2-7-1-15-79-70-88-89
 19, 11745, 3499.8, 558.0223, 4, 2228.0651, -0.0050, -2.2, 21.4, VKFDWYVNPNGHVVSGGEGPA, 18-4-44-53-44-28-25-24-3-1-6-1-3-43-4-2-6-46-54-3-3";
 
-const DATA_NEW_PSM: &str = r"#id, spectraId, scanNum, RT, mz, z, pepMass, err, ppm, score, protein, start, length, origin, peptide, noPTMPeptide, aac, allProteins
+pub(super) const DATA_NEW_PSM: &str = r"#id, spectraId, scanNum, RT, mz, z, pepMass, err, ppm, score, protein, start, length, origin, peptide, noPTMPeptide, aac, allProteins
 1, 14, 14034, 4187.9, 531.2891, 2, 1060.5593, 0.0044, 4.1, 2.3662, 6, 5, 10, APSVFIFPPS, APSVFIFPPS, APSVFIFPPS, 2-5-17-18-23-52-52-52-38-37, 5@6;112@7
 2, 20, 9784, 2928.1, 429.2599, 3, 1284.7554, 0.0024, 1.9, 5.3597, 1, 183, 11, YRVVSVLTVLH, YRVVSVLTVLH, YRVVSVLTVLH, 36-37-52-52-43-52-52-52-42-44-44, 183@1;302@2;180@4
 3, 21, 4244, 1311.6, 452.5754, 3, 1354.6980, 0.0063, 4.6, 6.1235, 6, 70, 12, STLTLSKADYEK, STLTLSKADYEK, STLTLSKADYEK, 36-44-33-52-48-52-35-43-38-39-36-28, 70@6;177@7
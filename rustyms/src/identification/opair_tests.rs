@@ -20,7 +20,7 @@ fn opair() {
     }
 }
 
-const DATA: &str = r#"File Name	Scan Number	Scan Retention Time	Precursor Scan Number	Precursor MZ	Precursor Charge	Precursor Mass	Protein Accession	Organism	Protein Name	Start and End Residues In Protein	Base Sequence	FlankingResidues	Full Sequence	Number of Mods	Peptide Monoisotopic Mass	Score	Rank	Matched Ion Series	Matched Ion Mass-To-Charge Ratios	Matched Ion Mass Diff (Da)	Matched Ion Mass Diff (Ppm)	Matched Ion Intensities	Matched Ion Counts	Decoy/Contaminant/Target	QValue	PEP	PEP_QValue	Localization Score	Yion Score	DiagonosticIon Score	Plausible Number Of Glycans	Total Glycosylation sites	GlycanMass	Plausible GlycanComposition	N-Glycan motif Check	R138/144	Plausible GlycanStructure	GlycanLocalizationLevel	Localized Glycans with Peptide Site Specific Probability	Localized Glycans with Protein Site Specific Probability	All potential glycan localizations	AllSiteSpecificLocalizationProbability
+pub(super) const DATA: &str = r#"File Name	Scan Number	Scan Retention Time	Precursor Scan Number	Precursor MZ	Precursor Charge	Precursor Mass	Protein Accession	Organism	Protein Name	Start and End Residues In Protein	Base Sequence	FlankingResidues	Full Sequence	Number of Mods	Peptide Monoisotopic Mass	Score	Rank	Matched Ion Series	Matched Ion Mass-To-Charge Ratios	Matched Ion Mass Diff (Da)	Matched Ion Mass Diff (Ppm)	Matched Ion Intensities	Matched Ion Counts	Decoy/Contaminant/Target	QValue	PEP	PEP_QValue	Localization Score	Yion Score	DiagonosticIon Score	Plausible Number Of Glycans	Total Glycosylation sites	GlycanMass	Plausible GlycanComposition	N-Glycan motif Check	R138/144	Plausible GlycanStructure	GlycanLocalizationLevel	Localized Glycans with Peptide Site Specific Probability	Localized Glycans with Protein Site Specific Probability	All potential glycan localizations	AllSiteSpecificLocalizationProbability
 D:\Dolphins\GluCAspN\20230708_L1_UM5_shamo002_SA_EXT02_BWCoV_PngaseF_AspN_pdETHCD.raw	21301	56.63369894	21289	1207.891456	3	3620.652538	BdCoV_S			[272 to 301]	FVDDALGPDYPCPTLSSLQASYVPGDCAIK	"K,D"	FVDDALGPDYPC[Common Fixed:Carbamidomethyl on C]PT[O-Glycosylation:H1N1 on X]LSSLQASYVPGDC[Common Fixed:Carbamidomethyl on C]AIK	3	3620.642607	78.42935043	1	"{21301@[y1+1, y2+1, y3+1, y4+1, y5+1, y6+1, y7+1, y8+1, y9+1, y10+1, y11+1, y12+1, y13+1, y14+1, y15+1, y16+1, y17+1, y18+1, y20+1, y23+1, y24+2];[b2+1, b3+1, b4+1, b5+1, b6+1, b7+1, b9+1, b10+1, b11+1, b12+1, b15+1, b18+1];[D126+1, D138+1, D144+1, D168+1, D186+1, D204+1, D366+1]}{21303@[y3+1, y4+1, y5+1, y7+1, y8+1, y9+1, y10+1, y11+1, y12+1, y13+1, y14+1, y15+1, y20+1, y21+1];[b6+1, b7+1, b9+1, b10+1, b12+1, b18+1, b23+1];[(M0-365.13)+2];[D126+1, D138+1, D144+1, D186+1, D204+1, D366+1];[zDot3+1, zDot8+1, zDot9+1, zDot10+1, zDot11+1, zDot12+1, zDot13+1, zDot14+1, zDot15+1, zDot16+1];[c4+1, c5+1, c6+1, c8+1, c9+1, c11+1, c13+1, c14+1, c15+1, c16+1, c17+1, c19+1, c20+1]}"	"{21301@[y1+1:147.11435, y2+1:260.19937, y3+1:331.23728, y4+1:491.26922, y5+1:606.29822, y6+1:663.32088, y7+1:760.37330, y8+1:859.44182, y9+1:1022.50690, y10+1:1109.54047, y11+1:1180.57715, y12+1:1308.63660, y13+1:1421.72068, y14+1:1508.75398, y15+1:1595.78670, y16+1:1708.86707, y17+1:1809.90076, y18+1:1906.97297, y20+1:2164.05618, y23+1:2539.18652, y24+2:1298.61873];[b2+1:247.14659, b3+1:362.17419, b4+1:477.20367, b5+1:548.24051, b6+1:661.32634, b7+1:718.34622, b9+1:930.43216, b10+1:1093.49393, b11+1:1190.55469, b12+1:1350.57932, b15+1:1661.75183, b18+1:1948.92798];[D126+1:126.05638, D138+1:138.05651, D144+1:144.06712, D168+1:168.06701, D186+1:186.07809, D204+1:204.08889, D366+1:366.14332]}{21303@[y3+1:331.23709, y4+1:491.26804, y5+1:606.29657, y7+1:760.37274, y8+1:859.44170, y9+1:1022.50482, y10+1:1109.54077, y11+1:1180.57568, y12+1:1308.62948, y13+1:1421.72302, y14+1:1508.75498, y15+1:1595.77673, y20+1:2164.03979, y21+1:2327.11670];[b6+1:661.32332, b7+1:718.34808, b9+1:930.42505, b10+1:1093.49951, b12+1:1350.58191, b18+1:1948.92505, b23+1:2497.18587];[(M0-365.13)+2:1628.77659];[D126+1:126.05563, D138+1:138.05574, D144+1:144.06648, D186+1:186.07724, D204+1:204.08786, D366+1:366.14269];[zDot3+1:315.21692, zDot8+1:843.42419, zDot9+1:1006.49011, zDot10+1:1093.49951, zDot11+1:1164.55996, zDot12+1:1292.61494, zDot13+1:1405.70551, zDot14+1:1492.72681, zDot15+1:1579.77744, zDot16+1:1692.84814];[c4+1:494.22870, c5+1:565.26801, c6+1:678.34991, c8+1:832.42760, c9+1:947.45481, c11+1:1207.57751, c13+1:1464.66516, c14+1:1930.85315, c15+1:2043.91089, c16+1:2130.95874, c17+1:2217.99909, c19+1:2459.11963, c20+1:2530.17651]}"	"{21301@[y1+1:0.00155, y2+1:0.00250, y3+1:0.00329, y4+1:0.00459, y5+1:0.00664, y6+1:0.00784, y7+1:0.00750, y8+1:0.00761, y9+1:0.00935, y10+1:0.01090, y11+1:0.01046, y12+1:0.01133, y13+1:0.01136, y14+1:0.01262, y15+1:0.01332, y16+1:0.00962, y17+1:-0.00437, y18+1:0.01508, y20+1:0.01488, y23+1:0.00219, y24+2:0.02438];[b2+1:0.00249, b3+1:0.00315, b4+1:0.00568, b5+1:0.00540, b6+1:0.00717, b7+1:0.00559, b9+1:0.01182, b10+1:0.01026, b11+1:0.01826, b12+1:0.01224, b15+1:0.00024, b18+1:0.02827];[D126+1:0.00143, D138+1:0.00156, D144+1:0.00160, D168+1:0.00149, D186+1:0.00202, D204+1:0.00224, D366+1:0.00385]}{21303@[y3+1:0.00311, y4+1:0.00341, y5+1:0.00500, y7+1:0.00694, y8+1:0.00749, y9+1:0.00727, y10+1:0.01120, y11+1:0.00900, y12+1:0.00422, y13+1:0.01370, y14+1:0.01362, y15+1:0.00335, y20+1:-0.00151, y21+1:0.01207];[b6+1:0.00415, b7+1:0.00745, b9+1:0.00471, b10+1:0.01584, b12+1:0.01483, b18+1:0.02534, b23+1:0.02670];[(M0-365.13)+2:0.02821];[D126+1:0.00069, D138+1:0.00079, D144+1:0.00097, D186+1:0.00116, D204+1:0.00121, D366+1:0.00323];[zDot3+1:0.00166, zDot8+1:0.00870, zDot9+1:0.01129, zDot10+1:-0.01134, zDot11+1:0.01199, zDot12+1:0.00840, zDot13+1:0.01491, zDot14+1:0.00417, zDot15+1:0.02278, zDot16+1:0.00942];[c4+1:0.00416, c5+1:0.00635, c6+1:0.00420, c8+1:0.00766, c9+1:0.00792, c11+1:0.01453, c13+1:0.01877, c14+1:0.02689, c15+1:0.00056, c16+1:0.01639, c17+1:0.02471, c19+1:0.00261, c20+1:0.02238]}"	"{21301@[y1+1:10.58, y2+1:9.66, y3+1:9.98, y4+1:9.35, y5+1:10.98, y6+1:11.84, y7+1:9.87, y8+1:8.86, y9+1:9.16, y10+1:9.83, y11+1:8.87, y12+1:8.67, y13+1:7.99, y14+1:8.37, y15+1:8.35, y16+1:5.63, y17+1:-2.42, y18+1:7.91, y20+1:6.88, y23+1:0.86, y24+2:9.40];[b2+1:10.10, b3+1:8.71, b4+1:11.94, b5+1:9.87, b6+1:10.86, b7+1:7.79, b9+1:12.72, b10+1:9.39, b11+1:15.35, b12+1:9.07, b15+1:0.15, b18+1:14.51];[D126+1:11.44, D138+1:11.42, D144+1:11.18, D168+1:8.94, D186+1:10.91, D204+1:11.04, D366+1:10.55]}{21303@[y3+1:9.42, y4+1:6.95, y5+1:8.25, y7+1:9.14, y8+1:8.72, y9+1:7.12, y10+1:10.10, y11+1:7.63, y12+1:3.23, y13+1:9.64, y14+1:9.04, y15+1:2.10, y20+1:-0.70, y21+1:5.19];[b6+1:6.29, b7+1:10.39, b9+1:5.07, b10+1:14.50, b12+1:10.99, b18+1:13.01, b23+1:10.70];[(M0-365.13)+2:8.67];[D126+1:5.49, D138+1:5.79, D144+1:6.75, D186+1:6.29, D204+1:5.98, D366+1:8.84];[zDot3+1:5.29, zDot8+1:10.33, zDot9+1:11.23, zDot10+1:-10.38, zDot11+1:10.31, zDot12+1:6.50, zDot13+1:10.61, zDot14+1:2.80, zDot15+1:14.43, zDot16+1:5.57];[c4+1:8.43, c5+1:11.26, c6+1:6.20, c8+1:9.21, c9+1:8.37, c11+1:12.05, c13+1:12.82, c14+1:13.93, c15+1:0.28, c16+1:7.69, c17+1:11.14, c19+1:1.06, c20+1:8.85]}"	"{21301@[y1+1:8359, y2+1:7912, y3+1:7408, y4+1:64728, y5+1:8597, y6+1:16997, y7+1:683107, y8+1:106454, y9+1:51351, y10+1:101248, y11+1:72173, y12+1:35317, y13+1:20165, y14+1:24049, y15+1:33377, y16+1:10059, y17+1:5987, y18+1:35741, y20+1:48086, y23+1:5646, y24+2:16552];[b2+1:8454, b3+1:9607, b4+1:12299, b5+1:34362, b6+1:27190, b7+1:25112, b9+1:17018, b10+1:36264, b11+1:4278, b12+1:20203, b15+1:4923, b18+1:4338];[D126+1:206964, D138+1:119816, D144+1:121123, D168+1:47239, D186+1:214790, D204+1:289102, D366+1:108221]}{21303@[y3+1:5867, y4+1:8430, y5+1:3167, y7+1:93515, y8+1:27754, y9+1:12319, y10+1:24328, y11+1:12104, y12+1:5025, y13+1:3565, y14+1:3126, y15+1:3259, y20+1:4205, y21+1:7699];[b6+1:7195, b7+1:6146, b9+1:4607, b10+1:21285, b12+1:5324, b18+1:3918, b23+1:12464];[(M0-365.13)+2:64925];[D126+1:11989, D138+1:5070, D144+1:3924, D186+1:27970, D204+1:61922, D366+1:65666];[zDot3+1:13574, zDot8+1:7005, zDot9+1:11251, zDot10+1:21285, zDot11+1:18783, zDot12+1:9590, zDot13+1:12102, zDot14+1:7448, zDot15+1:3445, zDot16+1:14242];[c4+1:9785, c5+1:8575, c6+1:7525, c8+1:5718, c9+1:18605, c11+1:10304, c13+1:4560, c14+1:13188, c15+1:4846, c16+1:8543, c17+1:15467, c19+1:5405, c20+1:5967]}"	{21301@40}{21303@51}	T	0	0	0	23.08999842	1.018861761	0	1	4	365.13219	H1N1	FALSE	1.007187503	(N(H))	Level1	"[14,H1N1,1.000]"	"[285,H1N1,1.000]"	{@1[14-1]}	"{@14[1,1.000]}{@16[1,0.000]}{@17[1,0.000]}{@21[1,0.000]}"
 D:\Dolphins\GluCAspN\20230708_L1_UM5_shamo002_SA_EXT02_BWCoV_PngaseF_AspN_pdETHCD.raw	21619	57.39797984	21607	948.4681593	3	2842.382649	BdCoV_S			[1381 to 1401]	NQIQNLNSSYIDLEWLNKYER	"R,L"	NQIQNLNS[O-Glycosylation:N1 on X]SYIDLEWLNKYER	1	2842.377349	75.31335734	0	"{21619@[y1+1, y2+1, y3+1, y4+1, y5+1, y6+1, y7+2, y8+1, y9+2, y10+2, y11+2, y12+2, y13+2, y14+2, y15+2, y16+2, y17+2, y18+1, y19+1];[b2+1, b3+1, b4+1, b5+1, b6+1, b7+1, b8+1, b9+1];[(M0-203.08)+2];[D126+1, D138+1, D144+1, D168+1, D186+1, D204+1]}{21621@[y1+1, y3+1, y4+1, y5+1, y6+1, y7+1, y9+1, y13+1, y19+2];[b2+1, b3+1, b4+1, b5+1, b6+1];[(M0-203.08)+3];[D126+1, D138+1, D204+1];[c1+1, c4+1, c5+1, c6+1, c8+1, c9+1, c10+1, c11+1, c12+1, c13+1, c14+1, c15+1, c17+1, c18+1, c19+1];[zDot1+1, zDot2+1, zDot3+1, zDot4+1, zDot5+1, zDot6+1, zDot7+1, zDot8+1, zDot9+1, zDot10+1, zDot11+1, zDot12+1, zDot13+1, zDot17+1, zDot18+2, zDot19+2, zDot21+2]}"	"{21619@[y1+1:175.12045, y2+1:304.16418, y3+1:467.22937, y4+1:595.32624, y5+1:709.36954, y6+1:822.45446, y7+2:504.77145, y8+1:1137.57874, y9+2:625.83509, y10+2:683.34944, y11+2:739.89209, y12+2:821.42458, y13+2:864.94088, y14+2:908.45717, y15+2:965.47837, y16+2:1022.02023, y17+2:1079.04292, y18+1:2285.13159, y19+1:2398.19238];[b2+1:243.11131, b3+1:356.19619, b4+1:484.25612, b5+1:598.30103, b6+1:711.38434, b7+1:825.42517, b8+1:912.45660, b9+1:999.48932];[(M0-203.08)+2:1320.66895];[D126+1:126.05624, D138+1:138.05612, D144+1:144.06682, D168+1:168.06689, D186+1:186.07758, D204+1:204.08865]}{21621@[y1+1:175.12045, y3+1:467.22940, y4+1:595.32233, y5+1:709.37085, y6+1:822.44995, y7+1:1008.53426, y9+1:1250.65063, y13+1:1728.86490, y19+2:1199.61670];[b2+1:243.11108, b3+1:356.19552, b4+1:484.25589, b5+1:598.29547, b6+1:711.38641];[(M0-203.08)+3:880.78032];[D126+1:126.05598, D138+1:138.05658, D204+1:204.08842];[c1+1:132.07822, c4+1:501.28329, c5+1:615.32661, c6+1:728.41189, c8+1:1132.56580, c9+1:1219.60147, c10+1:1382.66746, c11+1:1495.75028, c12+1:1610.77732, c13+1:1723.86597, c14+1:1852.91043, c15+1:2038.99021, c17+1:2266.10303, c18+1:2394.18701, c19+1:2557.27490];[zDot1+1:159.10155, zDot2+1:288.14511, zDot3+1:451.21089, zDot4+1:579.30766, zDot5+1:693.35028, zDot6+1:806.43536, zDot7+1:992.51755, zDot8+1:1121.56162, zDot9+1:1234.64279, zDot10+1:1349.67051, zDot11+1:1462.75391, zDot12+1:1625.82153, zDot13+1:1712.85579, zDot17+1:2344.13696, zDot18+2:1236.60411, zDot19+2:1293.14778, zDot21+2:1414.19862]}"	"{21619@[y1+1:0.00150, y2+1:0.00264, y3+1:0.00450, y4+1:0.00641, y5+1:0.00677, y6+1:0.00763, y7+2:0.00949, y8+1:0.01000, y9+2:0.01010, y10+2:0.01187, y11+2:0.01310, y12+2:0.01475, y13+2:0.01532, y14+2:0.01587, y15+2:0.01534, y16+2:0.01500, y17+2:0.01746, y18+1:0.01190, y19+1:-0.01137];[b2+1:0.00253, b3+1:0.00335, b4+1:0.00470, b5+1:0.00668, b6+1:0.00592, b7+1:0.00383, b8+1:0.00323, b9+1:0.00392];[(M0-203.08)+2:0.02536];[D126+1:0.00129, D138+1:0.00118, D144+1:0.00130, D168+1:0.00138, D186+1:0.00150, D204+1:0.00201]}{21621@[y1+1:0.00150, y3+1:0.00453, y4+1:0.00249, y5+1:0.00809, y6+1:0.00312, y7+1:0.00812, y9+1:-0.00216, y13+1:0.00574, y19+2:0.02237];[b2+1:0.00229, b3+1:0.00268, b4+1:0.00447, b5+1:0.00112, b6+1:0.00800];[(M0-203.08)+3:0.02115];[D126+1:0.00103, D138+1:0.00163, D204+1:0.00178];[c1+1:0.00146, c4+1:0.00532, c5+1:0.00571, c6+1:0.00693, c8+1:0.00651, c9+1:0.01015, c10+1:0.01281, c11+1:0.01157, c12+1:0.01167, c13+1:0.01625, c14+1:0.01812, c15+1:0.01859, c17+1:0.00441, c18+1:-0.00657, c19+1:0.01800];[zDot1+1:0.00132, zDot2+1:0.00229, zDot3+1:0.00474, zDot4+1:0.00654, zDot5+1:0.00624, zDot6+1:0.00726, zDot7+1:0.01014, zDot8+1:0.01161, zDot9+1:0.00871, zDot10+1:0.00949, zDot11+1:0.00883, zDot12+1:0.01312, zDot13+1:0.01535, zDot17+1:0.01521, zDot18+2:0.02060, zDot19+2:0.02389, zDot21+2:0.02406]}"	"{21619@[y1+1:8.62, y2+1:8.71, y3+1:9.65, y4+1:10.78, y5+1:9.56, y6+1:9.29, y7+2:9.41, y8+1:8.80, y9+2:8.08, y10+2:8.69, y11+2:8.87, y12+2:8.99, y13+2:8.87, y14+2:8.75, y15+2:7.96, y16+2:7.34, y17+2:8.10, y18+1:5.21, y19+1:-4.74];[b2+1:10.45, b3+1:9.42, b4+1:9.73, b5+1:11.18, b6+1:8.34, b7+1:4.64, b8+1:3.55, b9+1:3.93];[(M0-203.08)+2:9.61];[D126+1:10.34, D138+1:8.58, D144+1:9.10, D168+1:8.25, D186+1:8.10, D204+1:9.88]}{21621@[y1+1:8.62, y3+1:9.71, y4+1:4.19, y5+1:11.41, y6+1:3.80, y7+1:8.06, y9+1:-1.73, y13+1:3.32, y19+2:9.33];[b2+1:9.48, b3+1:7.53, b4+1:9.24, b5+1:1.88, b6+1:11.26];[(M0-203.08)+3:8.01];[D126+1:8.24, D138+1:11.92, D204+1:8.76];[c1+1:11.17, c4+1:10.64, c5+1:9.29, c6+1:9.53, c8+1:5.75, c9+1:8.33, c10+1:9.27, c11+1:7.74, c12+1:7.25, c13+1:9.43, c14+1:9.79, c15+1:9.12, c17+1:1.95, c18+1:-2.74, c19+1:7.04];[zDot1+1:8.34, zDot2+1:7.97, zDot3+1:10.54, zDot4+1:11.32, zDot5+1:9.01, zDot6+1:9.01, zDot7+1:10.22, zDot8+1:10.36, zDot9+1:7.06, zDot10+1:7.04, zDot11+1:6.04, zDot12+1:8.08, zDot13+1:8.97, zDot17+1:6.49, zDot18+2:8.34, zDot19+2:9.24, zDot21+2:8.51]}"	"{21619@[y1+1:141106, y2+1:81131, y3+1:244890, y4+1:117661, y5+1:307580, y6+1:295977, y7+2:33178, y8+1:359356, y9+2:61476, y10+2:36348, y11+2:52161, y12+2:32192, y13+2:46360, y14+2:100506, y15+2:96098, y16+2:42423, y17+2:98113, y18+1:15172, y19+1:6885];[b2+1:1120248, b3+1:486735, b4+1:54561, b5+1:44642, b6+1:73046, b7+1:6757, b8+1:8540, b9+1:10081];[(M0-203.08)+2:42805];[D126+1:460986, D138+1:125195, D144+1:22612, D168+1:78011, D186+1:52055, D204+1:303026]}{21621@[y1+1:28943, y3+1:10796, y4+1:13282, y5+1:12293, y6+1:12942, y7+1:31789, y9+1:20424, y13+1:13652, y19+2:42246];[b2+1:101805, b3+1:57867, b4+1:19143, b5+1:13013, b6+1:23168];[(M0-203.08)+3:47979];[D126+1:22502, D138+1:6283, D204+1:59050];[c1+1:9855, c4+1:26911, c5+1:59281, c6+1:109785, c8+1:73450, c9+1:88456, c10+1:25919, c11+1:46793, c12+1:56147, c13+1:32366, c14+1:80163, c15+1:42866, c17+1:24980, c18+1:16628, c19+1:12614];[zDot1+1:64928, zDot2+1:31648, zDot3+1:50095, zDot4+1:42951, zDot5+1:73157, zDot6+1:68771, zDot7+1:73810, zDot8+1:42435, zDot9+1:31945, zDot10+1:22690, zDot11+1:16691, zDot12+1:59057, zDot13+1:45138, zDot17+1:10967, zDot18+2:113340, zDot19+2:103062, zDot21+2:374790]}"	{21619@34}{21621@50}	T	0	0	0	32.20775729	2.004704052	0	1	2	203.07937	N1	TRUE	5.536521559	(N)	Level1	"[8,N1,1.000]"	"[1388,N1,1.000]"	{@0[8-0]}	"{@8[0,1.000]}{@9[0,0.000]}"
 D:\Dolphins\GluCAspN\20230708_L1_UM5_shamo002_SA_EXT02_BWCoV_PngaseF_GluC_SteppdETHCD.raw	21707	56.84657807	21695	1246.233612	3	3735.679007	BdCoV_S			[272 to 302]	FVDDALGPDYPCPTLSSLQASYVPGDCAIKD	"K,W"	FVDDALGPDYPC[Common Fixed:Carbamidomethyl on C]PT[O-Glycosylation:H1N1 on X]LSSLQASYVPGDC[Common Fixed:Carbamidomethyl on C]AIKD	3	3735.66955	74.31478287	0	"{21707@[b2+1, b3+1, b4+1, b5+1, b6+1, b7+1, b9+1, b10+1, b11+1, b14+1, b23+1, b29+2];[y2+1, y3+1, y4+1, y5+1, y6+1, y7+1, y8+1, y9+1, y10+1, y11+1, y12+1, y13+1, y14+1, y15+1, y16+1, y19+1, y21+1, y25+2];[(M0-162.05)+3, (M0-365.13)+3];[D126+1, D138+1, D144+1, D168+1, D186+1, D204+1, D366+1]}{21709@[y2+1, y4+1, y5+1, y8+1, y9+1, y10+1, y11+1, y12+1, y13+1, y16+1, y21+1, y24+2, y25+2];[b6+1, b7+1, b10+1, b12+1, b15+1, b19+1, b23+1];[(M0-365.13)+2];[D126+1, D138+1, D144+1, D168+1, D186+1, D204+1, D366+1];[c4+1, c5+1, c6+1, c8+1, c9+1, c11+1, c13+1, c14+1, c16+1, c17+1, c20+1, c30+2];[zDot4+1, zDot9+1, zDot10+1, zDot12+1, zDot13+1, zDot14+1, zDot15+1, zDot17+1, zDot20+1]}"	"{21707@[b2+1:247.14656, b3+1:362.17435, b4+1:477.20407, b5+1:548.24151, b6+1:661.32750, b7+1:718.34637, b9+1:930.43082, b10+1:1093.49707, b11+1:1190.54797, b14+1:1548.68970, b23+1:2497.16151, b29+2:1555.71854];[y2+1:262.14233, y3+1:375.22745, y4+1:446.26526, y5+1:606.29817, y6+1:721.32684, y7+1:778.34845, y8+1:875.40231, y9+1:974.47124, y10+1:1137.53627, y11+1:1224.56524, y12+1:1295.60809, y13+1:1423.66770, y14+1:1536.75256, y15+1:1623.78190, y16+1:1710.81736, y19+1:2022.00321, y21+1:2279.07963, y25+2:1356.12553];[(M0-162.05)+3:1192.22542, (M0-365.13)+3:1124.53150];[D126+1:126.05637, D138+1:138.05651, D144+1:144.06701, D168+1:168.06705, D186+1:186.07809, D204+1:204.08883, D366+1:366.14352]}{21709@[y2+1:262.14224, y4+1:446.26550, y5+1:606.29688, y8+1:875.40109, y9+1:974.46902, y10+1:1137.53340, y11+1:1224.57119, y12+1:1295.60620, y13+1:1423.66174, y16+1:1710.80774, y21+1:2279.07031, y24+2:1327.62322, y25+2:1356.12400];[b6+1:661.32678, b7+1:718.34955, b10+1:1093.49259, b12+1:1350.57678, b15+1:1661.76050, b19+1:2076.97925, b23+1:2497.16870];[(M0-365.13)+2:1686.29056];[D126+1:126.05608, D138+1:138.05629, D144+1:144.06686, D168+1:168.06683, D186+1:186.07770, D204+1:204.08883, D366+1:366.14358];[c4+1:494.22882, c5+1:565.26556, c6+1:678.35303, c8+1:832.43396, c9+1:947.45648, c11+1:1207.56995, c13+1:1464.65271, c14+1:1930.84191, c16+1:2130.97778, c17+1:2217.98926, c20+1:2530.19238, c30+2:1810.84713];[zDot4+1:430.24908, zDot9+1:958.45233, zDot10+1:1121.51514, zDot12+1:1279.59021, zDot13+1:1407.65369, zDot14+1:1520.72934, zDot15+1:1607.76465, zDot17+1:1807.87683, zDot20+1:2531.16846]}"	"{21707@[b2+1:0.00246, b3+1:0.00330, b4+1:0.00608, b5+1:0.00641, b6+1:0.00833, b7+1:0.00574, b9+1:0.01048, b10+1:0.01340, b11+1:0.01154, b14+1:0.02218, b23+1:0.00234, b29+2:0.01763];[y2+1:0.00259, y3+1:0.00364, y4+1:0.00433, y5+1:0.00659, y6+1:0.00833, y7+1:0.00847, y8+1:0.00956, y9+1:0.01009, y10+1:0.01179, y11+1:0.00872, y12+1:0.01446, y13+1:0.01550, y14+1:0.01629, y15+1:0.01360, y16+1:0.01703, y19+1:0.01837, y21+1:0.01138, y25+2:0.01104];[(M0-162.05)+3:0.03769, (M0-365.13)+3:0.03530];[D126+1:0.00143, D138+1:0.00156, D144+1:0.00149, D168+1:0.00153, D186+1:0.00201, D204+1:0.00218, D366+1:0.00405]}{21709@[y2+1:0.00250, y4+1:0.00458, y5+1:0.00530, y8+1:0.00834, y9+1:0.00786, y10+1:0.00892, y11+1:0.01468, y12+1:0.01257, y13+1:0.00954, y16+1:0.00741, y21+1:0.00207, y24+2:0.02788, y25+2:0.00799];[b6+1:0.00761, b7+1:0.00892, b10+1:0.00892, b12+1:0.00970, b15+1:0.00891, b19+1:0.02096, b23+1:0.00953];[(M0-365.13)+2:0.02920];[D126+1:0.00114, D138+1:0.00134, D144+1:0.00135, D168+1:0.00132, D186+1:0.00162, D204+1:0.00218, D366+1:0.00411];[c4+1:0.00428, c5+1:0.00391, c6+1:0.00731, c8+1:0.01402, c9+1:0.00960, c11+1:0.00697, c13+1:0.00632, c14+1:0.01565, c16+1:0.03543, c17+1:0.01488, c20+1:0.03825, c30+2:0.02112];[zDot4+1:0.00688, zDot9+1:0.00990, zDot10+1:0.00937, zDot12+1:0.01531, zDot13+1:0.02020, zDot14+1:0.01180, zDot15+1:0.01507, zDot17+1:0.01116, zDot20+1:0.03951]}"	"{21707@[b2+1:9.98, b3+1:9.14, b4+1:12.77, b5+1:11.71, b6+1:12.62, b7+1:8.00, b9+1:11.28, b10+1:12.26, b11+1:9.70, b14+1:14.33, b23+1:0.94, b29+2:5.67];[y2+1:9.91, y3+1:9.72, y4+1:9.73, y5+1:10.89, y6+1:11.56, y7+1:10.90, y8+1:10.94, y9+1:10.36, y10+1:10.37, y11+1:7.13, y12+1:11.17, y13+1:10.89, y14+1:10.61, y15+1:8.38, y16+1:9.96, y19+1:9.09, y21+1:5.00, y25+2:4.07];[(M0-162.05)+3:10.55, (M0-365.13)+3:10.47];[D126+1:11.41, D138+1:11.42, D144+1:10.44, D168+1:9.16, D186+1:10.87, D204+1:10.74, D366+1:11.10]}{21709@[y2+1:9.56, y4+1:10.28, y5+1:8.76, y8+1:9.54, y9+1:8.07, y10+1:7.85, y11+1:12.00, y12+1:9.71, y13+1:6.70, y16+1:4.34, y21+1:0.91, y24+2:10.51, y25+2:2.95];[b6+1:11.53, b7+1:12.43, b10+1:8.16, b12+1:7.19, b15+1:5.37, b19+1:10.10, b23+1:3.82];[(M0-365.13)+2:8.66];[D126+1:9.09, D138+1:9.80, D144+1:9.42, D168+1:7.88, D186+1:8.76, D204+1:10.74, D366+1:11.26];[c4+1:8.68, c5+1:6.93, c6+1:10.79, c8+1:16.86, c9+1:10.14, c11+1:5.77, c13+1:4.32, c14+1:8.11, c16+1:16.63, c17+1:6.71, c20+1:15.12, c30+2:5.83];[zDot4+1:16.04, zDot9+1:10.34, zDot10+1:8.37, zDot12+1:11.97, zDot13+1:14.36, zDot14+1:7.76, zDot15+1:9.38, zDot17+1:6.18, zDot20+1:15.62]}"	"{21707@[b2+1:11211, b3+1:10153, b4+1:11010, b5+1:13030, b6+1:33021, b7+1:16497, b9+1:10276, b10+1:33086, b11+1:7998, b14+1:4251, b23+1:8941, b29+2:8704];[y2+1:55444, y3+1:6270, y4+1:6431, y5+1:28094, y6+1:6266, y7+1:9011, y8+1:443644, y9+1:77512, y10+1:19090, y11+1:32111, y12+1:34097, y13+1:12726, y14+1:5739, y15+1:11448, y16+1:14135, y19+1:13087, y21+1:12573, y25+2:10568];[(M0-162.05)+3:208909, (M0-365.13)+3:35809];[D126+1:132203, D138+1:86883, D144+1:83299, D168+1:29583, D186+1:138780, D204+1:277846, D366+1:235237]}{21709@[y2+1:11000, y4+1:2909, y5+1:6098, y8+1:102154, y9+1:22140, y10+1:9835, y11+1:16914, y12+1:8188, y13+1:9992, y16+1:5399, y21+1:4427, y24+2:7405, y25+2:16351];[b6+1:11325, b7+1:5091, b10+1:17995, b12+1:5393, b15+1:4398, b19+1:3738, b23+1:8145];[(M0-365.13)+2:46460];[D126+1:13838, D138+1:7706, D144+1:6137, D168+1:3804, D186+1:24275, D204+1:59051, D366+1:57541];[c4+1:9616, c5+1:7297, c6+1:7613, c8+1:5797, c9+1:22029, c11+1:18612, c13+1:3633, c14+1:17406, c16+1:5730, c17+1:13202, c20+1:4622, c30+2:59258];[zDot4+1:2671, zDot9+1:3638, zDot10+1:7715, zDot12+1:7300, zDot13+1:6006, zDot14+1:17140, zDot15+1:3771, zDot17+1:15750, zDot20+1:5897]}"	{21707@39}{21709@49}	T	0	0	0	21.10588838	3.044651961	0	1	4	365.13219	H1N1	FALSE	1.063300917	(N(H))	Level1	"[14,H1N1,1.000]"	"[285,H1N1,1.000]"	{@1[14-1]}	"{@14[1,1.000]}{@16[1,0.000]}{@17[1,0.000]}{@21[1,0.000]}"
@@ -294,7 +294,7 @@ fn fuzz_hangs() {
     assert!(all_passing, "Some fuzz tests did not pass");
 }
 
-const DATA_AB: &str = r"Scan,Peptide,Tag Length,ALC (%),length,m/z,z,RT,Area,Mass,ppm,Accession,PTM,local confidence (%),tag (>=0%),mode
+pub(super) const DATA_AB: &str = r"Scan,Peptide,Tag Length,ALC (%),length,m/z,z,RT,Area,Mass,ppm,Accession,PTM,local confidence (%),tag (>=0%),mode
 F2:10351,MHQN(+.98)WLWL,8,98,8,564.7653,2,23.75,5.73E7,1127.5222,-5.5,,Deamidation (NQ),96 98 98 99 99 100 99 99,MHQN(+.98)WLWL,CID
 F3:3063,M(+15.99)PHNHHTE,8,98,8,509.7123,2,10.99,4.42E6,1017.4087,1.4,,Oxidation (M),98 98 98 98 99 100 100 96,M(+15.99)PHNHHTE,CID
 F3:3534,M(+15.99)PHNHHTE,8,98,8,509.7128,2,11.79,1.82E6,1017.4087,2.4,,Oxidation (M),98 99 99 98 99 99 100 95,M(+15.99)PHNHHTE,CID
@@ -315,7 +315,7 @@ F4:6305,NHATYYAENK(+72.06),10,96,10,641.8087,2,16.22,2.99E6,1281.6040,-1.0,,Carb
 F2:8831,VCAAVHGV,8,96,8,378.1942,2,21.04,3.21E6,754.3796,-7.5,,,84 92 98 99 99 100 99 99,VCAAVHGV,CID
 F4:3135,TPVSEHQK(+72.06),8,96,8,499.2701,2,10.87,3.28E7,996.5292,-3.5,,Carboxyethyl,99 99 99 97 99 94 88 95,TPVSEHQK(+72.06),CID";
 
-const DATA_X: &str = r"Scan,Peptide,Tag Length,ALC (%),length,m/z,z,RT,Area,Mass,ppm,PTM,local confidence (%),tag (>=0%),mode
+pub(super) const DATA_X: &str = r"Scan,Peptide,Tag Length,ALC (%),length,m/z,z,RT,Area,Mass,ppm,PTM,local confidence (%),tag (>=0%),mode
 F4:14106,TTPPVLDSDGSFFLYSK,17,99,17,625.3127,3,55.62,8.89E6,1872.9146,1.0,,100 100 100 99 100 100 100 100 100 100 100 100 100 100 100 100 100,TTPPVLDSDGSFFLYSK,HCD
 F1:14431,TTPPVLDSDGSFFLYSK,17,99,17,625.3127,3,55.41,6.07E6,1872.9146,0.9,,100 100 100 99 100 100 100 100 100 100 100 100 100 100 100 100 100,TTPPVLDSDGSFFLYSK,HCD
 F3:3112,LSKADYEKHK,10,99,10,406.8876,3,16.39,2.6E4,1217.6404,0.6,,100 100 100 100 100 100 100 100 100 100,LSKADYEKHK,HCD
@@ -336,13 +336,13 @@ F4:14079,TTPPVLDSDGSFFLYSK,17,99,17,937.4672,2,55.56,2.38E8,1872.9146,2.9,,100 1
 F1:14468,TTPPVLDSDGSFFLYSK,17,99,17,937.4670,2,55.50,2.06E7,1872.9146,2.6,,100 100 100 100 100 100 100 100 100 98 98 99 100 100 100 100 100,TTPPVLDSDGSFFLYSK,HCD
 F3:3851,FYPREAK,7,99,7,455.7434,2,19.26,5.57E6,909.4708,1.7,,100 100 99 99 100 100 100,FYPREAK,HCD";
 
-const DATA_X_PATCHED_SEP: &str = r"sep=;
+pub(super) const DATA_X_PATCHED_SEP: &str = r"sep=;
 Fraction;Source File;Feature;Peptide;Scan;Tag Length;ALC (%);length;m/z;z;RT;Area;Mass;ppm;PTM;local confidence (%);tag (>=0%);mode
 1;20190517_F1_Ag5_3117030_SA_ETHCD_131-2a_Tryp01.raw;F1:5056;LLYLVSK;F1:6994;7;99;7;418.2689;2;39.59;1.47E6;834.5215;2.2;;100 100 100 100 100 100 100;LLYLVSK;ETHCD
 2;20190517_F1_Ag5_3117030_SA_ETHCD_131-2a_Chym01.raw;-;MYYSKL;F2:6253;6;99;6;402.7029;2;35.37;;803.3887;3.2;;100 100 100 100 100 100;MYYSKL;ETHCD
 4;20190517_F1_Ag5_3117030_SA_ETHCD_131-2a_LysN01.raw;F4:9634;KAAVFNHFLSDGVK;F4:6537;14;99;14;511.6133;3;36.67;2.16E6;1531.8147;2.2;;100 100 100 100 100 100 100 100 100 100 100 100 100 100;KAAVFNHFLSDGVK;ETHCD";
 
-const DATA_X_PATCHED: &str = r"Fraction,Source File,Feature,Peptide,Scan,Tag Length,ALC (%),length,m/z,z,RT,Area,Mass,ppm,PTM,local confidence (%),tag (>=0%),mode
+pub(super) const DATA_X_PATCHED: &str = r"Fraction,Source File,Feature,Peptide,Scan,Tag Length,ALC (%),length,m/z,z,RT,Area,Mass,ppm,PTM,local confidence (%),tag (>=0%),mode
 1,20190517_F1_Ag5_3117030_SA_ETHCD_131-2a_Tryp01.raw,F1:5056,LLYLVSK,F1:6994,7,99,7,418.2689,2,39.59,1.47E6,834.5215,2.2,,100 100 100 100 100 100 100,LLYLVSK,ETHCD
 2,20190517_F1_Ag5_3117030_SA_ETHCD_131-2a_Chym01.raw,-,MYYSKL,F2:6253,6,99,6,402.7029,2,35.37,,803.3887,3.2,,100 100 100 100 100 100,MYYSKL,ETHCD
 4,20190517_F1_Ag5_3117030_SA_ETHCD_131-2a_LysN01.raw,F4:9634,KAAVFNHFLSDGVK,F4:6537,14,99,14,511.6133,3,36.67,2.16E6,1531.8147,2.2,,100 100 100 100 100 100 100 100 100 100 100 100 100 100,KAAVFNHFLSDGVK,ETHCD
@@ -363,7 +363,7 @@ const DATA_X_PATCHED: &str = r"Fraction,Source File,Feature,Peptide,Scan,Tag Len
 5,20190517_F1_Ag5_3117030_SA_ETHCD_131-2a_Ela01.raw,F5:6860,TFGAGTKLELKRA,F5:5414,13,99,13,464.6069,3,30.47,2.49E6,1390.7932,4.0,,100 100 100 100 100 100 100 100 100 100 100 100 100,TFGAGTKLELKRA,ETHCD
 2,20190517_F1_Ag5_3117030_SA_ETHCD_131-2a_Chym01.raw,F2:7876,TLSRDDSKSSVY,F2:3962,12,99,12,453.2252,3,21.77,3.86E6,1356.6521,1.1,,100 100 100 100 100 100 100 100 100 99 100 100,TLSRDDSKSSVY,ETHCD";
 
-const DATA_XPLUS: &str = r"Fraction,Source File,Feature,Peptide,Scan,Tag Length,Denovo Score,ALC (%),length,m/z,z,RT,Predict RT,Area,Mass,ppm,PTM,local confidence (%),tag (>=0%),mode
+pub(super) const DATA_XPLUS: &str = r"Fraction,Source File,Feature,Peptide,Scan,Tag Length,Denovo Score,ALC (%),length,m/z,z,RT,Predict RT,Area,Mass,ppm,PTM,local confidence (%),tag (>=0%),mode
 10,20191211_F1_Ag5_peng0013_SA_her_Asp_N.raw,F10:3434,DYEKHKVYAC(+58.01),F10:3629,10,99,99,10,438.5332,3,19.91,-,2.3176E6,1312.5757,1.6,Carboxymethyl,100 100 100 100 100 100 100 100 100 100,DYEKHKVYAC(+58.01),ETHCD
 4,20191211_F1_Ag5_peng0013_SA_her_Ela.raw,F4:4797,SGFGGLKN(+.98)TYLHW,F4:9505,13,99,99,13,494.2459,3,52.43,-,2.4924E7,1479.7146,0.9,Deamidation (NQ),100 100 100 100 100 100 100 100 100 100 100 100 100,SGFGGLKN(+.98)TYLHW,HCD
 3,20191211_F1_Ag5_peng0013_SA_her_thermo.raw,F3:12703,LSC(+58.01)AASGFNLKDTY,F3:7983,14,99,99,14,774.3562,2,43.80,-,7.2888E7,1546.6973,0.4,Carboxymethyl,99 100 100 100 100 100 100 100 100 100 100 100 100 100,LSC(+58.01)AASGFNLKDTY,HCD
@@ -384,7 +384,7 @@ const DATA_XPLUS: &str = r"Fraction,Source File,Feature,Peptide,Scan,Tag Length,
 3,20191211_F1_Ag5_peng0013_SA_her_thermo.raw,F3:3555,LTLSKADYEKHK,F3:3745,12,99,99,12,478.2650,3,20.28,-,1.1906E8,1431.7722,0.7,,100 100 100 100 100 100 100 99 99 100 100 100,LTLSKADYEKHK,HCD
 11,20191211_F1_Ag5_peng0013_SA_her_CB.raw,F11:12673,EVQLVESGGGLVQPGGSLRAK,F11:8595,21,99,99,21,694.3840,3,47.38,-,7.0626E7,2080.1274,1.4,,98 98 99 100 100 100 100 100 100 100 100 100 100 100 100 100 100 100 100 100 100,EVQLVESGGGLVQPGGSLRAK,ETHCD";
 
-const DATA_11: &str = r#""Source File","Scan","Peptide","Tag length","ALC (%)","Length","m/z","z","RT","Area","Mass","ppm","PTM","local confidence (%)","mode",tag(>=0.0%),"Feature Id"
+pub(super) const DATA_11: &str = r#""Source File","Scan","Peptide","Tag length","ALC (%)","Length","m/z","z","RT","Area","Mass","ppm","PTM","local confidence (%)","mode",tag(>=0.0%),"Feature Id"
 "20191211_F1_Ag5_peng0013_SA_her_Arg_C.raw",6514,YSSDEKVLGEDFSDTR,16,99.9,16,616.61450,3,35.1011,190625.72,1846.8220,-0.2,"",100 100 100 100 100 100 100 100 100 100 100 100 100 100 100 98,HCD,YSSDEKVLGEDFSDTR,14223
 "20191211_F1_Ag5_peng0013_SA_her_Arg_C.raw",6743,DLQMTQSPSSLSASVGDR,18,99.2,18,626.96686,3,36.1993,794748.8,1877.8789,-0.1,"",99 99 100 100 100 100 99 99 100 100 100 100 100 100 100 100 100 90,ETHCD,DLQMTQSPSSLSASVGDR,14572
 "20191211_F1_Ag5_peng0013_SA_her_Arg_C.raw",5644,DLQM(+15.99)TQSPSSLSASVGDR,18,99.0,18,947.94415,2,30.1547,548587.2,1893.8738,0.0,"Oxidation (M)",97 96 100 100 100 100 100 100 100 100 100 100 100 100 100 95 97 97,HCD,DLQM(+15.99)TQSPSSLSASVGDR,22869
@@ -405,7 +405,7 @@ const DATA_11: &str = r#""Source File","Scan","Peptide","Tag length","ALC (%)","
 "20191211_F1_Ag5_peng0013_SA_her_Arg_C.raw",3880,ADSVFKGR,8,97.5,8,440.23810,2,20.6071,27530.41,878.4610,0.7,"",96 99 100 100 99 98 95 93,HCD,ADSVFKGR,6714
 "20191211_F1_Ag5_peng0013_SA_her_Arg_C.raw",4217,HEYTHYLQAR,10,97.5,10,439.88297,3,22.4412,1841098.6,1316.6262,0.7,"",94 97 99 100 100 100 100 100 100 84,ETHCD,HEYTHYLQAR,6678"#;
 
-const DATA_11_FEATURES: &str = r#""Source File","Feature Id","m/z","z","RT","RT Begin","RT End","Area","Quality","Denovo Peptide","ALC (%)","ppm"
+pub(super) const DATA_11_FEATURES: &str = r#""Source File","Feature Id","m/z","z","RT","RT Begin","RT End","Area","Quality","Denovo Peptide","ALC (%)","ppm"
 "20191211_F1_Ag5_peng0013_SA_her_Arg_C.raw",14223,616.61450,3,35.1011,35.0728,35.1775,190625.72,0.00,YSSDEKVLGEDFSDTR,99.9,-0.2
 "20191211_F1_Ag5_peng0013_SA_her_Arg_C.raw",14572,626.96686,3,36.1993,35.9724,36.6269,794748.8,0.00,DLQMTQSPSSLSASVGDR;DLQMTQSPSSLSASVGDR,99.2,-0.1
 "20191211_F1_Ag5_peng0013_SA_her_Arg_C.raw",22869,947.94415,2,30.1547,29.8533,30.5004,548587.2,0.00,LDQM(+15.99)TQSPSSLSASVGDR;DLQM(+15.99)TQSPSSLSASVGDR,99.0,0.0
@@ -426,7 +426,7 @@ const DATA_11_FEATURES: &str = r#""Source File","Feature Id","m/z","z","RT","RT
 "20191211_F1_Ag5_peng0013_SA_her_Arg_C.raw",6678,439.88297,3,22.4412,22.3549,22.7358,1841098.6,0.00,HEYTHYLQAR;HEYTHYLQAR,97.5,0.7
 "20191211_F1_Ag5_peng0013_SA_her_Arg_C.raw",16579,696.69293,3,42.1785,42.0943,42.3913,145613.02,0.00,VVGNDKALEVGNADDVLGFR,97.4,-3.7"#;
 
-const DATA_11_ALL_CANDIDATES: &str = r#""Source File","Scan","Peptide","Tag length","ALC (%)","Length","m/z","z","RT","Area","Mass","ppm","PTM","local confidence (%)","mode",tag(>=0.0%),"Feature Id"
+pub(super) const DATA_11_ALL_CANDIDATES: &str = r#""Source File","Scan","Peptide","Tag length","ALC (%)","Length","m/z","z","RT","Area","Mass","ppm","PTM","local confidence (%)","mode",tag(>=0.0%),"Feature Id"
 "20240903_SA_HTA_KR40_500ng_CID_130min.wiff",16281,Q(-17.03)PEDF,5,99.9,5,618.24072,1,48.7702,59523.16,617.2333,0.3,"Pyro-glu from Q",100 100 100 100 100,CID,Q(-17.03)PEDF,16481
 "20240903_SA_HTA_KR40_500ng_CID_130min.wiff",16265,Q(-17.03)PEDF,5,99.9,5,618.24072,1,48.7702,59523.16,617.2333,0.3,"Pyro-glu from Q",100 100 100 100 100,CID,Q(-17.03)PEDF,16481
 "20240903_SA_HTA_KR40_500ng_CID_130min.wiff",20270,LLGGPSVF,8,99.7,8,789.44788,1,72.3085,5838.5063,788.4432,-3.3,"",100 100 100 99 99 100 100 100,CID,LLGGPSVF,33201
@@ -447,7 +447,7 @@ const DATA_11_ALL_CANDIDATES: &str = r#""Source File","Scan","Peptide","Tag leng
 "20240903_SA_HTA_KR40_500ng_CID_60min.wiff",9567,VVDVSHED,8,99.3,8,899.41119,1,20.0530,831.1227,898.4032,0.8,"",100 99 100 100 100 98 99 99,CID,VVDVSHED,26075
 "20240903_SA_HTA_VE20_500ng_CID_60min.wiff",15912,TKNQVSLTCLVKGF,14,99.3,14,1537.84521,1,34.7840,1830.2261,1536.8334,3.0,"",99 98 97 98 100 100 100 100 100 100 100 100 100 100,CID,TKNQVSLTCLVKGF,29989"#;
 
-const DATA_11_CUSTOM_MODIFICATION: &str = r#""Source File","Scan","Peptide","Tag length","ALC (%)","Length","m/z","z","RT","Area","Mass","ppm","PTM","local confidence (%)","mode",tag(>=0.0%),"Feature Id"
+pub(super) const DATA_11_CUSTOM_MODIFICATION: &str = r#""Source File","Scan","Peptide","Tag length","ALC (%)","Length","m/z","z","RT","Area","Mass","ppm","PTM","local confidence (%)","mode",tag(>=0.0%),"Feature Id"
 "20191211_F1_Ag5_peng0013_SA_her_Arg_C.raw",6514,YSSDEKVLGEDFSDTR,16,99.9,16,616.61450,3,35.1011,190625.72,1846.8220,-0.2,"",100 100 100 100 100 100 100 100 100 100 100 100 100 100 100 98,HCD,YSSDEKVLGEDFSDTR,14223
 "20191211_F1_Ag5_peng0013_SA_her_Arg_C.raw",6743,DLQMTQSPSSLSASVGDR,18,99.2,18,626.96686,3,36.1993,794748.8,1877.8789,-0.1,"",99 99 100 100 100 100 99 99 100 100 100 100 100 100 100 100 100 90,ETHCD,DLQMTQSPSSLSASVGDR,14572
 "20191211_F1_Ag5_peng0013_SA_her_Arg_C.raw",5644,DLQM[C:Oxidation]TQSPSSLSASVGDR,18,99.0,18,947.94415,2,30.1547,548587.2,1893.8738,0.0,"Oxidation (M)",97 96 100 100 100 100 100 100 100 100 100 100 100 100 100 95 97 97,HCD,DLQM(+15.99)TQSPSSLSASVGDR,22869
@@ -468,7 +468,7 @@ const DATA_11_CUSTOM_MODIFICATION: &str = r#""Source File","Scan","Peptide","Tag
 "20191211_F1_Ag5_peng0013_SA_her_Arg_C.raw",3880,ADSVFKGR,8,97.5,8,440.23810,2,20.6071,27530.41,878.4610,0.7,"",96 99 100 100 99 98 95 93,HCD,ADSVFKGR,6714
 "20191211_F1_Ag5_peng0013_SA_her_Arg_C.raw",4217,HEYTHYLQAR,10,97.5,10,439.88297,3,22.4412,1841098.6,1316.6262,0.7,"",94 97 99 100 100 100 100 100 100 84,ETHCD,HEYTHYLQAR,6678"#;
 
-const DATA_12: &str = r#""Source File","Scan","Peptide","Tag length","Deep Novo Score (%)","ALC (%)","Length","m/z","z","RT","Area","Mass","ppm","PTM","local confidence (%)","mode",tag(>=0%),"Feature Id"
+pub(super) const DATA_12: &str = r#""Source File","Scan","Peptide","Tag length","Deep Novo Score (%)","ALC (%)","Length","m/z","z","RT","Area","Mass","ppm","PTM","local confidence (%)","mode",tag(>=0%),"Feature Id"
 "9661_weiwei_mabs_novo_chymo.raw",4245;4251,TLSKADY,7,100.0,100.0,7,399.20514,2,20.9525,4.8573476E7,796.3967,-1.2,"",100 100 100 100 100 100 100,MIX,TLSKADY,1008
 "9661_weiwei_mabs_novo_chymo.raw",4478;4483,SSPVTKSF,8,100.0,100.0,8,426.72626,2,27.9639,2.40281728E8,851.4388,-1.1,"",100 100 100 100 100 100 100 100,MIX,SSPVTKSF,1492
 "9661_weiwei_mabs_novo_chymo.raw",3553;3556,TLSKADY,7,100.0,100.0,7,399.20514,2,20.9525,4.8573476E7,796.3967,-1.2,"",100 100 100 100 100 100 100,MIX,TLSKADY,1008
@@ -489,7 +489,7 @@ const DATA_12: &str = r#""Source File","Scan","Peptide","Tag length","Deep Novo
 "9661_weiwei_mabs_novo_chymo.raw",3222;3229,SGALTSGVH,9,99.9,99.9,9,414.71368,2,14.7044,6212751.5,827.4137,-1.1,"",100 100 100 100 100 100 100 100 100,MIX,SGALTSGVH,1268
 "9661_weiwei_mabs_novo_chymo.raw",4947;4951,TFRNY,5,99.9,99.9,5,350.67383,2,25.7710,,699.3340,-1.3,"",100 100 100 100 100,MIX,TFRNY,0"#;
 
-const DATA_X_PLUS_SEP: &str = r"sep=	
+pub(super) const DATA_X_PLUS_SEP: &str = r"sep=	
 Fraction	Source File	Feature	Peptide	Scan	Tag Length	Denovo Score	ALC (%)	length	m/z	z	RT	Predict RT	Area	Mass	ppm	PTM	local confidence (%)	tag (>=0%)	mode
 1	20221121_F1_UM4_Peng0013_SA_EXT00_MIX3_SP3_LysN_1h.raw	6857	KVYAC(+58.01)EVTHQGLSSPVT	10653	17	99	99	17	938.9585	2	60.14	-	1.52E+09	1875.9036	-0.6	Carboxymethyl	96 100 100 100 100 100 100 100 100 99 100 100 100 100 100 99 99	KVYAC(+58.01)EVTHQGLSSPVT	HCD
 1	20221121_F1_UM4_Peng0013_SA_EXT00_MIX3_SP3_LysN_1h.raw	6520	KVYAAEVTHQGLSSPVT	10831	17	99	99	17	893.9703	2	61.3	-	1.90E+07	1785.926	0		96 100 100 100 100 100 100 100 99 99 99 100 100 100 100 99 99	KVYAAEVTHQGLSSPVT	HCD
@@ -511,7 +511,7 @@ Fraction	Source File	Feature	Peptide	Scan	Tag Length	Denovo Score	ALC (%)	length
 1	20221121_F1_UM4_Peng0013_SA_EXT00_MIX3_SP3_LysN_1h.raw	-	KADSSPVKAGVETTTPS	8144	17	98	98	17	558.9572	3	45.59	-	0	1673.8472	1.6		82 100 100 100 100 100 100 99 97 99 99 100 100 100 99 98 98	KADSSPVKAGVETTTPS	ETHCD
 1	20221121_F1_UM4_Peng0013_SA_EXT00_MIX3_SP3_LysN_1h.raw	-	KADSSPVKAGVETTTPS	8084	17	98	98	17	558.9574	3	45.23	-	0	1673.8472	1.9		85 100 100 100 99 99 100 99 97 99 99 100 100 100 98 98 97	KADSSPVKAGVETTTPS	ETHCD";
 
-const DATA_DB_PEPTIDE: &str = r"Peptide,-10lgP,Mass,Length,ppm,m/z,RT,Area Tryp-CID,Area Tryp EAD,Fraction,Scan,Source File,#Feature,#Feature Tryp-CID,#Feature Tryp EAD,Accession,PTM,AScore,Found By
+pub(super) const DATA_DB_PEPTIDE: &str = r"Peptide,-10lgP,Mass,Length,ppm,m/z,RT,Area Tryp-CID,Area Tryp EAD,Fraction,Scan,Source File,#Feature,#Feature Tryp-CID,#Feature Tryp EAD,Accession,PTM,AScore,Found By
 ASQDVNTAVAWYQQKPGKAPK,133.29,2286.1755,21,2.4,572.5526,24.82,2.3211E2,2.4834E2,1,F1:150245,230629 1-Trypsin CID 06.mzML,3,2,1,Herceptin,,,PEAKS DB
 VDNALQSGNSQESVTEQDSK,120.05,2134.9614,20,1.0,1068.4890,21.61,2.3684E3,5.8385E3,1,F1:141739,230629 1-Trypsin CID 06.mzML,4,3,1,Herceptin,,,PEAKS DB
 HKVYAC(+57.02)EVTHQGLSSPVTK,111.34,2140.0735,19,1.9,536.0267,14.21,1.444E2,1.7678E2,1,F1:120669,230629 1-Trypsin CID 06.mzML,3,2,1,Herceptin,Carbamidomethylation,C6:Carbamidomethylation:1000.00,PEAKS DB
@@ -532,7 +532,7 @@ VVSVLTVLHQDWLNGK,88.68,1806.9991,16,-0.1,904.5068,35.17,2.394E2,1.0949E3,1,F1:17
 DSTYSLSSTLTLSK,88.53,1501.7511,14,-0.4,751.8825,30.22,1.0615E2,1.1102E3,1,F1:163866,230629 1-Trypsin CID 06.mzML,6,4,2,Herceptin,,,PEAKS DB
 FNWYVDGVEVHNAK,88.47,1676.7947,14,0.5,839.4050,29.08,5.6654E3,1.6995E4,1,F1:160881,230629 1-Trypsin CID 06.mzML,6,3,3,Herceptin,,,PEAKS DB";
 
-const DATA_DB_PSM: &str = r"Peptide,-10lgP,Mass,Length,ppm,m/z,Z,RT,Area,Fraction,Id,Scan,from Chimera,Source File,Accession,PTM,AScore,Found By
+pub(super) const DATA_DB_PSM: &str = r"Peptide,-10lgP,Mass,Length,ppm,m/z,Z,RT,Area,Fraction,Id,Scan,from Chimera,Source File,Accession,PTM,AScore,Found By
 ASQDVNTAVAWYQQKPGKAPK,133.29,2286.1755,21,2.4,572.5526,4,24.82,2.0118E2,1,1551,F1:150245,No,230629 1-Trypsin CID 06.mzML,Herceptin,,,PEAKS DB
 ASQDVNTAVAWYQQKPGKAPK,100.35,2286.1755,21,-0.5,763.0654,3,24.82,3.0934E1,1,1552,F1:150301,No,230629 1-Trypsin CID 06.mzML,Herceptin,,,PEAKS DB
 ASQDVNTAVAWYQQKPGKAPK,30.99,2286.1755,21,1.4,572.5519,4,24.99,2.4834E2,2,13517,F2:87375,No,230629 1-Trypsin EAD 02.mzML,Herceptin,,,PEAKS DB
@@ -553,7 +553,7 @@ WQQGNVFSC(+57.02)SVMHEALHNHYTQK,90.42,2800.2598,23,-1.4,561.0585,5,27.85,2.3978E
 WQQGNVFSC(+57.02)SVMHEALHNHYTQK,42.54,2800.2598,23,-1.5,934.4258,3,27.83,2.4453E0,1,2897,F1:157770,Yes,230629 1-Trypsin CID 06.mzML,Herceptin,Carbamidomethylation,C9:Carbamidomethylation:1000.00,PEAKS DB
 WQQGNVFSC(+57.02)SVMHEALHNHYTQK,34.88,2800.2598,23,-12.0,701.0638,4,28.42,0,1,9493,F1:159270,No,230629 1-Trypsin CID 06.mzML,Herceptin,Carbamidomethylation,C9:Carbamidomethylation:1000.00,PEAKS DB";
 
-const DATA_DB_PROTEIN_PEPTIDE: &str = r"Protein Group,Protein ID,Protein Accession,Peptide,Unique,-10lgP,Mass,Length,ppm,m/z,z,RT,Area Tryp-CID,Area Tryp EAD,Fraction,Scan,Source File,#Feature,#Feature Tryp-CID,#Feature Tryp EAD,Start,End,PTM,AScore,Found By
+pub(super) const DATA_DB_PROTEIN_PEPTIDE: &str = r"Protein Group,Protein ID,Protein Accession,Peptide,Unique,-10lgP,Mass,Length,ppm,m/z,z,RT,Area Tryp-CID,Area Tryp EAD,Fraction,Scan,Source File,#Feature,#Feature Tryp-CID,#Feature Tryp EAD,Start,End,PTM,AScore,Found By
 1,1,Herceptin,R.ASQDVNTAVAWYQQKPGKAPK.L,Y,133.29,2286.1755,21,2.4,572.5526,4,24.82,2.3211E2,2.4834E2,1,F1:150245,230629 1-Trypsin CID 06.mzML,3,2,1,475,495,,,PEAKS DB
 1,1,Herceptin,K.VDNALQSGNSQESVTEQDSK.D,Y,120.05,2134.9614,20,1.0,1068.4890,2,21.61,2.3684E3,5.8385E3,1,F1:141739,230629 1-Trypsin CID 06.mzML,4,3,1,600,619,,,PEAKS DB
 1,1,Herceptin,K.HKVYAC(+57.02)EVTHQGLSSPVTK.S,Y,111.34,2140.0735,19,1.9,536.0267,4,14.21,1.444E2,1.7678E2,1,F1:120669,230629 1-Trypsin CID 06.mzML,3,2,1,639,657,Carbamidomethylation,C6:Carbamidomethylation:1000.00,PEAKS DB
@@ -20,7 +20,7 @@ fn pepnet() {
     }
 }
 
-const PEPNET_V1_0: &str = "TITLE	DENOVO	Score	PPM Difference	Positional Score
+pub(super) const PEPNET_V1_0: &str = "TITLE	DENOVO	Score	PPM Difference	Positional Score
 AAAAA	SLGGLLLKLLLLLLLLDLLLLLLLLLLSGR	0.0000	397079.88	[0.4487191, 0.5919148, 0.21016912, 0.22742778, 0.15318571, 0.14045578, 0.1543631, 0.13271129, 0.13508919, 0.13140991, 0.14783373, 0.15767457, 0.15513828, 0.1553122, 0.14219347, 0.13958956, 0.10268273, 0.124118775, 0.12767245, 0.13248141, 0.13808915, 0.13166994, 0.12505008, 0.11253849, 0.12842964, 0.14734271, 0.12984684, 0.14804533, 0.13275814, 0.30982876]
 AAAAA	SRRRDDRKKKDDDDDAELSSLSSSSSSSSK	0.0000	379780.6	[0.14136443, 0.16953997, 0.26315865, 0.21568063, 0.13758808, 0.20392312, 0.1987997, 0.17391275, 0.14208835, 0.14573258, 0.1871934, 0.14422397, 0.23250878, 0.21724927, 0.21437551, 0.20044756, 0.17911723, 0.149593, 0.20169377, 0.17628202, 0.16772373, 0.15744668, 0.20052117, 0.15512784, 0.20407508, 0.13276365, 0.1960932, 0.32661346, 0.18954112, 0.17194319]
 AAAAA	DVYCYYYYPPPYYYYGFRPRRRR	0.0000	-37561.832	[0.2419891, 0.3680785, 0.34105796, 0.1292199, 0.5620244, 0.2786073, 0.26188976, 0.14739695, 0.16374455, 0.16572084, 0.12927413, 0.11078749, 0.10235891, 0.12636182, 0.11751035, 0.096022405, 0.121672556, 0.19747463, 0.16499923, 0.11875335, 0.24365018, 0.50002474, 0.39629322]
@@ -37,7 +37,7 @@ fn plgs_peptide() {
     }
 }
 
-const DATA_FRAGMENT: &str = r"protein.key,protein.Entry,protein.Accession,protein.Description,protein.dataBaseType,protein.score,protein.falsePositiveRate,protein.avgMass,protein.MatchedProducts,protein.matchedPeptides,protein.digestPeps,protein.seqCover(%),protein.MatchedPeptideIntenSum,protein.top3MatchedPeptideIntenSum,protein.MatchedProductIntenSum,protein.fmolOnColumn,protein.ngramOnColumn,protein.AutoCurate,protein.Key_ForHomologs,peptide.Rank,peptide.Pass,peptide.matchType,peptide.modification,peptide.mhp,peptide.seq,peptide.OriginatingSeq,peptide.seqStart,peptide.seqLength,peptide.pI,peptide.componentID,peptide.MatchedProducts,peptide.UniqueProducts,peptide.ConsectiveMatchedProducts,peptide.ComplementaryMatchedProducts,peptide.rawScore,peptide.score,peptide.(X)-P Bond,peptide.MatchedProductsSumInten,peptide.MatchedProductsTheoretical,peptide.MatchedProductsString,peptide.ModelRT,peptide.Volume,peptide.CSA,peptide.ModelDrift,peptide.RelIntensity,peptide.AutoCurate,precursor.leID,precursor.mhp,precursor.mhpCal,precursor.retT,precursor.inten,precursor.calcInten,precursor.charge,precursor.z,precursor.mz,precursor.fwhm,precursor.liftOffRT,precursor.infUpRT,precursor.infDownRT,precursor.touchDownRT,prec.rmsFWHMDelta,protein.SumForTotalProteins,peptide.SumForTotalPeps,fragment.mhp,fragment.fragmentType,fragment.fragInd,Neutral.LossType,fragment.str,fragment.seq,fragment.fragSite,product.rank,product.isLinked,product.heID,product.mhp,product.mhpCal,product.m_z,product.retT,product.inten,product.charge,product.z,product.fwhm,product.liftOffRT,product.infUpRT,product.infDownRT,product.touchDownRT,fragmentProduct.deltaMhpPPM,precursorProduct.deltaRetT,peptidePrecursor.deltaMhpPPM
+pub(super) const DATA_FRAGMENT: &str = r"protein.key,protein.Entry,protein.Accession,protein.Description,protein.dataBaseType,protein.score,protein.falsePositiveRate,protein.avgMass,protein.MatchedProducts,protein.matchedPeptides,protein.digestPeps,protein.seqCover(%),protein.MatchedPeptideIntenSum,protein.top3MatchedPeptideIntenSum,protein.MatchedProductIntenSum,protein.fmolOnColumn,protein.ngramOnColumn,protein.AutoCurate,protein.Key_ForHomologs,peptide.Rank,peptide.Pass,peptide.matchType,peptide.modification,peptide.mhp,peptide.seq,peptide.OriginatingSeq,peptide.seqStart,peptide.seqLength,peptide.pI,peptide.componentID,peptide.MatchedProducts,peptide.UniqueProducts,peptide.ConsectiveMatchedProducts,peptide.ComplementaryMatchedProducts,peptide.rawScore,peptide.score,peptide.(X)-P Bond,peptide.MatchedProductsSumInten,peptide.MatchedProductsTheoretical,peptide.MatchedProductsString,peptide.ModelRT,peptide.Volume,peptide.CSA,peptide.ModelDrift,peptide.RelIntensity,peptide.AutoCurate,precursor.leID,precursor.mhp,precursor.mhpCal,precursor.retT,precursor.inten,precursor.calcInten,precursor.charge,precursor.z,precursor.mz,precursor.fwhm,precursor.liftOffRT,precursor.infUpRT,precursor.infDownRT,precursor.touchDownRT,prec.rmsFWHMDelta,protein.SumForTotalProteins,peptide.SumForTotalPeps,fragment.mhp,fragment.fragmentType,fragment.fragInd,Neutral.LossType,fragment.str,fragment.seq,fragment.fragSite,product.rank,product.isLinked,product.heID,product.mhp,product.mhpCal,product.m_z,product.retT,product.inten,product.charge,product.z,product.fwhm,product.liftOffRT,product.infUpRT,product.infDownRT,product.touchDownRT,fragmentProduct.deltaMhpPPM,precursorProduct.deltaRetT,peptidePrecursor.deltaMhpPPM
 0,Accession,Unknown,mono2C_36_cleaved Unknown Entry,Regular,89816.0800,0.00,33499.5799,7491,4224,5901,100.00,9.382709E+07,1.364381E+07,4.463762E+07,,,Green,,1,Pass1,PepFrag1,None,2212.2489,KYAPLYAAEAKRVFSLEKK,KYAPLYAAEAKRVFSLEKK,59,19,10.11,19,11,11,6,3,3051.0050,8.4378,Identified,271476,30.0,b3b4b5b6b9b10°b11°b12b12°b18y14y14°y15y16y16°y17y17°,4.5239,2107,198.702191926964,-0.744767652397498,0.0454,Green,4582,2212.2540,2212.2540,4.5239,4264229,4264229.00,3.38,4,553.8190,0.08156413,4.340279,4.486571,4.558985,4.888723,2.4631E-001,1,1,363.2027,b,3,None,b3,KYA,AP,1,False,37990,363.2104,363.2104,363.2104,4.5221,15601,1.00,1,0.09049957,4.391343,4.486395,4.556062,4.681931,21.3220,0.0018,2.3163
 0,Accession,Unknown,mono2C_36_cleaved Unknown Entry,Regular,89816.0800,0.00,33499.5799,7491,4224,5901,100.00,9.382709E+07,1.364381E+07,4.463762E+07,,,Green,,1,Pass1,PepFrag1,None,2212.2489,KYAPLYAAEAKRVFSLEKK,KYAPLYAAEAKRVFSLEKK,59,19,10.11,19,11,11,6,3,3051.0050,8.4378,Identified,271476,30.0,b3b4b5b6b9b10°b11°b12b12°b18y14y14°y15y16y16°y17y17°,4.5239,2107,198.702191926964,-0.744767652397498,0.0454,Green,4582,2212.2540,2212.2540,4.5239,4264229,4264229.00,3.38,4,553.8190,0.08156413,4.340279,4.486571,4.558985,4.888723,2.4631E-001,0,0,460.2554,b,4,None,b4,KYAP,PL,2,False,37940,460.2597,460.2597,460.2597,4.5218,16673,1.00,1,0.09416644,4.381315,4.485775,4.556681,4.688846,9.1465,0.0021,2.3163
 0,Accession,Unknown,mono2C_36_cleaved Unknown Entry,Regular,89816.0800,0.00,33499.5799,7491,4224,5901,100.00,9.382709E+07,1.364381E+07,4.463762E+07,,,Green,,1,Pass1,PepFrag1,None,2212.2489,KYAPLYAAEAKRVFSLEKK,KYAPLYAAEAKRVFSLEKK,59,19,10.11,19,11,11,6,3,3051.0050,8.4378,Identified,271476,30.0,b3b4b5b6b9b10°b11°b12b12°b18y14y14°y15y16y16°y17y17°,4.5239,2107,198.702191926964,-0.744767652397498,0.0454,Green,4582,2212.2540,2212.2540,4.5239,4264229,4264229.00,3.38,4,553.8190,0.08156413,4.340279,4.486571,4.558985,4.888723,2.4631E-001,0,0,573.3395,b,5,None,b5,KYAPL,LY,3,False,38042,573.3387,573.3387,573.3387,4.5226,30837,1.00,1,0.0951906,4.378022,4.486072,4.556904,4.685687,-1.4629,0.0012,2.3163
@@ -61,7 +61,7 @@ const DATA_FRAGMENT: &str = r"protein.key,protein.Entry,protein.Accession,protei
 0,Accession,Unknown,mono2C_36_cleaved Unknown Entry,Regular,89816.0800,0.00,33499.5799,7491,4224,5901,100.00,9.382709E+07,1.364381E+07,4.463762E+07,,,Green,,4179,Pass2,VarMod,Oxidation+M(28);Oxidation+M(35),4614.2827,LASTNAGSINAPTVSDSRALARRFHFDMNIEVISMYSQNGKI,LASTNAGSINAPTVSDSRALARRFHFDMNIEVISMYSQNGKI,189,42,9.71,962,5,5,1,1,213.5992,7.2830,Missing,1202,2.2,b15b33y8y9y15*y37,7.8136,4244,316.91530814765,-1.5534276440232,0.0000,Yellow,53735,4614.2897,4614.2897,7.8136,1001,1001.00,4.54,4,1154.3279,0.08579935,7.721161,7.744169,7.829408,7.849741,4.2991E-001,0,0,1741.8135,y,15,NH3,y15*,MNIEVISMYSQNGKI,DM,5,False,202688,1741.8278,1741.8278,1741.8278,7.8228,99,1.00,1,0.04574742,7.774236,7.796287,7.84793,7.874473,8.2307,-0.0092,1.5090
 0,Accession,Unknown,mono2C_36_cleaved Unknown Entry,Regular,89816.0800,0.00,33499.5799,7491,4224,5901,100.00,9.382709E+07,1.364381E+07,4.463762E+07,,,Green,,429,Pass2,PepFrag2,None,2359.0626,APSQSDQEQLFSNVQYFAHY,APSQSDQEQLFSNVQYFAHY,37,20,4.06,2798,0,0,0,0,140.6496,7.1015,Missing,0,6.5,,4.6156,2094,197.884032748452,-0.849627901687047,0.0004,Yellow,5144,2359.1177,2359.1177,4.6156,40089,40089.00,3.42,3,787.0441,0.0824611,4.492433,4.580017,4.655515,4.862597,0.0000E+000,0,1,,,,,,,,-1,,,,,,,,,,,,,,,,,23.3782";
 
-const DATA_PEPTIDE: &str = r"protein.key,protein.Entry,protein.Accession,protein.Description,protein.dataBaseType,protein.score,protein.falsePositiveRate,protein.avgMass,protein.MatchedProducts,protein.matchedPeptides,protein.digestPeps,protein.seqCover(%),protein.MatchedPeptideIntenSum,protein.top3MatchedPeptideIntenSum,protein.MatchedProductIntenSum,protein.fmolOnColumn,protein.ngramOnColumn,protein.AutoCurate,protein.Key_ForHomologs,protein.SumForTotalProteins,peptide.Rank,peptide.Pass,peptide.matchType,peptide.modification,peptide.mhp,peptide.seq,peptide.OriginatingSeq,peptide.seqStart,peptide.seqLength,peptide.pI,peptide.componentID,peptide.MatchedProducts,peptide.UniqueProducts,peptide.ConsectiveMatchedProducts,peptide.ComplementaryMatchedProducts,peptide.rawScore,peptide.score,peptide.(X)-P Bond,peptide.MatchedProductsSumInten,peptide.MatchedProductsTheoretical,peptide.MatchedProductsString,peptide.ModelRT,peptide.Volume,peptide.CSA,peptide.ModelDrift,peptide.RelIntensity,peptide.AutoCurate,precursor.leID,precursor.mhp,precursor.mhpCal,precursor.retT,precursor.inten,precursor.calcInten,precursor.charge,precursor.z,precursor.mz,precursor.fwhm,precursor.liftOffRT,precursor.infUpRT,precursor.infDownRT,precursor.touchDownRT,prec.rmsFWHMDelta,peptidePrecursor.deltaMhpPPM
+pub(super) const DATA_PEPTIDE: &str = r"protein.key,protein.Entry,protein.Accession,protein.Description,protein.dataBaseType,protein.score,protein.falsePositiveRate,protein.avgMass,protein.MatchedProducts,protein.matchedPeptides,protein.digestPeps,protein.seqCover(%),protein.MatchedPeptideIntenSum,protein.top3MatchedPeptideIntenSum,protein.MatchedProductIntenSum,protein.fmolOnColumn,protein.ngramOnColumn,protein.AutoCurate,protein.Key_ForHomologs,protein.SumForTotalProteins,peptide.Rank,peptide.Pass,peptide.matchType,peptide.modification,peptide.mhp,peptide.seq,peptide.OriginatingSeq,peptide.seqStart,peptide.seqLength,peptide.pI,peptide.componentID,peptide.MatchedProducts,peptide.UniqueProducts,peptide.ConsectiveMatchedProducts,peptide.ComplementaryMatchedProducts,peptide.rawScore,peptide.score,peptide.(X)-P Bond,peptide.MatchedProductsSumInten,peptide.MatchedProductsTheoretical,peptide.MatchedProductsString,peptide.ModelRT,peptide.Volume,peptide.CSA,peptide.ModelDrift,peptide.RelIntensity,peptide.AutoCurate,precursor.leID,precursor.mhp,precursor.mhpCal,precursor.retT,precursor.inten,precursor.calcInten,precursor.charge,precursor.z,precursor.mz,precursor.fwhm,precursor.liftOffRT,precursor.infUpRT,precursor.infDownRT,precursor.touchDownRT,prec.rmsFWHMDelta,peptidePrecursor.deltaMhpPPM
 0,Accession,Unknown,mono2C_36_cleaved Unknown Entry,Regular,89816.0800,0.00,33499.5799,7491,4224,5901,100.00,9.382709E+07,1.364381E+07,4.463762E+07,,,Green,,1,1,Pass1,PepFrag1,None,2212.2489,KYAPLYAAEAKRVFSLEKK,KYAPLYAAEAKRVFSLEKK,59,19,10.11,19,11,11,6,3,3051.0050,8.4378,Identified,271476,30.0,b3b4b5b6b9b10°b11°b12b12°b18y14y14°y15y16y16°y17y17°,4.5239,2107,198.702191926964,-0.744767652397498,0.0454,Green,4582,2212.2540,2212.2540,4.5239,4264229,4264229.00,3.38,4,553.8190,0.08156413,4.340279,4.486571,4.558985,4.888723,2.4631E-001,2.3163
 0,Accession,Unknown,mono2C_36_cleaved Unknown Entry,Regular,89816.0800,0.00,33499.5799,7491,4224,5901,100.00,9.382709E+07,1.364381E+07,4.463762E+07,,,Green,,0,2,Pass1,PepFrag1,None,1245.6296,MSNYIQFKSK,MSNYIQFKSK,78,10,10.18,13,13,13,9,5,6831.2570,8.7879,,163857,12.0,b3b4b4*b4°b5b5°b6b7b7*b9b10b10*y3y3°y4y5y5*y5°y6y7y7*y7°y9y9*,4.2470,1150,132.706296038843,-0.512986876092312,0.0210,Green,3147,1245.6235,1245.6235,4.2470,1969575,1969575.00,1.94,2,623.3154,0.07912405,4.160105,4.213992,4.280109,4.350046,1.6046E-001,-4.9410
 0,Accession,Unknown,mono2C_36_cleaved Unknown Entry,Regular,89816.0800,0.00,33499.5799,7491,4224,5901,100.00,9.382709E+07,1.364381E+07,4.463762E+07,,,Green,,0,3,Pass1,PepFrag1,None,1849.9775,CRIEPVCLLLHGSPGAGK,CRIEPVCLLLHGSPGAGK,88,18,8.30,28,20,20,14,6,13028.4700,9.0682,Identified,1089488,23.9,b3b4b4°b5b6b7b8b9b12b18y4y5y6y6°y7y7°y8y8°y9y10y12y13y14y18,4.9427,1747,175.369839368313,-0.666278437248658,0.0167,Green,7242,1849.9703,1849.9703,4.9427,1563412,1563412.00,2.72,3,617.3283,0.1074543,4.793483,4.908295,4.981444,5.361441,1.8692E-001,-3.9185
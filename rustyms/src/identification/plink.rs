@@ -241,8 +241,8 @@ format_family!(
                                             c_term.push(m.clone());
                                         }
                                     }
-                                } else if Modification::Simple(Arc::new(SimpleModificationInner::Mass(Mass::default().into()))) == *m {
-                                    *m = Modification::Simple(fitting[0].clone());
+                                } else if Modification::from(SimpleModificationInner::Mass(Mass::default().into())) == *m {
+                                    *m = Modification::from(fitting[0].clone());
                                 }
                             }
                             if let Some(i) = remove {
@@ -20,7 +20,7 @@ fn plink() {
     }
 }
 
-const DATA_V2_3: &str = r"Order,Title,Charge,Precursor_MH,Peptide_Type,Peptide,Peptide_MH,Modifications,Refined_Score,SVM_Score,Score,E-value,Precursor_Mass_Error(Da),Precursor_Mass_Error(ppm),Target_Decoy,Q-value,Proteins,Protein_Type,FileID,isComplexSatisfied,isFilterIn
+pub(super) const DATA_V2_3: &str = r"Order,Title,Charge,Precursor_MH,Peptide_Type,Peptide,Peptide_MH,Modifications,Refined_Score,SVM_Score,Score,E-value,Precursor_Mass_Error(Da),Precursor_Mass_Error(ppm),Target_Decoy,Q-value,Proteins,Protein_Type,FileID,isComplexSatisfied,isFilterIn
 1,20240205_EX3_UM5_perez044_SA_EXT00_MitoDMTMM_WT2_F16.19136.19136.3.0.dta,3,1642.831563,2,IDPEKLSVNSHFMK(2)(5),1642.825678,Oxidation[M](13),199.200765,107.603000,1.856141e-047,1.000000e+000,0.005885,3.582243,2,0.000000,sp|Q9CR21|ACPM_MOUSE (26)(29)/,0,121,1,1
 2,20240205_EX3_UM5_perez044_SA_EXT00_MitoDMTMM_WT2_F16.19593.19593.3.0.dta,3,1642.835591,2,IDPEKLSVNSHFMK(4)(5),1642.825678,Oxidation[M](13),175.123247,106.257000,7.131342e-047,1.000000e+000,0.009913,6.034116,2,0.000000,sp|Q9CR21|ACPM_MOUSE (28)(29)/,0,121,1,1
 3,20240205_EX3_UM5_perez044_SA_EXT00_MitoDMTMM_WT2_F16.24008.24008.3.0.dta,3,1626.832670,2,IDPEKLSVNSHFMK(4)(5),1626.830764,null,175.761841,95.988800,2.053969e-042,1.000000e+000,0.001906,1.171603,2,0.000000,sp|Q9CR21|ACPM_MOUSE (28)(29)/,0,121,1,1
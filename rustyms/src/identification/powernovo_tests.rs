@@ -20,7 +20,7 @@ fn powernovo() {
     }
 }
 
-const POWERNOVO_V1_0_1: &str = "Spectrum Name,PowerNovo Peptides,PowerNovo aaScore,PowerNovo Score,Area
+pub(super) const POWERNOVO_V1_0_1: &str = "Spectrum Name,PowerNovo Peptides,PowerNovo aaScore,PowerNovo Score,Area
 20230408_F1_UM4_Peng0013_SA_EXT00_her_01_tryp:index=0,ELLNKHLLLLVANDQGNRTTPSYVAFTDTERLLGDAQTLLSLVTGSTSTS,0.3100000023841858 0.3100000023841858 0.30000001192092896 0.23000000417232513 0.3100000023841858 0.30000001192092896 0.3100000023841858 0.25999999046325684 0.27000001072883606 0.30000001192092896 0.3100000023841858 0.30000001192092896 0.3100000023841858 0.3100000023841858 0.30000001192092896 0.3100000023841858 0.3100000023841858 0.28999999165534973 0.30000001192092896 0.3100000023841858 0.3100000023841858 0.3100000023841858 0.28999999165534973 0.3100000023841858 0.3100000023841858 0.2800000011920929 0.3100000023841858 0.3100000023841858 0.3100000023841858 0.3100000023841858 0.2800000011920929 0.3100000023841858 0.3100000023841858 0.3100000023841858 0.3100000023841858 0.3100000023841858 0.30000001192092896 0.30000001192092896 0.3100000023841858 0.3100000023841858 0.3100000023841858 0.3100000023841858 0.3100000023841858 0.28999999165534973 0.30000001192092896 0.1599999964237213 0.28999999165534973 0.28999999165534973 0.30000001192092896 0.18000000715255737,0.57,1
 20230408_F1_UM4_Peng0013_SA_EXT00_her_01_tryp:index=1,LLTSQATAAAGAHDGGRDGRDPAEFTDEEESDEGHSEDEEDVTVTTTGSSTAS,0.10000000149011612 0.10000000149011612 0.10000000149011612 0.09000000357627869 0.10000000149011612 0.09000000357627869 0.07999999821186066 0.09000000357627869 0.07999999821186066 0.07999999821186066 0.07999999821186066 0.09000000357627869 0.07000000029802322 0.07999999821186066 0.09000000357627869 0.07999999821186066 0.09000000357627869 0.05999999865889549 0.07000000029802322 0.09000000357627869 0.07000000029802322 0.10000000149011612 0.09000000357627869 0.07999999821186066 0.05000000074505806 0.10000000149011612 0.07000000029802322 0.10000000149011612 0.09000000357627869 0.09000000357627869 0.09000000357627869 0.07000000029802322 0.07999999821186066 0.10000000149011612 0.07000000029802322 0.07000000029802322 0.09000000357627869 0.07999999821186066 0.07999999821186066 0.09000000357627869 0.07999999821186066 0.10999999940395355 0.10000000149011612 0.10999999940395355 0.10999999940395355 0.10999999940395355 0.10000000149011612 0.07999999821186066 0.07999999821186066 0.07000000029802322 0.07000000029802322 0.10000000149011612 0.07000000029802322,0.49,1
 20230408_F1_UM4_Peng0013_SA_EXT00_her_01_tryp:index=2,GLQFESSDDEDDDDDDDFLPAA,0.09000000357627869 0.07999999821186066 0.07999999821186066 0.09000000357627869 0.07999999821186066 0.09000000357627869 0.05999999865889549 0.07999999821186066 0.05999999865889549 0.09000000357627869 0.03999999910593033 0.05999999865889549 0.07999999821186066 0.05999999865889549 0.07000000029802322 0.05999999865889549 0.07999999821186066 0.07999999821186066 0.09000000357627869 0.09000000357627869 0.07999999821186066 0.07999999821186066,0.29,1
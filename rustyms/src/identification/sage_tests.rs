@@ -20,7 +20,7 @@ fn sage() {
     }
 }
 
-const DATA: &str = r"psm_id	peptide	proteins	num_proteins	filename	scannr	rank	label	expmass	calcmass	charge	peptide_len	missed_cleavages	semi_enzymatic	isotope_error	precursor_ppm	fragment_ppm	hyperscore	delta_next	delta_best	rt	aligned_rt	predicted_rt	delta_rt_model	ion_mobility	predicted_mobility	delta_mobility	matched_peaks	longest_b	longest_y	longest_y_pct	matched_intensity_pct	scored_candidates	poisson	sage_discriminant_score	posterior_error	spectrum_q	peptide_q	protein_q	ms2_intensity
+pub(super) const DATA: &str = r"psm_id	peptide	proteins	num_proteins	filename	scannr	rank	label	expmass	calcmass	charge	peptide_len	missed_cleavages	semi_enzymatic	isotope_error	precursor_ppm	fragment_ppm	hyperscore	delta_next	delta_best	rt	aligned_rt	predicted_rt	delta_rt_model	ion_mobility	predicted_mobility	delta_mobility	matched_peaks	longest_b	longest_y	longest_y_pct	matched_intensity_pct	scored_candidates	poisson	sage_discriminant_score	posterior_error	spectrum_q	peptide_q	protein_q	ms2_intensity
 68	Q[-17.027]VQLQQSAAE	anti-FLAG-M2_HC	1	20240113_EX3_UM5_Peng0013_SA_EXT00_GluC_2h_standard.mzML	controllerType=0 controllerNumber=1 scan=13947	1	1	1083.5209	1083.5192	2	10	0	0	0.0	1.5772523	4.326962	48.89544628732507	3.804374154620227	0.0	29.031733	0.4219151	0.42962697	0.0077118576	0.0	0.0	0.0	15	6	6	0.6	52.72756	48	-5.310279475246126	-0.2721751	-30.489534	0.003984064	0.013706031	1.0	2537541.5
 258	AGNTFTCSVLHE	139H2_HC;anti-FLAG-M2_HC	2	20240113_EX3_UM5_Peng0013_SA_EXT00_GluC_2h_exBusHCD.mzML	controllerType=0 controllerNumber=1 scan=18157	1	1	1277.5806	1277.571	2	12	0	0	0.0	7.4527745	5.461328	29.359412403792973	29.359412403792973	0.0	30.288746	0.61734784	0.62651765	0.009169817	0.0	0.0	0.0	9	2	5	0.41666666	44.847633	1	-1.3794106637415624	-0.27700272	-30.085367	0.003984064	0.013706031	1.0	37966.004
 297	ATHKTSTSPIVKSFNR[+14.016]NE[+57.0214]	139H2_LC;anti-FLAG-M2_LC	2	20240113_EX3_UM5_Peng0013_SA_EXT00_GluC_2h_exBUstandard.mzML	controllerType=0 controllerNumber=1 scan=40290	1	1	2087.0747	2087.0762	3	18	0	0	0.0	0.7018643	4.2632957	45.554752225675415	0.0	0.0	51.26408	0.89359313	0.89359313	0.0	0.0	0.0	0.0	16	4	5	0.2777778	48.30265	147	-6.97961068204291	-0.29033038	-28.985697	0.003984064	0.013706031	1.0	144385.33
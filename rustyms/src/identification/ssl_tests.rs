@@ -54,7 +54,7 @@ fn peptide_example() {
     }
 }
 
-const CASCADIA_V0_0_5: &str = "file	scan	charge	sequence	score-type	score	retention-time	start-time	end-time
+pub(super) const CASCADIA_V0_0_5: &str = "file	scan	charge	sequence	score-type	score	retention-time	start-time	end-time
 ../test_data/test/20230408_F1_UM4_Peng0013_SA_EXT00_her_01_tryp.mzML	140	4.0	VNHKPSNTKVDKK	Cascadia Score	0.8716757	13.830939	13.700380273173717	13.961498312641712
 ../test_data/test/20230408_F1_UM4_Peng0013_SA_EXT00_her_01_tryp.mzML	143	4.0	LANVNHKPSNTKVDK	Cascadia Score	0.9688815	13.73074	13.600180573771862	13.861298613239857
 ../test_data/test/20230408_F1_UM4_Peng0013_SA_EXT00_her_01_tryp.mzML	284	4.0	LANVNHKPSNTKVDK	Cascadia Score	0.91546655	14.112868	13.982309289286999	14.243427328754994
@@ -76,7 +76,7 @@ const CASCADIA_V0_0_5: &str = "file	scan	charge	sequence	score-type	score	retent
 ../test_data/test/20230408_F1_UM4_Peng0013_SA_EXT00_her_01_tryp.mzML	26981	4.0	THTC[Carbamidomethyl]PPC[Carbamidomethyl]PAPELLGGPSVFLFPPKPK	Cascadia Score	0.85148484	75.06698	74.93641943485585	75.19753747432384
 ../test_data/test/20230408_F1_UM4_Peng0013_SA_EXT00_her_01_tryp.mzML	1397	4.0	HKVYAGEVTHQGLSSPVTK	Cascadia Score	0.99907994	16.68329	16.552731461833385	16.81384950130138";
 
-const SMALL_MOLECULES_EXAMPLE: &str = r#"file	scan	charge	adduct	inchikey	chemicalformula	moleculename	otherkeys
+pub(super) const SMALL_MOLECULES_EXAMPLE: &str = r#"file	scan	charge	adduct	inchikey	chemicalformula	moleculename	otherkeys
 dexcaf_051017.mzML	01369	-1	[M-H]	ZXPLRDFHBYIQOX-BTBVOZEKSA-N	C24H44O21N0	Glc04Reduced	 
 dexcaf_051017.mzML	01639	-1	[M-H]	NBVGBCYERZIRIP-JAMOUWTMSA-N	C30H54O26N0	Glc05Reduced	
 dexcaf_051017.mzML	01855	-1	[M-H]	PNHJKLJIDNHXFR-ZGJYWSOBSA-N	C36H64O31N0	Glc06Reduced	
@@ -84,7 +84,7 @@ dexcaf_051017.mzML	02029	-1	[M-H]	NVKJDLBVRSXYRE-BMFDHOHESA-N	C42H74O36N0	Glc07R
 dexcaf_051017.mzML	02179	-1	[M-H]	YMRGEPQWJZHXFF-MGQBKJSVSA-N	C48H84O41N0	Glc08Reduced	
 dexcaf_051017.mzML	01079	-1	[M-H]	RYYVLZVUVIJVGH-UHFFFAOYSA-N	C8H10N4O2	Caffeine	"InChI:1S/C8H10N4O2/c1-10-4-9-6-5(10)7(13)12(3)8(14)11(6)2/h4H,1-3H3	HMDB:01847	CAS:58-08-2	SMILES:Cn1cnc2n(C)c(=O)n(C)c(=O)c12""#;
 
-const PEPTIDE_EXAMPLE: &str = "file	scan	charge	sequence
+pub(super) const PEPTIDE_EXAMPLE: &str = "file	scan	charge	sequence
 demo.ms2	8	3	VGAGAPVYLAAVLEYLAAEVLELAGNAAR
 demo.ms2	1806	2	LAESITIEQGK
 demo.ms2	2572	2	ELAEDGC[+57.0]SGVEVR
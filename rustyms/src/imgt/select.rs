@@ -26,6 +26,8 @@ pub struct Selection<S1: std::hash::BuildHasher, S2: std::hash::BuildHasher> {
     pub chains: Option<HashSet<ChainType, S2>>,
     /// The kind of genes you want, None allows all, otherwise only the genes specified will be returned
     pub genes: Option<HashSet<GeneType>>,
+    /// The functionality of the genes you want, None allows all, otherwise only the genes with the specified functionality will be returned
+    pub functionality: Option<HashSet<Functionality>>,
     /// The way of handling alleles you want
     pub allele: AlleleSelection,
 }
@@ -58,6 +60,15 @@ impl<S1: std::hash::BuildHasher, S2: std::hash::BuildHasher> Selection<S1, S2> {
         }
     }
 
+    /// Builder pattern method to add a functionality selection, will replace any previously set functionality selection
+    #[must_use]
+    pub fn functionality(self, functionality: impl Into<HashSet<Functionality>>) -> Self {
+        Self {
+            functionality: Some(functionality.into()),
+            ..self
+        }
+    }
+
     /// Builder pattern method to add an allele selection, will replace any previously set allele selection
     #[must_use]
     pub fn allele(self, allele: AlleleSelection) -> Self {
@@ -90,12 +101,36 @@ impl<
             .flat_map(move |(species, germline)| {
                 germline
                     .into_iter()
+                    .filter(|(_, seq)| {
+                        self.functionality
+                            .as_ref()
+                            .map_or(true, |f| f.contains(&seq.functionality))
+                    })
                     .take(self.allele.take_num())
-                    .map(move |(a, seq)| (species, &germline.name, *a, seq))
+                    .map(|(a, seq)| (species, &germline.name, *a, seq))
+                    .collect::<Vec<_>>()
             })
             .map(Into::into)
     }
 
+    /// Get all pairs of a heavy chain allele with a light chain (kappa or lambda) allele from the
+    /// same species, as needed for modelling full antibodies. All other selection rules are applied
+    /// to both chains, if a chain selection is set only the heavy and light chains in that
+    /// selection are paired.
+    pub fn paired_germlines(self) -> impl Iterator<Item = (Allele<'static>, Allele<'static>)> {
+        let (heavy, light): (Vec<_>, Vec<_>) = self
+            .germlines()
+            .filter(|a| a.gene.chain != ChainType::Iota)
+            .partition(|a| a.gene.chain == ChainType::Heavy);
+        heavy.into_iter().flat_map(move |h| {
+            light
+                .iter()
+                .filter(|l| l.species == h.species)
+                .map(|l| (h.clone(), l.clone()))
+                .collect::<Vec<_>>()
+        })
+    }
+
     #[cfg(feature = "rayon")]
     /// Get the selected alleles in parallel fashion, only available if you enable the feature "rayon" (on by default)
     pub fn par_germlines(self) -> impl ParallelIterator<Item = Allele<'static>> {
@@ -118,9 +153,15 @@ impl<
             })
             .flat_map(move |(species, germline)| {
                 germline
-                    .into_par_iter()
+                    .into_iter()
+                    .filter(|(_, seq)| {
+                        self.functionality
+                            .as_ref()
+                            .map_or(true, |f| f.contains(&seq.functionality))
+                    })
                     .take(self.allele.take_num())
-                    .map(move |(a, seq)| (species, &germline.name, *a, seq))
+                    .map(|(a, seq)| (species, &germline.name, *a, seq))
+                    .collect::<Vec<_>>()
             })
             .map(Into::into)
     }
@@ -137,6 +178,7 @@ impl<S1: std::hash::BuildHasher, S2: std::hash::BuildHasher> Default for Selecti
             species: None,
             chains: None,
             genes: None,
+            functionality: None,
             allele: AlleleSelection::First,
         }
     }
@@ -168,6 +210,8 @@ pub struct Allele<'a> {
     pub species: Species,
     /// The gene where this is the sequence for, eg `IGHV3-23`
     pub gene: std::borrow::Cow<'a, Gene>,
+    /// The IMGT functionality of the allele
+    pub functionality: Functionality,
     /// The allele number, in IMGT this follows the name, eg `*01` is the allele in `IGHV3-23*01`
     pub number: usize,
     /// The actual sequence, the sequences present in the database are pure amino acids, no modifications are to be expected
@@ -208,6 +252,7 @@ impl<'a> From<(Species, &'a Gene, usize, &'a AnnotatedSequence)> for Allele<'a>
         Self {
             species: value.0,
             gene: std::borrow::Cow::Borrowed(value.1),
+            functionality: value.3.functionality,
             number: value.2,
             sequence: &value.3.sequence,
             regions: &value.3.regions,
@@ -249,6 +294,7 @@ impl Germlines {
             .map(move |(a, seq)| Allele {
                 species,
                 gene: std::borrow::Cow::Owned(gene),
+                functionality: seq.functionality,
                 number: *a,
                 sequence: &seq.sequence,
                 regions: &seq.regions,
@@ -260,12 +306,12 @@ impl Germlines {
 #[cfg(test)]
 #[allow(clippy::missing_panics_doc)]
 mod tests {
-    use std::collections::HashSet;
+    use std::collections::{hash_map::RandomState, HashSet};
 
     use crate::imgt::select::contains_gene;
 
     use super::Selection;
-    use super::{ChainType, GeneType, Species};
+    use super::{AlleleSelection, ChainType, Functionality, GeneType, Species};
 
     #[test]
     fn try_first_human() {
@@ -287,6 +333,39 @@ mod tests {
         assert_eq!(first.name(), "IGHGP*01");
     }
 
+    #[test]
+    fn paired_human() {
+        let selection = Selection::<RandomState, RandomState>::default()
+            .species([Species::HomoSapiens])
+            .gene([GeneType::V])
+            .functionality([Functionality::Functional]);
+        let count = |chains: &[ChainType]| {
+            selection
+                .clone()
+                .chain(chains.iter().copied().collect::<HashSet<_>>())
+                .germlines()
+                .count()
+        };
+        let heavy = count(&[ChainType::Heavy]);
+        let light = count(&[ChainType::LightKappa, ChainType::LightLambda]);
+        assert!(heavy > 0 && light > 0);
+        let pairs = selection.clone().paired_germlines().collect::<Vec<_>>();
+        assert_eq!(pairs.len(), heavy * light);
+        assert!(pairs.iter().all(|(h, l)| h.gene.chain == ChainType::Heavy
+            && matches!(l.gene.chain, ChainType::LightKappa | ChainType::LightLambda)
+            && h.gene.kind == GeneType::V
+            && l.gene.kind == GeneType::V));
+        // Only kappa light chains
+        let kappa = count(&[ChainType::LightKappa]);
+        assert_eq!(
+            selection
+                .chain([ChainType::Heavy, ChainType::LightKappa])
+                .paired_germlines()
+                .count(),
+            heavy * kappa
+        );
+    }
+
     #[test]
     fn gene_selections() {
         let constant = HashSet::from([GeneType::C(None)]);
@@ -310,4 +389,24 @@ mod tests {
             GeneType::C(Some(crate::imgt::Constant::A))
         ));
     }
+
+    #[test]
+    fn functional_human() {
+        let all = Selection::<RandomState, RandomState>::default()
+            .species([Species::HomoSapiens])
+            .allele(AlleleSelection::All);
+        let functional = all.clone().functionality([Functionality::Functional]);
+        assert!(functional.clone().germlines().count() < all.germlines().count());
+        assert!(functional
+            .germlines()
+            .all(|a| a.functionality == Functionality::Functional
+                && !a.name().starts_with("IGHGP")
+                && !a.name().starts_with("IGHJ1P")));
+        let pseudo = Selection::default()
+            .species([Species::HomoSapiens])
+            .chain([ChainType::Heavy])
+            .gene([GeneType::C(Some(crate::imgt::Constant::G))])
+            .functionality([Functionality::Pseudogene]);
+        assert_eq!(pseudo.germlines().next().unwrap().name(), "IGHGP*01");
+    }
 }
@@ -167,9 +167,11 @@ impl Chain {
                             "Not allowed to have multiple sequences for one allele in a germline"
                         )
                     }
-                    Err(allele_index) => db[index]
-                        .alleles
-                        .insert(allele_index, germline.alleles.pop().unwrap()),
+                    Err(allele_index) => {
+                        db[index]
+                            .alleles
+                            .insert(allele_index, germline.alleles.pop().unwrap());
+                    }
                 }
             }
             Err(index) => db.insert(index, germline),
@@ -309,6 +311,8 @@ pub struct AnnotatedSequence {
     pub regions: Vec<(Region, usize)>,
     /// 0 based locations of single amino acid annotations, overlapping with the regions defined above
     pub annotations: Vec<(Annotation, usize)>,
+    /// The IMGT functionality of this allele
+    pub functionality: Functionality,
 }
 
 impl AnnotatedSequence {
@@ -317,12 +321,14 @@ impl AnnotatedSequence {
         sequence: Peptidoform<UnAmbiguous>,
         regions: Vec<(Region, usize)>,
         mut conserved: Vec<(Annotation, usize)>,
+        functionality: Functionality,
     ) -> Self {
         conserved.sort_unstable_by_key(|c| c.1);
         Self {
             sequence,
             regions,
             annotations: conserved,
+            functionality,
         }
     }
 }
@@ -618,6 +624,43 @@ impl Display for GeneType {
     }
 }
 
+/// The IMGT functionality of a germline allele
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, PartialOrd, Ord, Clone, Copy, Hash)]
+pub enum Functionality {
+    /// Functional gene (F)
+    Functional,
+    /// Open reading frame, a gene without known defects but not found in rearranged sequences (ORF)
+    Orf,
+    /// Pseudogene, a gene with defects that make it unable to be expressed (P)
+    Pseudogene,
+}
+
+impl FromStr for Functionality {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "F" | "functional" => Ok(Self::Functional),
+            "ORF" => Ok(Self::Orf),
+            "P" | "pseudogene" => Ok(Self::Pseudogene),
+            _ => Err(()),
+        }
+    }
+}
+
+impl Display for Functionality {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Functional => "F",
+                Self::Orf => "ORF",
+                Self::Pseudogene => "P",
+            }
+        )
+    }
+}
+
 #[allow(clippy::missing_panics_doc)]
 #[test]
 fn imgt_names() {
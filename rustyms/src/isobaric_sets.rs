@@ -189,7 +189,7 @@ pub fn building_blocks(
                         })
                         .map(|m| {
                             let mut modifications = seq.modifications.clone();
-                            modifications.push(Modification::Simple(m.0.clone()));
+                            modifications.push(Modification::from(m.0.clone()));
                             let mut result = SequenceElement::new(seq.aminoacid, None);
                             result.modifications = modifications;
                             result
@@ -232,7 +232,25 @@ pub fn find_isobaric_sets(
     variable: &[(SimpleModification, Option<PlacementRule>)],
     base: Option<&Peptidoform<SimpleLinear>>,
 ) -> IsobaricSetIterator {
-    let bounds = tolerance.bounds(mass);
+    let bounds = tolerance.bounds_for_experimental(mass);
+    find_isobaric_sets_in_range(bounds.0, bounds.1, amino_acids, fixed, variable, base)
+}
+
+/// Find the isobaric sets with a mass within the given range (inclusive on both ends) with the
+/// given modifications. This is the same as [`find_isobaric_sets`] but with an explicit mass
+/// window instead of a tolerance around a single mass, the masses are the monoisotopic mass of
+/// the residues (so without the terminal water).
+/// # Panics
+/// Panics if any of the modifications does not have a defined mass. Or if the weight of the
+/// base selection is already in the given range.
+pub fn find_isobaric_sets_in_range(
+    min_mass: Mass,
+    max_mass: Mass,
+    amino_acids: &[AminoAcid],
+    fixed: &[(SimpleModification, Option<PlacementRule>)],
+    variable: &[(SimpleModification, Option<PlacementRule>)],
+    base: Option<&Peptidoform<SimpleLinear>>,
+) -> IsobaricSetIterator {
     let base_mass = base
         .and_then(|b| {
             b.formulas()
@@ -241,7 +259,7 @@ pub fn find_isobaric_sets(
                 .map(|(f, _)| f.monoisotopic_mass())
         })
         .unwrap_or_default();
-    let bounds = (bounds.0 - base_mass, bounds.1 - base_mass);
+    let bounds = (min_mass - base_mass, max_mass - base_mass);
     assert!(bounds.0.value > 0.0, "Cannot have a base selection that has a weight within the tolerance of the intended final mass for isobaric search.");
     let (n_term, center, c_term) = building_blocks(amino_acids, fixed, variable);
 
@@ -361,7 +379,7 @@ impl IsobaricSetIterator {
             .n_term(self.base.as_ref().map_or_else(
                 || {
                     self.state.0.map_or(Vec::new(), |i| {
-                        vec![Modification::Simple(self.n_term[i].1.clone())]
+                        vec![Modification::from(self.n_term[i].1.clone())]
                     })
                 },
                 |b| b.get_n_term().to_vec(),
@@ -369,7 +387,7 @@ impl IsobaricSetIterator {
             .c_term(self.base.as_ref().map_or_else(
                 || {
                     self.state.0.map_or(Vec::new(), |i| {
-                        vec![Modification::Simple(self.c_term[i].1.clone())]
+                        vec![Modification::from(self.c_term[i].1.clone())]
                     })
                 },
                 |b| b.get_c_term().to_vec(),
@@ -531,4 +549,30 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn isobaric_sets_in_range() {
+        let min = Mass::new::<crate::system::dalton>(200.0);
+        let max = Mass::new::<crate::system::dalton>(210.0);
+        let sets: Vec<Peptidoform<SimpleLinear>> = find_isobaric_sets_in_range(
+            min,
+            max,
+            AminoAcid::UNIQUE_MASS_AMINO_ACIDS,
+            &[],
+            &[],
+            None,
+        )
+        .collect();
+        assert!(!sets.is_empty());
+        for set in &sets {
+            let mass = set.bare_formulas()[0].monoisotopic_mass();
+            assert!(min <= mass && mass <= max, "{set} {mass:?}");
+        }
+        assert!(sets.contains(
+            &Peptidoform::pro_forma("AE", None)
+                .unwrap()
+                .into_simple_linear()
+                .unwrap()
+        ));
+    }
 }
@@ -1,18 +1,18 @@
-use crate::MolecularFormula;
+use crate::{
+    system::{da, Mass},
+    Element, MolecularFormula,
+};
 use itertools::Itertools;
-use ndarray::{arr1, concatenate, s, Array1, Axis};
-use probability::distribution::{Binomial, Discrete};
-use std::cmp::Ordering;
+use ndarray::{arr1, s, Array1};
 
 impl MolecularFormula {
     /// Get the isotopic distribution, using the natural distribution as defined by CIAAW.
     /// All elements are considered. The return is an array with the probability per offset.
-    /// The first element of the array is the base peak, every consecutive peak is 1 Dalton heavier.
-    /// The probability is normalized to (approximately) 1 total area.
-    ///
-    /// This approximation slightly overestimates the tail end of the distribution. Especially
-    /// for species with multiple higher mass isotopes as it does not take the number of already
-    /// chosen atom for lower weighed isotopes into account.
+    /// The first element of the array is the lightest isotopic species, every consecutive peak
+    /// is 1 Dalton heavier. For most formulas the lightest species is the monoisotopic mass, but
+    /// for elements where the lightest isotope is not the most abundant (like selenium) this is
+    /// lighter, see [`Self::isotopic_distribution_start`]. The probability is normalized to
+    /// (approximately) 1 total area. Any tail with a probability below the threshold is ignored.
     #[allow(clippy::missing_panics_doc)]
     pub fn isotopic_distribution(&self, threshold: f64) -> Array1<f64> {
         let mut result = arr1(&[1.0]);
@@ -21,7 +21,6 @@ impl MolecularFormula {
                 // TODO: think about negative numbers?
                 continue;
             }
-            let amount = usize::try_from(*amount).unwrap();
             let isotopes = element
                 .isotopes()
                 .iter()
@@ -31,74 +30,237 @@ impl MolecularFormula {
                 // Only a single species, so no distribution is needed
                 continue;
             }
-            // Get the probability and base offset (weight) for all non base isotopes
-            let base = isotopes[0];
-            let isotopes = isotopes
-                .into_iter()
-                .skip(1)
-                .map(|i| (i.0 - base.0, i.2))
-                .collect_vec();
+            // The distribution for a single atom, relative to the lightest isotope
+            let base = isotopes[0].0;
+            let mut atom = Array1::zeros(usize::from(isotopes[isotopes.len() - 1].0 - base) + 1);
+            for isotope in &isotopes {
+                atom[usize::from(isotope.0 - base)] = isotope.2;
+            }
+
+            // Combine the distribution of all atoms of this element by repeated squaring
+            let mut amount = usize::try_from(*amount).unwrap();
+            let mut distribution = arr1(&[1.0]);
+            while amount > 0 {
+                if amount & 1 == 1 {
+                    distribution = convolve(&distribution, &atom, threshold);
+                }
+                amount >>= 1;
+                if amount > 0 {
+                    atom = convolve(&atom, &atom, threshold);
+                }
+            }
 
-            for isotope in isotopes {
-                // Generate distribution (take already chosen into account?)
-                let binomial = Binomial::new(amount, isotope.1);
+            result = convolve(&result, &distribution, threshold);
+        }
+        result
+    }
 
-                // See how many numbers are below the threshold from the end of the distribution
-                let tail = (0..=amount)
-                    .rev()
-                    .map(|t| binomial.mass(t))
-                    .take_while(|a| *a < threshold)
-                    .count();
+    /// Get the isotopic envelope as pairs of mass and relative intensity, normalised so that the
+    /// most abundant peak is 1.0. Only peaks with a relative intensity of at least
+    /// `min_abundance` are returned. Similar to [`Self::most_abundant_mass`] every peak is placed
+    /// at a whole Dalton offset from the monoisotopic mass. To keep large formulas tractable any
+    /// tail that is well below the threshold is already pruned during the convolution of the
+    /// distributions of the separate elements.
+    pub fn isotopic_envelope(&self, min_abundance: f64) -> Vec<(Mass, f64)> {
+        // The most abundant peak of large molecules can be well below 1.0, so prune with a
+        // margin to not lose any peaks that end up above the threshold after normalisation
+        let distribution = self.isotopic_distribution(min_abundance * 1e-3);
+        let max = distribution.iter().copied().fold(0.0, f64::max);
+        if max <= 0.0 {
+            return Vec::new();
+        }
+        let monoisotopic = self.monoisotopic_mass();
+        let start = self.isotopic_distribution_start();
+        distribution
+            .iter()
+            .enumerate()
+            .map(|(offset, probability)| (offset, probability / max))
+            .filter(|(_, intensity)| *intensity >= min_abundance)
+            .map(|(offset, intensity)| {
+                (
+                    monoisotopic + da(offset as f64 + f64::from(start)),
+                    intensity,
+                )
+            })
+            .collect()
+    }
 
-                // Get all numbers start to the tail threshold
-                let mut distribution: Array1<f64> = (0..=amount - tail)
-                    .map(|t| binomial.mass(t))
-                    .flat_map(|a| {
-                        // Interweave the probability of this isotope with the mass difference to generate the correct distribution
-                        std::iter::once(a)
-                            .chain(std::iter::repeat(0.0))
-                            .take(isotope.0 as usize)
+    /// The nominal offset in Dalton of the first element in the [`Self::isotopic_distribution`]
+    /// compared to the monoisotopic mass. This is zero unless the formula contains elements where
+    /// the lightest isotope is not the one used for the monoisotopic mass (like selenium).
+    pub fn isotopic_distribution_start(&self) -> i32 {
+        self.elements()
+            .iter()
+            .filter(|(_, isotope, amount)| isotope.is_none() && *amount > 0)
+            .map(|(element, _, amount)| {
+                let isotopes = element
+                    .isotopes()
+                    .iter()
+                    .filter(|i| i.2 != 0.0)
+                    .collect_vec();
+                let monoisotopic = element.mass(None);
+                isotopes
+                    .iter()
+                    .find(|i| Some(i.1) == monoisotopic)
+                    .zip(isotopes.first())
+                    .map_or(0, |(mono, lightest)| {
+                        (i32::from(lightest.0) - i32::from(mono.0)) * amount
                     })
-                    .collect();
-
-                // Make the lengths equal
-                match result.len().cmp(&distribution.len()) {
-                    Ordering::Less => {
-                        result
-                            .append(
-                                Axis(0),
-                                Array1::zeros(distribution.len() - result.len()).view(),
-                            )
-                            .unwrap();
-                    }
-                    Ordering::Greater => {
-                        distribution
-                            .append(
-                                Axis(0),
-                                Array1::zeros(result.len() - distribution.len()).view(),
-                            )
-                            .unwrap();
-                    }
-                    Ordering::Equal => (),
-                }
+            })
+            .sum()
+    }
 
-                // Combine distribution with previous distribution
-                let mut new = Array1::zeros(result.len());
-                for (i, a) in distribution.into_iter().enumerate() {
-                    new += &(concatenate(
-                        Axis(0),
-                        &[
-                            Array1::zeros(i).view(),
-                            result.slice(s![0..result.len() - i]),
-                        ],
-                    )
-                    .unwrap()
-                        * a);
-                }
+    /// Get the averagine formula for the given monoisotopic mass, this is a peptide like formula
+    /// with the average elemental composition of amino acids (C 4.9384, H 7.7583, N 1.3577,
+    /// O 1.4773, and S 0.0417 with a monoisotopic mass of 111.0543 Da, Senko et al. 1995) scaled
+    /// to the given mass and rounded to whole atoms. Any mass left after rounding is filled up
+    /// with hydrogen. This can be used to approximate the isotopic distribution of peptides of an
+    /// unknown composition.
+    #[allow(clippy::missing_panics_doc, clippy::cast_possible_truncation)]
+    pub fn averagine(mass: Mass) -> Self {
+        let units = (mass.value / 111.054_3).max(0.0);
+        let count = |average: f64| (average * units).round() as i32;
+        let mut formula = Self::new(
+            &[
+                (Element::C, None, count(4.938_4)),
+                (Element::H, None, count(7.758_3)),
+                (Element::N, None, count(1.357_7)),
+                (Element::O, None, count(1.477_3)),
+                (Element::S, None, count(0.041_7)),
+            ],
+            &[],
+        )
+        .unwrap();
+        let hydrogen = Element::H.mass(None).unwrap();
+        let remainder = ((mass - formula.monoisotopic_mass()) / hydrogen)
+            .value
+            .round() as i32;
+        if remainder != 0 && formula != Self::default() {
+            let _ = formula.add((Element::H, None, remainder));
+        }
+        formula
+    }
+}
 
-                result = new;
+/// Convolve the two distributions, removing any tail below the threshold
+fn convolve(a: &Array1<f64>, b: &Array1<f64>, threshold: f64) -> Array1<f64> {
+    let mut result = Array1::zeros(a.len() + b.len() - 1);
+    for (i, x) in a.iter().enumerate() {
+        if *x != 0.0 {
+            for (j, y) in b.iter().enumerate() {
+                result[i + j] += x * y;
             }
         }
-        result
+    }
+    let tail = result.iter().rev().take_while(|p| **p < threshold).count();
+    let length = (result.len() - tail).max(1);
+    result.slice_move(s![..length])
+}
+
+#[cfg(test)]
+#[allow(clippy::missing_panics_doc)]
+mod tests {
+    use crate::{
+        fragment::FragmentType, model::PrimaryIonSeries, system::usize::Charge, AminoAcid, Model,
+        MolecularFormula, MultiChemical, Peptidoform,
+    };
+
+    #[test]
+    fn averagine() {
+        let formula = MolecularFormula::averagine(crate::system::da(1000.0));
+        assert!((formula.monoisotopic_mass().value - 1000.0).abs() < 1.0);
+        let distribution = formula.isotopic_distribution(0.001);
+        assert!(distribution[0] > distribution[1]);
+        assert!(distribution[1] > distribution[2]);
+        assert_eq!(
+            MolecularFormula::averagine(crate::system::da(0.0)),
+            MolecularFormula::default()
+        );
+    }
+
+    #[test]
+    fn envelope() {
+        let formula = molecular_formula!(C 50 H 80 N 14 O 15 S 1);
+        let envelope = formula.isotopic_envelope(0.01);
+        assert!(envelope.len() > 3);
+        assert!((envelope[0].0 - formula.monoisotopic_mass()).value.abs() < f64::EPSILON);
+        assert!(((envelope[1].0 - envelope[0].0).value - 1.0).abs() < 1e-9);
+        assert!(envelope.iter().all(|(_, i)| (0.01..=1.0).contains(i)));
+        assert_eq!(
+            envelope
+                .iter()
+                .filter(|(_, i)| (*i - 1.0).abs() < f64::EPSILON)
+                .count(),
+            1
+        );
+        // A small protein, the monoisotopic peak is below the threshold
+        let protein = MolecularFormula::averagine(crate::system::da(50_000.0));
+        let envelope = protein.isotopic_envelope(0.01);
+        assert!(envelope[0].0 > protein.monoisotopic_mass());
+        let most_abundant = envelope
+            .iter()
+            .find(|(_, i)| (*i - 1.0).abs() < f64::EPSILON)
+            .unwrap();
+        let offset = (most_abundant.0 - protein.monoisotopic_mass()).value;
+        assert!((20.0..40.0).contains(&offset), "{offset}");
+        assert!(MolecularFormula::default().isotopic_envelope(0.01).len() == 1);
+    }
+
+    #[test]
+    fn selenium() {
+        let distribution = molecular_formula!(Se 1).isotopic_distribution(0.0);
+        let expected = [0.0086, 0.0, 0.0923, 0.076, 0.2369, 0.0, 0.498, 0.0, 0.0882];
+        assert_eq!(distribution.len(), expected.len());
+        for (a, b) in distribution.iter().zip(expected) {
+            assert!((a - b).abs() < 1e-9);
+        }
+        assert_eq!(molecular_formula!(Se 1).isotopic_distribution_start(), -6);
+        assert_eq!(
+            molecular_formula!(C 6 H 12 O 6).isotopic_distribution_start(),
+            0
+        );
+    }
+
+    #[test]
+    fn selenocysteine_fragments() {
+        assert_eq!(
+            AminoAcid::Selenocysteine.formulas()[0],
+            molecular_formula!(C 3 H 5 N 1 O 1 Se 1)
+        );
+        assert_eq!(
+            AminoAcid::Pyrrolysine.formulas()[0],
+            molecular_formula!(C 12 H 19 N 3 O 2)
+        );
+        let peptide = Peptidoform::pro_forma("PEUTIDOE", None)
+            .unwrap()
+            .into_linear()
+            .unwrap();
+        let fragments = peptide.generate_theoretical_fragments(
+            Charge::new::<crate::system::e>(1),
+            &Model::none().b(PrimaryIonSeries::default()),
+        );
+        let b = |index: usize| {
+            fragments
+                .iter()
+                .find(|f| matches!(&f.ion, FragmentType::b(p) if p.sequence_index == crate::SequencePosition::Index(index)))
+                .and_then(|f| f.formula.clone())
+                .unwrap()
+        };
+        // The selenium containing fragments are most abundant with 80Se, which is the
+        // monoisotopic mass, but the distribution also contains the lighter selenium isotopes
+        assert!(!b(1).elements().iter().any(|e| e.0 == crate::Element::Se));
+        let b3 = b(2);
+        assert_eq!(&b3 - &b(1), AminoAcid::Selenocysteine.formulas()[0]);
+        assert!(
+            (b3.most_abundant_mass() - b3.monoisotopic_mass())
+                .value
+                .abs()
+                < f64::EPSILON
+        );
+        assert_eq!(b3.isotopic_distribution_start(), -6);
+        let distribution = b3.isotopic_distribution(0.0);
+        // The 78Se peak is about half of the 80Se peak
+        let ratio = distribution[4] / distribution[6];
+        assert!((ratio - 0.2369 / 0.498).abs() < 0.05, "{ratio}");
     }
 }
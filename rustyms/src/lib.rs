@@ -29,6 +29,7 @@ mod fragmentation_tests;
 mod helper_functions;
 #[macro_use]
 mod formula;
+mod formula_search;
 
 #[doc(hidden)]
 #[path = "shared/csv.rs"]
@@ -53,6 +54,7 @@ mod molecular_charge;
 mod multi;
 mod mzpaf;
 mod neutral_loss;
+mod nucleic_acid;
 pub mod ontologies;
 pub mod peptidoform;
 pub mod placement_rule;
@@ -70,13 +72,18 @@ mod tolerance;
 
 pub use crate::element::*;
 pub use crate::formula::*;
-pub use crate::isobaric_sets::{building_blocks, find_isobaric_sets};
+pub use crate::formula_search::{find_formulas, find_formulas_iter};
+pub use crate::isobaric_sets::{building_blocks, find_isobaric_sets, find_isobaric_sets_in_range};
 pub use crate::mass_mode::MassMode;
 pub use crate::model::Model;
 pub use crate::modification::{CrossLinkName, Modification};
 pub use crate::molecular_charge::MolecularCharge;
 pub use crate::multi::*;
 pub use crate::neutral_loss::*;
+pub use crate::nucleic_acid::{
+    nucleic_acid_formula, nucleic_acid_formula_with_terminals, NucleicAcid, NucleicAcidKind,
+    NucleicAcidTerminal,
+};
 pub use crate::peptidoform::*;
 pub use crate::protease::*;
 pub use crate::sequence_element::SequenceElement;
@@ -85,7 +92,7 @@ pub use crate::spectrum::{AnnotatableSpectrum, AnnotatedSpectrum, RawSpectrum};
 pub use crate::tolerance::*;
 pub use aminoacids::AminoAcid;
 pub use checked_aminoacid::CheckedAminoAcid;
-pub use fragment::Fragment;
+pub use fragment::{group_fragments_by_position, Fragment};
 pub use peptidoform::{CompoundPeptidoformIon, Peptidoform, PeptidoformIon};
 
 #[macro_use]
@@ -1,13 +1,21 @@
 //! Handle model instantiation.
 
-use std::ops::RangeInclusive;
+use std::{ops::RangeInclusive, path::Path};
 
+use ordered_float::OrderedFloat;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    fragment::PeptidePosition,
-    system::{e, f64::MassOverCharge, isize::Charge, mz},
-    NeutralLoss, Tolerance,
+    error::{Context, CustomError},
+    fragment::{FragmentKind, FragmentType, PeptidePosition},
+    modification::SimpleModification,
+    system::{
+        e,
+        f64::{Mass, MassOverCharge},
+        isize::Charge,
+        mz,
+    },
+    AminoAcid, Fragment, MassMode, NeutralLoss, Peptidoform, SequencePosition, Tolerance,
 };
 
 /// Control what charges are allowed for an ion series. Defined as an inclusive range.
@@ -24,6 +32,11 @@ pub struct ChargeRange {
 }
 
 impl ChargeRange {
+    /// Create a new charge range, both points are inclusive.
+    pub const fn new(start: ChargePoint, end: ChargePoint) -> Self {
+        Self { start, end }
+    }
+
     /// Get all possible charges for the given precursor charge.
     pub fn charges(&self, precursor: Charge) -> RangeInclusive<Charge> {
         Charge::new::<e>(self.start.to_absolute(precursor).value.max(1))
@@ -103,18 +116,34 @@ pub struct Model {
     pub immonium: (bool, ChargeRange),
     /// m ions, loss of the amino acid side chain from the precursor (follows precursor charge)
     pub m: bool,
-    /// If the neutral losses specific for modifications should be generated
+    /// If the neutral losses specific for modifications should be generated, any fragment that
+    /// contains a modification with a neutral loss is also generated with that loss applied and
+    /// listed in [`Fragment::neutral_loss`](crate::Fragment::neutral_loss)
     pub modification_specific_neutral_losses: bool,
+    /// Custom neutral losses for modifications, if a modification is present in this list these
+    /// losses are used instead of the neutral losses defined for the modification itself
+    #[serde(default)]
+    pub modification_neutral_losses: Vec<(SimpleModification, Vec<NeutralLoss>)>,
     /// If the diagnostic ions specific for modifications should be generated with the allowed charge range
     pub modification_specific_diagnostic_ions: (bool, ChargeRange),
     /// Glycan fragmentation
     pub glycan: GlycanModel,
     /// Allow any MS cleavable cross-link to be cleaved
     pub allow_cross_link_cleavage: bool,
+    /// If the peptide loses water at the C-terminus, this removes H2O from all C-terminal
+    /// fragments (v/w/x/y/z) and the precursor, so the b/y complementarity is retained
+    pub c_terminal_water_loss: bool,
     /// The matching tolerance
     pub tolerance: Tolerance<MassOverCharge>,
     /// The range in which fragments fall, can be used to limit the theoretical fragments to a known window
     pub mz_range: RangeInclusive<MassOverCharge>,
+    /// The minimal mass of fragments, any lighter fragment is not generated as a theoretical fragment
+    #[serde(default)]
+    pub min_fragment_mass: Option<Mass>,
+    /// The residue context rules for the predicted intensity of backbone fragments, see
+    /// [`Self::predicted_intensity`]
+    #[serde(default)]
+    pub cleavage_rules: Vec<CleavageRule>,
 }
 
 /// The settings for any primary ion series
@@ -126,6 +155,11 @@ pub struct PrimaryIonSeries {
     pub neutral_losses: Vec<NeutralLoss>,
     /// The allowed charges
     pub charge_range: ChargeRange,
+    /// If set this series is only a minor (secondary) channel for this fragmentation method,
+    /// for example c/z ions in CID. Fragments from this series get this relative weight set as
+    /// [`Fragment::secondary_weight`](crate::Fragment::secondary_weight).
+    #[serde(default)]
+    pub secondary: Option<OrderedFloat<f64>>,
 }
 
 impl PrimaryIonSeries {
@@ -150,6 +184,14 @@ impl PrimaryIonSeries {
             ..self
         }
     }
+    /// Mark this as a secondary series with the given relative weight, see [`Self::secondary`]
+    #[must_use]
+    pub fn secondary(self, weight: Option<f64>) -> Self {
+        Self {
+            secondary: weight.map(OrderedFloat),
+            ..self
+        }
+    }
 }
 
 impl std::default::Default for PrimaryIonSeries {
@@ -158,6 +200,7 @@ impl std::default::Default for PrimaryIonSeries {
             location: Location::All,
             neutral_losses: Vec::new(),
             charge_range: ChargeRange::ONE_TO_PRECURSOR,
+            secondary: None,
         }
     }
 }
@@ -176,6 +219,18 @@ pub struct GlycanModel {
     pub oxonium_charge_range: ChargeRange,
     /// The allowed charges for other glycan fragments (Y)
     pub other_charge_range: ChargeRange,
+    /// Allows the cross-ring fragments 0,2X and 2,4A from glycans with defined structures, see
+    /// [`FragmentType::X`](crate::fragment::FragmentType::X) and
+    /// [`FragmentType::A`](crate::fragment::FragmentType::A). These are generated for all
+    /// pyranose hexoses (eg Hex, `HexNAc`, dHex).
+    #[serde(default)]
+    pub cross_ring_fragments: bool,
+    /// Allows the common glycan oxonium ions (eg `HexNAc` 204.087, `HexHexNAc` 366.140, and the
+    /// `HexNAc` diagnostic ions 138.055 etc) for any glycan, even if the structural or
+    /// compositional glycan fragments are not allowed. For structural glycans these are generated
+    /// from the composition of the structure.
+    #[serde(default)]
+    pub oxonium: bool,
 }
 
 impl GlycanModel {
@@ -219,6 +274,19 @@ impl GlycanModel {
             ..self
         }
     }
+    /// Set the status of glycan cross-ring fragments, see [`Self::cross_ring_fragments`]
+    #[must_use]
+    pub fn cross_ring_fragments(self, cross_ring_fragments: bool) -> Self {
+        Self {
+            cross_ring_fragments,
+            ..self
+        }
+    }
+    /// Set the status of the common glycan oxonium ions, see [`Self::oxonium`]
+    #[must_use]
+    pub fn oxonium(self, oxonium: bool) -> Self {
+        Self { oxonium, ..self }
+    }
     /// Default set for models that allow glycan fragmentation
     pub const ALLOW: Self = Self {
         allow_structural: true,
@@ -226,6 +294,8 @@ impl GlycanModel {
         neutral_losses: Vec::new(),
         oxonium_charge_range: ChargeRange::ONE,
         other_charge_range: ChargeRange::ONE_TO_PRECURSOR,
+        cross_ring_fragments: false,
+        oxonium: true,
     };
     /// Default set for models that disallow glycan fragmentation
     pub const DISALLOW: Self = Self {
@@ -234,9 +304,54 @@ impl GlycanModel {
         neutral_losses: Vec::new(),
         oxonium_charge_range: ChargeRange::ONE,
         other_charge_range: ChargeRange::ONE_TO_PRECURSOR,
+        cross_ring_fragments: false,
+        oxonium: false,
     };
 }
 
+/// A rule that changes the predicted intensity of backbone fragments (a/b/c/x/y/z) based on
+/// the residues around the cleavage site, see [`Model::predicted_intensity`].
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Serialize, Deserialize)]
+pub struct CleavageRule {
+    /// The residues allowed N terminal of the cleavage site, if empty any residue is allowed
+    pub n_terminal: Vec<AminoAcid>,
+    /// The residues allowed C terminal of the cleavage site, if empty any residue is allowed
+    pub c_terminal: Vec<AminoAcid>,
+    /// The factor with which the predicted intensity is multiplied if this rule matches
+    pub factor: OrderedFloat<f64>,
+}
+
+impl CleavageRule {
+    /// Create a new rule, see [`Self::n_terminal`] and [`Self::c_terminal`]
+    pub const fn new(n_terminal: Vec<AminoAcid>, c_terminal: Vec<AminoAcid>, factor: f64) -> Self {
+        Self {
+            n_terminal,
+            c_terminal,
+            factor: OrderedFloat(factor),
+        }
+    }
+
+    /// The proline effect: enhanced cleavage N terminal to proline and suppressed cleavage C
+    /// terminal to proline.
+    pub fn proline_effect() -> Vec<Self> {
+        vec![
+            Self::new(Vec::new(), vec![AminoAcid::Proline], 4.0),
+            Self::new(vec![AminoAcid::Proline], Vec::new(), 0.25),
+        ]
+    }
+
+    /// The glycine effect: suppressed cleavage C terminal to glycine.
+    pub fn glycine_effect() -> Vec<Self> {
+        vec![Self::new(vec![AminoAcid::Glycine], Vec::new(), 0.5)]
+    }
+
+    /// Check if this rule matches a cleavage between the given residues
+    fn matches(&self, n_terminal: AminoAcid, c_terminal: AminoAcid) -> bool {
+        (self.n_terminal.is_empty() || self.n_terminal.contains(&n_terminal))
+            && (self.c_terminal.is_empty() || self.c_terminal.contains(&c_terminal))
+    }
+}
+
 /// A struct to handle all possible fragments that could be generated on a single location
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
@@ -364,6 +479,17 @@ impl Model {
             ..self
         }
     }
+    /// Set custom neutral losses for modifications, overriding the losses defined for these modifications
+    #[must_use]
+    pub fn modification_neutral_losses(
+        self,
+        losses: Vec<(SimpleModification, Vec<NeutralLoss>)>,
+    ) -> Self {
+        Self {
+            modification_neutral_losses: losses,
+            ..self
+        }
+    }
     /// Set modification specific diagnostic ions
     #[must_use]
     pub fn modification_specific_diagnostic_ions(self, state: (bool, ChargeRange)) -> Self {
@@ -380,6 +506,14 @@ impl Model {
             ..self
         }
     }
+    /// Set if the peptide loses water at the C-terminus
+    #[must_use]
+    pub fn c_terminal_water_loss(self, state: bool) -> Self {
+        Self {
+            c_terminal_water_loss: state,
+            ..self
+        }
+    }
     /// Set the tolerance
     #[must_use]
     pub fn tolerance(self, tolerance: impl Into<Tolerance<MassOverCharge>>) -> Self {
@@ -388,14 +522,247 @@ impl Model {
             ..self
         }
     }
-    /// Set the mz range
+    /// Set the mz range, for example the detector window. Any fragment (at any charge) outside
+    /// of this range is not generated as a theoretical fragment and is not used in annotation.
+    #[must_use]
+    pub fn mz_range(self, min: MassOverCharge, max: MassOverCharge) -> Self {
+        Self {
+            mz_range: min..=max,
+            ..self
+        }
+    }
+    /// Set the minimal mass of fragments, for example to ignore b1 ions and other small fragments.
+    /// Any fragment with a (charged) mass below this threshold is not generated as a theoretical
+    /// fragment and is not used in annotation.
     #[must_use]
-    pub fn mz_range(self, mz_range: RangeInclusive<MassOverCharge>) -> Self {
-        Self { mz_range, ..self }
+    pub fn min_fragment_mass(self, mass: Mass) -> Self {
+        Self {
+            min_fragment_mass: Some(mass),
+            ..self
+        }
+    }
+    /// Set the residue context rules for the predicted intensity, see [`Self::cleavage_rules`]
+    #[must_use]
+    pub fn cleavage_rules(self, cleavage_rules: Vec<CleavageRule>) -> Self {
+        Self {
+            cleavage_rules,
+            ..self
+        }
     }
 }
 
+/// A builder for a custom [`Model`], see [`Model::builder`]. It starts from [`Model::none`]
+/// so only the series that are explicitly enabled are generated.
+/// ```rust
+/// # use rustyms::model::{ChargeRange, Model};
+/// let model = Model::builder()
+///     .with_b(ChargeRange::ONE)
+///     .with_y(ChargeRange::ONE_TO_PRECURSOR)
+///     .immonium(true)
+///     .build();
+/// ```
+#[derive(Clone, PartialEq, Debug)]
+pub struct ModelBuilder(Model);
+
 impl Model {
+    /// Start building a custom model, see [`ModelBuilder`]
+    pub fn builder() -> ModelBuilder {
+        ModelBuilder(Self::none())
+    }
+}
+
+impl ModelBuilder {
+    /// Enable the a series with the given charge range
+    #[must_use]
+    pub fn with_a(self, charge_range: ChargeRange) -> Self {
+        Self(
+            self.0
+                .a(PrimaryIonSeries::default().charge_range(charge_range)),
+        )
+    }
+    /// Enable the b series with the given charge range
+    #[must_use]
+    pub fn with_b(self, charge_range: ChargeRange) -> Self {
+        Self(
+            self.0
+                .b(PrimaryIonSeries::default().charge_range(charge_range)),
+        )
+    }
+    /// Enable the c series with the given charge range
+    #[must_use]
+    pub fn with_c(self, charge_range: ChargeRange) -> Self {
+        Self(
+            self.0
+                .c(PrimaryIonSeries::default().charge_range(charge_range)),
+        )
+    }
+    /// Enable the d (side chain fragmentation from a) series with the given charge range
+    #[must_use]
+    pub fn with_d(self, charge_range: ChargeRange) -> Self {
+        Self(
+            self.0
+                .d(PrimaryIonSeries::default().charge_range(charge_range)),
+        )
+    }
+    /// Enable the v (full side chain broken off) series with the given charge range
+    #[must_use]
+    pub fn with_v(self, charge_range: ChargeRange) -> Self {
+        Self(
+            self.0
+                .v(PrimaryIonSeries::default().charge_range(charge_range)),
+        )
+    }
+    /// Enable the w (side chain fragmentation from z) series with the given charge range
+    #[must_use]
+    pub fn with_w(self, charge_range: ChargeRange) -> Self {
+        Self(
+            self.0
+                .w(PrimaryIonSeries::default().charge_range(charge_range)),
+        )
+    }
+    /// Enable the x series with the given charge range
+    #[must_use]
+    pub fn with_x(self, charge_range: ChargeRange) -> Self {
+        Self(
+            self.0
+                .x(PrimaryIonSeries::default().charge_range(charge_range)),
+        )
+    }
+    /// Enable the y series with the given charge range
+    #[must_use]
+    pub fn with_y(self, charge_range: ChargeRange) -> Self {
+        Self(
+            self.0
+                .y(PrimaryIonSeries::default().charge_range(charge_range)),
+        )
+    }
+    /// Enable the z series with the given charge range
+    #[must_use]
+    pub fn with_z(self, charge_range: ChargeRange) -> Self {
+        Self(
+            self.0
+                .z(PrimaryIonSeries::default().charge_range(charge_range)),
+        )
+    }
+    /// Enable the series with the given name (a, b, c, d, v, w, x, y, or z) with the given charge range
+    /// # Errors
+    /// If the ion series is not recognised
+    pub fn with_series(
+        mut self,
+        name: &str,
+        charge_range: ChargeRange,
+    ) -> Result<Self, CustomError> {
+        *self.0.series_mut(name)? = PrimaryIonSeries::default().charge_range(charge_range);
+        Ok(self)
+    }
+    /// Set if immonium ions are generated (always singly charged)
+    #[must_use]
+    pub fn immonium(self, state: bool) -> Self {
+        Self(self.0.immonium((state, ChargeRange::ONE)))
+    }
+    /// Set the precursor neutral losses
+    #[must_use]
+    pub fn precursor_losses(self, neutral_losses: Vec<NeutralLoss>) -> Self {
+        let charges = self.0.precursor.1;
+        Self(self.0.precursor(neutral_losses, charges))
+    }
+    /// Build the model
+    pub fn build(self) -> Model {
+        self.0
+    }
+}
+
+impl Model {
+    /// Get the primary ion series with the given name (a, b, c, d, v, w, x, y, or z)
+    /// # Errors
+    /// If the ion series is not recognised
+    fn series_mut(&mut self, name: &str) -> Result<&mut PrimaryIonSeries, CustomError> {
+        match name {
+            "a" => Ok(&mut self.a),
+            "b" => Ok(&mut self.b),
+            "c" => Ok(&mut self.c),
+            "d" => Ok(&mut self.d),
+            "v" => Ok(&mut self.v),
+            "w" => Ok(&mut self.w),
+            "x" => Ok(&mut self.x),
+            "y" => Ok(&mut self.y),
+            "z" => Ok(&mut self.z),
+            _ => Err(CustomError::error(
+                "Invalid ion series",
+                "The ion series is not recognised, use one of: a, b, c, d, v, w, x, y, or z",
+                Context::show(name),
+            )),
+        }
+    }
+
+    /// Get the relative weight of the given series if it is set up as a secondary series
+    pub(crate) const fn secondary_weight(&self, kind: FragmentKind) -> Option<OrderedFloat<f64>> {
+        match kind {
+            FragmentKind::a => self.a.secondary,
+            FragmentKind::b => self.b.secondary,
+            FragmentKind::c => self.c.secondary,
+            FragmentKind::d => self.d.secondary,
+            FragmentKind::v => self.v.secondary,
+            FragmentKind::w => self.w.secondary,
+            FragmentKind::x => self.x.secondary,
+            FragmentKind::y => self.y.secondary,
+            FragmentKind::z => self.z.secondary,
+            _ => None,
+        }
+    }
+
+    /// The predicted relative intensity of the given fragment from the given peptidoform. This
+    /// is the relative weight of the series if it is set up as a secondary series (or 1.0
+    /// otherwise) multiplied by the factors of all [`Self::cleavage_rules`] that match the
+    /// residues around the cleavage site of a/b/c/x/y/z fragments. For example with
+    /// [`CleavageRule::proline_effect`] the y ion resulting from cleavage N terminal to a proline
+    /// is predicted to be more intense.
+    pub fn predicted_intensity<Complexity>(
+        &self,
+        fragment: &Fragment,
+        peptidoform: &Peptidoform<Complexity>,
+    ) -> f64 {
+        let base = self
+            .secondary_weight(fragment.ion.kind())
+            .map_or(1.0, OrderedFloat::into_inner);
+        // The index of the first residue C terminal of the cleavage site
+        let site = match &fragment.ion {
+            FragmentType::a(position) | FragmentType::b(position) | FragmentType::c(position) => {
+                match position.sequence_index {
+                    SequencePosition::Index(index) => Some(index + 1),
+                    _ => None,
+                }
+            }
+            FragmentType::x(position)
+            | FragmentType::y(position)
+            | FragmentType::z(position)
+            | FragmentType::z·(position) => match position.sequence_index {
+                SequencePosition::Index(index) => Some(index),
+                _ => None,
+            },
+            _ => None,
+        };
+        site.filter(|site| *site > 0 && *site < peptidoform.len())
+            .map_or(base, |site| {
+                let n_terminal = peptidoform.sequence()[site - 1].aminoacid.aminoacid();
+                let c_terminal = peptidoform.sequence()[site].aminoacid.aminoacid();
+                self.cleavage_rules
+                    .iter()
+                    .filter(|rule| rule.matches(n_terminal, c_terminal))
+                    .fold(base, |intensity, rule| intensity * rule.factor.into_inner())
+            })
+    }
+
+    /// Check if the given fragment is at least the minimal fragment mass, see [`Self::min_fragment_mass`]
+    pub(crate) fn heavy_enough(&self, fragment: &Fragment, mode: MassMode) -> bool {
+        self.min_fragment_mass.map_or(true, |min| {
+            fragment
+                .formula
+                .as_ref()
+                .map_or(true, |f| f.mass(mode) >= min)
+        })
+    }
+
     /// Give all possible ions for the given N position
     pub fn ions(&self, position: PeptidePosition) -> PossibleIons {
         let c_position = position.flip_terminal();
@@ -478,12 +845,16 @@ impl Model {
             immonium: (true, ChargeRange::ONE),
             m: true,
             modification_specific_neutral_losses: true,
+            modification_neutral_losses: Vec::new(),
             modification_specific_diagnostic_ions: (true, ChargeRange::ONE),
             glycan: GlycanModel::ALLOW
                 .neutral_losses(vec![NeutralLoss::Loss(molecular_formula!(H 2 O 1))]),
             allow_cross_link_cleavage: true,
+            c_terminal_water_loss: false,
             tolerance: Tolerance::new_ppm(20.0),
             mz_range: MassOverCharge::new::<mz>(0.0)..=MassOverCharge::new::<mz>(f64::MAX),
+            min_fragment_mass: None,
+            cleavage_rules: Vec::new(),
         }
     }
 
@@ -503,11 +874,15 @@ impl Model {
             immonium: (false, ChargeRange::ONE),
             m: false,
             modification_specific_neutral_losses: false,
+            modification_neutral_losses: Vec::new(),
             modification_specific_diagnostic_ions: (false, ChargeRange::ONE),
             glycan: GlycanModel::DISALLOW,
             allow_cross_link_cleavage: false,
+            c_terminal_water_loss: false,
             tolerance: Tolerance::new_ppm(20.0),
             mz_range: MassOverCharge::new::<mz>(0.0)..=MassOverCharge::new::<mz>(f64::MAX),
+            min_fragment_mass: None,
+            cleavage_rules: Vec::new(),
         }
     }
 
@@ -535,12 +910,16 @@ impl Model {
             immonium: (false, ChargeRange::ONE),
             m: false,
             modification_specific_neutral_losses: true,
+            modification_neutral_losses: Vec::new(),
             modification_specific_diagnostic_ions: (true, ChargeRange::ONE),
             glycan: GlycanModel::ALLOW
                 .neutral_losses(vec![NeutralLoss::Loss(molecular_formula!(H 2 O 1))]),
             allow_cross_link_cleavage: true,
+            c_terminal_water_loss: false,
             tolerance: Tolerance::new_ppm(20.0),
             mz_range: MassOverCharge::new::<mz>(0.0)..=MassOverCharge::new::<mz>(f64::MAX),
+            min_fragment_mass: None,
+            cleavage_rules: Vec::new(),
         }
     }
 
@@ -572,12 +951,16 @@ impl Model {
             immonium: (true, ChargeRange::ONE),
             m: false,
             modification_specific_neutral_losses: true,
+            modification_neutral_losses: Vec::new(),
             modification_specific_diagnostic_ions: (true, ChargeRange::ONE),
             glycan: GlycanModel::ALLOW
                 .neutral_losses(vec![NeutralLoss::Loss(molecular_formula!(H 2 O 1))]),
             allow_cross_link_cleavage: true,
+            c_terminal_water_loss: false,
             tolerance: Tolerance::new_ppm(20.0),
             mz_range: MassOverCharge::new::<mz>(0.0)..=MassOverCharge::new::<mz>(f64::MAX),
+            min_fragment_mass: None,
+            cleavage_rules: Vec::new(),
         }
     }
 
@@ -608,12 +991,16 @@ impl Model {
             immonium: (false, ChargeRange::ONE),
             m: false,
             modification_specific_neutral_losses: true,
+            modification_neutral_losses: Vec::new(),
             modification_specific_diagnostic_ions: (true, ChargeRange::ONE),
             glycan: GlycanModel::ALLOW
                 .neutral_losses(vec![NeutralLoss::Loss(molecular_formula!(H 2 O 1))]),
             allow_cross_link_cleavage: true,
+            c_terminal_water_loss: false,
             tolerance: Tolerance::new_ppm(20.0),
             mz_range: MassOverCharge::new::<mz>(0.0)..=MassOverCharge::new::<mz>(f64::MAX),
+            min_fragment_mass: None,
+            cleavage_rules: Vec::new(),
         }
     }
 
@@ -642,11 +1029,15 @@ impl Model {
             immonium: (false, ChargeRange::ONE),
             m: false,
             modification_specific_neutral_losses: true,
+            modification_neutral_losses: Vec::new(),
             modification_specific_diagnostic_ions: (true, ChargeRange::ONE),
             glycan: GlycanModel::DISALLOW,
             allow_cross_link_cleavage: true,
+            c_terminal_water_loss: false,
             tolerance: Tolerance::new_ppm(20.0),
             mz_range: MassOverCharge::new::<mz>(0.0)..=MassOverCharge::new::<mz>(f64::MAX),
+            min_fragment_mass: None,
+            cleavage_rules: Vec::new(),
         }
     }
 
@@ -681,11 +1072,15 @@ impl Model {
             immonium: (false, ChargeRange::ONE),
             m: false,
             modification_specific_neutral_losses: true,
+            modification_neutral_losses: Vec::new(),
             modification_specific_diagnostic_ions: (true, ChargeRange::ONE),
             glycan: GlycanModel::DISALLOW,
             allow_cross_link_cleavage: true,
+            c_terminal_water_loss: false,
             tolerance: Tolerance::new_ppm(20.0),
             mz_range: MassOverCharge::new::<mz>(0.0)..=MassOverCharge::new::<mz>(f64::MAX),
+            min_fragment_mass: None,
+            cleavage_rules: Vec::new(),
         }
     }
 
@@ -729,12 +1124,210 @@ impl Model {
             immonium: (false, ChargeRange::ONE),
             m: false,
             modification_specific_neutral_losses: true,
+            modification_neutral_losses: Vec::new(),
             modification_specific_diagnostic_ions: (true, ChargeRange::ONE),
             glycan: GlycanModel::DISALLOW,
             allow_cross_link_cleavage: true,
+            c_terminal_water_loss: false,
             tolerance: Tolerance::new_ppm(20.0),
             mz_range: MassOverCharge::new::<mz>(0.0)..=MassOverCharge::new::<mz>(f64::MAX),
+            min_fragment_mass: None,
+            cleavage_rules: Vec::new(),
+        }
+    }
+}
+
+impl Model {
+    /// Build a model from an instrument method config file in JSON. All keys are optional, the
+    /// model starts from `base` and any key that is present overrides that part of the model.
+    /// ```json
+    /// {
+    ///     "base": "none",
+    ///     "series": ["b", "y"],
+    ///     "charges": [1, 2],
+    ///     "neutral_losses": ["-H2O1"],
+    ///     "precursor_neutral_losses": ["-H2O1"],
+    ///     "immonium": true,
+    ///     "glycan": {
+    ///         "allow_structural": true,
+    ///         "compositional_range": [1, 10],
+    ///         "neutral_losses": [],
+    ///         "cross_ring_fragments": false,
+    ///         "oxonium": true
+    ///     },
+    ///     "mz_range": [100.0, 2000.0],
+    ///     "tolerance": {"ppm": 20.0}
+    /// }
+    /// ```
+    /// * `base`: the starting model, one of `all`, `none` (default), `ethcd`, `ead`, `hot_eacid`, `cid_hcd`, `etd`, or `td_etd`.
+    /// * `series`: the enabled primary ion series (a, b, c, d, v, w, x, y, z), all other series are disabled.
+    /// * `charges`: the inclusive absolute charge range for the enabled series, defaults to 1 up to the precursor charge, only valid together with `series`.
+    /// * `neutral_losses`: the neutral losses for the enabled series, only valid together with `series`.
+    /// * `precursor_neutral_losses`: the neutral losses for the precursor.
+    /// * `immonium`: if immonium ions are generated (always singly charged).
+    /// * `glycan`: the glycan settings, see [`GlycanModel`], any missing key is taken from the base model.
+    /// * `mz_range`: the inclusive m/z range in which fragments are generated.
+    /// * `tolerance`: the matching tolerance, either `{"ppm": number}` or `{"mz": number}`.
+    ///
+    /// # Errors
+    /// It returns an error when:
+    /// * The file could not be opened
+    /// * The file is not valid JSON or does not follow the schema
+    /// * Any ion series, neutral loss, or base model is not recognised
+    /// * The charges or neutral losses are given without the series
+    pub fn from_config_file(path: impl AsRef<Path>) -> Result<Self, CustomError> {
+        let path = path.as_ref();
+        let text = std::fs::read_to_string(path).map_err(|err| {
+            CustomError::error(
+                "Could not open file",
+                format!("Additional info: {err}"),
+                Context::show(path.display()),
+            )
+        })?;
+        let config: ModelConfig = serde_json::from_str(&text).map_err(|err| {
+            CustomError::error(
+                "Invalid model config",
+                err.to_string(),
+                Context::show(path.display()),
+            )
+        })?;
+        config.build()
+    }
+}
+
+/// The instrument method config as read from JSON, see [`Model::from_config_file`]
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ModelConfig {
+    base: Option<String>,
+    series: Option<Vec<String>>,
+    charges: Option<(isize, isize)>,
+    neutral_losses: Option<Vec<String>>,
+    precursor_neutral_losses: Option<Vec<String>>,
+    immonium: Option<bool>,
+    glycan: Option<GlycanConfig>,
+    mz_range: Option<(f64, f64)>,
+    tolerance: Option<ToleranceConfig>,
+}
+
+/// The glycan settings in an instrument method config
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct GlycanConfig {
+    allow_structural: Option<bool>,
+    compositional_range: Option<(usize, usize)>,
+    neutral_losses: Option<Vec<String>>,
+    cross_ring_fragments: Option<bool>,
+    oxonium: Option<bool>,
+}
+
+/// The tolerance in an instrument method config
+#[derive(Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum ToleranceConfig {
+    Ppm(f64),
+    Mz(f64),
+}
+
+impl ModelConfig {
+    /// Build the model from this config
+    /// # Errors
+    /// If any ion series, neutral loss, or base model is not recognised, or if the charges or
+    /// neutral losses are given without the series
+    fn build(self) -> Result<Model, CustomError> {
+        let parse_losses = |losses: &[String]| {
+            losses
+                .iter()
+                .map(|l| l.parse::<NeutralLoss>())
+                .collect::<Result<Vec<_>, _>>()
+        };
+        let mut model = match self.base.as_deref() {
+            None | Some("none") => Model::none(),
+            Some("all") => Model::all(),
+            Some("ethcd") => Model::ethcd(),
+            Some("ead") => Model::ead(),
+            Some("hot_eacid") => Model::hot_eacid(),
+            Some("cid_hcd") => Model::cid_hcd(),
+            Some("etd") => Model::etd(),
+            Some("td_etd") => Model::td_etd(),
+            Some(other) => {
+                return Err(CustomError::error(
+                    "Invalid model config",
+                    "The base model is not recognised, use one of: all, none, ethcd, ead, hot_eacid, cid_hcd, etd, or td_etd",
+                    Context::show(other),
+                ))
+            }
+        };
+        if let Some(series) = self.series {
+            let enabled = PrimaryIonSeries::default()
+                .neutral_losses(parse_losses(
+                    self.neutral_losses.as_deref().unwrap_or_default(),
+                )?)
+                .charge_range(self.charges.map_or(
+                    ChargeRange::ONE_TO_PRECURSOR,
+                    |(start, end)| ChargeRange {
+                        start: ChargePoint::Absolute(start),
+                        end: ChargePoint::Absolute(end),
+                    },
+                ));
+            let disabled = PrimaryIonSeries::default().location(Location::None);
+            for ion in [
+                &mut model.a,
+                &mut model.b,
+                &mut model.c,
+                &mut model.d,
+                &mut model.v,
+                &mut model.w,
+                &mut model.x,
+                &mut model.y,
+                &mut model.z,
+            ] {
+                *ion = disabled.clone();
+            }
+            for name in series {
+                *model.series_mut(&name)? = enabled.clone();
+            }
+        } else if self.charges.is_some() || self.neutral_losses.is_some() {
+            return Err(CustomError::error(
+                "Invalid model config",
+                "The charges and neutral losses apply to the enabled series, so these can only be given together with the series",
+                Context::None,
+            ));
+        }
+        if let Some(losses) = self.precursor_neutral_losses {
+            model.precursor.0 = parse_losses(&losses)?;
+        }
+        if let Some(immonium) = self.immonium {
+            model.immonium = (immonium, ChargeRange::ONE);
+        }
+        if let Some(glycan) = self.glycan {
+            if let Some(allow_structural) = glycan.allow_structural {
+                model.glycan.allow_structural = allow_structural;
+            }
+            if let Some((start, end)) = glycan.compositional_range {
+                model.glycan.compositional_range = start..=end;
+            }
+            if let Some(losses) = glycan.neutral_losses {
+                model.glycan.neutral_losses = parse_losses(&losses)?;
+            }
+            if let Some(cross_ring_fragments) = glycan.cross_ring_fragments {
+                model.glycan.cross_ring_fragments = cross_ring_fragments;
+            }
+            if let Some(oxonium) = glycan.oxonium {
+                model.glycan.oxonium = oxonium;
+            }
         }
+        if let Some((min, max)) = self.mz_range {
+            model.mz_range = MassOverCharge::new::<mz>(min)..=MassOverCharge::new::<mz>(max);
+        }
+        match self.tolerance {
+            Some(ToleranceConfig::Ppm(ppm)) => model.tolerance = Tolerance::new_ppm(ppm),
+            Some(ToleranceConfig::Mz(value)) => {
+                model.tolerance = Tolerance::new_absolute(MassOverCharge::new::<mz>(value));
+            }
+            None => (),
+        }
+        Ok(model)
     }
 }
 
@@ -796,3 +1389,93 @@ fn location_all() {
     assert!(!ions_c0.a.0);
     assert!(ions_c0.x.0);
 }
+
+#[test]
+#[allow(clippy::missing_panics_doc)]
+fn from_config_file() {
+    let model = Model::from_config_file("data/model_config.json").unwrap();
+    let position = PeptidePosition::n(crate::SequencePosition::Index(2), 5);
+    let ions = model.ions(position);
+    assert!(!ions.a.0);
+    assert!(ions.b.0);
+    assert!(!ions.c.0);
+    assert!(!ions.x.0);
+    assert!(ions.y.0);
+    assert!(!ions.z.0);
+    assert!(model.immonium.0);
+    assert_eq!(
+        model.b.neutral_losses,
+        vec![NeutralLoss::Loss(molecular_formula!(H 2 O 1))]
+    );
+    assert_eq!(model.y.charge_range.end, ChargePoint::Absolute(2));
+    assert_eq!(model.glycan.compositional_range, 1..=10);
+    assert!((model.mz_range.start().value - 100.0).abs() < f64::EPSILON);
+    assert!(Model::from_config_file("data/does_not_exist.json").is_err());
+    for config in [
+        r#"{"charges": [1, 2]}"#,
+        r#"{"neutral_losses": ["-H2O1"]}"#,
+        r#"{"series": ["q"]}"#,
+    ] {
+        assert!(serde_json::from_str::<ModelConfig>(config)
+            .unwrap()
+            .build()
+            .is_err());
+    }
+}
+
+#[test]
+#[allow(clippy::missing_panics_doc)]
+fn builder() {
+    let model = Model::builder()
+        .with_b(ChargeRange::ONE)
+        .with_y(ChargeRange::new(
+            ChargePoint::Absolute(1),
+            ChargePoint::Absolute(2),
+        ))
+        .immonium(true)
+        .precursor_losses(vec![NeutralLoss::Loss(crate::molecular_formula!(H 2 O 1))])
+        .build();
+    assert_eq!(model.a.location, Location::None);
+    assert_eq!(model.x.location, Location::None);
+    assert_eq!(model.b.location, Location::All);
+    assert_eq!(model.b.charge_range, ChargeRange::ONE);
+    assert_eq!(model.y.location, Location::All);
+    assert_eq!(
+        model.y.charge_range.charges(Charge::new::<e>(5)),
+        Charge::new::<e>(1)..=Charge::new::<e>(2)
+    );
+    assert!(model.immonium.0);
+    assert_eq!(model.precursor.0.len(), 1);
+}
+
+#[test]
+#[allow(clippy::missing_panics_doc)]
+fn proline_effect() {
+    let peptide = Peptidoform::pro_forma("PEPTPIDE", None)
+        .unwrap()
+        .into_linear()
+        .unwrap();
+    let model = Model::builder()
+        .with_y(ChargeRange::ONE)
+        .build()
+        .cleavage_rules(CleavageRule::proline_effect());
+    let fragments =
+        peptide.generate_theoretical_fragments(crate::system::usize::Charge::new::<e>(1), &model);
+    let y_at = |index: usize| {
+        let fragment = fragments
+            .iter()
+            .find(|f| {
+                matches!(&f.ion, FragmentType::y(position)
+                    if position.sequence_index == SequencePosition::Index(index))
+            })
+            .unwrap();
+        model.predicted_intensity(fragment, &peptide)
+    };
+    // y4 (PIDE) is N terminal to proline, y3 (IDE) is C terminal to proline, y2 (DE) is neither
+    assert!(y_at(4) > y_at(6));
+    assert!(y_at(5) < y_at(6));
+    assert!((y_at(6) - 1.0).abs() < f64::EPSILON);
+    assert!(
+        (Model::none().predicted_intensity(&fragments[0], &peptide) - 1.0).abs() < f64::EPSILON
+    );
+}
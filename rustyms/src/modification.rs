@@ -407,13 +407,16 @@ impl Display for SimpleModificationInner {
 
 impl From<SimpleModification> for Modification {
     fn from(value: SimpleModification) -> Self {
-        Self::Simple(value)
+        Self::Simple {
+            modification: value,
+            info: Vec::new(),
+        }
     }
 }
 
 impl From<SimpleModificationInner> for Modification {
     fn from(value: SimpleModificationInner) -> Self {
-        Self::Simple(Arc::new(value))
+        Self::from(Arc::new(value))
     }
 }
 
@@ -480,7 +483,7 @@ impl CrossLinkSide {
 impl Modification {
     /// Check if this modification is a simple modification.
     pub const fn is_simple(&self) -> bool {
-        matches!(self, Self::Simple(_))
+        matches!(self, Self::Simple { .. })
     }
     /// Check if this modification is a cross-link.
     pub const fn is_cross_link(&self) -> bool {
@@ -502,7 +505,7 @@ impl Modification {
         peptidoform_index: usize,
     ) -> (Multi<MolecularFormula>, HashSet<CrossLinkName>) {
         match self {
-            Self::Simple(modification) | Self::Ambiguous { modification, .. } => {
+            Self::Simple { modification, .. } | Self::Ambiguous { modification, .. } => {
                 match &**modification {
                     // A linker that is not cross-linked is hydrolysed
                     SimpleModificationInner::Linker { formula, .. } => (
@@ -523,7 +526,8 @@ impl Modification {
                 ..
             } => {
                 if applied_cross_links.contains(name) {
-                    (Multi::default(), HashSet::default())
+                    // Already counted, but still seen so that loops can be detected
+                    (Multi::default(), HashSet::from([name.clone()]))
                 } else if visited_peptides.contains(other_peptide) {
                     applied_cross_links.push(name.clone());
                     (
@@ -588,9 +592,10 @@ impl Modification {
     /// Get the formula for a modification, if it is a cross linked modification only get the cross link
     pub fn formula(&self) -> MolecularFormula {
         match self {
-            Self::Simple(s) => s.formula(),
+            Self::Simple { modification, .. } | Self::Ambiguous { modification, .. } => {
+                modification.formula()
+            }
             Self::CrossLink { linker, .. } => linker.formula(),
-            Self::Ambiguous { modification, .. } => modification.formula(),
         }
     }
 }
@@ -599,7 +604,7 @@ impl Modification {
     /// Check if this is a simple modification
     pub const fn simple(&self) -> Option<&SimpleModification> {
         match self {
-            Self::Simple(sim) => Some(sim),
+            Self::Simple { modification, .. } => Some(modification),
             Self::CrossLink { .. } | Self::Ambiguous { .. } => None,
         }
     }
@@ -607,16 +612,37 @@ impl Modification {
     /// Check if this is a simple modification
     pub fn into_simple(self) -> Option<SimpleModification> {
         match self {
-            Self::Simple(sim) => Some(sim),
+            Self::Simple { modification, .. } => Some(modification),
             Self::CrossLink { .. } | Self::Ambiguous { .. } => None,
         }
     }
 
+    /// Get the free text information tags (ProForma `INFO:...`) for this modification, these are
+    /// given alongside the modification itself (`[Oxidation|INFO:...]`). Info tags given on their
+    /// own (`[INFO:...]`) are stored on the position instead, see [`crate::SequenceElement::info`].
+    /// Info tags on global, labile, and unknown position modifications are not stored.
+    pub fn info_tags(&self) -> &[String] {
+        match self {
+            Self::Simple { info, .. }
+            | Self::Ambiguous { info, .. }
+            | Self::CrossLink { info, .. } => info,
+        }
+    }
+
+    /// Get the free text information tags (ProForma `INFO:...`) for this modification mutably
+    pub fn info_tags_mut(&mut self) -> &mut Vec<String> {
+        match self {
+            Self::Simple { info, .. }
+            | Self::Ambiguous { info, .. }
+            | Self::CrossLink { info, .. } => info,
+        }
+    }
+
     /// Get a url for more information on this modification. Only defined for modifications from ontologies.
     #[allow(clippy::missing_panics_doc)]
     pub fn ontology_url(&self) -> Option<String> {
         match self {
-            Self::Simple(modification)
+            Self::Simple { modification, .. }
             | Self::Ambiguous { modification, .. }
             | Self::CrossLink {
                 linker: modification,
@@ -648,15 +674,16 @@ impl Modification {
         attachment: Option<(AminoAcid, usize)>,
     ) -> Vec<Fragment> {
         match self {
-            Self::Simple(modification) | Self::Ambiguous { modification, .. } => modification
-                .generate_theoretical_fragments(
+            Self::Simple { modification, .. } | Self::Ambiguous { modification, .. } => {
+                modification.generate_theoretical_fragments(
                     model,
                     peptidoform_ion_index,
                     peptidoform_index,
                     charge_carriers,
                     full_formula,
                     attachment,
-                ),
+                )
+            }
             Self::CrossLink { .. } => Vec::new(),
         }
     }
@@ -674,6 +701,31 @@ impl SimpleModificationInner {
         attachment: Option<(AminoAcid, usize)>,
     ) -> Vec<Fragment> {
         match self {
+            Self::GlycanStructure(glycan)
+            | Self::Gno {
+                composition: GnoComposition::Topology(glycan),
+                ..
+            } if !model.glycan.allow_structural && model.glycan.oxonium => {
+                let composition = glycan.composition();
+                let mut fragments = MonoSaccharide::common_oxonium_ions(
+                    &composition,
+                    &(0..=0),
+                    model,
+                    peptidoform_ion_index,
+                    peptidoform_index,
+                    charge_carriers,
+                    attachment,
+                );
+                fragments.extend(MonoSaccharide::compositional_diagnostic_ions(
+                    &composition,
+                    model,
+                    peptidoform_ion_index,
+                    peptidoform_index,
+                    charge_carriers,
+                    attachment,
+                ));
+                fragments
+            }
             Self::GlycanStructure(glycan)
             | Self::Gno {
                 composition: GnoComposition::Topology(glycan),
@@ -780,16 +832,26 @@ impl Modification {
         display_ambiguous: bool,
     ) -> std::fmt::Result {
         match self {
-            Self::Simple(sim) => sim.display(f, specification_compliant),
-            Self::CrossLink { name, linker, .. } => {
+            Self::Simple { modification, info } => {
+                modification.display(f, specification_compliant)?;
+                if !info.is_empty() {
+                    write!(f, "|")?;
+                }
+            }
+            Self::CrossLink {
+                name, linker, info, ..
+            } => {
                 linker.display(f, specification_compliant)?;
                 write!(f, "{name}")?;
-                Ok(())
+                if !info.is_empty() {
+                    write!(f, "|")?;
+                }
             }
             Self::Ambiguous {
                 group,
                 modification,
                 localisation_score,
+                info,
                 ..
             } => {
                 if display_ambiguous {
@@ -802,9 +864,19 @@ impl Modification {
                         .map(|v| format!("({v})"))
                         .unwrap_or_default()
                 )?;
-                Ok(())
+                if !info.is_empty() {
+                    write!(f, "|")?;
+                }
             }
         }
+        write!(
+            f,
+            "{}",
+            self.info_tags()
+                .iter()
+                .map(|tag| format!("INFO:{tag}"))
+                .join("|")
+        )
     }
 }
 
@@ -1,4 +1,6 @@
-use std::{cmp::Ordering, collections::HashMap, hash::Hash};
+use std::{collections::HashMap, hash::Hash};
+
+use itertools::Itertools;
 
 use crate::{
     model::ChargeRange, system::isize::Charge, Chemical, Element, MolecularFormula,
@@ -94,41 +96,29 @@ impl MolecularCharge {
         let remainder = charge.value.rem_euclid(own_charge.value);
         let quotient = charge.value.div_euclid(own_charge.value).max(0);
 
-        let mut too_low_options: Vec<Vec<(isize, MolecularFormula)>> = Vec::new();
-        let mut options = Vec::new();
+        // Build all combinations of the charge carriers one carrier at a time, any combination
+        // that already overshoots the remainder cannot become valid by adding more carriers
+        let carriers_charge = |carriers: &[(isize, MolecularFormula)]| {
+            carriers
+                .iter()
+                .fold(Charge::default(), |acc, (amount, formula)| {
+                    acc + *amount * formula.charge()
+                })
+        };
+        let mut options: Vec<Vec<(isize, MolecularFormula)>> = vec![Vec::new()];
         for carrier in &self.charge_carriers {
-            let mut new_too_low_options = Vec::new();
-            if too_low_options.is_empty() {
-                for n in 0..=carrier.0 {
-                    let charge = n * carrier.1.charge();
-                    match charge.value.cmp(&remainder) {
-                        Ordering::Less => new_too_low_options.push(vec![(n, carrier.1.clone())]),
-                        Ordering::Equal => options.push(vec![(n, carrier.1.clone())]),
-                        Ordering::Greater => (),
-                    }
-                }
-            } else {
-                for n in 0..=carrier.0 {
-                    for o in &too_low_options {
-                        let mut new = o.clone();
+            options = options
+                .into_iter()
+                .flat_map(|option| {
+                    (0..=carrier.0.max(0)).filter_map(move |n| {
+                        let mut new = option.clone();
                         new.push((n, carrier.1.clone()));
-                        let full_charge = new
-                            .iter()
-                            .fold(Charge::default(), |acc, (amount, formula)| {
-                                acc + *amount * formula.charge()
-                            });
-
-                        let charge = n * carrier.1.charge() + full_charge;
-                        match charge.value.cmp(&remainder) {
-                            Ordering::Less => new_too_low_options.push(new),
-                            Ordering::Equal => options.push(new),
-                            Ordering::Greater => (),
-                        }
-                    }
-                }
-            }
-            too_low_options = new_too_low_options;
+                        (carriers_charge(&new).value <= remainder).then_some(new)
+                    })
+                })
+                .collect();
         }
+        options.retain(|option| carriers_charge(option).value == remainder);
 
         options
             .into_iter()
@@ -141,6 +131,7 @@ impl MolecularCharge {
                 );
                 Self { charge_carriers }.simplified()
             })
+            .unique()
             .collect()
     }
 
@@ -240,4 +231,18 @@ mod tests {
         assert_eq!(options.len(), 1);
         assert_eq!(options[0].formula(), molecular_formula!(H 1 Electron -1));
     }
+
+    #[test]
+    fn mixed_charge_options() {
+        let proton = molecular_formula!(H 1 Electron -1);
+        let sodium = molecular_formula!(Na 1 Electron -1);
+        let mc = MolecularCharge::new(&[(1, proton.clone()), (1, sodium.clone())]);
+        let options = mc.options(crate::system::isize::Charge::new::<crate::system::e>(1));
+        assert_eq!(options.len(), 2);
+        assert!(options.iter().any(|o| o.formula() == proton));
+        assert!(options.iter().any(|o| o.formula() == sodium));
+        let options = mc.options(crate::system::isize::Charge::new::<crate::system::e>(2));
+        assert_eq!(options.len(), 1);
+        assert_eq!(options[0].formula(), proton + sodium);
+    }
 }
@@ -4,10 +4,11 @@ use std::{ops::Range, sync::OnceLock};
 
 use crate::{
     error::{Context, CustomError},
+    fragment::{DiagnosticPosition, FragmentType, PeptidePosition},
     helper_functions::{explain_number_error, next_number, Characters, RangeExtension, RangeMaths},
     modification::{Ontology, SimpleModification},
     system::{e, isize::Charge, mz, MassOverCharge},
-    AminoAcid, MolecularCharge, MolecularFormula, NeutralLoss, Tolerance,
+    AminoAcid, MolecularCharge, MolecularFormula, NeutralLoss, SequencePosition, Tolerance,
 };
 // TODO: custom errors are off as they assume the input to be Bytes but now get Character offsets.
 
@@ -24,7 +25,7 @@ pub fn parse_mzpaf(line: &str) -> Result<Vec<PeakAnnotation>, CustomError> {
     // Parse any following
     while !range.is_empty() {
         if line.chars().nth(range.start_index()) == Some(',') {
-            range.add_start(1_usize);
+            range = range.add_start(1_usize);
         } else {
             return Err(CustomError::error(
                 "Invalid mzPAF annotation delimiter",
@@ -103,6 +104,32 @@ enum IonType {
     Formula(MolecularFormula),
 }
 
+impl PeakAnnotation {
+    /// Get the fragment type for the ion of this annotation on a peptide of the given length, see
+    /// [`IonType::fragment_type`].
+    pub fn fragment_type(&self, sequence_length: usize) -> Option<FragmentType> {
+        self.ion.fragment_type(sequence_length)
+    }
+}
+
+impl IonType {
+    /// Get the fragment type for this ion on a peptide of the given length. Returns None for ions
+    /// that cannot be represented as a fragment type without further information.
+    pub fn fragment_type(&self, sequence_length: usize) -> Option<FragmentType> {
+        match self {
+            Self::Internal(start, end) => Some(FragmentType::Internal(
+                None,
+                PeptidePosition::n(SequencePosition::Index(start - 1), sequence_length),
+                PeptidePosition::n(SequencePosition::Index(end - 1), sequence_length),
+            )),
+            Self::Reporter(_) => Some(FragmentType::Diagnostic(DiagnosticPosition::Reporter)),
+            Self::Precursor => Some(FragmentType::Precursor),
+            Self::Unknown(series) => Some(FragmentType::Unknown(*series)),
+            _ => None,
+        }
+    }
+}
+
 /// Parse a mzPAF analyte number. '1@...'
 /// # Errors
 /// When the ion is not formatted correctly.
@@ -113,11 +140,11 @@ fn parse_analyte_number(
     next_number::<false, false, usize>(line, range.clone()).map_or_else(
         || Ok((range.clone(), None)),
         |num| {
-            if line.chars().nth(num.0) != Some('@') {
+            if line.chars().nth(range.start_index() + num.0) != Some('@') {
                 return Err(CustomError::error(
                     "Invalid mzPAF analyte number",
                     "The analyte number should be followed by an at sign '@'",
-                    Context::line(None, line, num.0, 1),
+                    Context::line(None, line, range.start_index() + num.0, 1),
                 ));
             }
             Ok((
@@ -242,17 +269,13 @@ fn parse_ion(
                         Context::line(None, line, range.start_index(), 1),
                     )
                 })?;
-            if line[range.clone()].chars().nth(first_ordinal.0) != Some(':') {
+            if line.chars().nth(range.start_index() + 1 + first_ordinal.0) != Some(':') {
                 return Err(CustomError::error(
                     "Invalid mzPAF internal ion ordinal separator",
                     "The internal ion ordinal separator should be a colon ':', like 'm4:6'",
                     Context::line(None, line, range.start_index() + 1 + first_ordinal.0, 1),
                 ));
             }
-            assert!(
-                line[range.clone()].chars().nth(first_ordinal.0) == Some(':'),
-                "Needs to be separated by colon"
-            );
             let second_ordinal = next_number::<false, false, usize>(
                 line,
                 range.add_start(2 + first_ordinal.0 as isize),
@@ -283,6 +306,18 @@ fn parse_ion(
                     ),
                 )
             })?;
+            if first_location == 0 || first_location > second_location {
+                return Err(CustomError::error(
+                    "Invalid mzPAF internal ion range",
+                    "The ordinals for an internal ion are 1 based and the first ordinal cannot be bigger than the second, like 'm4:6'",
+                    Context::line(
+                        None,
+                        line,
+                        range.start_index() + 1,
+                        1 + first_ordinal.0 + second_ordinal.0,
+                    ),
+                ));
+            }
             Ok((
                 range.add_start(2 + first_ordinal.0 + second_ordinal.0),
                 IonType::Internal(first_location, second_location),
@@ -338,6 +373,7 @@ fn parse_ion(
                     Context::line(None, line, range.start_index(), 1),
                 ))
             }?;
+            let name = name.to_ascii_lowercase();
             mz_paf_named_molecules()
                 .iter()
                 .find_map(|n| (n.0 == name).then_some(n.1.clone()))
@@ -399,18 +435,22 @@ fn parse_neutral_loss(
     let mut offset = 0;
     let mut neutral_losses = Vec::new();
     while let Some(c @ ('-' | '+')) = line.chars().nth(range.start_index() + offset) {
-        if line.chars().nth(range.start_index() + 1) == Some('[') {
-            let first = line.char_indices().nth(range.start_index() + 2).unwrap().0;
+        if line.chars().nth(range.start_index() + offset + 1) == Some('[') {
+            let first = line
+                .char_indices()
+                .nth(range.start_index() + offset + 2)
+                .unwrap()
+                .0;
             let last = line
                 .char_indices()
-                .skip(range.start_index() + 2)
+                .skip(range.start_index() + offset + 2)
                 .take_while(|(_, c)| *c != ']')
                 .last()
                 .unwrap();
             //Ok(first..last.0 + last.1.len_utf8());
             let name = line[first..last.0 + last.1.len_utf8()].to_ascii_lowercase();
 
-            offset += 1 + last.0 + last.1.len_utf8() - first;
+            offset += 3 + last.0 + last.1.len_utf8() - first;
 
             if let Some(formula) = mz_paf_named_molecules()
                 .iter()
@@ -429,20 +469,32 @@ fn parse_neutral_loss(
                 ));
             }
         } else {
-            let first = line.char_indices().nth(range.start_index() + 1).unwrap().0;
+            let first = line
+                .char_indices()
+                .nth(range.start_index() + offset + 1)
+                .unwrap()
+                .0;
             let last = line
                 .char_indices()
-                .skip(range.start_index() + 2)
+                .skip(range.start_index() + offset + 2)
                 .take_while(|(_, c)| c.is_ascii_alphanumeric())
                 .last()
                 .unwrap();
-            let formula = MolecularFormula::from_pro_forma(
-                line,
-                first..last.0 + last.1.len_utf8(),
-                false,
-                false,
-                true,
-            )?;
+            let token = &line[first..last.0 + last.1.len_utf8()];
+            let formula = if let Some(formula) = mz_paf_conventional_molecules()
+                .iter()
+                .find_map(|n| (n.0 == token).then_some(n.1.clone()))
+            {
+                formula
+            } else {
+                MolecularFormula::from_pro_forma(
+                    line,
+                    first..last.0 + last.1.len_utf8(),
+                    false,
+                    false,
+                    true,
+                )?
+            };
             neutral_losses.push(match c {
                 '+' => NeutralLoss::Gain(formula),
                 '-' => NeutralLoss::Loss(formula),
@@ -742,3 +794,62 @@ fn mz_paf_named_molecules() -> &'static Vec<(&'static str, MolecularFormula)> {
 }
 
 static MZPAF_NAMED_MOLECULES_CELL: OnceLock<Vec<(&str, MolecularFormula)>> = OnceLock::new();
+
+/// The neutral losses that are conventionally written with implicit counts of one in mzPAF, these
+/// cannot always be parsed as ProForma formula (`NH3` would be read as nihonium).
+pub fn mz_paf_conventional_molecules() -> &'static Vec<(&'static str, MolecularFormula)> {
+    MZPAF_CONVENTIONAL_MOLECULES_CELL.get_or_init(|| {
+        vec![
+            ("H2O", molecular_formula!(H 2 O 1)),
+            ("NH3", molecular_formula!(N 1 H 3)),
+            ("CO", molecular_formula!(C 1 O 1)),
+            ("CO2", molecular_formula!(C 1 O 2)),
+            ("H3PO4", molecular_formula!(H 3 P 1 O 4)),
+            ("HPO3", molecular_formula!(H 1 P 1 O 3)),
+            ("CH4OS", molecular_formula!(C 1 H 4 O 1 S 1)),
+        ]
+    })
+}
+
+static MZPAF_CONVENTIONAL_MOLECULES_CELL: OnceLock<Vec<(&str, MolecularFormula)>> = OnceLock::new();
+
+#[cfg(test)]
+#[allow(clippy::missing_panics_doc)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn internal_ion() {
+        let annotation = parse_mzpaf("m3:7/0.001").unwrap();
+        assert_eq!(annotation.len(), 1);
+        assert!(matches!(annotation[0].ion, IonType::Internal(3, 7)));
+        assert_eq!(
+            annotation[0].fragment_type(10),
+            Some(FragmentType::Internal(
+                None,
+                PeptidePosition::n(SequencePosition::Index(2), 10),
+                PeptidePosition::n(SequencePosition::Index(6), 10),
+            ))
+        );
+        assert!(parse_mzpaf("m7:3").is_err());
+        assert!(parse_mzpaf("m0:3").is_err());
+        assert!(parse_mzpaf("m3-7").is_err());
+    }
+
+    #[test]
+    fn reporter_ion() {
+        let annotation = parse_mzpaf("r[TMT126],r[tmt127N]^1").unwrap();
+        assert_eq!(annotation.len(), 2);
+        assert!(
+            matches!(&annotation[0].ion, IonType::Reporter(formula) if *formula == molecular_formula!(C 8 N 1 H 15))
+        );
+        assert!(
+            matches!(&annotation[1].ion, IonType::Reporter(formula) if *formula == molecular_formula!(C 8 [15 N 1] H 15))
+        );
+        assert_eq!(
+            annotation[0].fragment_type(10),
+            Some(FragmentType::Diagnostic(DiagnosticPosition::Reporter))
+        );
+        assert!(parse_mzpaf("r[TMT999]").is_err());
+    }
+}
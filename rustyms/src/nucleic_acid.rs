@@ -0,0 +1,202 @@
+//! Nucleic acids, to calculate the formulas of DNA and RNA oligonucleotides
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::{Context, CustomError},
+    MolecularFormula,
+};
+
+/// A nucleotide in a DNA or RNA strand, defined by its base
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize, Deserialize)]
+pub enum NucleicAcid {
+    /// Adenine
+    A,
+    /// Cytosine
+    C,
+    /// Guanine
+    G,
+    /// Thymine
+    T,
+    /// Uracil
+    U,
+}
+
+/// The kind of nucleic acid, which determines the sugar in the backbone
+#[derive(
+    Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default, Serialize, Deserialize,
+)]
+pub enum NucleicAcidKind {
+    /// Deoxyribonucleic acid, with deoxyribose as sugar
+    #[default]
+    Dna,
+    /// Ribonucleic acid, with ribose as sugar
+    Rna,
+}
+
+/// The group on the 5' or 3' end of a nucleic acid strand
+#[derive(
+    Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default, Serialize, Deserialize,
+)]
+pub enum NucleicAcidTerminal {
+    /// A free hydroxyl group
+    #[default]
+    Hydroxyl,
+    /// A (mono)phosphate group
+    Phosphate,
+}
+
+impl NucleicAcid {
+    /// The formula of this nucleotide as a residue in a strand, so the nucleoside monophosphate
+    /// minus water. This includes the phosphate of the phosphodiester bond to the next nucleotide.
+    pub fn formula(self, kind: NucleicAcidKind) -> MolecularFormula {
+        let deoxy = match self {
+            Self::A => molecular_formula!(C 10 H 12 N 5 O 5 P 1),
+            Self::C => molecular_formula!(C 9 H 12 N 3 O 6 P 1),
+            Self::G => molecular_formula!(C 10 H 12 N 5 O 6 P 1),
+            Self::T => molecular_formula!(C 10 H 13 N 2 O 7 P 1),
+            Self::U => molecular_formula!(C 9 H 11 N 2 O 7 P 1),
+        };
+        match kind {
+            NucleicAcidKind::Dna => deoxy,
+            NucleicAcidKind::Rna => deoxy + molecular_formula!(O 1),
+        }
+    }
+
+    /// The one letter code for this nucleotide
+    pub const fn char(self) -> char {
+        match self {
+            Self::A => 'A',
+            Self::C => 'C',
+            Self::G => 'G',
+            Self::T => 'T',
+            Self::U => 'U',
+        }
+    }
+}
+
+impl TryFrom<char> for NucleicAcid {
+    type Error = ();
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'A' | 'a' => Ok(Self::A),
+            'C' | 'c' => Ok(Self::C),
+            'G' | 'g' => Ok(Self::G),
+            'T' | 't' => Ok(Self::T),
+            'U' | 'u' => Ok(Self::U),
+            _ => Err(()),
+        }
+    }
+}
+
+impl std::fmt::Display for NucleicAcid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.char())
+    }
+}
+
+/// Get the formula of a nucleic acid strand with a hydroxyl group on both the 5' and 3' end, as
+/// is common for synthetic oligonucleotides. See [`nucleic_acid_formula_with_terminals`] to set
+/// other terminal groups.
+/// # Errors
+/// If the sequence contains a character that is not a nucleotide (A, C, G, T, or U), or if the
+/// sequence is empty.
+pub fn nucleic_acid_formula(
+    sequence: &str,
+    kind: NucleicAcidKind,
+) -> Result<MolecularFormula, CustomError> {
+    nucleic_acid_formula_with_terminals(
+        sequence,
+        kind,
+        NucleicAcidTerminal::Hydroxyl,
+        NucleicAcidTerminal::Hydroxyl,
+    )
+}
+
+/// Get the formula of a nucleic acid strand with the given groups on the 5' and 3' end.
+/// # Errors
+/// If the sequence contains a character that is not a nucleotide (A, C, G, T, or U), or if the
+/// sequence is empty.
+pub fn nucleic_acid_formula_with_terminals(
+    sequence: &str,
+    kind: NucleicAcidKind,
+    five_prime: NucleicAcidTerminal,
+    three_prime: NucleicAcidTerminal,
+) -> Result<MolecularFormula, CustomError> {
+    if sequence.is_empty() {
+        return Err(CustomError::error(
+            "Invalid nucleic acid sequence",
+            "The sequence is empty",
+            Context::none(),
+        ));
+    }
+    let mut formula = sequence
+        .char_indices()
+        .map(|(index, c)| {
+            NucleicAcid::try_from(c)
+                .map(|n| n.formula(kind))
+                .map_err(|()| {
+                    CustomError::error(
+                        "Invalid nucleic acid sequence",
+                        "This is not a valid nucleotide, use one of A, C, G, T, or U",
+                        Context::line(None, sequence, index, c.len_utf8()),
+                    )
+                })
+        })
+        .sum::<Result<MolecularFormula, _>>()?;
+    // Every residue contains a phosphate on the 5' side, closing the strand adds water giving
+    // a 5' phosphate and 3' hydroxyl
+    formula += molecular_formula!(H 2 O 1);
+    if five_prime == NucleicAcidTerminal::Hydroxyl {
+        formula = formula - molecular_formula!(H 1 P 1 O 3);
+    }
+    if three_prime == NucleicAcidTerminal::Phosphate {
+        formula += molecular_formula!(H 1 P 1 O 3);
+    }
+    Ok(formula)
+}
+
+#[cfg(test)]
+#[allow(clippy::missing_panics_doc)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nucleosides() {
+        // Deoxyadenosine
+        assert_eq!(
+            nucleic_acid_formula("A", NucleicAcidKind::Dna).unwrap(),
+            molecular_formula!(C 10 H 13 N 5 O 3)
+        );
+        // Uridine
+        assert_eq!(
+            nucleic_acid_formula("U", NucleicAcidKind::Rna).unwrap(),
+            molecular_formula!(C 9 H 12 N 2 O 6)
+        );
+        // Guanosine monophosphate
+        assert_eq!(
+            nucleic_acid_formula_with_terminals(
+                "G",
+                NucleicAcidKind::Rna,
+                NucleicAcidTerminal::Phosphate,
+                NucleicAcidTerminal::Hydroxyl
+            )
+            .unwrap(),
+            molecular_formula!(C 10 H 14 N 5 O 8 P 1)
+        );
+    }
+
+    #[test]
+    fn oligonucleotide() {
+        // Dinucleotide d(ApT) is dA + dT - H2O joined by a phosphodiester bond
+        assert_eq!(
+            nucleic_acid_formula("AT", NucleicAcidKind::Dna).unwrap(),
+            molecular_formula!(C 10 H 13 N 5 O 3)
+                + molecular_formula!(C 10 H 14 N 2 O 5)
+                + molecular_formula!(H 3 P 1 O 4)
+                - molecular_formula!(H 2 O 1) * 2
+        );
+        assert!(nucleic_acid_formula("ATX", NucleicAcidKind::Dna).is_err());
+        assert!(nucleic_acid_formula("", NucleicAcidKind::Dna).is_err());
+    }
+}
@@ -13,6 +13,9 @@ use crate::{
 /// A database of custom modifications
 pub type CustomDatabase = OntologyModificationList;
 
+/// The minimal length of a name to be suggested because it is a prefix of the requested name
+const MINIMAL_PREFIX_LENGTH: usize = 4;
+
 /// An empty list of modifications (needed for lifetime reasons)
 static EMPTY_LIST: OntologyModificationList = Vec::new();
 
@@ -66,12 +69,16 @@ impl Ontology {
         .with_suggestions(Self::similar_names(ontologies, code, custom_database))
     }
 
-    /// Get the closest similar names in the given ontologies. Finds both modifications and linkers
+    /// Get the closest similar names in the given ontologies. Finds both modifications and linkers.
+    /// The comparison is case insensitive. Names that are a prefix of the given code (eg 'Phospho'
+    /// for 'Phosphorylation') are given first, followed by the names with the smallest edit
+    /// distance to the code.
     pub fn similar_names(
         ontologies: &[Self],
         code: &str,
         custom_database: Option<&CustomDatabase>,
     ) -> Vec<String> {
+        let code = code.trim().to_ascii_lowercase();
         let mut resulting = Vec::new();
         for ontology in ontologies {
             let options: Vec<&str> = ontology
@@ -79,9 +86,20 @@ impl Ontology {
                 .iter()
                 .map(|option| option.1.as_str())
                 .collect();
+            let prefixes = options
+                .iter()
+                .filter(|option| {
+                    option.len() >= MINIMAL_PREFIX_LENGTH
+                        && option.len() < code.len()
+                        && code.starts_with(**option)
+                })
+                .sorted_by_key(|option| std::cmp::Reverse(option.len()))
+                .take(3);
             resulting.extend(
-                similar::get_close_matches(code, &options, 3, 0.7)
-                    .iter()
+                prefixes
+                    .copied()
+                    .chain(similar::get_close_matches(code.as_str(), &options, 3, 0.7))
+                    .unique()
                     .map(|o| format!("{}:{}", ontology.char(), o)),
             );
         }
@@ -4,8 +4,10 @@ use itertools::Itertools;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    peptidoform::Linked, system::usize::Charge, Fragment, Model, MolecularFormula, Multi,
-    Peptidoform, PeptidoformIon,
+    error::{Context, CustomError},
+    peptidoform::Linked,
+    system::usize::Charge,
+    Fragment, Model, MolecularFormula, Multi, Peptidoform, PeptidoformIon,
 };
 
 /// A single full ProForma entry. This entry can contain multiple sets of cross-linked peptides.
@@ -16,6 +18,33 @@ use crate::{
 pub struct CompoundPeptidoformIon(pub(super) Vec<PeptidoformIon>);
 
 impl CompoundPeptidoformIon {
+    /// Serialize this compound peptidoform into a compact binary format, useful for caching large sets
+    /// of compound peptidoforms. Use [`Self::from_bincode`] to read it back.
+    /// # Errors
+    /// If the compound peptidoform could not be serialized.
+    pub fn to_bincode(&self) -> Result<Vec<u8>, CustomError> {
+        bincode::serialize(self).map_err(|err| {
+            CustomError::error(
+                "Could not serialize compound peptidoform",
+                err,
+                Context::none(),
+            )
+        })
+    }
+
+    /// Deserialize a compound peptidoform from the binary format as written by [`Self::to_bincode`].
+    /// # Errors
+    /// If the bytes do not contain a valid compound peptidoform.
+    pub fn from_bincode(bytes: &[u8]) -> Result<Self, CustomError> {
+        bincode::deserialize(bytes).map_err(|err| {
+            CustomError::error(
+                "Could not deserialize compound peptidoform",
+                err,
+                Context::none(),
+            )
+        })
+    }
+
     /// Create a new [`CompoundPeptidoform`] from many [`Peptidoform`]s. This returns None if the
     /// global isotope modifications of all peptidoforms are not identical.
     pub fn new(iter: impl IntoIterator<Item = PeptidoformIon>) -> Option<Self> {
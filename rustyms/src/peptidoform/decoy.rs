@@ -0,0 +1,53 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{CompoundPeptidoformIon, Peptidoform, PeptidoformIon};
+
+/// The method to generate a decoy peptidoform from a target peptidoform
+#[derive(
+    Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+pub enum DecoyMethod {
+    /// Reverse the full sequence, see [`Peptidoform::reverse`]
+    Reverse,
+    /// Reverse the sequence but keep the C-terminal amino acid in place, see [`Peptidoform::pseudo_reverse`]
+    #[default]
+    PseudoReverse,
+    /// Shuffle the full sequence with the given seed, see [`Peptidoform::shuffle`]
+    Shuffle(u64),
+    /// Shuffle the sequence with the given seed but keep the C-terminal amino acid in place, see [`Peptidoform::shuffle`]
+    PseudoShuffle(u64),
+}
+
+impl<Complexity> Peptidoform<Complexity> {
+    /// Generate the decoy of this peptidoform with the given method.
+    #[must_use]
+    pub fn decoy(&self, method: DecoyMethod) -> Self {
+        match method {
+            DecoyMethod::Reverse => self.reverse(),
+            DecoyMethod::PseudoReverse => self.pseudo_reverse(),
+            DecoyMethod::Shuffle(seed) => self.shuffle(seed, false),
+            DecoyMethod::PseudoShuffle(seed) => self.shuffle(seed, true),
+        }
+    }
+}
+
+impl CompoundPeptidoformIon {
+    /// Generate the decoy of this compound peptidoform ion with the given method, this generates
+    /// the decoy for every peptidoform separately.
+    #[must_use]
+    pub fn decoy(&self, method: DecoyMethod) -> Self {
+        Self(
+            self.0
+                .iter()
+                .map(|ion| {
+                    PeptidoformIon(
+                        ion.0
+                            .iter()
+                            .map(|peptidoform| peptidoform.decoy(method))
+                            .collect(),
+                    )
+                })
+                .collect(),
+        )
+    }
+}
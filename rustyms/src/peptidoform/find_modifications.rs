@@ -293,9 +293,12 @@ impl PeptideModificationSearch {
             in_place: &Modification,
         ) -> Option<Modification> {
             match in_place {
-                Modification::Simple(simple) => settings
-                    .find_replacement(position, aminoacid, simple)
-                    .map(Modification::Simple),
+                Modification::Simple { modification, info } => settings
+                    .find_replacement(position, aminoacid, modification)
+                    .map(|replace| Modification::Simple {
+                        modification: replace,
+                        info: info.clone(),
+                    }),
                 Modification::CrossLink { .. } | Modification::Ambiguous { .. } => None, // TODO: potentially the cross-linker could be replaced?
             }
         }
@@ -336,7 +339,7 @@ impl PeptideModificationSearch {
             let mut remove = None;
             for (i, m) in position.modifications.iter_mut().enumerate() {
                 match m {
-                    Modification::Simple(modification) => {
+                    Modification::Simple { modification, info } => {
                         if let Some((replace, location)) = find_replacement_all_positions(
                             self,
                             is_n_term,
@@ -344,14 +347,18 @@ impl PeptideModificationSearch {
                             Some(position.aminoacid.aminoacid()),
                             modification,
                         ) {
+                            let replace = Modification::Simple {
+                                modification: replace,
+                                info: info.clone(),
+                            };
                             if location == Position::AnyNTerm {
-                                n_term.push(Modification::Simple(replace));
+                                n_term.push(replace);
                                 remove = Some(i);
                             } else if location == Position::AnyCTerm {
-                                c_term.push(Modification::Simple(replace));
+                                c_term.push(replace);
                                 remove = Some(i);
                             } else if location == Position::Anywhere {
-                                *m = Modification::Simple(replace);
+                                *m = replace;
                             }
                             // If it can only be a terminal mod but there already is a terminal mod keep it in the original state
                         }
@@ -1,9 +1,11 @@
 #![warn(dead_code)]
 
 use crate::{
+    aminoacid_properties::{HydrophobicityScale, C_TERMINAL_PKA, N_TERMINAL_PKA},
     checked_aminoacid::CheckedAminoAcid,
+    error::{Context, CustomError},
     fragment::{DiagnosticPosition, Fragment, FragmentType, PeptidePosition},
-    glycan::MonoSaccharide,
+    glycan::{GlycanStructure, MonoSaccharide},
     helper_functions::{peptide_range_contains, RangeExtension},
     modification::{
         CrossLinkName, GnoComposition, LinkerSpecificity, Modification, SimpleModification,
@@ -12,9 +14,11 @@ use crate::{
     molecular_charge::{CachedCharge, MolecularCharge},
     peptidoform::*,
     placement_rule::PlacementRule,
-    system::usize::Charge,
-    AmbiguousLabel, DiagnosticIon, Element, Model, MolecularFormula, Multi, MultiChemical,
-    NeutralLoss, Protease, SequenceElement, SequencePosition,
+    sequence_element::DisplayOptions,
+    system::{f64::Mass, usize::Charge},
+    AmbiguousLabel, AminoAcid, Chemical, DiagnosticIon, Element, MassMode, Model, MolecularFormula,
+    Multi, MultiChemical, NeutralLoss, Protease, SequenceElement, SequencePosition, TerminusStatus,
+    Tolerance, WithinTolerance,
 };
 use itertools::Itertools;
 use ordered_float::OrderedFloat;
@@ -24,9 +28,10 @@ use std::{
     fmt::{Display, Write},
     marker::PhantomData,
     num::NonZeroU16,
-    ops::{Index, IndexMut, RangeBounds},
+    ops::{Bound, Index, IndexMut, RangeBounds, RangeInclusive},
     slice::SliceIndex,
 };
+use thin_vec::ThinVec;
 
 /// A peptide with all data as specified by [ProForma](https://github.com/HUPO-PSI/ProForma).
 /// Because the full ProForma specification allows very complex peptides the maximal complexity
@@ -96,6 +101,12 @@ pub struct Peptidoform<Complexity> {
     n_term: Vec<Modification>,
     /// C terminal modifications
     c_term: Vec<Modification>,
+    /// Free text information tags (ProForma `INFO:...`) given on their own on the N terminus
+    #[serde(default)]
+    n_term_info: ThinVec<String>,
+    /// Free text information tags (ProForma `INFO:...`) given on their own on the C terminus
+    #[serde(default)]
+    c_term_info: ThinVec<String>,
     /// The sequence of this peptide (includes local modifications)
     sequence: Vec<SequenceElement<Complexity>>,
     /// For each ambiguous modification list all possible positions it can be placed on.
@@ -118,6 +129,11 @@ struct AmbiguousEntry {
     colocalise_modifications_of_unknown_position: bool,
     /// Group, used for '^x'  
     group: Option<usize>,
+    /// If this was placed as a modification of unknown position on all possible locations
+    /// (`[Phospho]?PEPTIDE`), the group name is generated and the positions are not written
+    /// back as these are found again when parsed
+    #[serde(default)]
+    generated: bool,
 }
 
 impl<Complexity> Default for Peptidoform<Complexity> {
@@ -127,6 +143,8 @@ impl<Complexity> Default for Peptidoform<Complexity> {
             labile: Vec::new(),
             n_term: Vec::new(),
             c_term: Vec::new(),
+            n_term_info: ThinVec::new(),
+            c_term_info: ThinVec::new(),
             sequence: Vec::new(),
             modifications_of_unknown_position: Vec::new(),
             charge_carriers: None,
@@ -142,6 +160,8 @@ impl<Complexity> Clone for Peptidoform<Complexity> {
             labile: self.labile.clone(),
             n_term: self.n_term.clone(),
             c_term: self.c_term.clone(),
+            n_term_info: self.n_term_info.clone(),
+            c_term_info: self.c_term_info.clone(),
             sequence: self.sequence.clone(),
             modifications_of_unknown_position: self.modifications_of_unknown_position.clone(),
             charge_carriers: self.charge_carriers.clone(),
@@ -158,6 +178,8 @@ impl<OwnComplexity, OtherComplexity> PartialEq<Peptidoform<OtherComplexity>>
             && self.labile == other.labile
             && self.n_term == other.n_term
             && self.c_term == other.c_term
+            && self.n_term_info == other.n_term_info
+            && self.c_term_info == other.c_term_info
             && self.sequence == other.sequence
             && self.modifications_of_unknown_position == other.modifications_of_unknown_position
             && self.charge_carriers == other.charge_carriers
@@ -170,6 +192,8 @@ impl<Complexity> std::hash::Hash for Peptidoform<Complexity> {
         self.labile.hash(state);
         self.n_term.hash(state);
         self.c_term.hash(state);
+        self.n_term_info.hash(state);
+        self.c_term_info.hash(state);
         self.sequence.hash(state);
         self.modifications_of_unknown_position.hash(state);
         self.charge_carriers.hash(state);
@@ -297,6 +321,8 @@ impl<Complexity> Peptidoform<Complexity> {
             labile: self.labile,
             n_term: self.n_term,
             c_term: self.c_term,
+            n_term_info: self.n_term_info,
+            c_term_info: self.c_term_info,
             sequence: self
                 .sequence
                 .into_iter()
@@ -370,24 +396,267 @@ impl<Complexity> Peptidoform<Complexity> {
         self.sequence.is_empty()
     }
 
-    /// Get the N terminal modifications.
+    /// Get the number of occurrences of each of the 20 canonical amino acids in this peptide. The
+    /// counts are in the same order as [`AminoAcid::CANONICAL_AMINO_ACIDS`]. Any other amino acids
+    /// (B/J/Z/U/O/X) are not counted.
+    pub fn composition_counts(&self) -> [u32; 20] {
+        let mut counts = [0; 20];
+        for element in &self.sequence {
+            if let Some(index) = AminoAcid::CANONICAL_AMINO_ACIDS
+                .iter()
+                .position(|aa| *aa == element.aminoacid.aminoacid())
+            {
+                counts[index] += 1;
+            }
+        }
+        counts
+    }
+
+    /// Get the number of modifications on this peptide, this counts the modifications on the
+    /// termini and on the amino acids. Labile and global isotope modifications are not counted.
+    pub fn number_of_modifications(&self) -> usize {
+        self.n_term.len()
+            + self.c_term.len()
+            + self
+                .sequence
+                .iter()
+                .map(|s| s.modifications.len())
+                .sum::<usize>()
+    }
+
+    /// Summarise the modifications on this peptide per category, for example for quality
+    /// control. Global isotope modifications are not counted.
+    pub fn modification_summary(&self) -> ModificationSummary {
+        let mut summary = ModificationSummary {
+            labile: self.labile.len(),
+            ..ModificationSummary::default()
+        };
+        let mut ambiguous = HashSet::new();
+        for (location, modification) in self
+            .n_term
+            .iter()
+            .map(|m| (SequencePosition::NTerm, m))
+            .chain(self.c_term.iter().map(|m| (SequencePosition::CTerm, m)))
+            .chain(self.sequence.iter().enumerate().flat_map(|(index, s)| {
+                s.modifications
+                    .iter()
+                    .map(move |m| (SequencePosition::Index(index), m))
+            }))
+        {
+            if let Modification::Ambiguous { id, .. } = modification {
+                ambiguous.insert(*id);
+                continue;
+            }
+            match location {
+                SequencePosition::NTerm => summary.n_terminal += 1,
+                SequencePosition::CTerm => summary.c_terminal += 1,
+                SequencePosition::Index(_) => summary.side_chain += 1,
+            }
+            match modification {
+                Modification::Simple {
+                    modification: simple,
+                    ..
+                } => {
+                    if matches!(
+                        &**simple,
+                        SimpleModificationInner::Glycan(_)
+                            | SimpleModificationInner::GlycanStructure(_)
+                            | SimpleModificationInner::Gno { .. }
+                    ) {
+                        summary.glycan += 1;
+                    } else {
+                        summary.variable += 1;
+                    }
+                }
+                Modification::CrossLink { .. } => summary.cross_link += 1,
+                Modification::Ambiguous { .. } => (),
+            }
+        }
+        summary.ambiguous = ambiguous.len();
+        summary
+    }
+
+    /// The predicted hydrophobicity of this peptide, the sum of the hydrophobicity of all
+    /// residues on the given scale. Modifications are not taken into account.
+    pub fn predicted_hydrophobicity(&self, scale: HydrophobicityScale) -> f64 {
+        self.sequence
+            .iter()
+            .map(|s| s.aminoacid.aminoacid().hydrophobicity(scale))
+            .sum()
+    }
+
+    /// The isoelectric point of this peptide, the pH where the net charge is zero, using the pKa
+    /// values from [`AminoAcid::side_chain_pka`]. Any modification on a residue with an
+    /// ionisable side chain is assumed to mask that side chain (eg acetylation of lysine or
+    /// carbamidomethylation of cysteine), similarly any terminal modification masks the terminal
+    /// amine or carboxyl group.
+    pub fn isoelectric_point(&self) -> f64 {
+        let mut groups = self
+            .sequence
+            .iter()
+            .filter(|s| s.modifications.is_empty())
+            .filter_map(|s| s.aminoacid.aminoacid().side_chain_pka())
+            .collect_vec();
+        if self.n_term.is_empty() {
+            groups.push((N_TERMINAL_PKA, true));
+        }
+        if self.c_term.is_empty() {
+            groups.push((C_TERMINAL_PKA, false));
+        }
+        let charge = |ph: f64| -> f64 {
+            groups
+                .iter()
+                .map(|(pka, basic)| {
+                    if *basic {
+                        1.0 / (1.0 + 10.0_f64.powf(ph - pka))
+                    } else {
+                        -1.0 / (1.0 + 10.0_f64.powf(pka - ph))
+                    }
+                })
+                .sum()
+        };
+        // The net charge decreases monotonically with the pH, so bisect to find the zero point
+        let (mut low, mut high) = (0.0, 14.0);
+        for _ in 0..32 {
+            let middle = (low + high) / 2.0;
+            if charge(middle) > 0.0 {
+                low = middle;
+            } else {
+                high = middle;
+            }
+        }
+        (low + high) / 2.0
+    }
+
+    /// List all N-glycosylation sequons (N-X-S/T, where X is not P) in this peptide, with the
+    /// glycan structure if the asparagine is occupied by a glycan. Only sequons that are fully
+    /// contained in this peptide are listed. Only glycans with a known structure can be reported,
+    /// so glycans that are only defined as composition or mass are reported as `None` as well.
+    pub fn glycosylation_sites(&self) -> Vec<(SequencePosition, Option<GlycanStructure>)> {
+        self.sequence
+            .windows(3)
+            .enumerate()
+            .filter(|(_, window)| {
+                window[0].aminoacid.aminoacid() == AminoAcid::Asparagine
+                    && window[1].aminoacid.aminoacid() != AminoAcid::Proline
+                    && matches!(
+                        window[2].aminoacid.aminoacid(),
+                        AminoAcid::Serine | AminoAcid::Threonine
+                    )
+            })
+            .map(|(index, window)| {
+                let glycan = window[0]
+                    .modifications
+                    .iter()
+                    .filter_map(Modification::simple)
+                    .find_map(|modification| match &**modification {
+                        SimpleModificationInner::GlycanStructure(structure)
+                        | SimpleModificationInner::Gno {
+                            composition: GnoComposition::Topology(structure),
+                            ..
+                        } => Some(structure.clone()),
+                        _ => None,
+                    });
+                (SequencePosition::Index(index), glycan)
+            })
+            .collect()
+    }
+
+    /// Serialize this peptidoform into a compact binary format, useful for caching large sets
+    /// of peptidoforms. Use [`Self::from_bincode`] to read it back.
+    /// # Errors
+    /// If the peptidoform could not be serialized.
+    pub fn to_bincode(&self) -> Result<Vec<u8>, CustomError>
+    where
+        Self: Serialize,
+    {
+        bincode::serialize(self).map_err(|err| {
+            CustomError::error("Could not serialize peptidoform", err, Context::none())
+        })
+    }
+
+    /// Deserialize a peptidoform from the binary format as written by [`Self::to_bincode`].
+    /// # Errors
+    /// If the bytes do not contain a valid peptidoform.
+    pub fn from_bincode(bytes: &[u8]) -> Result<Self, CustomError>
+    where
+        Self: for<'de> Deserialize<'de>,
+    {
+        bincode::deserialize(bytes).map_err(|err| {
+            CustomError::error("Could not deserialize peptidoform", err, Context::none())
+        })
+    }
+
+    /// Determine if the termini of this peptide are consistent with the cleavage specificity of the
+    /// given protease. If the protein is given the residues flanking this peptide in the protein
+    /// are used (with the protein termini always being consistent), otherwise (or if this peptide
+    /// cannot be found in the protein) only the residues of the peptide itself are used, so any
+    /// part of the specificity outside of the peptide cannot be verified and is seen as not
+    /// consistent. For example for trypsin without protein context only the C terminus can be
+    /// consistent.
+    pub fn enzymatic_terminus_status<OtherComplexity>(
+        &self,
+        protease: &Protease,
+        protein: Option<&Peptidoform<OtherComplexity>>,
+    ) -> TerminusStatus {
+        let (n_term, c_term) = protein
+            .filter(|_| !self.is_empty())
+            .and_then(|protein| {
+                let start = protein.sequence().windows(self.len()).position(|window| {
+                    window
+                        .iter()
+                        .zip(self.sequence())
+                        .all(|(a, b)| a.aminoacid.aminoacid() == b.aminoacid.aminoacid())
+                })?;
+                let end = start + self.len();
+                Some((
+                    start == 0 || protease.cuts_at(protein.sequence(), start),
+                    end == protein.len() || protease.cuts_at(protein.sequence(), end),
+                ))
+            })
+            .unwrap_or_else(|| {
+                (
+                    protease.cuts_at(self.sequence(), 0),
+                    protease.cuts_at(self.sequence(), self.len()),
+                )
+            });
+        TerminusStatus::new(n_term, c_term)
+    }
+
+    /// Get the N terminal modifications. A terminus can only carry a single modification, so if
+    /// multiple modifications are given these are seen as alternatives. Similar to B/Z all
+    /// alternatives are enumerated, so each results in separate formulas and fragments. Ambiguous
+    /// modifications are only taken as alternative if no defined modification is present.
     pub fn get_n_term(&self) -> &[Modification] {
         &self.n_term
     }
 
-    /// Get the C terminal modifications.
+    /// Get the C terminal modifications. A terminus can only carry a single modification, so if
+    /// multiple modifications are given these are seen as alternatives. Similar to B/Z all
+    /// alternatives are enumerated, so each results in separate formulas and fragments. Ambiguous
+    /// modifications are only taken as alternative if no defined modification is present.
     pub fn get_c_term(&self) -> &[Modification] {
         &self.c_term
     }
 
+    /// Get the free text information tags (ProForma `INFO:...`) given on their own on the N terminus
+    pub fn get_n_term_info(&self) -> &[String] {
+        &self.n_term_info
+    }
+
+    /// Get the free text information tags (ProForma `INFO:...`) given on their own on the C terminus
+    pub fn get_c_term_info(&self) -> &[String] {
+        &self.c_term_info
+    }
+
     /// Set the N terminal modification as a simple modification
     pub fn add_simple_n_term(&mut self, modification: SimpleModification) {
-        self.n_term.push(Modification::Simple(modification));
+        self.n_term.push(Modification::from(modification));
     }
 
     /// Set the C terminal modification as a simple modification
     pub fn add_simple_c_term(&mut self, modification: SimpleModification) {
-        self.c_term.push(Modification::Simple(modification));
+        self.c_term.push(Modification::from(modification));
     }
 
     /// Add a modification to this peptide
@@ -401,16 +670,165 @@ impl<Complexity> Peptidoform<Complexity> {
             SequencePosition::CTerm => self.add_simple_c_term(modification),
             SequencePosition::Index(index) => self.sequence[index]
                 .modifications
-                .push(Modification::Simple(modification)),
+                .push(Modification::from(modification)),
         }
     }
 
+    /// Apply fixed modifications, as used in database searches (e.g. carbamidomethyl on all C).
+    /// Every modification is added to every position (including the termini) that matches its
+    /// placement rule.
+    #[must_use]
+    pub fn apply_fixed_modifications(
+        &self,
+        modifications: &[(SimpleModification, PlacementRule)],
+    ) -> Self {
+        let mut result = self.clone();
+        for (modification, rule) in modifications {
+            let positions = result
+                .iter(..)
+                .filter(|(position, seq)| rule.is_possible(seq, position.sequence_index))
+                .map(|(position, _)| position.sequence_index)
+                .collect_vec();
+            for position in positions {
+                result.add_simple_modification(position, modification.clone());
+            }
+        }
+        result
+    }
+
+    /// Enumerate all peptidoforms that can be made by placing the given variable modifications,
+    /// as used in database searches (e.g. oxidation on M). Every modification is given together
+    /// with the maximal number of times it can be placed, and at most `max_total` variable
+    /// modifications are placed in total. The possible locations are determined by the placement
+    /// rules of the modifications themselves (see [`SimpleModificationInner::is_possible`]). Every
+    /// location receives at most one variable modification. The termini and the side chains of the
+    /// terminal amino acids are separate locations, a modification is only placed on a terminus if
+    /// one of its placement rules is specific to that terminus (e.g. acetyl on the N terminus of a
+    /// peptide starting with K is placed both on the N terminus and on the K). The unmodified
+    /// peptidoform is always the first element of the result.
+    pub fn apply_variable_modifications(
+        &self,
+        modifications: &[(SimpleModification, usize)],
+        max_total: usize,
+    ) -> Vec<Peptidoform<Linked>> {
+        let base = self.clone().mark::<Linked>();
+        if self.is_empty() {
+            return vec![base];
+        }
+        let sites = self
+            .iter(..)
+            .filter_map(|(position, seq)| {
+                let position = position.sequence_index;
+                let options = modifications
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, (modification, _))| match position {
+                        SequencePosition::Index(_) => {
+                            modification.is_possible(seq, position).any_possible()
+                        }
+                        SequencePosition::NTerm | SequencePosition::CTerm => {
+                            match &**modification {
+                                SimpleModificationInner::Database { specificities, .. } => {
+                                    specificities.iter().any(|(rules, _, _)| {
+                                        rules.iter().any(|rule| {
+                                            rule.is_terminal_specific()
+                                                && rule.is_possible(seq, position)
+                                        })
+                                    })
+                                }
+                                _ => false,
+                            }
+                        }
+                    })
+                    .map(|(index, _)| index)
+                    .collect_vec();
+                (!options.is_empty()).then_some((position, options))
+            })
+            .collect_vec();
+
+        let mut result = vec![base.clone()];
+        let mut stack = vec![(0, vec![0; modifications.len()], base)];
+        while let Some((start, counts, peptidoform)) = stack.pop() {
+            if counts.iter().sum::<usize>() >= max_total {
+                continue;
+            }
+            for (site, (position, options)) in sites.iter().enumerate().skip(start) {
+                for option in options {
+                    if counts[*option] >= modifications[*option].1 {
+                        continue;
+                    }
+                    let mut new_counts = counts.clone();
+                    new_counts[*option] += 1;
+                    let mut new = peptidoform.clone();
+                    new.add_simple_modification(*position, modifications[*option].0.clone());
+                    result.push(new.clone());
+                    stack.push((site + 1, new_counts, new));
+                }
+            }
+        }
+        result.into_iter().unique().collect()
+    }
+
+    /// Expand all ambiguous amino acid groups (`(?DN)` in ProForma) into all concrete orderings of
+    /// the amino acids in each group. For multiple groups all combinations of the orderings are
+    /// generated. Orderings that result in the same sequence (eg because an amino acid occurs
+    /// twice in a group) are only given once. The modifications on the amino acids move with their
+    /// amino acid, but the positions for modifications of unknown position are not updated. If
+    /// there are no ambiguous groups the result only contains this peptidoform.
+    pub fn expand_ambiguous_sequences(&self) -> Vec<Self> {
+        let mut groups = Vec::new();
+        let mut index = 0;
+        while index < self.sequence.len() {
+            if let Some(id) = self.sequence[index].ambiguous {
+                let end = self.sequence[index..]
+                    .iter()
+                    .position(|seq| seq.ambiguous != Some(id))
+                    .map_or(self.sequence.len(), |offset| index + offset);
+                groups.push(index..end);
+                index = end;
+            } else {
+                index += 1;
+            }
+        }
+        let orderings = groups
+            .iter()
+            .map(|range| {
+                self.sequence[range.clone()]
+                    .iter()
+                    .cloned()
+                    .map(|mut seq| {
+                        seq.ambiguous = None;
+                        seq
+                    })
+                    .permutations(range.len())
+                    .unique()
+                    .collect_vec()
+            })
+            .collect_vec();
+        if orderings.is_empty() {
+            return vec![self.clone()];
+        }
+
+        orderings
+            .into_iter()
+            .multi_cartesian_product()
+            .map(|option| {
+                let mut result = self.clone();
+                for (range, ordering) in groups.iter().zip(option) {
+                    result.sequence.splice(range.clone(), ordering);
+                }
+                result
+            })
+            .collect()
+    }
+
     /// Set the charge carriers, use [`Self::charge_carriers`] unless absolutely necessary.
     pub(super) fn set_charge_carriers(&mut self, charge_carriers: Option<MolecularCharge>) {
         self.charge_carriers = charge_carriers;
     }
 
-    /// The mass of the N terminal modifications. The global isotope modifications are NOT applied.
+    /// The mass of the N terminal modifications. Multiple modifications are alternatives, see
+    /// [`Self::get_n_term`]. The global isotope modifications are NOT applied.
     fn get_n_term_mass(
         &self,
         all_peptides: &[Peptidoform<Linked>],
@@ -419,25 +837,80 @@ impl<Complexity> Peptidoform<Complexity> {
         allow_ms_cleavable: bool,
         peptidoform_index: usize,
     ) -> Multi<MolecularFormula> {
-        self.n_term.iter().fold(Multi::default(), |acc, f| {
-            if let Modification::Ambiguous { .. } = f {
-                acc
-            } else {
-                acc * f
-                    .formula_inner(
-                        all_peptides,
-                        visited_peptides,
-                        applied_cross_links,
-                        allow_ms_cleavable,
-                        SequencePosition::NTerm,
-                        peptidoform_index,
-                    )
-                    .0
-            }
-        }) + molecular_formula!(H 1)
+        let alternatives: Vec<MolecularFormula> = self
+            .n_term
+            .iter()
+            .filter(|m| !matches!(m, Modification::Ambiguous { .. }))
+            .flat_map(|m| {
+                m.formula_inner(
+                    all_peptides,
+                    visited_peptides,
+                    applied_cross_links,
+                    allow_ms_cleavable,
+                    SequencePosition::NTerm,
+                    peptidoform_index,
+                )
+                .0
+                .to_vec()
+            })
+            .collect();
+        let alternatives = if alternatives.is_empty() {
+            Multi::default()
+        } else {
+            Multi::from(alternatives)
+        };
+        alternatives + molecular_formula!(H 1)
     }
 
-    /// The mass of the C terminal modifications. The global isotope modifications are NOT applied.
+    /// The options for placing the not yet placed ambiguous modifications on the given terminus.
+    /// A terminus can only carry a single modification, so if the terminus has a defined
+    /// modification none are placed here. Otherwise every option places one of the ambiguous
+    /// modifications on the terminus, giving the updated `placed` state and the formula of the
+    /// placed modification. If nothing can be placed a single option without changes is given.
+    /// The global isotope modifications are NOT applied.
+    fn terminal_ambiguous_options(
+        &self,
+        placed: &[bool],
+        position: SequencePosition,
+        peptidoform_index: usize,
+    ) -> Vec<(Vec<bool>, Multi<MolecularFormula>)> {
+        let modifications: &[Modification] = match position {
+            SequencePosition::NTerm => &self.n_term,
+            SequencePosition::CTerm => &self.c_term,
+            SequencePosition::Index(_) => &[],
+        };
+        let options = if modifications
+            .iter()
+            .any(|m| !matches!(m, Modification::Ambiguous { .. }))
+        {
+            Vec::new()
+        } else {
+            modifications
+                .iter()
+                .filter_map(|m| match m {
+                    Modification::Ambiguous {
+                        id, modification, ..
+                    } if !placed[*id] => {
+                        let mut placed = placed.to_vec();
+                        placed[*id] = true;
+                        Some((
+                            placed,
+                            Multi::from(modification.formula_inner(position, peptidoform_index)),
+                        ))
+                    }
+                    _ => None,
+                })
+                .collect_vec()
+        };
+        if options.is_empty() {
+            vec![(placed.to_vec(), Multi::default())]
+        } else {
+            options
+        }
+    }
+
+    /// The mass of the C terminal modifications. Multiple modifications are alternatives, see
+    /// [`Self::get_c_term`]. The global isotope modifications are NOT applied.
     fn get_c_term_mass(
         &self,
         all_peptides: &[Peptidoform<Linked>],
@@ -446,28 +919,37 @@ impl<Complexity> Peptidoform<Complexity> {
         allow_ms_cleavable: bool,
         peptidoform_index: usize,
     ) -> Multi<MolecularFormula> {
-        self.c_term.iter().fold(Multi::default(), |acc, f| {
-            if let Modification::Ambiguous { .. } = f {
-                acc
-            } else {
-                acc * f
-                    .formula_inner(
-                        all_peptides,
-                        visited_peptides,
-                        applied_cross_links,
-                        allow_ms_cleavable,
-                        SequencePosition::CTerm,
-                        peptidoform_index,
-                    )
-                    .0
-            }
-        }) + molecular_formula!(H 1 O 1)
+        let alternatives: Vec<MolecularFormula> = self
+            .c_term
+            .iter()
+            .filter(|m| !matches!(m, Modification::Ambiguous { .. }))
+            .flat_map(|m| {
+                m.formula_inner(
+                    all_peptides,
+                    visited_peptides,
+                    applied_cross_links,
+                    allow_ms_cleavable,
+                    SequencePosition::CTerm,
+                    peptidoform_index,
+                )
+                .0
+                .to_vec()
+            })
+            .collect();
+        let alternatives = if alternatives.is_empty() {
+            Multi::default()
+        } else {
+            Multi::from(alternatives)
+        };
+        alternatives + molecular_formula!(H 1 O 1)
     }
 
-    /// Find all neutral losses in the given stretch of peptide (loss, peptide index, sequence index)
+    /// Find all neutral losses in the given stretch of peptide (loss, peptide index, sequence index).
+    /// The custom losses for modifications take precedence over the losses defined by the modifications.
     fn potential_neutral_losses(
         &self,
         range: impl RangeBounds<usize>,
+        modification_neutral_losses: &[(SimpleModification, Vec<NeutralLoss>)],
         all_peptides: &[Peptidoform<Linked>],
         peptidoform_index: usize,
         ignore_peptides: &mut Vec<usize>,
@@ -480,8 +962,23 @@ impl<Complexity> Peptidoform<Complexity> {
                 aa.modifications
                     .iter()
                     .filter_map(|modification| match modification {
-                        Modification::Simple(modification)
+                        Modification::Simple { modification, .. }
                         | Modification::Ambiguous { modification, .. } => match &**modification {
+                            _ if modification_neutral_losses
+                                .iter()
+                                .any(|(m, _)| m == modification) =>
+                            {
+                                Some(
+                                    modification_neutral_losses
+                                        .iter()
+                                        .filter(|(m, _)| m == modification)
+                                        .flat_map(|(_, losses)| losses)
+                                        .map(|loss| {
+                                            (loss.clone(), peptidoform_index, pos.sequence_index)
+                                        })
+                                        .collect_vec(),
+                                )
+                            }
                             SimpleModificationInner::Database { specificities, .. } => Some(
                                 specificities
                                     .iter()
@@ -529,7 +1026,13 @@ impl<Complexity> Peptidoform<Complexity> {
         own_losses
             .into_iter()
             .chain(found_peptides.into_iter().flat_map(|p| {
-                all_peptides[p].potential_neutral_losses(.., all_peptides, p, ignore_peptides)
+                all_peptides[p].potential_neutral_losses(
+                    ..,
+                    modification_neutral_losses,
+                    all_peptides,
+                    p,
+                    ignore_peptides,
+                )
             }))
             .collect()
     }
@@ -763,6 +1266,11 @@ impl<Complexity> Peptidoform<Complexity> {
             .into();
 
         let mut output = Vec::with_capacity(20 * self.sequence.len() + 75); // Empirically derived required size of the buffer (Derived from Hecklib)
+        let c_terminal_loss = if model.c_terminal_water_loss {
+            molecular_formula!(H 2 O 1)
+        } else {
+            MolecularFormula::default()
+        };
         for sequence_index in 0..self.sequence.len() {
             let position = PeptidePosition::n(SequencePosition::Index(sequence_index), self.len());
             let mut cross_links = Vec::new();
@@ -778,6 +1286,7 @@ impl<Complexity> Peptidoform<Complexity> {
                     peptidoform_index,
                 ),
                 model.modification_specific_neutral_losses,
+                &model.modification_neutral_losses,
                 all_peptides,
                 &visited_peptides,
                 &mut cross_links,
@@ -787,14 +1296,15 @@ impl<Complexity> Peptidoform<Complexity> {
             let (c_term, c_term_seen) = self.all_masses(
                 sequence_index..,
                 sequence_index + 1..,
-                &self.get_c_term_mass(
+                &(self.get_c_term_mass(
                     all_peptides,
                     &visited_peptides,
                     &mut cross_links,
                     model.allow_cross_link_cleavage,
                     peptidoform_index,
-                ),
+                ) - c_terminal_loss.clone()),
                 model.modification_specific_neutral_losses,
+                &model.modification_neutral_losses,
                 all_peptides,
                 &visited_peptides,
                 &mut cross_links,
@@ -864,7 +1374,8 @@ impl<Complexity> Peptidoform<Complexity> {
                             .flat_map(|aa| {
                                 Fragment::generate_all(
                                     &((-modifications_total.clone()) + m.clone() - aa.clone()
-                                        + molecular_formula!(C 2 H 2 N 1 O 1)),
+                                        + molecular_formula!(C 2 H 2 N 1 O 1)
+                                        - c_terminal_loss.clone()),
                                     peptidoform_ion_index,
                                     peptidoform_index,
                                     &FragmentType::PrecursorSideChainLoss(
@@ -890,19 +1401,27 @@ impl<Complexity> Peptidoform<Complexity> {
         }
 
         // Generate precursor peak
-        let (full_precursor, _all_cross_links) = self.formulas_inner(
-            peptidoform_index,
-            all_peptides,
-            &[],
-            &mut Vec::new(),
-            model.allow_cross_link_cleavage,
-        );
+        let full_precursor =
+            self.formulas_inner(
+                peptidoform_index,
+                all_peptides,
+                &[],
+                &mut Vec::new(),
+                model.allow_cross_link_cleavage,
+            )
+            .0 - c_terminal_loss.clone();
         // Allow neutral losses from modifications for the precursor
         let mut precursor_neutral_losses = if model.modification_specific_neutral_losses {
-            self.potential_neutral_losses(.., all_peptides, peptidoform_index, &mut Vec::new())
-                .into_iter()
-                .map(|(n, _, _)| n)
-                .collect_vec()
+            self.potential_neutral_losses(
+                ..,
+                &model.modification_neutral_losses,
+                all_peptides,
+                peptidoform_index,
+                &mut Vec::new(),
+            )
+            .into_iter()
+            .map(|(n, _, _)| n)
+            .collect_vec()
         } else {
             Vec::new()
         };
@@ -922,15 +1441,15 @@ impl<Complexity> Peptidoform<Complexity> {
         // Add glycan fragmentation to all peptide fragments
         // Assuming that only one glycan can ever fragment at the same time,
         // and that no peptide fragmentation occurs during glycan fragmentation
-        let full_formula = self
-            .formulas_inner(
+        let full_formula =
+            self.formulas_inner(
                 peptidoform_index,
                 all_peptides,
                 &[],
                 &mut Vec::new(),
                 model.allow_cross_link_cleavage,
             )
-            .0;
+            .0 - c_terminal_loss;
         for (sequence_index, position) in self.sequence.iter().enumerate() {
             let attachment = (position.aminoacid.aminoacid(), sequence_index);
             for modification in &position.modifications {
@@ -959,6 +1478,7 @@ impl<Complexity> Peptidoform<Complexity> {
                         deviation: None,
                         confidence: None,
                         auxiliary: false,
+                        secondary_weight: None,
                     }
                     .with_charge_range(
                         &mut charge_carriers,
@@ -1005,6 +1525,21 @@ impl<Complexity> Peptidoform<Complexity> {
             }
         }
 
+        // Remove all fragments outside of the detector range or below the minimal mass
+        output.retain(|fragment| {
+            fragment
+                .mz(MassMode::Monoisotopic)
+                .map_or(true, |mz| model.mz_range.contains(&mz))
+                && model.heavy_enough(fragment, MassMode::Monoisotopic)
+        });
+
+        // Mark all fragments from secondary ion series
+        for fragment in &mut output {
+            if let Some(weight) = model.secondary_weight(fragment.ion.kind()) {
+                fragment.secondary_weight = Some(weight);
+            }
+        }
+
         output
     }
 
@@ -1018,6 +1553,7 @@ impl<Complexity> Peptidoform<Complexity> {
         aa_range: impl RangeBounds<usize> + Clone,
         base: &Multi<MolecularFormula>,
         apply_neutral_losses: bool,
+        modification_neutral_losses: &[(SimpleModification, Vec<NeutralLoss>)],
         all_peptides: &[Peptidoform<Linked>],
         visited_peptides: &[usize],
         applied_cross_links: &mut Vec<CrossLinkName>,
@@ -1037,6 +1573,7 @@ impl<Complexity> Peptidoform<Complexity> {
         if apply_neutral_losses {
             let neutral_losses = self.potential_neutral_losses(
                 range,
+                modification_neutral_losses,
                 all_peptides,
                 peptidoform_index,
                 &mut Vec::new(),
@@ -1045,7 +1582,11 @@ impl<Complexity> Peptidoform<Complexity> {
                 Vec::with_capacity(ambiguous_mods_masses.len() * (1 + neutral_losses.len()));
             all_masses.extend(ambiguous_mods_masses.iter().cloned());
             for loss in &neutral_losses {
-                all_masses.extend((ambiguous_mods_masses.clone() + loss.0.clone()).to_vec());
+                all_masses.extend(
+                    (ambiguous_mods_masses.clone() + loss.0.clone())
+                        .with_label(&AmbiguousLabel::NeutralLoss(loss.0.clone()))
+                        .to_vec(),
+                );
             }
             (all_masses.into(), seen)
         } else {
@@ -1110,7 +1651,7 @@ impl<Complexity> Peptidoform<Complexity> {
         );
         let mut new_visited_peptides = vec![peptidoform_index];
         new_visited_peptides.extend_from_slice(visited_peptides);
-        let mut formulas: Multi<MolecularFormula> = self.get_n_term_mass(
+        let formulas: Multi<MolecularFormula> = self.get_n_term_mass(
             all_peptides,
             visited_peptides,
             applied_cross_links,
@@ -1123,23 +1664,44 @@ impl<Complexity> Peptidoform<Complexity> {
             allow_ms_cleavable,
             peptidoform_index,
         );
-        let mut placed = vec![false; self.modifications_of_unknown_position.len()];
         let mut seen = HashSet::new();
-        for (index, pos) in self.sequence.iter().enumerate() {
-            let (pos_f, pos_seen) = pos.formulas_greedy(
-                &mut placed,
-                all_peptides,
-                &new_visited_peptides,
-                applied_cross_links,
-                allow_ms_cleavable,
-                SequencePosition::Index(index),
-                peptidoform_index,
-            );
-            formulas *= pos_f;
-            seen.extend(pos_seen);
+        let mut all_applied_cross_links = Vec::new();
+        let mut all_formulas = Vec::new();
+        for (mut placed, n_term) in self.terminal_ambiguous_options(
+            &vec![false; self.modifications_of_unknown_position.len()],
+            SequencePosition::NTerm,
+            peptidoform_index,
+        ) {
+            let mut option_applied_cross_links = applied_cross_links.clone();
+            let mut option = &formulas * n_term;
+            for (index, pos) in self.sequence.iter().enumerate() {
+                let (pos_f, pos_seen) = pos.formulas_greedy(
+                    &mut placed,
+                    all_peptides,
+                    &new_visited_peptides,
+                    &mut option_applied_cross_links,
+                    allow_ms_cleavable,
+                    SequencePosition::Index(index),
+                    peptidoform_index,
+                );
+                option *= pos_f;
+                seen.extend(pos_seen);
+            }
+            option *= self
+                .terminal_ambiguous_options(&placed, SequencePosition::CTerm, peptidoform_index)
+                .into_iter()
+                .flat_map(|(_, c_term)| c_term.to_vec())
+                .collect::<Multi<_>>();
+            all_formulas.extend(option.iter().cloned());
+            for name in option_applied_cross_links {
+                if !all_applied_cross_links.contains(&name) {
+                    all_applied_cross_links.push(name);
+                }
+            }
         }
+        *applied_cross_links = all_applied_cross_links;
 
-        (formulas
+        (all_formulas
             .iter()
             .map(|f| f.with_global_isotope_modifications(&self.global).expect("Global isotope modification invalid in determination of all formulas for a peptide"))
             .collect(), seen)
@@ -1172,8 +1734,9 @@ impl<Complexity> Peptidoform<Complexity> {
             write!(f, "{{{labile}}}")?;
         }
         // Write any modification of unknown position that has no preferred location at the start of the peptide
-        let mut any_ambiguous = false;
+        let mut unplaced_ambiguous = Vec::new();
         let mut placed_ambiguous = Vec::new();
+        let mut hidden_ambiguous = Vec::new();
         let mut preferred_ambiguous_position =
             vec![None; self.modifications_of_unknown_position.len()];
         for (id, ambiguous) in self.modifications_of_unknown_position.iter().enumerate() {
@@ -1247,19 +1810,34 @@ impl<Complexity> Peptidoform<Complexity> {
                     None => None,
                 };
                 if let Some(m) = m {
-                    write!(f, "[")?;
-                    m.display(f, specification_compliant, true)?;
-                    write!(f, "]")?;
+                    let mut text = String::new();
+                    match m {
+                        // Without an explicit group the modification is placed on the same
+                        // positions when parsed again, so the positions do not have to be written
+                        Modification::Ambiguous { modification, .. } if ambiguous.generated => {
+                            modification.display(&mut text, specification_compliant)?;
+                            hidden_ambiguous.push(id);
+                        }
+                        _ => m.display(&mut text, specification_compliant, true)?,
+                    }
+                    unplaced_ambiguous.push(text);
                     placed_ambiguous.push(id);
-                    any_ambiguous = true;
                 }
             }
         }
-        if any_ambiguous {
+        // Multiple copies of the same modification (as parsed from `[Phospho]^2?`) are written with a multiplicity
+        for (copies, text) in unplaced_ambiguous.iter().dedup_with_count() {
+            write!(f, "[{text}]")?;
+            if copies > 1 {
+                write!(f, "^{copies}")?;
+            }
+        }
+        if !unplaced_ambiguous.is_empty() {
             write!(f, "?")?;
         }
+        let is_hidden = |m: &Modification| matches!(m, Modification::Ambiguous { id, .. } if hidden_ambiguous.contains(id));
         let mut any_n = false;
-        for m in self.get_n_term() {
+        for m in self.get_n_term().iter().filter(|m| !is_hidden(m)) {
             let mut display_ambiguous = false;
 
             if let Modification::Ambiguous { id, .. } = m {
@@ -1277,6 +1855,10 @@ impl<Complexity> Peptidoform<Complexity> {
             write!(f, "]")?;
             any_n = true;
         }
+        for tag in &self.n_term_info {
+            write!(f, "[INFO:{tag}]")?;
+            any_n = true;
+        }
         if any_n {
             write!(f, "-")?;
         }
@@ -1284,11 +1866,14 @@ impl<Complexity> Peptidoform<Complexity> {
         for (index, position) in self.sequence.iter().enumerate() {
             placed_ambiguous.extend(position.display(
                 f,
-                &placed_ambiguous,
-                &preferred_ambiguous_position,
+                &DisplayOptions {
+                    placed_ambiguous: &placed_ambiguous,
+                    preferred_ambiguous_location: &preferred_ambiguous_position,
+                    hidden_ambiguous: &hidden_ambiguous,
+                    specification_compliant,
+                },
                 index,
                 last_ambiguous,
-                specification_compliant,
             )?);
             last_ambiguous = position.ambiguous;
         }
@@ -1296,7 +1881,7 @@ impl<Complexity> Peptidoform<Complexity> {
             write!(f, ")")?;
         }
         let mut first = true;
-        for m in self.get_c_term() {
+        for m in self.get_c_term().iter().filter(|m| !is_hidden(m)) {
             let mut display_ambiguous = false;
             if let Modification::Ambiguous { id, .. } = m {
                 display_ambiguous = !placed_ambiguous.contains(id);
@@ -1310,6 +1895,13 @@ impl<Complexity> Peptidoform<Complexity> {
             m.display(f, specification_compliant, display_ambiguous)?;
             write!(f, "]")?;
         }
+        for tag in &self.c_term_info {
+            if first {
+                write!(f, "-")?;
+                first = false;
+            }
+            write!(f, "[INFO:{tag}]")?;
+        }
         if let Some(c) = &self.charge_carriers {
             write!(f, "/{c}")?;
         }
@@ -1322,6 +1914,8 @@ impl<Complexity> Peptidoform<Complexity> {
         Self {
             n_term: self.c_term.clone(),
             c_term: self.n_term.clone(),
+            n_term_info: self.c_term_info.clone(),
+            c_term_info: self.n_term_info.clone(),
             sequence: self.sequence.clone().into_iter().rev().collect(),
             modifications_of_unknown_position: self
                 .modifications_of_unknown_position
@@ -1339,13 +1933,143 @@ impl<Complexity> Peptidoform<Complexity> {
             ..self.clone()
         }
     }
+    /// Get the pseudo reverse of this peptide, all amino acids are reversed except for the
+    /// C-terminal amino acid (which is the cleavage site for most proteases), the terminal
+    /// modifications stay on their terminus.
+    #[must_use]
+    pub fn pseudo_reverse(&self) -> Self {
+        let last = self.len().saturating_sub(1);
+        let pseudo_reverse = |position: SequencePosition| match position {
+            SequencePosition::Index(i) if i < last => SequencePosition::Index(last - 1 - i),
+            other => other,
+        };
+        Self {
+            sequence: self.sequence[..last]
+                .iter()
+                .rev()
+                .chain(self.sequence.get(last))
+                .cloned()
+                .collect(),
+            modifications_of_unknown_position: self
+                .modifications_of_unknown_position
+                .iter()
+                .cloned()
+                .map(|m| AmbiguousEntry {
+                    positions: m.positions.into_iter().map(pseudo_reverse).collect(),
+                    ..m
+                })
+                .collect(),
+            ..self.clone()
+        }
+    }
+
+    /// Get a shuffled version of this peptide, the modifications stay on their amino acids and
+    /// the terminal modifications stay on their terminus. The shuffle is deterministic for a
+    /// given seed. If `fixed_c_term` is set the C-terminal amino acid (which is the cleavage site
+    /// for most proteases) stays in place.
+    #[must_use]
+    pub fn shuffle(&self, seed: u64, fixed_c_term: bool) -> Self {
+        let movable = if fixed_c_term {
+            self.len().saturating_sub(1)
+        } else {
+            self.len()
+        };
+        // Fisher-Yates shuffle driven by splitmix64, `order[new] = old`
+        let mut state = seed;
+        let mut order = (0..self.len()).collect_vec();
+        for i in (1..movable).rev() {
+            state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+            let mut z = state;
+            z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+            z ^= z >> 31;
+            order.swap(i, (z % (i as u64 + 1)) as usize);
+        }
+        let mut new_index = vec![0; self.len()];
+        for (new, old) in order.iter().enumerate() {
+            new_index[*old] = new;
+        }
+        Self {
+            sequence: order.iter().map(|i| self.sequence[*i].clone()).collect(),
+            modifications_of_unknown_position: self
+                .modifications_of_unknown_position
+                .iter()
+                .cloned()
+                .map(|m| AmbiguousEntry {
+                    positions: m
+                        .positions
+                        .into_iter()
+                        .map(|position| match position {
+                            SequencePosition::Index(i) => SequencePosition::Index(new_index[i]),
+                            other => other,
+                        })
+                        .collect(),
+                    ..m
+                })
+                .collect(),
+            ..self.clone()
+        }
+    }
+
     /// Get all labile modifications
     pub(super) fn get_labile_mut_inner(&mut self) -> &mut Vec<SimpleModification> {
         &mut self.labile
     }
+
+    /// Get the modifications placed at the given position mutably
+    pub(super) fn modifications_mut(&mut self, position: SequencePosition) -> &mut [Modification] {
+        match position {
+            SequencePosition::NTerm => &mut self.n_term,
+            SequencePosition::CTerm => &mut self.c_term,
+            SequencePosition::Index(index) => &mut self.sequence[index].modifications,
+        }
+    }
+
+    /// Get the free text information tags given on their own on the given position mutably
+    pub(super) fn info_mut(&mut self, position: SequencePosition) -> &mut ThinVec<String> {
+        match position {
+            SequencePosition::NTerm => &mut self.n_term_info,
+            SequencePosition::CTerm => &mut self.c_term_info,
+            SequencePosition::Index(index) => &mut self.sequence[index].info,
+        }
+    }
 }
 
 impl Peptidoform<Linked> {
+    /// Create a peptidoform from a plain protein sequence, as found in a fasta record, with the
+    /// given fixed modifications applied (e.g. carbamidomethyl on all C). Any whitespace in the
+    /// sequence is ignored.
+    /// # Errors
+    /// If any of the characters in the sequence is not an amino acid.
+    pub fn from_fasta_record(
+        sequence: &str,
+        fixed_modifications: &[(SimpleModification, PlacementRule)],
+    ) -> Result<Self, CustomError> {
+        let peptidoform: Peptidoform<SemiAmbiguous> = sequence
+            .lines()
+            .enumerate()
+            .flat_map(|(line_index, line)| {
+                line.char_indices()
+                    .filter(|(_, c)| !c.is_ascii_whitespace())
+                    .map(move |(offset, c)| {
+                        AminoAcid::try_from(c)
+                            .map(|aa| SequenceElement::new(aa.into(), None))
+                            .map_err(|()| {
+                                CustomError::error(
+                                    "Invalid protein sequence",
+                                    "This character is not an amino acid",
+                                    Context::line(Some(line_index), line, offset, c.len_utf8()),
+                                )
+                            })
+                    })
+            })
+            .collect::<Result<Vec<SequenceElement<SemiAmbiguous>>, _>>()?
+            .into();
+        Ok(peptidoform
+            .apply_fixed_modifications(fixed_modifications)
+            .into())
+    }
+
     /// Add a modification to this peptide
     pub(crate) fn add_modification(
         &mut self,
@@ -1380,27 +2104,100 @@ impl Peptidoform<Linear> {
 }
 
 impl<Complexity: AtMax<Linear>> Peptidoform<Complexity> {
-    /// Get a region of this peptide as a new peptide (with all terminal/global/ambiguous modifications).
+    /// Get a region of this peptide as a new peptide. The terminal modifications are only kept if
+    /// the region contains that terminus. The global and labile modifications and the charge
+    /// carriers are kept. Ambiguous modifications are only kept if all their possible positions
+    /// are contained in the region, otherwise all their positions are dropped.
     #[must_use]
     pub fn sub_peptide(&self, index: impl RangeBounds<usize>) -> Self {
+        let start = match index.start_bound() {
+            Bound::Included(s) => *s,
+            Bound::Excluded(s) => s + 1,
+            Bound::Unbounded => 0,
+        };
+        let end = match index.end_bound() {
+            Bound::Included(e) => e + 1,
+            Bound::Excluded(e) => *e,
+            Bound::Unbounded => self.len(),
+        };
+        let contains = |position: &SequencePosition| match position {
+            SequencePosition::NTerm => start == 0,
+            SequencePosition::Index(i) => (start..end).contains(i),
+            SequencePosition::CTerm => end == self.len(),
+        };
+        // The new id for all ambiguous modifications that are fully contained
+        let mut ids = Vec::with_capacity(self.modifications_of_unknown_position.len());
+        let mut modifications_of_unknown_position = Vec::new();
+        for entry in &self.modifications_of_unknown_position {
+            if entry.positions.iter().all(contains) {
+                ids.push(Some(modifications_of_unknown_position.len()));
+                modifications_of_unknown_position.push(AmbiguousEntry {
+                    positions: entry
+                        .positions
+                        .iter()
+                        .map(|p| match p {
+                            SequencePosition::Index(i) => SequencePosition::Index(i - start),
+                            other => *other,
+                        })
+                        .collect(),
+                    ..entry.clone()
+                });
+            } else {
+                ids.push(None);
+            }
+        }
+        let update = |modifications: &[Modification]| -> Vec<Modification> {
+            modifications
+                .iter()
+                .filter_map(|m| match m {
+                    Modification::Ambiguous {
+                        id,
+                        group,
+                        modification,
+                        localisation_score,
+                        preferred,
+                        info,
+                    } => ids[*id].map(|id| Modification::Ambiguous {
+                        id,
+                        group: group.clone(),
+                        modification: modification.clone(),
+                        localisation_score: *localisation_score,
+                        preferred: *preferred,
+                        info: info.clone(),
+                    }),
+                    m => Some(m.clone()),
+                })
+                .collect()
+        };
         Self {
-            n_term: if index.contains(&0) {
-                self.n_term.clone()
+            n_term: if start == 0 {
+                update(&self.n_term)
             } else {
                 Vec::new()
             },
-            c_term: if index.contains(&(self.len() - 1)) {
-                self.c_term.clone()
+            c_term: if end == self.len() {
+                update(&self.c_term)
             } else {
                 Vec::new()
             },
-            sequence: self.sequence[(index.start_bound().cloned(), index.end_bound().cloned())]
-                .to_vec(),
+            sequence: self.sequence[start..end]
+                .iter()
+                .map(|s| {
+                    let mut s = s.clone();
+                    s.modifications = update(&s.modifications).into();
+                    s
+                })
+                .collect(),
+            modifications_of_unknown_position,
             ..self.clone()
         }
     }
 
-    /// Digest this sequence with the given protease and the given maximal number of missed cleavages.
+    /// Digest this sequence with the given protease and the given maximal number of missed
+    /// cleavages. The terminal modifications are only kept on the peptides that contain that
+    /// terminus, see [`Self::sub_peptide`] for the handling of the other modifications. To not
+    /// cut before proline (as for trypsin) see [`Protease::c_terminal_of_except`], for the common
+    /// proteases see [`Protease::by_name`].
     pub fn digest(&self, protease: &Protease, max_missed_cleavages: usize) -> Vec<Self> {
         let mut sites = vec![0];
         sites.extend_from_slice(&protease.match_locations(&self.sequence));
@@ -1409,7 +2206,7 @@ impl<Complexity: AtMax<Linear>> Peptidoform<Complexity> {
         let mut result = Vec::new();
 
         for (index, start) in sites.iter().enumerate() {
-            for end in sites.iter().skip(index).take(max_missed_cleavages + 1) {
+            for end in sites.iter().skip(index + 1).take(max_missed_cleavages + 1) {
                 result.push(self.sub_peptide((*start)..*end));
             }
         }
@@ -1448,34 +2245,186 @@ impl<Complexity: AtMax<Linear>> Peptidoform<Complexity> {
     /// Gives the formulas for the whole peptide. With the global isotope modifications applied. (Any B/Z will result in multiple possible formulas.)
     #[allow(clippy::missing_panics_doc)] // Can not panic (unless state is already corrupted)
     pub fn formulas(&self) -> Multi<MolecularFormula> {
-        let mut formulas: Multi<MolecularFormula> =
+        let terminals: Multi<MolecularFormula> =
             self.get_n_term_mass(&[], &[], &mut Vec::new(), false, 0)
                 * self.get_c_term_mass(&[], &[], &mut Vec::new(), false, 0);
-        let mut placed = vec![false; self.modifications_of_unknown_position.len()];
+        self.terminal_ambiguous_options(
+            &vec![false; self.modifications_of_unknown_position.len()],
+            SequencePosition::NTerm,
+            0,
+        )
+        .into_iter()
+        .flat_map(|(mut placed, n_term)| {
+            let mut formulas = &terminals * n_term;
+            for (index, pos) in self.sequence.iter().enumerate() {
+                formulas *= pos
+                    .formulas_greedy(
+                        &mut placed,
+                        &[],
+                        &[],
+                        &mut Vec::new(),
+                        false,
+                        SequencePosition::Index(index),
+                        0,
+                    )
+                    .0;
+            }
+            formulas *= self
+                .terminal_ambiguous_options(&placed, SequencePosition::CTerm, 0)
+                .into_iter()
+                .flat_map(|(_, c_term)| c_term.to_vec())
+                .collect::<Multi<_>>();
+            formulas.to_vec()
+        })
+            .map(|f| f.with_global_isotope_modifications(&self.global).expect("Global isotope modification invalid in determination of all formulas for a peptide"))
+            .collect()
+    }
+
+    /// Gives the formulas for the precursor, so the whole peptide including all labile
+    /// modifications. With the global isotope modifications applied. (Any B/Z will result in
+    /// multiple possible formulas.) Use [`Self::fragment_backbone_formula`] for the formulas
+    /// of the bare backbone without any modifications.
+    #[allow(clippy::missing_panics_doc)] // Can not panic (unless state is already corrupted)
+    pub fn precursor_formula(&self) -> Multi<MolecularFormula> {
+        let labile = self
+            .labile
+            .iter()
+            .map(|modification| modification.formula())
+            .sum::<MolecularFormula>()
+            .with_global_isotope_modifications(&self.global)
+            .expect("Global isotope modification invalid in determination of the labile formula for a peptide");
+        self.formulas().iter().map(|f| f + &labile).collect()
+    }
+
+    /// Gives the formulas for the bare peptide backbone, so only the amino acids with the
+    /// terminal water, without any side chain, terminal, or labile modifications. With the global
+    /// isotope modifications applied. (Any B/Z will result in multiple possible formulas.)
+    #[allow(clippy::missing_panics_doc)] // Can not panic (unless state is already corrupted)
+    pub fn fragment_backbone_formula(&self) -> Multi<MolecularFormula> {
+        let mut formulas: Multi<MolecularFormula> = molecular_formula!(H 2 O 1).into();
         for (index, pos) in self.sequence.iter().enumerate() {
             formulas *= pos
-                .formulas_greedy(
-                    &mut placed,
-                    &[],
-                    &[],
-                    &mut Vec::new(),
-                    false,
-                    SequencePosition::Index(index),
-                    0,
-                )
-                .0;
+                .aminoacid
+                .formulas_inner(SequencePosition::Index(index), 0);
         }
-
         formulas
             .iter()
-            .map(|f| f.with_global_isotope_modifications(&self.global).expect("Global isotope modification invalid in determination of all formulas for a peptide"))
+            .map(|f| {
+                f.with_global_isotope_modifications(&self.global)
+                    .expect("Invalid global isotope modification in fragment_backbone_formula")
+            })
             .collect()
     }
 
+    /// Gives the contribution of every residue (including its modifications) to the formula of
+    /// the whole peptide. The terminal modifications are listed as separate entries at
+    /// [`SequencePosition::NTerm`] and [`SequencePosition::CTerm`], but only if any are present.
+    /// The terminal water is not included, so the sum of all formulas plus water is the formula
+    /// of the whole peptide. With the global isotope modifications applied. For residues with
+    /// multiple possible formulas (B/Z) only the first option is given.
+    #[allow(clippy::missing_panics_doc)] // Can not panic (unless state is already corrupted)
+    pub fn residue_formulas(&self) -> Vec<(SequencePosition, MolecularFormula)> {
+        let first = |formulas: Multi<MolecularFormula>| {
+            formulas
+                .iter()
+                .next()
+                .cloned()
+                .unwrap_or_default()
+                .with_global_isotope_modifications(&self.global)
+                .expect("Global isotope modification invalid in determination of residue formulas for a peptide")
+        };
+        let mut result = Vec::with_capacity(self.len() + 2);
+        let (mut placed, n_term) = self
+            .terminal_ambiguous_options(
+                &vec![false; self.modifications_of_unknown_position.len()],
+                SequencePosition::NTerm,
+                0,
+            )
+            .remove(0);
+        if !self.n_term.is_empty() {
+            result.push((
+                SequencePosition::NTerm,
+                first(
+                    self.get_n_term_mass(&[], &[], &mut Vec::new(), false, 0) * n_term
+                        - molecular_formula!(H 1),
+                ),
+            ));
+        }
+        for (index, pos) in self.sequence.iter().enumerate() {
+            result.push((
+                SequencePosition::Index(index),
+                first(
+                    pos.formulas_greedy(
+                        &mut placed,
+                        &[],
+                        &[],
+                        &mut Vec::new(),
+                        false,
+                        SequencePosition::Index(index),
+                        0,
+                    )
+                    .0,
+                ),
+            ));
+        }
+        if !self.c_term.is_empty() {
+            result.push((
+                SequencePosition::CTerm,
+                first(
+                    self.get_c_term_mass(&[], &[], &mut Vec::new(), false, 0)
+                        * self
+                            .terminal_ambiguous_options(&placed, SequencePosition::CTerm, 0)
+                            .remove(0)
+                            .1
+                        - molecular_formula!(H 1 O 1),
+                ),
+            ));
+        }
+        result
+    }
+
     /// Gives all the formulas for the whole peptide with no C and N terminal modifications. With the global isotope modifications applied.
     pub fn bare_formulas(&self) -> Multi<MolecularFormula> {
         self.bare_formulas_inner(&[], &[], &mut Vec::new(), false, 0)
     }
+
+    /// Find the first of the given target masses that matches the monoisotopic mass of this
+    /// peptide within the tolerance, allowing for the given isotope errors (the number of ¹³C
+    /// atoms picked instead of the monoisotopic peak). Returns the index of the matched target
+    /// and the isotope offset. For each target the isotope offsets are tried in order.
+    #[allow(clippy::missing_panics_doc)] // Carbon 13 is always defined
+    pub fn matches_any(
+        &self,
+        masses: &[Mass],
+        tolerance: Tolerance<Mass>,
+        isotope_errors: RangeInclusive<i8>,
+    ) -> Option<(usize, i8)> {
+        let isotope_spacing =
+            Element::C.mass(NonZeroU16::new(13)).unwrap() - Element::C.mass(None).unwrap();
+        let peptide_masses = self
+            .formulas()
+            .iter()
+            .map(MolecularFormula::monoisotopic_mass)
+            .collect_vec();
+        masses.iter().enumerate().find_map(|(index, target)| {
+            isotope_errors.clone().find_map(|offset| {
+                peptide_masses
+                    .iter()
+                    .any(|mass| {
+                        tolerance.within(target, &(*mass + isotope_spacing * f64::from(offset)))
+                    })
+                    .then_some((index, offset))
+            })
+        })
+    }
+
+    /// The difference between the average weight and the monoisotopic mass of this peptide in
+    /// dalton. This grows with the size of the peptide, as more heavy isotopes are expected. If
+    /// the peptide has multiple formulas the first is used.
+    pub fn mass_defect(&self) -> f64 {
+        let formula = &self.formulas()[0];
+        (formula.average_weight() - formula.monoisotopic_mass()).value
+    }
 }
 
 impl Peptidoform<UnAmbiguous> {
@@ -1499,6 +2448,45 @@ impl Peptidoform<UnAmbiguous> {
         assert_eq!(options.len(), 1);
         options.pop().unwrap()
     }
+
+    /// Gives the cumulative neutral masses of the N terminal (b) and C terminal (y) ion series
+    /// including all modifications, without generating the full fragments. The prefix masses are
+    /// the neutral b ion masses (N terminal residues and modifications) and the suffix masses are
+    /// the neutral y ion masses (C terminal residues and modifications plus water). Both are
+    /// indexed by series number minus one, so there is one mass for each cleavage site, giving
+    /// `len - 1` masses per series. Ambiguous amino acids (B/Z) have multiple possible ladders
+    /// and are not allowed in this complexity, see [`Peptidoform::into_unambiguous`].
+    pub fn mass_ladder(&self, mode: MassMode) -> (Vec<Mass>, Vec<Mass>) {
+        let mut n_term = Mass::default();
+        let mut c_term = molecular_formula!(H 2 O 1).mass(mode);
+        let mut residues = Vec::with_capacity(self.len());
+        for (position, formula) in self.residue_formulas() {
+            match position {
+                SequencePosition::NTerm => n_term += formula.mass(mode),
+                SequencePosition::CTerm => c_term += formula.mass(mode),
+                SequencePosition::Index(_) => residues.push(formula.mass(mode)),
+            }
+        }
+        let sites = self.len().saturating_sub(1);
+        let prefix = residues
+            .iter()
+            .take(sites)
+            .scan(n_term, |total, mass| {
+                *total += *mass;
+                Some(*total)
+            })
+            .collect();
+        let suffix = residues
+            .iter()
+            .rev()
+            .take(sites)
+            .scan(c_term, |total, mass| {
+                *total += *mass;
+                Some(*total)
+            })
+            .collect();
+        (prefix, suffix)
+    }
 }
 
 impl<Complexity: AtLeast<Linear>> Peptidoform<Complexity> {
@@ -1566,6 +2554,10 @@ impl<Complexity: AtLeast<SimpleLinear>> Peptidoform<Complexity> {
         range: impl RangeBounds<usize>,
         settings: &crate::MUPSettings,
     ) -> bool {
+        // Placed on all possible locations, so the positions are found again when parsed
+        let generated = matches!(range.start_bound(), Bound::Unbounded)
+            && matches!(range.end_bound(), Bound::Unbounded)
+            && settings.position.is_none();
         let possible_positions = self
             .iter(range)
             .filter(|(position, seq)| {
@@ -1587,14 +2579,19 @@ impl<Complexity: AtLeast<SimpleLinear>> Peptidoform<Complexity> {
             .map(|(position, _)| (position.sequence_index, None))
             .collect_vec();
 
-        self.add_ambiguous_modification(
+        let id = self.modifications_of_unknown_position.len();
+        let placed = self.add_ambiguous_modification(
             modification,
             None,
             &possible_positions,
             None,
             settings.limit,
             settings.colocalise_modifications_of_unknown_position,
-        )
+        );
+        if let Some(entry) = self.modifications_of_unknown_position.get_mut(id) {
+            entry.generated = generated;
+        }
+        placed
     }
 
     /// Add an ambiguous modification on the given positions, the placement rules are NOT checked.
@@ -1646,6 +2643,7 @@ impl<Complexity: AtLeast<SimpleLinear>> Peptidoform<Complexity> {
                                     modification: modification.clone(),
                                     localisation_score: *score,
                                     preferred: preferred_position.is_some_and(|p| p == *spos),
+                                    info: Vec::new(),
                                 });
                                 placed = true;
                                 *spos
@@ -1659,6 +2657,7 @@ impl<Complexity: AtLeast<SimpleLinear>> Peptidoform<Complexity> {
                                         modification: modification.clone(),
                                         localisation_score: *score,
                                         preferred: preferred_position.is_some_and(|p| p == *spos),
+                                        info: Vec::new(),
                                     });
                                 self.sequence[*pos].modifications.sort_unstable();
                                 placed = true;
@@ -1671,6 +2670,7 @@ impl<Complexity: AtLeast<SimpleLinear>> Peptidoform<Complexity> {
                                     modification: modification.clone(),
                                     localisation_score: *score,
                                     preferred: preferred_position.is_some_and(|p| p == *spos),
+                                    info: Vec::new(),
                                 });
                                 placed = true;
                                 *spos
@@ -1680,6 +2680,7 @@ impl<Complexity: AtLeast<SimpleLinear>> Peptidoform<Complexity> {
                     limit,
                     colocalise_modifications_of_unknown_position,
                     group: None,
+                    generated: false,
                 });
                 placed
             }
@@ -1689,8 +2690,8 @@ impl<Complexity: AtLeast<SimpleLinear>> Peptidoform<Complexity> {
 
 impl<OwnComplexity: AtMax<SemiAmbiguous>> Peptidoform<OwnComplexity> {
     /// Concatenate another peptide after this peptide. This will fail if any of these conditions are true:
-    /// * This peptide has a C terminal modification
-    /// * The other peptide has an N terminal modification
+    /// * This peptide has a C terminal modification or info tag
+    /// * The other peptide has an N terminal modification or info tag
     // Because it is complexity SemiAmbiguous these peptides are guaranteed to not contain charge
     // carriers, global or ambiguous modifications.
     pub fn concatenate<OtherComplexity: AtMax<SemiAmbiguous>>(
@@ -1700,12 +2701,18 @@ impl<OwnComplexity: AtMax<SemiAmbiguous>> Peptidoform<OwnComplexity> {
     where
         OwnComplexity: HighestOf<OtherComplexity>,
     {
-        if self.c_term.is_empty() && other.n_term.is_empty() {
+        if self.c_term.is_empty()
+            && self.c_term_info.is_empty()
+            && other.n_term.is_empty()
+            && other.n_term_info.is_empty()
+        {
             Some(Peptidoform::<OwnComplexity::HighestLevel> {
                 global: self.global,
                 labile: self.labile.into_iter().chain(other.labile).collect(),
                 n_term: self.n_term,
                 c_term: other.c_term,
+                n_term_info: self.n_term_info,
+                c_term_info: other.c_term_info,
                 sequence: self
                     .sequence
                     .into_iter()
@@ -1739,6 +2746,8 @@ where
             labile: Vec::new(),
             n_term: Vec::new(),
             c_term: Vec::new(),
+            n_term_info: ThinVec::new(),
+            c_term_info: ThinVec::new(),
             sequence: value.into_iter().map(std::convert::Into::into).collect(),
             modifications_of_unknown_position: Vec::new(),
             charge_carriers: None,
@@ -1770,21 +2779,14 @@ impl<Complexity> Index<SequencePosition> for Peptidoform<Complexity> {
     type Output = SequenceElement<Complexity>;
 
     fn index(&self, index: SequencePosition) -> &Self::Output {
-        match index {
-            SequencePosition::NTerm => &self.sequence[0],
-            SequencePosition::Index(i) => &self.sequence[i],
-            SequencePosition::CTerm => self.sequence.last().unwrap(),
-        }
+        &self.sequence[index.as_index(self.len())]
     }
 }
 
 impl<Complexity> IndexMut<SequencePosition> for Peptidoform<Complexity> {
     fn index_mut(&mut self, index: SequencePosition) -> &mut Self::Output {
-        match index {
-            SequencePosition::NTerm => &mut self.sequence[0],
-            SequencePosition::Index(i) => &mut self.sequence[i],
-            SequencePosition::CTerm => self.sequence.last_mut().unwrap(),
-        }
+        let index = index.as_index(self.len());
+        &mut self.sequence[index]
     }
 }
 
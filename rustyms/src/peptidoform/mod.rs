@@ -3,12 +3,15 @@
 mod annotated;
 mod complexity;
 mod compound_peptidoform_ion;
+mod decoy;
 mod find_modifications;
 mod linear_peptide;
+mod modification_summary;
 mod parse;
 mod parse_modification;
 mod parse_sloppy;
 mod peptidoform_ion;
+mod terminal_chemistry;
 #[cfg(test)]
 mod tests;
 mod validate;
@@ -16,8 +19,11 @@ mod validate;
 pub use annotated::*;
 pub use complexity::*;
 pub use compound_peptidoform_ion::*;
+pub use decoy::*;
 pub use find_modifications::*;
 pub use linear_peptide::*;
+pub use modification_summary::*;
 pub use parse_modification::*;
 pub use parse_sloppy::SloppyParsingParameters;
 pub use peptidoform_ion::*;
+pub use terminal_chemistry::*;
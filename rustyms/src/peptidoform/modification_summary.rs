@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+
+/// The number of modifications on a peptidoform per category, see
+/// [`Peptidoform::modification_summary`](crate::Peptidoform::modification_summary). The location
+/// categories (terminal and side chain) count every modification with a defined location, the
+/// kind categories (variable, glycan, and cross-link) overlap with these. Modifications of
+/// unknown position are only counted as ambiguous.
+#[derive(
+    Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+pub struct ModificationSummary {
+    /// The number of modifications on the N terminus
+    pub n_terminal: usize,
+    /// The number of modifications on the C terminus
+    pub c_terminal: usize,
+    /// The number of modifications on the side chains of the amino acids
+    pub side_chain: usize,
+    /// The number of simple modifications with a defined location that are not glycans, these
+    /// are the modifications that are placed as variable modifications in a database search (e.g.
+    /// acetyl, phospho, or oxidation)
+    pub variable: usize,
+    /// The number of modifications of unknown position, every modification is counted once
+    /// regardless of the number of possible locations
+    pub ambiguous: usize,
+    /// The number of glycans (compositions, structures, or GNOme glycans) with a defined location
+    pub glycan: usize,
+    /// The number of cross-links and branches bound to this peptidoform
+    pub cross_link: usize,
+    /// The number of labile modifications
+    pub labile: usize,
+}
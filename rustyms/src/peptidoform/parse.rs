@@ -1,7 +1,6 @@
 use std::{collections::BTreeMap, num::NonZeroU16};
 
 use itertools::Itertools;
-use ordered_float::OrderedFloat;
 
 use crate::{
     error::{Context, CustomError},
@@ -17,7 +16,7 @@ use crate::{
     PeptidoformIon, SequenceElement, SequencePosition,
 };
 
-use super::{GlobalModification, Linear, ReturnModification, SemiAmbiguous};
+use super::{GlobalModification, Linear, ReturnModification, SemiAmbiguous, UnAmbiguous};
 
 #[derive(Debug, PartialEq, Eq)]
 enum End {
@@ -30,7 +29,7 @@ struct LinearPeptideResult {
     peptide: Peptidoform<Linear>,
     index: usize,
     ending: End,
-    cross_links: Vec<(usize, SequencePosition)>,
+    cross_links: Vec<(usize, SequencePosition, Vec<String>)>,
 }
 
 impl Peptidoform<Linked> {
@@ -128,6 +127,28 @@ impl CompoundPeptidoformIon {
         }
     }
 
+    /// Validate a compound peptidoform in the [ProForma specification](https://github.com/HUPO-PSI/ProForma)
+    /// without keeping the parsed structure. This is equivalent to [`Self::pro_forma`] but
+    /// reports the errors as a list. If the error has underlying errors (for example multiple
+    /// invalid unknown position modifications) these are all reported separately.
+    ///
+    /// # Errors
+    /// It gives all errors if the string is not a valid ProForma string.
+    pub fn validate(
+        value: &str,
+        custom_database: Option<&CustomDatabase>,
+    ) -> Result<(), Vec<CustomError>> {
+        Self::pro_forma(value, custom_database)
+            .map(|_| ())
+            .map_err(|error| {
+                if error.underlying_errors().is_empty() {
+                    vec![error]
+                } else {
+                    error.underlying_errors().to_vec()
+                }
+            })
+    }
+
     /// # Errors
     /// It returns an error if the line is not a supported ProForma line.
     fn parse_peptidoform(
@@ -139,7 +160,7 @@ impl CompoundPeptidoformIon {
         let mut peptides = Vec::new();
         let mut ending = End::CrossLink;
         let mut cross_link_lookup = Vec::new();
-        // Grouped on cross link id and stores peptide id, sequence index
+        // Grouped on cross link id and stores peptide id, sequence index, info tags
         let mut cross_links_found = BTreeMap::new();
 
         // Parse any following cross-linked species
@@ -169,7 +190,7 @@ impl CompoundPeptidoformIon {
                 cross_links_found
                     .entry(cross_link.0)
                     .or_insert(Vec::new())
-                    .push((peptides.len() - 1, cross_link.1));
+                    .push((peptides.len() - 1, cross_link.1, cross_link.2));
             }
         }
 
@@ -212,13 +233,9 @@ impl CompoundPeptidoformIon {
         let mut ambiguous_aa_counter = std::num::NonZeroU32::MIN;
         let mut ambiguous_aa = None;
         let mut ambiguous_lookup = Vec::new();
-        let mut cross_link_found_positions: Vec<(usize, SequencePosition)> = Vec::new();
-        let mut ambiguous_found_positions: Vec<(
-            SequencePosition,
-            bool,
-            usize,
-            Option<OrderedFloat<f64>>,
-        )> = Vec::new();
+        let mut cross_link_found_positions: Vec<(usize, SequencePosition, Vec<String>)> =
+            Vec::new();
+        let mut ambiguous_found_positions = Vec::new();
         let mut unknown_position_modifications = Vec::new();
         let mut ranged_unknown_position_modifications = Vec::new();
         let mut ending = End::Empty;
@@ -253,30 +270,38 @@ impl CompoundPeptidoformIon {
                     "No valid closing delimiter, an N terminal modification should be closed by ']-'",
                     Context::line(None, line, index, 1),
                 ))?;
-            if let Some(m) = SimpleModificationInner::try_from(
+            if let Some(m) = SimpleModificationInner::try_from_optional(
                 line,
                 index + 1..end_index - 1,
                 &mut ambiguous_lookup,
                 cross_link_lookup,
                 custom_database,
             )
-            .map(|m| match m.0 {
-                ReturnModification::Defined(simple) => Some(simple),
-                ReturnModification::CrossLinkReferenced(id) => {
-                    cross_link_found_positions.push((id, SequencePosition::NTerm));
+            .map(|(m, info, _)| match m {
+                None => {
+                    peptide.info_mut(SequencePosition::NTerm).extend(info);
+                    None
+                }
+                Some(ReturnModification::Defined(simple)) => Some(Modification::Simple {
+                    modification: simple,
+                    info,
+                }),
+                Some(ReturnModification::CrossLinkReferenced(id)) => {
+                    cross_link_found_positions.push((id, SequencePosition::NTerm, info));
                     None
                 }
-                ReturnModification::Ambiguous(id, localisation_score, preferred) => {
+                Some(ReturnModification::Ambiguous(id, localisation_score, preferred)) => {
                     ambiguous_found_positions.push((
                         SequencePosition::NTerm,
                         preferred,
                         id,
                         localisation_score,
+                        info,
                     ));
                     None
                 }
             })? {
-                peptide.add_simple_n_term(m);
+                peptide.set_n_term(vec![m]);
             }
             index = end_index + 1;
         }
@@ -378,7 +403,7 @@ impl CompoundPeptidoformIon {
                         "No valid closing delimiter",
                         Context::line(None, line, index, 1),
                     ))?;
-                    let (modification,_) = SimpleModificationInner::try_from(
+                    let (modification, info, _) = SimpleModificationInner::try_from_optional(
                         line, index + 1..end_index,
                         &mut ambiguous_lookup, cross_link_lookup, custom_database,
                     )?;
@@ -387,20 +412,22 @@ impl CompoundPeptidoformIon {
                     if is_c_term {
                         if let Some(m) =
                             match modification {
-                                ReturnModification::Defined(simple) => Ok(Some(simple)),
-                                ReturnModification::CrossLinkReferenced(id) =>
-                                    {cross_link_found_positions.push((id, SequencePosition::CTerm)); Ok(None)},
-                                ReturnModification::Ambiguous(id, localisation_score, preferred) => {
+                                None => {peptide.info_mut(SequencePosition::CTerm).extend(info); Ok(None)},
+                                Some(ReturnModification::Defined(simple)) => Ok(Some(Modification::Simple { modification: simple, info })),
+                                Some(ReturnModification::CrossLinkReferenced(id)) =>
+                                    {cross_link_found_positions.push((id, SequencePosition::CTerm, info)); Ok(None)},
+                                Some(ReturnModification::Ambiguous(id, localisation_score, preferred)) => {
                                     ambiguous_found_positions.push((
                                         SequencePosition::CTerm,
                                         preferred,
                                         id,
                                         localisation_score,
+                                        info,
                                     ));
                                     Ok(None)
                                 }
                             }? {
-                            peptide.add_simple_c_term(m);
+                            peptide.set_c_term(vec![m]);
                         }
 
                         if index + 1 < chars.len() && chars[index] == b'/' && chars[index+1] != b'/' {
@@ -421,11 +448,12 @@ impl CompoundPeptidoformIon {
 
                     if let Some((sequence_index, aa)) = peptide.sequence_mut().iter_mut().enumerate().next_back() {
                         match modification {
-                            ReturnModification::Defined(m) => aa.modifications.push(Modification::Simple(m)),
-                            ReturnModification::Ambiguous(id, localisation_score, preferred) =>
-                                ambiguous_found_positions.push((SequencePosition::Index(sequence_index), preferred, id, localisation_score)),
-                            ReturnModification::CrossLinkReferenced(id) =>
-                                cross_link_found_positions.push((id, SequencePosition::Index(sequence_index))),
+                            None => aa.info.extend(info),
+                            Some(ReturnModification::Defined(m)) => aa.modifications.push(Modification::Simple { modification: m, info }),
+                            Some(ReturnModification::Ambiguous(id, localisation_score, preferred)) =>
+                                ambiguous_found_positions.push((SequencePosition::Index(sequence_index), preferred, id, localisation_score, info)),
+                            Some(ReturnModification::CrossLinkReferenced(id)) =>
+                                cross_link_found_positions.push((id, SequencePosition::Index(sequence_index), info)),
                         }
                     } else {
                         return Err(
@@ -448,14 +476,23 @@ impl CompoundPeptidoformIon {
                     break;
                 }
                 (false, ch) => {
-                    peptide.sequence_mut().push(SequenceElement::new(
-                        CheckedAminoAcid::<SemiAmbiguous>::try_from(ch).map_err(|()| CustomError::error(
-                            "Invalid amino acid",
-                            "This character is not a valid amino acid",
-                            Context::line(None, line, index, 1),
-                        ))?.into(),
-                        ambiguous_aa,
-                    ));
+                    if let Some(residue) = AminoAcid::custom(char::from(ch), custom_database) {
+                        let mut element = SequenceElement::new(
+                            CheckedAminoAcid::<UnAmbiguous>::Unknown.mark(),
+                            ambiguous_aa,
+                        );
+                        element.modifications.push(Modification::from(residue));
+                        peptide.sequence_mut().push(element);
+                    } else {
+                        peptide.sequence_mut().push(SequenceElement::new(
+                            CheckedAminoAcid::<SemiAmbiguous>::try_from(ch).map_err(|()| CustomError::error(
+                                "Invalid amino acid",
+                                "This character is not a valid amino acid",
+                                Context::line(None, line, index, 1),
+                            ))?.into(),
+                            ambiguous_aa,
+                        ));
+                    }
                     index += 1;
                 }
                 (true, _) => {
@@ -498,14 +535,14 @@ impl CompoundPeptidoformIon {
             ));
         }
 
-        // Fill in ambiguous positions, ambiguous contains (index, preferred, id, localisation_score)
+        // Fill in ambiguous positions, ambiguous contains (index, preferred, id, localisation_score, info)
         for (id, ambiguous) in ambiguous_found_positions
             .into_iter()
             .into_group_map_by(|aa| aa.2)
         {
             let positions = ambiguous
                 .iter()
-                .map(|(index, _, _, score)| (*index, *score))
+                .map(|(index, _, _, score, _)| (*index, *score))
                 .collect_vec();
             let preferred = ambiguous.iter().find_map(|p| p.1.then_some(p.0));
             if !peptide.add_ambiguous_modification(ambiguous_lookup[id].modification.clone().ok_or_else(||
@@ -521,6 +558,25 @@ impl CompoundPeptidoformIon {
                     Context::full_line(0, line),
                     ));
             }
+            // Attach the info tags, if only a single location was given the modification is placed as a simple modification
+            for (position, _, _, _, info) in ambiguous.into_iter().filter(|p| !p.4.is_empty()) {
+                if let Some(m) = peptide
+                    .modifications_mut(position)
+                    .iter_mut()
+                    .find(|m| match m {
+                        Modification::Ambiguous { group, .. } => {
+                            *group == ambiguous_lookup[id].name
+                        }
+                        Modification::Simple { modification, info } => {
+                            info.is_empty()
+                                && ambiguous_lookup[id].modification.as_ref() == Some(modification)
+                        }
+                        Modification::CrossLink { .. } => false,
+                    })
+                {
+                    *m.info_tags_mut() = info;
+                }
+            }
         }
 
         peptide.apply_unknown_position_modification(
@@ -727,7 +783,7 @@ pub(super) fn global_unknown_position_mods(
             &mut cross_link_lookup,
             custom_database,
         ) {
-            Ok((ReturnModification::Defined(m), settings)) => {
+            Ok((ReturnModification::Defined(m), _, settings)) => {
                 let id = ambiguous_lookup.len();
                 ambiguous_lookup.push(crate::modification::AmbiguousLookupEntry::new(
                     format!("u{id}"),
@@ -736,11 +792,11 @@ pub(super) fn global_unknown_position_mods(
                 ambiguous_lookup[id].copy_settings(&settings);
                 id
             }
-            Ok((ReturnModification::Ambiguous(id, _, _), settings)) => {
+            Ok((ReturnModification::Ambiguous(id, _, _), _, settings)) => {
                 ambiguous_lookup[id].copy_settings(&settings);
                 id
             }
-            Ok((ReturnModification::CrossLinkReferenced(_), _)) => {
+            Ok((ReturnModification::CrossLinkReferenced(_), _, _)) => {
                 errs.push(CustomError::error(
                     "Invalid unknown position modification",
                     "A modification of unknown position cannot be a cross-link",
@@ -776,14 +832,16 @@ pub(super) fn global_unknown_position_mods(
             1
         };
         if number > 1 {
+            // All copies share the same group and settings, so they are placed on the same set
+            // of candidate positions, the first copy is the original entry
             let group_name = ambiguous_lookup.len();
             ambiguous_lookup[id].group = Some(group_name);
             modifications.push(id);
-            for _ in 0..number {
+            for _ in 1..number {
                 modifications.push(ambiguous_lookup.len());
                 ambiguous_lookup.push(ambiguous_lookup[id].clone());
             }
-        } else {
+        } else if number == 1 {
             modifications.push(id);
         }
     }
@@ -28,7 +28,8 @@ use crate::{
 impl SimpleModificationInner {
     /// Try to parse the modification. Any ambiguous modification will be numbered
     /// according to the lookup (which may be added to if necessary). The result
-    /// is the modification, with, if applicable, its determined ambiguous group.
+    /// is the modification, with, if applicable, its determined ambiguous group,
+    /// alongside any info tags given with the modification.
     /// # Errors
     /// If it is not a valid modification return a `CustomError` explaining the error.
     pub fn try_from(
@@ -37,13 +38,43 @@ impl SimpleModificationInner {
         ambiguous_lookup: &mut AmbiguousLookup,
         cross_link_lookup: &mut CrossLinkLookup,
         custom_database: Option<&CustomDatabase>,
-    ) -> Result<(ReturnModification, MUPSettings), CustomError> {
+    ) -> Result<(ReturnModification, Vec<String>, MUPSettings), CustomError> {
+        Self::try_from_optional(
+            line,
+            range,
+            ambiguous_lookup,
+            cross_link_lookup,
+            custom_database,
+        )
+        .map(|(modification, info, settings)| {
+            (
+                modification.unwrap_or_else(|| {
+                    ReturnModification::Defined(Arc::new(Self::Mass(Mass::default().into())))
+                }),
+                info,
+                settings,
+            )
+        })
+    }
+
+    /// Try to parse the modification, see [`Self::try_from`]. If the modification only consists
+    /// of info tags no modification is returned, so that the info tags can be stored on their own.
+    /// # Errors
+    /// If it is not a valid modification return a `CustomError` explaining the error.
+    pub(crate) fn try_from_optional(
+        line: &str,
+        range: Range<usize>,
+        ambiguous_lookup: &mut AmbiguousLookup,
+        cross_link_lookup: &mut CrossLinkLookup,
+        custom_database: Option<&CustomDatabase>,
+    ) -> Result<(Option<ReturnModification>, Vec<String>, MUPSettings), CustomError> {
         // Because multiple modifications could be chained with the pipe operator
         // the parsing iterates over all links until it finds one it understands
         // it then returns that one. If no 'understandable' links are found it
-        // returns the last link, if this is an info it returns a mass shift of 0,
-        // but if any of the links returned an error it returns the last error.
+        // returns None, but if any of the links returned an error it returns the
+        // last error.
         let mut modification = None;
+        let mut info = Vec::new();
         let mut settings = MUPSettings::default();
         let mut last_error = None;
         let mut offset = range.start;
@@ -58,6 +89,7 @@ impl SimpleModificationInner {
             ) {
                 Ok(SingleReturnModification::None) => (),
                 Ok(SingleReturnModification::Modification(m)) => modification = Some(m),
+                Ok(SingleReturnModification::Info(tag)) => info.push(tag),
                 Ok(SingleReturnModification::Positions(p)) => settings.position = Some(p),
                 Ok(SingleReturnModification::Limit(l)) => settings.limit = Some(l),
                 Ok(SingleReturnModification::ColocalisePlacedModifications(s)) => {
@@ -73,17 +105,7 @@ impl SimpleModificationInner {
         if let Some(ReturnModification::Ambiguous(id, _, true)) = &modification {
             ambiguous_lookup[*id].copy_settings(&settings);
         }
-        last_error.map_or_else(
-            || {
-                Ok((
-                    modification.unwrap_or(ReturnModification::Defined(Arc::new(Self::Mass(
-                        Mass::default().into(),
-                    )))),
-                    settings,
-                ))
-            },
-            Err,
-        )
+        last_error.map_or_else(|| Ok((modification, info, settings)), Err)
     }
 }
 
@@ -92,6 +114,7 @@ static MOD_REGEX: OnceLock<Regex> = OnceLock::new();
 enum SingleReturnModification {
     None,
     Modification(ReturnModification),
+    Info(String),
     Positions(Vec<PlacementRule>),
     Limit(usize),
     ColocalisePlacedModifications(bool),
@@ -315,7 +338,7 @@ fn parse_single_modification(
                     offset + tail.1..offset + tail.1 + tail.2,
                 )
                 .map(|g| Some(Arc::new(SimpleModificationInner::GlycanStructure(g)))),
-                ("info", _) => Ok(None),
+                ("info", tail) => return Ok(SingleReturnModification::Info(tail.to_string())),
                 ("obs", tail) => numerical_mod(tail).map(Some).map_err(|_| {
                     basic_error.with_long_description(
                         "This modification cannot be read as a numerical modification",
@@ -29,6 +29,8 @@ pub struct SloppyParsingParameters {
     pub custom_alphabet: Vec<(u8, SequenceElement<SemiAmbiguous>)>,
     /// Replacing mass mods with known predefined mods, e.g. `AAA(+79.97)AAA` instead of `AAA[phospho]AAA` as used by InstaNovo
     pub replace_mass_modifications: Option<Vec<SimpleModification>>,
+    /// Additional names for modifications (matched case insensitively), e.g. `AAAM(ox1)AAA` as used by some tools, these take precedence over the normal modification names
+    pub modification_aliases: Vec<(String, SimpleModification)>,
 }
 
 impl Peptidoform<SemiAmbiguous> {
@@ -81,13 +83,23 @@ impl Peptidoform<SemiAmbiguous> {
                                     Context::line(None, line, location.start + index, 1),
                                 )
                             })?;
-                    let modification = Modification::sloppy_modification(
-                        line,
-                        location.start + index + 1..location.start + end_index,
-                        peptide.sequence().last(),
-                        custom_database,
-                    )
-                    .map(Modification::Simple)?;
+                    let name = line[location.start + index + 1..location.start + end_index].trim();
+                    let modification = parameters
+                        .modification_aliases
+                        .iter()
+                        .find(|(alias, _)| alias.eq_ignore_ascii_case(name))
+                        .map_or_else(
+                            || {
+                                Modification::sloppy_modification(
+                                    line,
+                                    location.start + index + 1..location.start + end_index,
+                                    peptide.sequence().last(),
+                                    custom_database,
+                                )
+                            },
+                            |(_, modification)| Ok(modification.clone()),
+                        )
+                        .map(Modification::from)?;
                     index = end_index + 1;
 
                     let pep_len = peptide.len();
@@ -141,7 +153,7 @@ impl Peptidoform<SemiAmbiguous> {
                             .1
                             .iter()
                             .find(|(aa, _)| *aa == seq.aminoacid.aminoacid())
-                            .map(|(_, m)| seq.modifications.push(Modification::Simple(m.clone())))
+                            .map(|(_, m)| seq.modifications.push(Modification::from(m.clone())))
                             .ok_or_else(|| {
                                 CustomError::error(
                                     "Invalid mod indication",
@@ -176,7 +188,7 @@ impl Peptidoform<SemiAmbiguous> {
                                 Context::line(None, line, location.start + index, length))
                             )?).into()).into();
                     match peptide.sequence_mut().last_mut() {
-                        Some(aa) => aa.modifications.push(Modification::Simple(modification)),
+                        Some(aa) => aa.modifications.push(Modification::from(modification)),
                         None => {
                             peptide.add_simple_n_term(modification);
                         }
@@ -1,21 +1,43 @@
-use std::{collections::BTreeSet, fmt::Write};
+use std::{collections::BTreeSet, fmt::Write, num::NonZeroU16};
 
 use itertools::Itertools;
 use serde::{Deserialize, Serialize};
 
 use crate::{
+    error::{Context, CustomError},
     modification::{
-        CrossLinkName, CrossLinkSide, RulePossible, SimpleModification, SimpleModificationInner,
+        CrossLinkName, CrossLinkSide, Ontology, RulePossible, SimpleModification,
+        SimpleModificationInner,
     },
     peptidoform::Linked,
     system::usize::Charge,
-    Fragment, Model, MolecularCharge, MolecularFormula, Multi, Peptidoform, SequencePosition,
+    AminoAcid, Element, Fragment, Model, Modification, MolecularCharge, MolecularFormula, Multi,
+    Peptidoform, SequencePosition,
 };
 /// A single peptidoform ion, can contain multiple peptidoforms
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Default, Serialize, Deserialize, Hash)]
 pub struct PeptidoformIon(pub(crate) Vec<Peptidoform<Linked>>);
 
 impl PeptidoformIon {
+    /// Serialize this peptidoform into a compact binary format, useful for caching large sets
+    /// of peptidoforms. Use [`Self::from_bincode`] to read it back.
+    /// # Errors
+    /// If the peptidoform could not be serialized.
+    pub fn to_bincode(&self) -> Result<Vec<u8>, CustomError> {
+        bincode::serialize(self).map_err(|err| {
+            CustomError::error("Could not serialize peptidoform", err, Context::none())
+        })
+    }
+
+    /// Deserialize a peptidoform from the binary format as written by [`Self::to_bincode`].
+    /// # Errors
+    /// If the bytes do not contain a valid peptidoform.
+    pub fn from_bincode(bytes: &[u8]) -> Result<Self, CustomError> {
+        bincode::deserialize(bytes).map_err(|err| {
+            CustomError::error("Could not deserialize peptidoform", err, Context::none())
+        })
+    }
+
     /// Create a new [`Peptidoform`] from many [`LinearPeptide`]s. This returns None if the
     /// global isotope modifications or the charge carriers of all peptides are not identical.
     pub fn new<Complexity>(
@@ -96,6 +118,29 @@ impl PeptidoformIon {
         &mut self.0
     }
 
+    /// Create a copy of this peptidoform ion with the given global isotope modifications applied
+    /// to all peptidoforms, for example `(Element::C, NonZeroU16::new(13))` for full 13C
+    /// labelling. Any existing global isotope modification for the same element is replaced. If
+    /// any of the given isotopes is not valid for its element it returns `None`.
+    #[must_use]
+    pub fn with_global_isotope_modifications(
+        &self,
+        substitutions: &[(Element, Option<NonZeroU16>)],
+    ) -> Option<Self> {
+        let mut result = self.clone();
+        for peptide in &mut result.0 {
+            for substitution in substitutions {
+                peptide
+                    .get_global_mut()
+                    .retain(|(element, _)| *element != substitution.0);
+                if !peptide.add_global(*substitution) {
+                    return None;
+                }
+            }
+        }
+        Some(result)
+    }
+
     /// Set the charge carriers
     #[allow(clippy::needless_pass_by_value)]
     pub fn set_charge_carriers(&mut self, charge_carriers: Option<MolecularCharge>) {
@@ -183,6 +228,7 @@ impl PeptidoformIon {
                         linker: linker.clone(),
                         name: name.clone(),
                         side: left,
+                        info: Vec::new(),
                     },
                 );
                 self.0[position_2.0].add_modification(
@@ -193,6 +239,7 @@ impl PeptidoformIon {
                         linker,
                         name,
                         side: right,
+                        info: Vec::new(),
                     },
                 );
                 true
@@ -204,6 +251,47 @@ impl PeptidoformIon {
         }
     }
 
+    /// Add a disulfide bond between the two given cysteines. The positions are first the peptide
+    /// index and second the sequence index. The bond is modelled as the XLMOD disulfide cross-link
+    /// (see [`Self::add_cross_link`]), so the formula loses two hydrogens per bond and no
+    /// backbone fragments are generated within a disulfide loop, unless cross-link cleavage is
+    /// allowed (see [`Model::allow_cross_link_cleavage`]). The bond is
+    /// given the first unused cross-link name of the form `ss1`, `ss2` etc. Returns false if
+    /// either position is not a cysteine.
+    #[allow(clippy::missing_panics_doc)] // The disulfide is always present in XLMOD
+    pub fn add_disulfide(
+        &mut self,
+        position_1: (usize, SequencePosition),
+        position_2: (usize, SequencePosition),
+    ) -> bool {
+        let is_cysteine = |(peptide, index): (usize, SequencePosition)| {
+            matches!(index, SequencePosition::Index(i) if self
+                .0
+                .get(peptide)
+                .and_then(|p| p.sequence().get(i))
+                .is_some_and(|s| s.aminoacid.aminoacid() == AminoAcid::Cysteine))
+        };
+        if !is_cysteine(position_1) || !is_cysteine(position_2) {
+            return false;
+        }
+        let used = self
+            .0
+            .iter()
+            .flat_map(Peptidoform::sequence)
+            .flat_map(|s| &s.modifications)
+            .filter_map(|m| match m {
+                Modification::CrossLink { name, .. } => Some(name.clone()),
+                _ => None,
+            })
+            .collect::<BTreeSet<_>>();
+        let name = (1..=used.len() + 1)
+            .map(|i| CrossLinkName::Name(format!("ss{i}")))
+            .find(|name| !used.contains(name))
+            .unwrap();
+        let disulfide = Ontology::Xlmod.find_name("disulfide", None).unwrap();
+        self.add_cross_link(position_1, position_2, disulfide, name)
+    }
+
     /// Display this peptidoform.
     /// `specification_compliant` Displays this peptidoform either normalised to the internal representation or as fully spec compliant ProForma
     /// (no glycan structure or custom modifications).
@@ -0,0 +1,62 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    modification::{Ontology, SimpleModificationInner},
+    Modification, Peptidoform,
+};
+
+/// The chemistry of the C terminus of a (synthetic) peptide
+#[derive(
+    Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+pub enum CTermChemistry {
+    /// A free acid (-COOH), the unmodified C terminus
+    #[default]
+    Acid,
+    /// An amide (-CONH2), represented as the Unimod `Amidated` (U:2) modification on the C terminus
+    Amide,
+}
+
+/// The Unimod accession number for C terminal amidation
+const AMIDATED: usize = 2;
+
+/// Check if this modification is the Unimod amidation
+fn is_amidation(modification: &Modification) -> bool {
+    matches!(modification, Modification::Simple { modification: simple, .. } if matches!(&**simple,
+        SimpleModificationInner::Database { id, .. }
+            if id.ontology == Ontology::Unimod && id.id == Some(AMIDATED)))
+}
+
+impl<Complexity> Peptidoform<Complexity> {
+    /// Get the chemistry of the C terminus, this is [`CTermChemistry::Amide`] if the C terminus
+    /// carries the Unimod amidation and [`CTermChemistry::Acid`] otherwise.
+    pub fn c_terminal_chemistry(&self) -> CTermChemistry {
+        if self.get_c_term().iter().any(is_amidation) {
+            CTermChemistry::Amide
+        } else {
+            CTermChemistry::Acid
+        }
+    }
+
+    /// Set the chemistry of the C terminus. An amide is represented as the Unimod amidation on the
+    /// C terminus, so it is reflected in the ProForma output (`-[U:Amidated]`) and the formulas of
+    /// this peptidoform. Any other C terminal modifications are kept.
+    /// # Panics
+    /// If the Unimod amidation could not be found in the ontology.
+    pub fn set_c_terminal_chemistry(&mut self, chemistry: CTermChemistry) {
+        let mut c_term = self
+            .get_c_term()
+            .iter()
+            .filter(|m| !is_amidation(m))
+            .cloned()
+            .collect::<Vec<_>>();
+        if chemistry == CTermChemistry::Amide {
+            c_term.push(Modification::from(
+                Ontology::Unimod
+                    .find_id(AMIDATED, None)
+                    .expect("Unimod amidation not present in the ontology"),
+            ));
+        }
+        self.set_c_term(c_term);
+    }
+}
@@ -6,6 +6,29 @@ mod pro_forma_negative;
 mod pro_forma_positive;
 mod sloppy;
 
+use crate::{CompoundPeptidoformIon, SequencePosition};
+
+/// Lowercase all info tags, as these are the only part of a peptidoform that keeps the case as given.
+pub(super) fn lowercase_info_tags(mut ion: CompoundPeptidoformIon) -> CompoundPeptidoformIon {
+    for peptidoform in ion.0.iter_mut().flat_map(|p| p.0.iter_mut()) {
+        let positions = std::iter::once(SequencePosition::NTerm)
+            .chain((0..peptidoform.len()).map(SequencePosition::Index))
+            .chain(std::iter::once(SequencePosition::CTerm))
+            .collect::<Vec<_>>();
+        for position in positions {
+            for tag in peptidoform.info_mut(position) {
+                *tag = tag.to_lowercase();
+            }
+            for m in peptidoform.modifications_mut(position) {
+                for tag in m.info_tags_mut() {
+                    *tag = tag.to_lowercase();
+                }
+            }
+        }
+    }
+    ion
+}
+
 /// Create a parse test based on a given case and its name.
 #[macro_export]
 macro_rules! parse_test {
@@ -19,8 +42,12 @@ macro_rules! parse_test {
                 $crate::CompoundPeptidoformIon::pro_forma(&$case.to_ascii_lowercase(), None);
             println!("{}", $case);
             assert!(res.is_ok(), "{}", res.err().unwrap());
-            assert_eq!(res, res_upper);
-            assert_eq!(res, res_lower);
+            // Info tags are free text, so only these keep the case as given
+            let ignore_info_case = |res: Result<$crate::CompoundPeptidoformIon, _>| {
+                res.map($crate::peptidoform::tests::lowercase_info_tags)
+            };
+            assert_eq!(ignore_info_case(res.clone()), ignore_info_case(res_upper));
+            assert_eq!(ignore_info_case(res.clone()), ignore_info_case(res_lower));
             let back = res.as_ref().unwrap().to_string();
             let res_back = $crate::CompoundPeptidoformIon::pro_forma(&back, None);
             assert_eq!(res, res_back, "{} != {back}", $case);
@@ -1,6 +1,9 @@
 use std::{num::NonZeroU16, sync::Arc};
 
+use itertools::Itertools;
+
 use crate::{
+    aminoacid_properties::HydrophobicityScale,
     model::PrimaryIonSeries,
     modification::{self, ModificationId, SimpleModificationInner},
     peptidoform::{
@@ -8,9 +11,9 @@ use crate::{
         GlobalModification,
     },
     placement_rule::{self, PlacementRule, Position},
-    system::{da, usize::Charge},
-    AminoAcid, CompoundPeptidoformIon, Element, Model, MolecularCharge, MultiChemical, Peptidoform,
-    PeptidoformIon,
+    system::{da, usize::Charge, Mass},
+    AminoAcid, Chemical, CompoundPeptidoformIon, DecoyMethod, Element, Linked, MassMode, Model,
+    MolecularCharge, MultiChemical, Peptidoform, PeptidoformIon, SequencePosition,
 };
 
 #[test]
@@ -226,6 +229,50 @@ fn parse_labile() {
     );
 }
 
+#[test]
+fn labile_precursor_formula() {
+    let peptide = Peptidoform::pro_forma("{Glycan:HexNAc1Hex1}[Acetyl]-NK[Oxidation]T", None)
+        .unwrap()
+        .into_linear()
+        .unwrap();
+    let modified = Peptidoform::pro_forma("[Acetyl]-NK[Oxidation]T", None)
+        .unwrap()
+        .into_linear()
+        .unwrap();
+    let bare = Peptidoform::pro_forma("NKT", None)
+        .unwrap()
+        .into_linear()
+        .unwrap();
+    let glycan = peptide.get_labile()[0].formula();
+    assert_eq!(peptide.fragment_backbone_formula(), bare.formulas());
+    assert_eq!(bare.fragment_backbone_formula(), bare.formulas());
+    assert_eq!(
+        peptide.precursor_formula(),
+        (modified.formulas()[0].clone() + glycan).into()
+    );
+}
+
+#[test]
+fn global_isotope_labelling() {
+    let peptide = PeptidoformIon::pro_forma("PEPTIDE", None).unwrap();
+    let labelled = peptide
+        .with_global_isotope_modifications(&[(Element::C, NonZeroU16::new(13))])
+        .unwrap();
+    assert_eq!(labelled.to_string(), "<13C>PEPTIDE");
+    let formula = peptide.formulas()[0].clone();
+    let carbons = formula
+        .elements()
+        .iter()
+        .find(|(e, _, _)| *e == Element::C)
+        .map_or(0, |(_, _, n)| *n);
+    let shift = labelled.formulas()[0].monoisotopic_mass() - formula.monoisotopic_mass();
+    let delta = Element::C.mass(NonZeroU16::new(13)).unwrap() - Element::C.mass(None).unwrap();
+    assert!((shift - delta * f64::from(carbons)).value.abs() < 1e-9);
+    assert!(peptide
+        .with_global_isotope_modifications(&[(Element::C, NonZeroU16::new(2))])
+        .is_none());
+}
+
 #[test]
 fn parse_ambiguous_modification() {
     let with = Peptidoform::pro_forma("A[Phospho#g0]A[#g0]", None).unwrap();
@@ -273,6 +320,33 @@ fn parse_terminal_ambiguous_modification() {
     assert!(placed_c.sequence()[3].modifications[0].is_ambiguous());
 }
 
+#[test]
+fn parse_unknown_position_multiplicity() {
+    for copies in 1..=3 {
+        let text = format!("[Phospho]^{copies}?EMEVTSESPEK");
+        let peptide = Peptidoform::pro_forma(&text, None).unwrap();
+        let ambiguous = peptide.get_ambiguous_modifications();
+        assert_eq!(ambiguous.len(), copies, "{text}");
+        assert!(!ambiguous[0].is_empty());
+        assert!(ambiguous.iter().all(|positions| *positions == ambiguous[0]));
+        let back = peptide.to_string();
+        if copies > 1 {
+            assert!(back.contains(&format!("]^{copies}?")), "{back}");
+        }
+        assert_eq!(Peptidoform::pro_forma(&back, None).unwrap(), peptide);
+    }
+    assert_eq!(
+        Peptidoform::pro_forma("[Phospho][Phospho]?EMEVTSESPEK", None)
+            .unwrap()
+            .get_ambiguous_modifications()
+            .len(),
+        2
+    );
+    // An explicit group is always written, even if it looks like a generated group name
+    let explicit = Peptidoform::pro_forma("EMEVT[Phospho#u0]S[#u0]ESPEK", None).unwrap();
+    assert_eq!(explicit.to_string(), "EMEVT[U:Phospho#u0]S[#u0]ESPEK");
+}
+
 #[test]
 fn parse_ambiguous_aminoacid() {
     let with = Peptidoform::pro_forma("(?AA)C(?A)(?A)", None)
@@ -428,6 +502,53 @@ fn parse_xl_inter() {
     );
 }
 
+#[test]
+fn add_disulfide() {
+    let reduced = PeptidoformIon::pro_forma("ECAKCE", None).unwrap();
+    let mut oxidised = reduced.clone();
+    assert!(!oxidised.add_disulfide(
+        (0, SequencePosition::Index(0)),
+        (0, SequencePosition::Index(4))
+    ));
+    assert!(oxidised.add_disulfide(
+        (0, SequencePosition::Index(1)),
+        (0, SequencePosition::Index(4))
+    ));
+    let difference =
+        reduced.formulas()[0].monoisotopic_mass() - oxidised.formulas()[0].monoisotopic_mass();
+    assert!(
+        (difference - molecular_formula!(H 2).monoisotopic_mass())
+            .value
+            .abs()
+            < 1e-6
+    );
+    assert!((difference.value - 2.016).abs() < 1e-3);
+    // Identical to the ProForma definition, except for the name of the cross-link
+    assert_eq!(
+        oxidised.formulas()[0].hill_notation(),
+        PeptidoformIon::pro_forma("EC[X:Disulfide#XL1]AKC[#XL1]E", None)
+            .unwrap()
+            .formulas()[0]
+            .hill_notation()
+    );
+    // No backbone fragments within the loop
+    let model = Model::none().b(PrimaryIonSeries::default());
+    let charge = Charge::new::<crate::system::charge::e>(1);
+    assert_eq!(
+        oxidised
+            .generate_theoretical_fragments(charge, &model)
+            .len(),
+        3
+    ); // b1, b5, p
+    assert_eq!(
+        PeptidoformIon::pro_forma("EC[X:Disulfide#XL1]AKC[#XL1]E", None)
+            .unwrap()
+            .generate_theoretical_fragments(charge, &model)
+            .len(),
+        3
+    );
+}
+
 #[test]
 fn dimeric_peptide() {
     // Only generate a single series, easier to reason about
@@ -463,6 +584,21 @@ fn parse_adduct_ions_01() {
     );
 }
 
+#[test]
+fn set_charge_carriers() {
+    let mut peptidoform = PeptidoformIon::pro_forma("PEPTIDE", None).unwrap();
+    assert!(peptidoform.peptidoforms()[0]
+        .get_charge_carriers()
+        .is_none());
+    peptidoform.set_charge_carriers(Some(MolecularCharge::proton(2)));
+    let charge = peptidoform.peptidoforms()[0].get_charge_carriers().unwrap();
+    assert_eq!(charge.charge().value, 2);
+    assert_eq!(
+        peptidoform,
+        PeptidoformIon::pro_forma("PEPTIDE/2[+H+,+H+]", None).unwrap()
+    );
+}
+
 #[test]
 fn hydrolysed_xl() {
     let peptide_xl = Peptidoform::pro_forma("EMEVTK[XLMOD:02001]SESPEK", None)
@@ -476,3 +612,620 @@ fn hydrolysed_xl() {
 
     assert_eq!(peptide_xl.formula(), peptide_mod.formula());
 }
+
+#[test]
+fn composition_counts() {
+    let peptide = Peptidoform::pro_forma("AAGG", None).unwrap();
+    let counts = peptide.composition_counts();
+    let index = |aa: AminoAcid| {
+        AminoAcid::CANONICAL_AMINO_ACIDS
+            .iter()
+            .position(|a| *a == aa)
+            .unwrap()
+    };
+    assert_eq!(counts[index(AminoAcid::Alanine)], 2);
+    assert_eq!(counts[index(AminoAcid::Glycine)], 2);
+    assert_eq!(counts.iter().sum::<u32>(), 4);
+    assert_eq!(peptide.number_of_modifications(), 0);
+    let peptide = Peptidoform::pro_forma("[Acetyl]-AM[Oxidation]GXU", None).unwrap();
+    assert_eq!(peptide.composition_counts().iter().sum::<u32>(), 3);
+    assert_eq!(peptide.number_of_modifications(), 2);
+}
+
+#[test]
+fn hydrophobicity_and_isoelectric_point() {
+    let peptide = Peptidoform::pro_forma("AAGG", None).unwrap();
+    assert!(
+        (peptide.predicted_hydrophobicity(HydrophobicityScale::KyteDoolittle) - 2.8).abs() < 1e-10
+    );
+    assert!((peptide.isoelectric_point() - 6.1).abs() < 0.01);
+    let lysine = Peptidoform::pro_forma("GKG", None).unwrap();
+    assert!((lysine.isoelectric_point() - 9.7).abs() < 0.01);
+    let acetylated = Peptidoform::pro_forma("GK[Acetyl]G", None).unwrap();
+    assert!((acetylated.isoelectric_point() - 6.1).abs() < 0.01);
+    let aspartic = Peptidoform::pro_forma("GDG-[Amidated]", None).unwrap();
+    assert!((aspartic.isoelectric_point() - 6.25).abs() < 0.01);
+}
+
+#[test]
+fn info_tags() {
+    let peptide = Peptidoform::pro_forma("PEP[info:manually validated]TIDE", None).unwrap();
+    assert_eq!(
+        peptide.sequence()[2].info,
+        ["manually validated".to_string()]
+    );
+    assert!(peptide.sequence()[2].modifications.is_empty());
+    let display = peptide.to_string();
+    assert_eq!(display, "PEP[INFO:manually validated]TIDE");
+    assert_eq!(Peptidoform::pro_forma(&display, None).unwrap(), peptide);
+
+    let terminal = Peptidoform::pro_forma("[INFO:n]-PEPTIDE-[INFO:c]", None).unwrap();
+    assert!(terminal.get_n_term().is_empty());
+    assert_eq!(terminal.get_n_term_info(), ["n".to_string()]);
+    assert_eq!(terminal.get_c_term_info(), ["c".to_string()]);
+    assert_eq!(terminal.to_string(), "[INFO:n]-PEPTIDE-[INFO:c]");
+}
+
+#[test]
+fn info_tags_alongside_modification() {
+    let peptide = Peptidoform::pro_forma("PEM[U:Oxidation|INFO:validated]TIDE", None).unwrap();
+    assert_eq!(
+        peptide.sequence()[2].modifications[0].info_tags(),
+        ["validated".to_string()]
+    );
+    let display = peptide.to_string();
+    assert_eq!(display, "PEM[U:Oxidation|INFO:validated]TIDE");
+    assert_eq!(Peptidoform::pro_forma(&display, None).unwrap(), peptide);
+
+    let terminal = Peptidoform::pro_forma("[U:Acetyl|INFO:validated]-PEPTIDE", None).unwrap();
+    assert_eq!(
+        terminal.get_n_term()[0].info_tags(),
+        ["validated".to_string()]
+    );
+    assert_eq!(terminal.to_string(), "[U:Acetyl|INFO:validated]-PEPTIDE");
+
+    let ambiguous =
+        Peptidoform::pro_forma("PEM[U:Oxidation#g1|INFO:validated]M[#g1]IDE", None).unwrap();
+    assert_eq!(
+        ambiguous.sequence()[2].modifications[0].info_tags(),
+        ["validated".to_string()]
+    );
+    let display = ambiguous.to_string();
+    assert_eq!(Peptidoform::pro_forma(&display, None).unwrap(), ambiguous);
+}
+
+#[test]
+fn bincode_round_trip() {
+    let peptide = Peptidoform::pro_forma("[Acetyl]-PEM[Oxidation]TIDE/2[+2Na+]", None).unwrap();
+    let bytes = peptide.to_bincode().unwrap();
+    let back = Peptidoform::<Linked>::from_bincode(&bytes).unwrap();
+    assert_eq!(back, peptide);
+    assert_eq!(back.number_of_modifications(), 2);
+    assert!(back.get_charge_carriers().is_some());
+
+    let compound =
+        CompoundPeptidoformIon::pro_forma("EM[Oxidation]EVEES[Phospho]PEK/2+ELVISLIVER/3", None)
+            .unwrap();
+    let bytes = compound.to_bincode().unwrap();
+    assert_eq!(
+        CompoundPeptidoformIon::from_bincode(&bytes).unwrap(),
+        compound
+    );
+    assert!(CompoundPeptidoformIon::from_bincode(&bytes[..bytes.len() / 2]).is_err());
+}
+
+#[test]
+fn apply_fixed_modifications() {
+    let carbamidomethyl = modification::Ontology::Unimod.find_id(4, None).unwrap();
+    let rule = PlacementRule::AminoAcid(vec![AminoAcid::Cysteine], Position::Anywhere);
+    let peptide = Peptidoform::pro_forma("ACDCK", None)
+        .unwrap()
+        .into_unambiguous()
+        .unwrap();
+    let modified = peptide.apply_fixed_modifications(&[(carbamidomethyl.clone(), rule)]);
+    assert_eq!(modified.number_of_modifications(), 2);
+    assert_eq!(
+        modified.to_string(),
+        "AC[U:Carbamidomethyl]DC[U:Carbamidomethyl]K"
+    );
+    assert_eq!(
+        modified.formula() - peptide.formula(),
+        carbamidomethyl.formula() * 2
+    );
+    assert_eq!(peptide.number_of_modifications(), 0);
+}
+
+#[test]
+fn apply_variable_modifications() {
+    let acetyl = modification::Ontology::Unimod.find_id(1, None).unwrap();
+    let oxidation = modification::Ontology::Unimod.find_id(35, None).unwrap();
+    let peptide = Peptidoform::pro_forma("AMAMA", None)
+        .unwrap()
+        .into_unambiguous()
+        .unwrap();
+    let count = |max_oxidation: usize, max_total: usize| {
+        let modforms = peptide.apply_variable_modifications(
+            &[(oxidation.clone(), max_oxidation), (acetyl.clone(), 1)],
+            max_total,
+        );
+        assert_eq!(modforms[0], peptide);
+        assert!(modforms
+            .iter()
+            .all(|m| m.get_c_term().is_empty() && m.sequence()[0].modifications.is_empty()));
+        modforms.len()
+    };
+    assert_eq!(count(2, 0), 1);
+    assert_eq!(count(2, 2), 7);
+    assert_eq!(count(2, 3), 8);
+    assert_eq!(count(1, 3), 6);
+    let modforms = peptide.apply_variable_modifications(&[(oxidation, 2)], 2);
+    assert_eq!(modforms.len(), 4);
+    assert_eq!(modforms.last().unwrap().number_of_modifications(), 2);
+    // The N terminus and the side chain of K are separate locations
+    let peptide = Peptidoform::pro_forma("KAAA", None)
+        .unwrap()
+        .into_unambiguous()
+        .unwrap();
+    let modforms = peptide.apply_variable_modifications(&[(acetyl, 2)], 2);
+    let modforms = modforms.iter().map(ToString::to_string).collect_vec();
+    assert_eq!(
+        modforms,
+        [
+            "KAAA",
+            "[U:Acetyl]-KAAA",
+            "K[U:Acetyl]AAA",
+            "[U:Acetyl]-K[U:Acetyl]AAA"
+        ]
+    );
+}
+
+#[test]
+fn from_fasta_record() {
+    let carbamidomethyl = modification::Ontology::Unimod.find_id(4, None).unwrap();
+    let rule = PlacementRule::AminoAcid(vec![AminoAcid::Cysteine], Position::Anywhere);
+    let peptide =
+        Peptidoform::<Linked>::from_fasta_record("ACDC\nK", &[(carbamidomethyl, rule)]).unwrap();
+    assert_eq!(
+        peptide.to_string(),
+        "AC[U:Carbamidomethyl]DC[U:Carbamidomethyl]K"
+    );
+    assert_eq!(
+        Peptidoform::<Linked>::from_fasta_record("ACDK", &[])
+            .unwrap()
+            .to_string(),
+        "ACDK"
+    );
+    assert!(Peptidoform::<Linked>::from_fasta_record("AC1K", &[]).is_err());
+    assert!(Peptidoform::<Linked>::from_fasta_record("ACDC\nK1", &[]).is_err());
+}
+
+#[test]
+fn modification_summary() {
+    let peptide = Peptidoform::pro_forma("[U:Acetyl]-AS[U:Phospho]N[Glycan:HexNAc2Hex3]K", None)
+        .unwrap()
+        .into_linear()
+        .unwrap();
+    let summary = peptide.modification_summary();
+    assert_eq!(summary.n_terminal, 1);
+    assert_eq!(summary.c_terminal, 0);
+    assert_eq!(summary.side_chain, 2);
+    assert_eq!(summary.variable, 2);
+    assert_eq!(summary.glycan, 1);
+    assert_eq!(summary.ambiguous, 0);
+    assert_eq!(summary.cross_link, 0);
+    assert_eq!(summary.labile, 0);
+    // Modifications of unknown position are only counted as ambiguous
+    let summary = Peptidoform::pro_forma("[Phospho]?STSK", None)
+        .unwrap()
+        .into_linear()
+        .unwrap()
+        .modification_summary();
+    assert_eq!(summary.ambiguous, 1);
+    assert_eq!(summary.side_chain, 0);
+    assert_eq!(summary.variable, 0);
+}
+
+#[test]
+fn expand_ambiguous_sequences() {
+    let expand = |proforma: &str| {
+        Peptidoform::pro_forma(proforma, None)
+            .unwrap()
+            .expand_ambiguous_sequences()
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(expand("P(?DN)K"), ["PDNK", "PNDK"]);
+    assert_eq!(expand("(?AA)K"), ["AAK"]);
+    assert_eq!(
+        expand("(?M[Oxidation]S)K"),
+        ["M[U:Oxidation]SK", "SM[U:Oxidation]K"]
+    );
+    assert_eq!(expand("(?DN)K(?ST)").len(), 4);
+    assert_eq!(expand("PEPTIDE"), ["PEPTIDE"]);
+}
+
+#[test]
+fn glycosylation_sites() {
+    let peptide = Peptidoform::pro_forma("HN[GNO:G43728NL]LTNETNPSNA", None)
+        .unwrap()
+        .into_linear()
+        .unwrap();
+    let sites = peptide.glycosylation_sites();
+    assert_eq!(sites.len(), 2, "{sites:?}");
+    assert_eq!(sites[0].0, SequencePosition::Index(1));
+    let structure = sites[0].1.as_ref().unwrap();
+    assert_eq!(
+        structure.formula(),
+        peptide.sequence()[1].modifications[0].formula()
+    );
+    assert_eq!(sites[1], (SequencePosition::Index(4), None));
+    // Glycans without a known structure cannot be reported
+    assert_eq!(
+        Peptidoform::pro_forma("HN[Glycan:HexNAc2Hex5]LTK", None)
+            .unwrap()
+            .glycosylation_sites(),
+        [(SequencePosition::Index(1), None)]
+    );
+    assert!(Peptidoform::pro_forma("PEPTIDE", None)
+        .unwrap()
+        .glycosylation_sites()
+        .is_empty());
+}
+
+#[test]
+fn residue_formulas() {
+    let peptide = Peptidoform::pro_forma("[Acetyl]-PEM[Oxidation]TIDE-[Amidated]", None)
+        .unwrap()
+        .into_linear()
+        .unwrap();
+    let residues = peptide.residue_formulas();
+    assert_eq!(residues.len(), 9);
+    assert_eq!(residues[0].0, SequencePosition::NTerm);
+    assert_eq!(residues[3].0, SequencePosition::Index(2));
+    assert_eq!(
+        residues[3].1,
+        AminoAcid::Methionine.formulas()[0].clone() + molecular_formula!(O 1)
+    );
+    assert_eq!(residues[8].0, SequencePosition::CTerm);
+    let sum = residues
+        .iter()
+        .fold(molecular_formula!(H 2 O 1), |acc, (_, f)| acc + f);
+    assert_eq!(sum, peptide.formulas()[0]);
+    let plain = Peptidoform::pro_forma("PEPTIDE", None)
+        .unwrap()
+        .into_linear()
+        .unwrap();
+    assert_eq!(plain.residue_formulas().len(), 7);
+}
+
+#[test]
+fn terminal_modification_alternatives() {
+    let mass = |p: &Peptidoform<crate::peptidoform::Linear>| {
+        p.formulas()
+            .iter()
+            .map(|f| f.monoisotopic_mass().value)
+            .sorted_by(f64::total_cmp)
+            .collect_vec()
+    };
+    let bare = mass(
+        &Peptidoform::pro_forma("GGGG", None)
+            .unwrap()
+            .into_linear()
+            .unwrap(),
+    )[0];
+    // Both modifications can only be placed on the N terminus, so these are alternatives
+    let peptide = Peptidoform::pro_forma("[U:Acetyl][U:Formyl]?GGGG", None)
+        .unwrap()
+        .into_linear()
+        .unwrap();
+    assert_eq!(peptide.get_n_term().len(), 2);
+    let masses = mass(&peptide);
+    assert_eq!(masses.len(), 2, "{masses:?}");
+    assert!((masses[0] - bare - 27.994_915).abs() < 1e-6, "{masses:?}");
+    assert!((masses[1] - bare - 42.010_565).abs() < 1e-6, "{masses:?}");
+    let fragments = peptide.generate_theoretical_fragments(
+        Charge::new::<crate::system::e>(1),
+        &Model::none().b(PrimaryIonSeries::default()),
+    );
+    let b1 = fragments
+        .iter()
+        .filter(|f| f.ion.position().is_some_and(|p| p.series_number == 1))
+        .filter_map(|f| f.mz(crate::MassMode::Monoisotopic).map(|mz| mz.value))
+        .sorted_by(f64::total_cmp)
+        .collect_vec();
+    assert_eq!(b1.len(), 2, "{b1:?}");
+    assert!((b1[1] - b1[0] - 14.015_650).abs() < 1e-6, "{b1:?}");
+
+    // Either modification can take the N terminus, the other is placed on the lysine
+    let peptide = Peptidoform::pro_forma("[U:Acetyl][U:Formyl]?GGKGG", None)
+        .unwrap()
+        .into_linear()
+        .unwrap();
+    let masses = mass(&peptide);
+    assert_eq!(masses.len(), 2, "{masses:?}");
+    assert!((masses[1] - masses[0]).abs() < 1e-6, "{masses:?}");
+
+    // An ambiguous modification is present on only one of its locations
+    let peptide = Peptidoform::pro_forma("[+42.011#g1]-PEPTIDE-[#g1]", None)
+        .unwrap()
+        .into_linear()
+        .unwrap();
+    let bare = mass(
+        &Peptidoform::pro_forma("PEPTIDE", None)
+            .unwrap()
+            .into_linear()
+            .unwrap(),
+    )[0];
+    let masses = mass(&peptide);
+    assert_eq!(masses.len(), 1, "{masses:?}");
+    assert!((masses[0] - bare - 42.011).abs() < 1e-6);
+    assert_eq!(peptide.residue_formulas()[0].0, SequencePosition::NTerm);
+    // The fragments are generated for both possible locations
+    let b1 = peptide
+        .generate_theoretical_fragments(
+            Charge::new::<crate::system::e>(1),
+            &Model::none().b(PrimaryIonSeries::default()),
+        )
+        .iter()
+        .filter(|f| f.ion.position().is_some_and(|p| p.series_number == 1))
+        .filter_map(|f| f.mz(crate::MassMode::Monoisotopic).map(|mz| mz.value))
+        .sorted_by(f64::total_cmp)
+        .collect_vec();
+    assert_eq!(b1.len(), 2, "{b1:?}");
+    assert!(b1[1] - b1[0] > 42.0, "{b1:?}");
+}
+
+#[test]
+fn enzymatic_terminus_status() {
+    let trypsin = crate::Protease::c_terminal_of(&[AminoAcid::Lysine, AminoAcid::Arginine]);
+    let peptide = |s: &str| {
+        Peptidoform::pro_forma(s, None)
+            .unwrap()
+            .into_linear()
+            .unwrap()
+    };
+    let none: Option<&Peptidoform<crate::Linear>> = None;
+    assert_eq!(
+        peptide("PEPTIDEK").enzymatic_terminus_status(&trypsin, none),
+        crate::TerminusStatus::SemiSpecificCTerm
+    );
+    assert_eq!(
+        peptide("PEPTIDE").enzymatic_terminus_status(&trypsin, none),
+        crate::TerminusStatus::NonSpecific
+    );
+    let protein = peptide("MAKPEPTIDEKWWRPEPTIDE");
+    assert_eq!(
+        peptide("PEPTIDEK").enzymatic_terminus_status(&trypsin, Some(&protein)),
+        crate::TerminusStatus::Specific
+    );
+    assert_eq!(
+        peptide("EPTIDEK").enzymatic_terminus_status(&trypsin, Some(&protein)),
+        crate::TerminusStatus::SemiSpecificCTerm
+    );
+    assert_eq!(
+        peptide("PEPTIDE").enzymatic_terminus_status(&trypsin, Some(&protein)),
+        crate::TerminusStatus::SemiSpecificNTerm
+    );
+    assert_eq!(
+        peptide("MAK").enzymatic_terminus_status(&trypsin, Some(&protein)),
+        crate::TerminusStatus::Specific
+    );
+    assert_eq!(
+        peptide("WRPEPTIDE").enzymatic_terminus_status(&trypsin, Some(&protein)),
+        crate::TerminusStatus::SemiSpecificCTerm
+    );
+}
+
+#[test]
+fn c_terminal_chemistry() {
+    let mut peptide = Peptidoform::pro_forma("PEPTIDE", None)
+        .unwrap()
+        .into_linear()
+        .unwrap();
+    assert_eq!(peptide.c_terminal_chemistry(), crate::CTermChemistry::Acid);
+    let acid = peptide.formulas()[0].monoisotopic_mass();
+    peptide.set_c_terminal_chemistry(crate::CTermChemistry::Amide);
+    assert_eq!(peptide.c_terminal_chemistry(), crate::CTermChemistry::Amide);
+    let amide = peptide.formulas()[0].monoisotopic_mass();
+    assert!(((acid - amide).value - 0.984_016).abs() < 1e-5);
+    assert_eq!(peptide.to_string(), "PEPTIDE-[U:Amidated]");
+    // Setting the same chemistry again does not stack the amidation
+    peptide.set_c_terminal_chemistry(crate::CTermChemistry::Amide);
+    assert_eq!(peptide.get_c_term().len(), 1);
+    let reparsed = Peptidoform::pro_forma(&peptide.to_string(), None)
+        .unwrap()
+        .into_linear()
+        .unwrap();
+    assert_eq!(
+        reparsed.c_terminal_chemistry(),
+        crate::CTermChemistry::Amide
+    );
+    peptide.set_c_terminal_chemistry(crate::CTermChemistry::Acid);
+    assert_eq!(peptide.to_string(), "PEPTIDE");
+    assert!(
+        (peptide.formulas()[0].monoisotopic_mass() - acid)
+            .value
+            .abs()
+            < 1e-9
+    );
+}
+
+#[test]
+fn matches_any() {
+    let peptide = Peptidoform::pro_forma("PEPTIDE", None)
+        .unwrap()
+        .into_linear()
+        .unwrap();
+    let mass = peptide.formulas()[0].monoisotopic_mass();
+    let c13 = Element::C.mass(NonZeroU16::new(13)).unwrap() - Element::C.mass(None).unwrap();
+    let tolerance = crate::Tolerance::new_ppm(10.0);
+    assert_eq!(
+        peptide.matches_any(&[da(500.0), mass], tolerance, 0..=1),
+        Some((1, 0))
+    );
+    assert_eq!(
+        peptide.matches_any(&[da(500.0), mass + c13], tolerance, 0..=1),
+        Some((1, 1))
+    );
+    assert_eq!(peptide.matches_any(&[mass + c13], tolerance, 0..=0), None);
+}
+
+#[test]
+fn mass_ladder() {
+    let peptide = Peptidoform::pro_forma("[Acetyl]-AGK", None)
+        .unwrap()
+        .into_unambiguous()
+        .unwrap();
+    let (prefix, suffix) = peptide.mass_ladder(MassMode::Monoisotopic);
+    let close = |masses: &[Mass], expected: &[f64]| {
+        masses.len() == expected.len()
+            && masses
+                .iter()
+                .zip(expected)
+                .all(|(mass, expected)| (mass.value - expected).abs() < 1e-4)
+    };
+    assert!(close(&prefix, &[113.04768, 170.06914]), "{prefix:?}");
+    assert!(close(&suffix, &[146.10552, 203.12698]), "{suffix:?}");
+    // The full peptide is the prefix and suffix of any cleavage site together
+    let full = peptide.formula().monoisotopic_mass();
+    assert!((prefix[0] + suffix[1] - full).value.abs() < 1e-6);
+    assert!(Peptidoform::pro_forma("ABK", None)
+        .unwrap()
+        .into_unambiguous()
+        .is_none());
+}
+
+#[test]
+fn decoy() {
+    let peptide = Peptidoform::pro_forma("[Acetyl]-PEM[Oxidation]S[Phospho]TIDEK", None).unwrap();
+    let sorted = |peptide: &Peptidoform<Linked>| {
+        peptide
+            .sequence()
+            .iter()
+            .map(|element| format!("{element:?}"))
+            .sorted()
+            .collect_vec()
+    };
+    for method in [
+        DecoyMethod::PseudoReverse,
+        DecoyMethod::PseudoShuffle(0),
+        DecoyMethod::PseudoShuffle(42),
+    ] {
+        let decoy = peptide.decoy(method);
+        assert_eq!(
+            decoy.sequence().last(),
+            peptide.sequence().last(),
+            "{method:?}"
+        );
+        assert_eq!(decoy.get_n_term(), peptide.get_n_term());
+        assert_eq!(sorted(&decoy), sorted(&peptide), "{method:?}");
+        assert_eq!(
+            decoy.clone().into_linear().unwrap().formulas(),
+            peptide.clone().into_linear().unwrap().formulas()
+        );
+    }
+    assert_eq!(
+        peptide.decoy(DecoyMethod::PseudoReverse),
+        Peptidoform::pro_forma("[Acetyl]-EDITS[Phospho]M[Oxidation]EPK", None).unwrap()
+    );
+    assert_eq!(
+        peptide.decoy(DecoyMethod::Shuffle(7)),
+        peptide.decoy(DecoyMethod::Shuffle(7))
+    );
+    assert_eq!(
+        sorted(&peptide.decoy(DecoyMethod::Shuffle(7))),
+        sorted(&peptide)
+    );
+}
+
+#[test]
+fn validate() {
+    assert_eq!(
+        CompoundPeptidoformIon::validate(
+            "[Phospho]?EM[Oxidation]EVT[#g1(0.01)]S[#g1(0.09)]ES[Phospho#g1(0.90)]PEK/2",
+            None
+        ),
+        Ok(())
+    );
+    assert_eq!(
+        CompoundPeptidoformIon::validate("[Phospho]^2?PEPTIDE", None),
+        Ok(())
+    );
+    let errors = CompoundPeptidoformIon::validate("PEP[Unknown]TIDE", None).unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert!(!errors[0].is_warning());
+    let errors = CompoundPeptidoformIon::validate("[Wrong][Invalid]?PEPTIDE", None).unwrap_err();
+    assert_eq!(errors.len(), 2);
+}
+
+#[test]
+fn digest() {
+    let trypsin = crate::Protease::c_terminal_of_except(
+        &[AminoAcid::Lysine, AminoAcid::Arginine],
+        &[AminoAcid::Proline],
+    );
+    let protein = Peptidoform::pro_forma("[U:Acetyl]-AKPEKMRS[U:Phospho]EPTR-[U:Amidated]", None)
+        .unwrap()
+        .into_linear()
+        .unwrap();
+    let show = |peptides: Vec<Peptidoform<_>>| {
+        peptides.iter().map(ToString::to_string).collect::<Vec<_>>()
+    };
+    assert_eq!(
+        show(protein.digest(&trypsin, 0)),
+        ["[U:Acetyl]-AKPEK", "MR", "S[U:Phospho]EPTR-[U:Amidated]"]
+    );
+    assert_eq!(
+        show(protein.digest(&trypsin, 2)),
+        [
+            "[U:Acetyl]-AKPEK",
+            "[U:Acetyl]-AKPEKMR",
+            "[U:Acetyl]-AKPEKMRS[U:Phospho]EPTR-[U:Amidated]",
+            "MR",
+            "MRS[U:Phospho]EPTR-[U:Amidated]",
+            "S[U:Phospho]EPTR-[U:Amidated]"
+        ]
+    );
+    // Ambiguous modifications are only kept if all positions are in the peptide
+    let protein = Peptidoform::pro_forma("S[#g1]EK[Phospho#g1]MRS[#g1]", None)
+        .unwrap()
+        .into_linear()
+        .unwrap();
+    let peptides = protein.digest(&trypsin, 2);
+    assert_eq!(
+        peptides
+            .iter()
+            .map(|p| p.get_ambiguous_modifications().len())
+            .collect::<Vec<_>>(),
+        [0, 0, 1, 0, 0, 0]
+    );
+    assert_eq!(peptides[0].to_string(), "SEK");
+    assert_eq!(peptides[2], protein);
+}
+
+#[test]
+fn mass_defect() {
+    let defect = |sequence: &str| {
+        Peptidoform::pro_forma(sequence, None)
+            .unwrap()
+            .into_linear()
+            .unwrap()
+            .mass_defect()
+    };
+    let small = defect("PEPTIDE");
+    let large = defect("PEPTIDEPEPTIDEPEPTIDE");
+    assert!(small > 0.0);
+    assert!(large > small);
+}
+
+#[test]
+fn modification_suggestions() {
+    let error = Peptidoform::pro_forma("AS[Phosphorylation]K", None).unwrap_err();
+    assert_eq!(
+        error.suggestions().first().map(String::as_str),
+        Some("U:phospho")
+    );
+    let error = Peptidoform::pro_forma("AM[Oxidatoin]K", None).unwrap_err();
+    assert!(error.suggestions().contains(&"U:oxidation".to_string()));
+}
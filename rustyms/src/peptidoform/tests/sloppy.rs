@@ -67,3 +67,71 @@ fn sloppy_msfragger() {
 parse_sloppy_test!(ne "_", fuzz_01);
 parse_sloppy_test!(ne "ffffffff[gln->|yro-glu]SC2N:iTRAQ4pleeeeeB]", hang_01);
 parse_sloppy_test!(ne "SEQUEN[Formula:[13B2YC2][12Cu2]HKKKyro-g|||||||||||||@@||||||||||||||lmmmmmm|||| |||||||||||||||||||||||||||||||||||||||||||||||||||||||||||||||||||||||||||||||||||o-glu]n[13YEQUEeedISEQU9SEmmmm]SBSE-@CSE->pyro-glm]n`n->pyrogl>pyro-gl", hang_02);
+
+#[test]
+fn sloppy_mass_modifications() {
+    let parameters = SloppyParsingParameters {
+        replace_mass_modifications: Some(vec![
+            Ontology::Unimod.find_name("oxidation", None).unwrap(),
+            Ontology::Unimod.find_name("acetyl", None).unwrap(),
+        ]),
+        ..Default::default()
+    };
+    let parse = |line: &str, parameters: &SloppyParsingParameters| {
+        Peptidoform::<SemiAmbiguous>::sloppy_pro_forma(line, 0..line.len(), None, parameters)
+            .unwrap()
+    };
+    let expected = |line: &str| {
+        Peptidoform::pro_forma(line, None)
+            .unwrap()
+            .into_semi_ambiguous()
+            .unwrap()
+    };
+    assert_eq!(
+        parse("M(+15.99)PEPTIDE", &SloppyParsingParameters::default()),
+        expected("M[+15.99]PEPTIDE")
+    );
+    assert_eq!(
+        parse("(+42)PEPTIDE", &SloppyParsingParameters::default()),
+        expected("[+42]-PEPTIDE")
+    );
+    assert_eq!(
+        parse("M(+15.99)PEPTIDE", &parameters),
+        expected("M[Oxidation]PEPTIDE")
+    );
+    assert_eq!(
+        parse("(+42)PEPTIDE", &parameters),
+        expected("[Acetyl]-PEPTIDE")
+    );
+}
+
+#[test]
+fn sloppy_modification_aliases() {
+    let parameters = SloppyParsingParameters {
+        modification_aliases: vec![(
+            "my_oxidation".to_string(),
+            Ontology::Unimod.find_name("oxidation", None).unwrap(),
+        )],
+        ..Default::default()
+    };
+    assert_eq!(
+        Peptidoform::<SemiAmbiguous>::sloppy_pro_forma(
+            "PEPM(My_Oxidation)K",
+            0..19,
+            None,
+            &parameters
+        )
+        .unwrap(),
+        Peptidoform::pro_forma("PEPM[Oxidation]K", None)
+            .unwrap()
+            .into_semi_ambiguous()
+            .unwrap()
+    );
+    assert!(Peptidoform::<SemiAmbiguous>::sloppy_pro_forma(
+        "PEPM(My_Oxidation)K",
+        0..19,
+        None,
+        &SloppyParsingParameters::default()
+    )
+    .is_err());
+}
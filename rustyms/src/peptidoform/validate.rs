@@ -15,7 +15,7 @@ use super::{GlobalModification, Linear};
 /// Or if there are peptides that cannot be reached from the first peptide.
 pub fn cross_links(
     peptides: Vec<Peptidoform<Linear>>,
-    cross_links_found: BTreeMap<usize, Vec<(usize, SequencePosition)>>,
+    cross_links_found: BTreeMap<usize, Vec<(usize, SequencePosition, Vec<String>)>>,
     cross_link_lookup: &[(CrossLinkName, Option<SimpleModification>)],
     line: &str,
 ) -> Result<PeptidoformIon, CustomError> {
@@ -30,9 +30,9 @@ pub fn cross_links(
                     Context::full_line(0, line),
                 ))},
                 1 => {
-                    let (index, position) = locations[0];
+                    let (index, position, info) = locations[0].clone();
                     if linker.is_possible(&peptidoform.0[index][position], position).any_possible() {
-                        peptidoform.0[index].add_simple_modification(position, linker.clone());
+                        peptidoform.0[index].add_modification(position, Modification::Simple { modification: linker.clone(), info });
                     } else {
                         let rules = linker.placement_rules();
                         return Err(CustomError::error(
@@ -56,13 +56,18 @@ pub fn cross_links(
                     }
                 },
                 2 => {
-                    if !peptidoform.add_cross_link(locations[0], locations[1], linker.clone(), definition.0.clone()) {
+                    if !peptidoform.add_cross_link((locations[0].0, locations[0].1), (locations[1].0, locations[1].1), linker.clone(), definition.0.clone()) {
                         return Err(CustomError::error(
                             "Invalid cross-link",
                             format!("The cross-link named '{}' cannot be placed according to its location specificities", definition.0),
                             Context::full_line(0, line),
                         ))
                     }
+                    for (index, position, info) in locations.into_iter().filter(|l| !l.2.is_empty()) {
+                        if let Some(m) = peptidoform.0[index].modifications_mut(position).iter_mut().find(|m| matches!(m, Modification::CrossLink { name, .. } if *name == definition.0)) {
+                            *m.info_tags_mut() = info;
+                        }
+                    }
                 },
                 _ => {return Err(CustomError::error(
                     "Invalid cross-link",
@@ -21,7 +21,10 @@ impl PlacementRule {
             }
             Self::PsiModification(mod_index, r_pos) => {
                 seq.modifications.iter().any(|m| {
-                    if let Modification::Simple(sim) = m {
+                    if let Modification::Simple {
+                        modification: sim, ..
+                    } = m
+                    {
                         if let SimpleModificationInner::Database {
                             id:
                                 ModificationId {
@@ -49,6 +52,17 @@ impl PlacementRule {
         }
     }
 
+    /// Check if this rule is specific to a terminus. Rules for a side chain anywhere (or for
+    /// anywhere at all) also match on the termini, but are not specific to them.
+    pub(crate) const fn is_terminal_specific(&self) -> bool {
+        !matches!(
+            self,
+            Self::Anywhere
+                | Self::AminoAcid(_, Position::Anywhere)
+                | Self::PsiModification(_, Position::Anywhere)
+        )
+    }
+
     /// Check if this rule fits with the given location
     pub fn is_possible_aa(&self, aa: AminoAcid, position: Position) -> bool {
         match self {
@@ -1,11 +1,16 @@
 use itertools::Itertools;
+use serde::{Deserialize, Serialize};
 
-use crate::{AminoAcid, SequenceElement};
+use crate::{
+    error::{Context, CustomError},
+    AminoAcid, SequenceElement,
+};
 
 /// A protease defined by it ability to cut at any site identified by the right amino acids at the n and c terminal.
 /// Each position is identified by an option, a none means that there is no specificity at this position. If there is
 /// a specificity at a certain position any amino acid that is contained in the set is allowed (see
 /// [`crate::CheckedAminoAcid::canonical_identical`]).
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct Protease {
     /// The amino acids n terminal of the cut site.
     pub n_term: Vec<Option<Vec<AminoAcid>>>,
@@ -25,26 +30,167 @@ impl Protease {
     /// Define a protease that cuts on the n terminal side of the provided amino acids.
     pub fn n_terminal_of(residues: &[AminoAcid]) -> Self {
         Self {
-            n_term: vec![Some(residues.to_vec())],
-            c_term: Vec::new(),
+            n_term: Vec::new(),
+            c_term: vec![Some(residues.to_vec())],
         }
     }
 
     /// Define a protease that cuts on the c terminal side of the provided amino acids.
     pub fn c_terminal_of(residues: &[AminoAcid]) -> Self {
         Self {
-            c_term: vec![Some(residues.to_vec())],
-            n_term: Vec::new(),
+            n_term: vec![Some(residues.to_vec())],
+            c_term: Vec::new(),
+        }
+    }
+
+    /// Define a protease that cuts on the c terminal side of the provided amino acids, except
+    /// if the next amino acid is one of the given exceptions. For example trypsin cuts after K
+    /// and R but not before P.
+    pub fn c_terminal_of_except(residues: &[AminoAcid], not_before: &[AminoAcid]) -> Self {
+        Self {
+            n_term: vec![Some(residues.to_vec())],
+            c_term: vec![Some(
+                all_amino_acids()
+                    .filter(|aa| !not_before.contains(aa))
+                    .collect_vec(),
+            )],
         }
     }
 
-    /// All locations in the given sequence where this protease could cut
+    /// Define a protease from a cleavage rule regex, in the lookaround syntax that is used for
+    /// the cleavage rules in the PSI-MS ontology, eg `(?<=[KR])(?!P)` for trypsin. The pattern can
+    /// only consist of lookbehinds (`(?<=...)` and `(?<!...)`) and lookaheads (`(?=...)` and
+    /// `(?!...)`) which together define the amino acids around the cut site. Within a lookaround
+    /// every position is a single amino acid `K`, a set `[KR]`, a negated set `[^P]`, or any
+    /// amino acid `.`. A negative lookaround can only contain a single position.
+    /// # Errors
+    /// If the pattern does not follow this syntax or contains invalid amino acids.
+    pub fn from_regex(pattern: &str) -> Result<Self, CustomError> {
+        let bytes = pattern.as_bytes();
+        let mut n_term: Vec<Option<Vec<AminoAcid>>> = Vec::new();
+        let mut c_term: Vec<Option<Vec<AminoAcid>>> = Vec::new();
+        let mut index = 0;
+        while index < bytes.len() {
+            let (behind, negative, start) = if bytes[index..].starts_with(b"(?<=") {
+                (true, false, index + 4)
+            } else if bytes[index..].starts_with(b"(?<!") {
+                (true, true, index + 4)
+            } else if bytes[index..].starts_with(b"(?=") {
+                (false, false, index + 3)
+            } else if bytes[index..].starts_with(b"(?!") {
+                (false, true, index + 3)
+            } else {
+                return Err(CustomError::error(
+                    "Invalid protease regex",
+                    "Only lookbehinds '(?<=...)' '(?<!...)' and lookaheads '(?=...)' '(?!...)' are allowed",
+                    Context::line(None, pattern, index, 1),
+                ));
+            };
+            let end = bytes[start..]
+                .iter()
+                .position(|c| *c == b')')
+                .map(|end| start + end)
+                .ok_or_else(|| {
+                    CustomError::error(
+                        "Invalid protease regex",
+                        "The lookaround is not closed",
+                        Context::line(None, pattern, index, bytes.len() - index),
+                    )
+                })?;
+            let mut positions = regex_positions(pattern, start, end)?;
+            if negative {
+                if positions.len() != 1 {
+                    return Err(CustomError::error(
+                        "Invalid protease regex",
+                        "A negative lookaround can only contain a single position",
+                        Context::line(None, pattern, index, end + 1 - index),
+                    ));
+                }
+                let excluded = positions[0].take().unwrap_or_default();
+                positions[0] = Some(
+                    all_amino_acids()
+                        .filter(|aa| !excluded.contains(aa))
+                        .collect_vec(),
+                );
+            }
+            // Lookbehinds are aligned on the cut site at their end, lookaheads at their start
+            let side = if behind { &mut n_term } else { &mut c_term };
+            if positions.len() > side.len() {
+                let extra = positions.len() - side.len();
+                if behind {
+                    side.splice(0..0, std::iter::repeat(None).take(extra));
+                } else {
+                    side.extend(std::iter::repeat(None).take(extra));
+                }
+            }
+            let offset = if behind {
+                side.len() - positions.len()
+            } else {
+                0
+            };
+            for (existing, position) in side[offset..].iter_mut().zip(positions) {
+                if let Some(position) = position {
+                    match existing {
+                        Some(existing) => existing.retain(|aa| position.contains(aa)),
+                        None => *existing = Some(position),
+                    }
+                }
+            }
+            index = end + 1;
+        }
+        if n_term.is_empty() && c_term.is_empty() {
+            return Err(CustomError::error(
+                "Invalid protease regex",
+                "The pattern does not define any cleavage specificity",
+                Context::show(pattern),
+            ));
+        }
+        Ok(Self { n_term, c_term })
+    }
+
+    /// Get one of the commonly used proteases by name. The name is matched case insensitive and
+    /// ignoring dashes, underscores, and spaces. The cleavage rules are based on the PSI-MS ontology.
+    ///
+    /// | Name           | Cleaves                                |
+    /// |----------------|----------------------------------------|
+    /// | `trypsin`      | after K or R, except before P          |
+    /// | `trypsin/p`    | after K or R                           |
+    /// | `chymotrypsin` | after F, Y, W, or L, except before P   |
+    /// | `lysc`         | after K, except before P               |
+    /// | `lysc/p`       | after K                                |
+    /// | `lysn`         | before K                               |
+    /// | `argc`         | after R, except before P               |
+    /// | `aspn`         | before D                               |
+    /// | `gluc`         | after E, except before P               |
+    pub fn by_name(name: &str) -> Option<Self> {
+        let name = name
+            .chars()
+            .filter(|c| !matches!(c, '-' | '_' | ' '))
+            .collect::<String>()
+            .to_ascii_lowercase();
+        NAMED_PROTEASES
+            .iter()
+            .find(|(n, _)| *n == name)
+            .and_then(|(_, pattern)| Self::from_regex(pattern).ok())
+    }
+
+    /// All locations in the given sequence where this protease could cut, as the index of the
+    /// first amino acid after the cut. Only cuts between two amino acids are reported.
     pub fn match_locations<T>(&self, sequence: &[SequenceElement<T>]) -> Vec<usize> {
-        (self.n_term.len()..sequence.len() - self.c_term.len())
-            .filter(|i| self.matches_at(&sequence[i - self.n_term.len()..i + self.c_term.len()]))
+        (1..sequence.len())
+            .filter(|i| self.cuts_at(sequence, *i))
             .collect_vec()
     }
 
+    /// Check if this protease could cut the given sequence at the given index (between `index - 1`
+    /// and `index`). Any part of the specificity that falls outside of the sequence cannot be
+    /// verified, so is seen as not matching.
+    pub(crate) fn cuts_at<T>(&self, sequence: &[SequenceElement<T>], index: usize) -> bool {
+        index >= self.n_term.len()
+            && index + self.c_term.len() <= sequence.len()
+            && self.matches_at(&sequence[index - self.n_term.len()..index + self.c_term.len()])
+    }
+
     fn matches_at<T>(&self, slice: &[SequenceElement<T>]) -> bool {
         debug_assert!(slice.len() == self.n_term.len() + self.c_term.len());
         'positions: for (actual, pattern) in slice
@@ -63,3 +209,163 @@ impl Protease {
         true
     }
 }
+
+/// The cleavage rules for the proteases available with [`Protease::by_name`]
+const NAMED_PROTEASES: &[(&str, &str)] = &[
+    ("trypsin", "(?<=[KR])(?!P)"),
+    ("trypsin/p", "(?<=[KR])"),
+    ("chymotrypsin", "(?<=[FYWL])(?!P)"),
+    ("lysc", "(?<=K)(?!P)"),
+    ("lysc/p", "(?<=K)"),
+    ("lysn", "(?=K)"),
+    ("argc", "(?<=R)(?!P)"),
+    ("aspn", "(?=D)"),
+    ("gluc", "(?<=E)(?!P)"),
+];
+
+/// All amino acids that can be part of a protease specificity
+fn all_amino_acids() -> impl Iterator<Item = AminoAcid> {
+    AminoAcid::CANONICAL_AMINO_ACIDS
+        .iter()
+        .chain(&[AminoAcid::Selenocysteine, AminoAcid::Pyrrolysine])
+        .copied()
+}
+
+/// Parse the positions in a lookaround of a protease regex, `start..end` is the range of the
+/// inner text of the lookaround.
+///
+/// # Errors
+/// If a set is not closed or any of the positions is not a valid amino acid.
+fn regex_positions(
+    pattern: &str,
+    start: usize,
+    end: usize,
+) -> Result<Vec<Option<Vec<AminoAcid>>>, CustomError> {
+    let bytes = pattern.as_bytes();
+    let amino_acid = |index: usize| {
+        AminoAcid::try_from(bytes[index] as char).map_err(|()| {
+            CustomError::error(
+                "Invalid protease regex",
+                "This is not a valid amino acid",
+                Context::line(None, pattern, index, 1),
+            )
+        })
+    };
+    let mut positions = Vec::new();
+    let mut index = start;
+    while index < end {
+        match bytes[index] {
+            b'.' => positions.push(None),
+            b'[' => {
+                let close = bytes[index..end]
+                    .iter()
+                    .position(|c| *c == b']')
+                    .map(|close| index + close)
+                    .ok_or_else(|| {
+                        CustomError::error(
+                            "Invalid protease regex",
+                            "The set is not closed",
+                            Context::line(None, pattern, index, end - index),
+                        )
+                    })?;
+                let negated = bytes.get(index + 1) == Some(&b'^');
+                let set = ((index + 1 + usize::from(negated))..close)
+                    .map(amino_acid)
+                    .collect::<Result<Vec<_>, _>>()?;
+                positions.push(Some(if negated {
+                    all_amino_acids()
+                        .filter(|aa| !set.contains(aa))
+                        .collect_vec()
+                } else {
+                    set
+                }));
+                index = close;
+            }
+            _ => positions.push(Some(vec![amino_acid(index)?])),
+        }
+        index += 1;
+    }
+    Ok(positions)
+}
+
+/// The consistency of the termini of a peptide with the cleavage specificity of a protease.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum TerminusStatus {
+    /// Both termini are consistent with the protease (e.g. fully tryptic)
+    Specific,
+    /// Only the N terminus is consistent with the protease
+    SemiSpecificNTerm,
+    /// Only the C terminus is consistent with the protease
+    SemiSpecificCTerm,
+    /// Neither terminus is consistent with the protease
+    NonSpecific,
+}
+
+impl TerminusStatus {
+    /// Get the status based on the consistency of both termini.
+    pub const fn new(n_term: bool, c_term: bool) -> Self {
+        match (n_term, c_term) {
+            (true, true) => Self::Specific,
+            (true, false) => Self::SemiSpecificNTerm,
+            (false, true) => Self::SemiSpecificCTerm,
+            (false, false) => Self::NonSpecific,
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::missing_panics_doc)]
+mod tests {
+    use super::*;
+    use crate::Peptidoform;
+
+    #[test]
+    fn from_regex() {
+        assert_eq!(
+            Protease::from_regex("(?<=[KR])(?!P)").unwrap(),
+            Protease::c_terminal_of_except(
+                &[AminoAcid::Lysine, AminoAcid::Arginine],
+                &[AminoAcid::Proline]
+            )
+        );
+        assert_eq!(
+            Protease::from_regex("(?=D)").unwrap(),
+            Protease::n_terminal_of(&[AminoAcid::AsparticAcid])
+        );
+        let protease = Protease::from_regex("(?<=G.[^P])(?<=[GA])(?=K)").unwrap();
+        assert_eq!(protease.n_term.len(), 3);
+        assert_eq!(protease.n_term[0], Some(vec![AminoAcid::Glycine]));
+        assert_eq!(protease.n_term[1], None);
+        assert_eq!(
+            protease.n_term[2],
+            Some(vec![AminoAcid::Glycine, AminoAcid::Alanine])
+        );
+        assert_eq!(protease.c_term, vec![Some(vec![AminoAcid::Lysine])]);
+        for invalid in ["", "K", "(?<=K", "(?<=[KR)", "(?<=1)", "(?!PP)", "(?<=K)P"] {
+            assert!(Protease::from_regex(invalid).is_err(), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn by_name() {
+        let protein = Peptidoform::pro_forma("AKPEKMRDSFPEWGK", None)
+            .unwrap()
+            .into_linear()
+            .unwrap();
+        let digest = |name: &str| {
+            protein
+                .digest(&Protease::by_name(name).unwrap(), 0)
+                .iter()
+                .map(ToString::to_string)
+                .collect_vec()
+        };
+        assert_eq!(digest("trypsin"), ["AKPEK", "MR", "DSFPEWGK"]);
+        assert_eq!(digest("Trypsin/P"), ["AK", "PEK", "MR", "DSFPEWGK"]);
+        assert_eq!(digest("Lys-C"), ["AKPEK", "MRDSFPEWGK"]);
+        assert_eq!(digest("LysN"), ["A", "KPE", "KMRDSFPEWG", "K"]);
+        assert_eq!(digest("chymotrypsin"), ["AKPEKMRDSFPEW", "GK"]);
+        assert_eq!(digest("AspN"), ["AKPEKMR", "DSFPEWGK"]);
+        assert_eq!(digest("Glu-C"), ["AKPE", "KMRDSFPE", "WGK"]);
+        assert!(Protease::by_name("unknown").is_none());
+    }
+}
@@ -1,7 +1,7 @@
-//! Handle MGF reader reading
+//! Handle MGF reading and writing
 use std::{
     fs::File,
-    io::{BufRead, BufReader},
+    io::{BufRead, BufReader, Write},
     path::Path,
 };
 
@@ -125,6 +125,11 @@ pub fn open_raw<T: std::io::Read>(reader: T) -> Result<Vec<RawSpectrum>, CustomE
                     }
                     "TITLE" => parse_title(value, &mut current),
                     "SEQUENCE" => current.sequence = Some(value.to_owned()),
+                    "SCANS" => {
+                        if let Ok(scan) = value.parse() {
+                            current.raw_scan_number = Some(scan);
+                        }
+                    }
                     "NUM_SCANS" => {
                         current.num_scans = value.parse().map_err(|_| {
                             base_error
@@ -168,6 +173,50 @@ pub fn open_raw<T: std::io::Read>(reader: T) -> Result<Vec<RawSpectrum>, CustomE
     Ok(output)
 }
 
+/// Write the given spectra as MGF. Every spectrum is written as a `BEGIN IONS`/`END IONS` block
+/// with the `TITLE`, `PEPMASS`, `CHARGE`, `RTINSECONDS`, and `SCANS` fields if these are set
+/// on the spectrum. A spectrum without a precursor charge (or a charge of zero) does not get a
+/// `CHARGE` line. The precursor charge is unsigned (as negative charges are also rejected when
+/// reading) so it is always written with a `+` suffix, e.g. `CHARGE=2+`.
+///
+/// # Errors
+/// If the writer returns an error.
+pub fn write(spectra: &[RawSpectrum], mut writer: impl Write) -> std::io::Result<()> {
+    for spectrum in spectra {
+        writeln!(writer, "BEGIN IONS")?;
+        if !spectrum.title.is_empty() {
+            writeln!(writer, "TITLE={}", spectrum.title)?;
+        }
+        if let Some(mass) = spectrum.mass {
+            write!(writer, "PEPMASS={}", mass.get::<dalton>())?;
+            if let Some(intensity) = spectrum.intensity {
+                write!(writer, " {intensity}")?;
+            }
+            writeln!(writer)?;
+        }
+        if let Some(charge) = spectrum.charge.filter(|c| c.value != 0) {
+            writeln!(writer, "CHARGE={}+", charge.value)?;
+        }
+        if let Some(rt) = spectrum.rt {
+            writeln!(writer, "RTINSECONDS={}", rt.get::<s>())?;
+        }
+        if let Some(scan) = spectrum.raw_scan_number {
+            writeln!(writer, "SCANS={scan}")?;
+        }
+        if let Some(sequence) = &spectrum.sequence {
+            writeln!(writer, "SEQUENCE={sequence}")?;
+        }
+        if spectrum.num_scans != 0 {
+            writeln!(writer, "NUM_SCANS={}", spectrum.num_scans)?;
+        }
+        for peak in spectrum.spectrum() {
+            writeln!(writer, "{} {}", peak.mz.get::<mz>(), peak.intensity)?;
+        }
+        writeln!(writer, "END IONS")?;
+    }
+    Ok(())
+}
+
 /// # Errors
 /// When the charge could not be properly parsed. For example if it has a negative charge.
 fn parse_charge(input: &str) -> Result<Charge, ()> {
@@ -227,6 +276,26 @@ mod tests {
         assert!(spectra[0][0].mz < spectra[0][1].mz);
     }
 
+    #[test]
+    fn write_round_trip() {
+        let mut spectra =
+            open(std::env::var("CARGO_MANIFEST_DIR").unwrap() + "/data/example.mgf").unwrap();
+        let mut uncharged = spectra[0].clone();
+        uncharged.charge = None;
+        uncharged.rt = Some(Time::new::<s>(92.1));
+        uncharged.raw_scan_number = Some(42);
+        spectra.push(uncharged);
+
+        let mut buffer = Vec::new();
+        write(&spectra, &mut buffer).unwrap();
+        let text = String::from_utf8(buffer.clone()).unwrap();
+        assert_eq!(text.matches("BEGIN IONS").count(), 2);
+        assert_eq!(text.matches("CHARGE=").count(), 1);
+        assert!(text.contains(&format!("CHARGE={}+\n", spectra[0].charge.unwrap().value)));
+        assert!(text.contains("SCANS=42"));
+        assert_eq!(open_raw(buffer.as_slice()).unwrap(), spectra);
+    }
+
     #[test]
     fn test_titles() {
         assert_eq!(
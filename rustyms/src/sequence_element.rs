@@ -10,8 +10,8 @@ use crate::{
     },
     peptidoform::{AtLeast, Linked},
     placement_rule::PlacementRule,
-    CheckedAminoAcid, DiagnosticIon, MolecularFormula, Multi, MultiChemical, Peptidoform,
-    SequencePosition,
+    AminoAcid, CheckedAminoAcid, DiagnosticIon, MolecularFormula, Multi, MultiChemical,
+    Peptidoform, SequencePosition,
 };
 use serde::{Deserialize, Serialize};
 use thin_vec::ThinVec;
@@ -25,6 +25,9 @@ pub struct SequenceElement<T> {
     pub modifications: ThinVec<Modification>,
     /// If this aminoacid is part of an ambiguous sequence group `(QA)?` in ProForma
     pub ambiguous: Option<NonZeroU32>,
+    /// Free text information tags (ProForma `INFO:...`) given on their own on this position
+    #[serde(default)]
+    pub info: ThinVec<String>,
     /// The marker indicating which level of complexity this sequence element uses as higher bound
     marker: PhantomData<T>,
 }
@@ -35,6 +38,7 @@ impl<T> Clone for SequenceElement<T> {
             aminoacid: self.aminoacid,
             modifications: self.modifications.clone(),
             ambiguous: self.ambiguous,
+            info: self.info.clone(),
             marker: PhantomData,
         }
     }
@@ -45,6 +49,7 @@ impl<A, B> PartialEq<SequenceElement<B>> for SequenceElement<A> {
         self.aminoacid == other.aminoacid
             && self.modifications == other.modifications
             && self.ambiguous == other.ambiguous
+            && self.info == other.info
     }
 }
 
@@ -53,6 +58,7 @@ impl<T> std::hash::Hash for SequenceElement<T> {
         self.aminoacid.hash(state);
         self.modifications.hash(state);
         self.ambiguous.hash(state);
+        self.info.hash(state);
     }
 }
 
@@ -65,6 +71,7 @@ impl<T> SequenceElement<T> {
             aminoacid: self.aminoacid.mark::<M>(),
             modifications: self.modifications,
             ambiguous: self.ambiguous,
+            info: self.info,
             marker: PhantomData,
         }
     }
@@ -81,6 +88,7 @@ impl<T> SequenceElement<T> {
             aminoacid,
             modifications: ThinVec::new(),
             ambiguous,
+            info: ThinVec::new(),
             marker: PhantomData,
         }
     }
@@ -88,27 +96,37 @@ impl<T> SequenceElement<T> {
     /// Add a modification to this sequence element
     #[must_use]
     pub fn with_simple_modification(mut self, modification: SimpleModification) -> Self {
-        self.modifications.push(Modification::Simple(modification));
+        self.modifications.push(Modification::from(modification));
         self
     }
 
     /// Add a modification to this sequence element
     pub fn add_simple_modification(&mut self, modification: SimpleModification) {
-        self.modifications.push(Modification::Simple(modification));
+        self.modifications.push(Modification::from(modification));
     }
 }
 
+/// The options to display a sequence element as part of a peptidoform
+pub struct DisplayOptions<'a> {
+    /// The ambiguous modifications that are already written including their definition
+    pub placed_ambiguous: &'a [usize],
+    /// The preferred location, if any, for each ambiguous modification
+    pub preferred_ambiguous_location: &'a [Option<SequencePosition>],
+    /// The ambiguous modifications that are not written on their positions
+    pub hidden_ambiguous: &'a [usize],
+    /// Display as fully valid ProForma (no glycan structure or custom modifications)
+    pub specification_compliant: bool,
+}
+
 impl<T> SequenceElement<T> {
     /// # Errors
     /// If the underlying formatter errors.
     pub(crate) fn display(
         &self,
         f: &mut impl Write,
-        placed_ambiguous: &[usize],
-        preferred_ambiguous_location: &[Option<SequencePosition>],
+        options: &DisplayOptions,
         index: usize,
         last_ambiguous: Option<NonZeroU32>,
-        specification_compliant: bool,
     ) -> Result<Vec<usize>, std::fmt::Error> {
         let mut extra_placed = Vec::new();
         if last_ambiguous.is_some() && last_ambiguous != self.ambiguous {
@@ -117,12 +135,20 @@ impl<T> SequenceElement<T> {
         if self.ambiguous.is_some() && last_ambiguous != self.ambiguous {
             write!(f, "(?")?;
         }
+        if let Some(symbol) = self.custom_symbol() {
+            write!(f, "{symbol}")?;
+            return Ok(extra_placed);
+        }
         write!(f, "{}", self.aminoacid.char())?;
         for m in &self.modifications {
             let mut display_ambiguous = false;
             if let Modification::Ambiguous { id, .. } = m {
-                if !placed_ambiguous.contains(id) && preferred_ambiguous_location[*id].is_none()
-                    || preferred_ambiguous_location[*id]
+                if options.hidden_ambiguous.contains(id) {
+                    continue;
+                }
+                if !options.placed_ambiguous.contains(id)
+                    && options.preferred_ambiguous_location[*id].is_none()
+                    || options.preferred_ambiguous_location[*id]
                         .is_some_and(|p| p == SequencePosition::Index(index))
                 {
                     display_ambiguous = true;
@@ -130,12 +156,29 @@ impl<T> SequenceElement<T> {
                 }
             }
             write!(f, "[")?;
-            m.display(f, specification_compliant, display_ambiguous)?;
+            m.display(f, options.specification_compliant, display_ambiguous)?;
             write!(f, "]")?;
         }
+        for tag in &self.info {
+            write!(f, "[INFO:{tag}]")?;
+        }
         Ok(extra_placed)
     }
 
+    /// Get the symbol of the custom residue this element represents, if it is an unknown amino
+    /// acid (X) with only a custom residue as modification, see [`AminoAcid::register_custom`].
+    fn custom_symbol(&self) -> Option<char> {
+        if self.aminoacid.aminoacid() != AminoAcid::Unknown || !self.info.is_empty() {
+            return None;
+        }
+        match self.modifications.as_slice() {
+            [Modification::Simple { modification, info }] if info.is_empty() => {
+                AminoAcid::custom_symbol(modification)
+            }
+            _ => None,
+        }
+    }
+
     /// Get the molecular formulas for this position without any the ambiguous modifications
     #[allow(clippy::filter_map_bool_then, clippy::too_many_arguments)] // has side effects
     pub(crate) fn formulas_base(
@@ -306,7 +349,7 @@ impl<T> SequenceElement<T> {
                 Modification::CrossLink { linker, side, .. } => {
                     diagnostic_ions.extend_from_slice(&side.allowed_rules(linker).2);
                 }
-                Modification::Simple(modification)
+                Modification::Simple { modification, .. }
                 | Modification::Ambiguous { modification, .. } => match &**modification {
                     SimpleModificationInner::Database { specificities, .. } => {
                         for (rules, _, ions) in specificities {
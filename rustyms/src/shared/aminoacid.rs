@@ -209,7 +209,7 @@ impl MultiChemical for AminoAcid {
             Self::Methionine => molecular_formula!(H 9 C 5 O 1 N 1 S 1).into(),
             Self::Phenylalanine => molecular_formula!(H 9 C 9 O 1 N 1).into(),
             Self::Proline => molecular_formula!(H 7 C 5 O 1 N 1).into(),
-            Self::Pyrrolysine => molecular_formula!(H 19 C 11 O 2 N 3).into(),
+            Self::Pyrrolysine => molecular_formula!(H 19 C 12 O 2 N 3).into(),
             Self::Selenocysteine => molecular_formula!(H 5 C 3 O 1 N 1 Se 1).into(),
             Self::Serine => molecular_formula!(H 5 C 3 O 2 N 1).into(),
             Self::Threonine => molecular_formula!(H 7 C 4 O 2 N 1).into(),
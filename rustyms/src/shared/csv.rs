@@ -234,6 +234,13 @@ pub struct CsvLineIter<T: std::io::Read> {
     separator: u8,
 }
 
+impl<T: std::io::Read> CsvLineIter<T> {
+    /// Get the column headers
+    pub fn headers(&self) -> &[String] {
+        &self.header
+    }
+}
+
 impl<T: std::io::Read> Iterator for CsvLineIter<T> {
     type Item = Result<CsvLine, CustomError>;
     fn next(&mut self) -> Option<Self::Item> {
@@ -28,7 +28,8 @@ impl MolecularFormula {
     /// Example: [13C2]C-2H2N
     /// ```
     /// ## Allow charge
-    /// Allows electrons to be used to define the charge of the formula
+    /// Allows electrons to be used to define the charge of the formula, either with a trailing
+    /// charge tag (`C2H6O:z+1`) or with a bracketed charged species (`[Na+]`, `[SO4-2]`).
     /// ## Allow empty
     /// Allows the string `(empty)` to be used to denote an empty formula
     /// # Errors
@@ -74,6 +75,60 @@ impl MolecularFormula {
                         .skip(index)
                         .take_while(|c| c.is_ascii_digit())
                         .count();
+                    // A charged species without isotope: `[Na+]` or `[SO4-2]`
+                    if allow_charge && isotope == 0 {
+                        let content = value[index..index + len].trim_end();
+                        if let Some(sign) = content
+                            .rfind(['+', '-'])
+                            .filter(|sign| content[sign + 1..].bytes().all(|c| c.is_ascii_digit()))
+                        {
+                            let magnitude = if sign + 1 == content.len() {
+                                1
+                            } else {
+                                content[sign + 1..].parse::<i32>().map_err(|err| {
+                                    CustomError::error(
+                                        "Invalid ProForma molecular formula",
+                                        format!(
+                                            "The charge number is {}",
+                                            explain_number_error(&err)
+                                        ),
+                                        Context::line(
+                                            None,
+                                            value,
+                                            index + sign + 1,
+                                            content.len() - sign - 1,
+                                        ),
+                                    )
+                                })?
+                            };
+                            let charge = if content.as_bytes()[sign] == b'-' {
+                                -magnitude
+                            } else {
+                                magnitude
+                            };
+                            if let Some(element) = element.take() {
+                                if !Self::add(&mut result, (element, None, 1)) {
+                                    return Err(CustomError::error(
+                                        "Invalid ProForma molecular formula",
+                                        format!("An element without a defined mass ({element}) was used"),
+                                        Context::line(None, value, index - 1, 1),
+                                    ));
+                                }
+                            }
+                            if sign > 0 {
+                                result += Self::from_pro_forma(
+                                    value,
+                                    index..index + sign,
+                                    false,
+                                    false,
+                                    allow_uncommon_elements,
+                                )?;
+                            }
+                            let _ = result.add((Element::Electron, None, -charge));
+                            index += len + 1;
+                            continue 'main_parse_loop;
+                        }
+                    }
                     let ws1 = bytes[index + isotope..]
                         .iter()
                         .take_while(|b| **b == b' ')
@@ -50,6 +50,8 @@ pub enum AmbiguousLabel {
     CrossLinkBound(CrossLinkName),
     /// A broken cross-link, having the name and the stub that was left in its place
     CrossLinkBroken(CrossLinkName, MolecularFormula),
+    /// A neutral loss defined by a modification that is applied to this formula
+    NeutralLoss(crate::NeutralLoss),
 }
 
 /// Any item that has a clearly defined single molecular formula
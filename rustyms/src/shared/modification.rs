@@ -2,7 +2,12 @@
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize, Deserialize)]
 pub enum Modification {
     /// Any of the simple modifications
-    Simple(SimpleModification),
+    Simple {
+        /// The modification itself
+        modification: SimpleModification,
+        /// Free text information tags (ProForma `INFO:...`) given alongside this modification
+        info: Vec<String>,
+    },
     /// A cross link to another (or the same) peptide, a branch is also seen as a cross-link but then the name is None.
     CrossLink {
         /// The index of the peptide this cross-link is bound to (can be the index for this peptide if it is an intra link)
@@ -15,6 +20,8 @@ pub enum Modification {
         name: CrossLinkName,
         /// To determine if the cross-link is placed symmetrically or if asymmetrically if this is the left or right side
         side: CrossLinkSide,
+        /// Free text information tags (ProForma `INFO:...`) given alongside this cross-link
+        info: Vec<String>,
     },
     /// An ambiguous modification, that can be placed at multiple locations
     Ambiguous {
@@ -28,6 +35,8 @@ pub enum Modification {
         localisation_score: Option<OrderedFloat<f64>>,
         /// If this is the preferred location or not
         preferred: bool,
+        /// Free text information tags (ProForma `INFO:...`) given alongside this location
+        info: Vec<String>,
     },
 }
 
@@ -1,4 +1,5 @@
-/// A position on a sequence
+/// A position on a sequence. Positions are ordered along the sequence, so the N-terminus sorts
+/// before any index, which in turn sorts before the C-terminus.
 #[derive(
     Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, serde::Serialize, serde::Deserialize,
 )]
@@ -36,4 +37,52 @@ impl SequencePosition {
             Self::CTerm => Self::NTerm,
         }
     }
+
+    /// Resolve this position to the index of the amino acid it is placed on, the N-terminus
+    /// resolves to the first and the C-terminus to the last amino acid.
+    pub const fn as_index(self, peptide_length: usize) -> usize {
+        match self {
+            Self::NTerm => 0,
+            Self::Index(i) => i,
+            Self::CTerm => peptide_length.saturating_sub(1),
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::missing_panics_doc)]
+mod tests {
+    use super::SequencePosition;
+
+    #[test]
+    fn ordering() {
+        let mut positions = vec![
+            SequencePosition::Index(3),
+            SequencePosition::CTerm,
+            SequencePosition::Index(0),
+            SequencePosition::NTerm,
+            SequencePosition::Index(usize::MAX),
+        ];
+        positions.sort();
+        assert_eq!(
+            positions,
+            vec![
+                SequencePosition::NTerm,
+                SequencePosition::Index(0),
+                SequencePosition::Index(3),
+                SequencePosition::Index(usize::MAX),
+                SequencePosition::CTerm,
+            ]
+        );
+        assert!(SequencePosition::NTerm < SequencePosition::Index(0));
+        assert!(SequencePosition::CTerm > SequencePosition::Index(usize::MAX));
+    }
+
+    #[test]
+    fn as_index() {
+        assert_eq!(SequencePosition::NTerm.as_index(5), 0);
+        assert_eq!(SequencePosition::Index(2).as_index(5), 2);
+        assert_eq!(SequencePosition::CTerm.as_index(5), 4);
+        assert_eq!(SequencePosition::CTerm.as_index(0), 0);
+    }
 }
@@ -1,17 +1,19 @@
 //! Annotated spectra
 
-use std::cmp::Ordering;
+use std::{cmp::Ordering, fmt::Write};
 
+use itertools::{EitherOrBoth, Itertools};
 use ordered_float::OrderedFloat;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    fragment::Fragment,
+    fragment::{Fragment, FragmentType},
     system::{
         f64::{Mass, MassOverCharge, Time},
         usize::Charge,
     },
-    CompoundPeptidoformIon,
+    Chemical, CompoundPeptidoformIon, MassMode, MolecularCharge, NeutralLoss, SequencePosition,
+    Tolerance, WithinTolerance,
 };
 
 use super::{PeakSpectrum, RawPeak};
@@ -35,6 +37,301 @@ pub struct AnnotatedSpectrum {
     pub(super) spectrum: Vec<AnnotatedPeak>,
 }
 
+impl AnnotatedSpectrum {
+    /// Render the peptide sequence with markers for the observed b and y ion cleavages, for quick
+    /// textual QC. For each peptidoform the amino acids are written on the middle line with a gap
+    /// between every two residues. If a b ion is annotated for a cleavage a `┐` is placed above
+    /// the gap, if a y ion is annotated a `└` is placed below the gap. Multiple peptidoforms are
+    /// separated by an empty line.
+    /// ```text
+    ///  ┐ ┐ ┐   ┐
+    /// P E P T I D E
+    ///    └ └ └ └ └
+    /// ```
+    pub fn coverage_string(&self) -> String {
+        let mut blocks = Vec::new();
+        for (peptidoform_ion_index, peptidoform_ion) in
+            self.peptide.peptidoform_ions().iter().enumerate()
+        {
+            for (peptidoform_index, peptidoform) in
+                peptidoform_ion.peptidoforms().iter().enumerate()
+            {
+                let gaps = peptidoform.len().saturating_sub(1);
+                let mut n_terminal = vec![false; gaps];
+                let mut c_terminal = vec![false; gaps];
+                for fragment in self
+                    .spectrum
+                    .iter()
+                    .flat_map(|p| &p.annotation)
+                    .filter(|f| {
+                        f.peptidoform_ion_index == Some(peptidoform_ion_index)
+                            && f.peptidoform_index == Some(peptidoform_index)
+                    })
+                {
+                    match &fragment.ion {
+                        FragmentType::b(position) => {
+                            if let SequencePosition::Index(i) = position.sequence_index {
+                                if i < gaps {
+                                    n_terminal[i] = true;
+                                }
+                            }
+                        }
+                        FragmentType::y(position) => {
+                            if let SequencePosition::Index(i) = position.sequence_index {
+                                if (1..=gaps).contains(&i) {
+                                    c_terminal[i - 1] = true;
+                                }
+                            }
+                        }
+                        _ => (),
+                    }
+                }
+                let markers = |observed: &[bool], marker: char| {
+                    observed
+                        .iter()
+                        .flat_map(|o| [' ', if *o { marker } else { ' ' }])
+                        .collect::<String>()
+                };
+                blocks.push(format!(
+                    "{}\n{}\n{}",
+                    markers(&n_terminal, '┐'),
+                    peptidoform
+                        .sequence()
+                        .iter()
+                        .map(|s| s.aminoacid.char())
+                        .join(" "),
+                    markers(&c_terminal, '└'),
+                ));
+            }
+        }
+        blocks.join("\n\n")
+    }
+
+    /// Find all pairs of complementary peaks, meaning peaks whose neutral masses sum up to the
+    /// precursor mass within the given tolerance (for example the b and y ion from the same
+    /// cleavage). The neutral mass of a peak is calculated from the charges of its annotations,
+    /// assuming protons as charge carriers, unannotated peaks are assumed to be singly charged.
+    /// The pairs are given as indices into the peaks of this spectrum with the lowest index
+    /// first. If the precursor mass is not known this returns no pairs.
+    pub fn complementary_pairs(&self, tolerance: Tolerance<Mass>) -> Vec<(usize, usize)> {
+        let Some(precursor) = self.mass else {
+            return Vec::new();
+        };
+        let proton = MolecularCharge::proton(1).formula().monoisotopic_mass();
+        let masses = self
+            .spectrum
+            .iter()
+            .enumerate()
+            .flat_map(|(index, peak)| {
+                let mut charges = peak
+                    .annotation
+                    .iter()
+                    .map(|f| f.charge.value)
+                    .filter(|z| *z > 0)
+                    .unique()
+                    .collect_vec();
+                if charges.is_empty() {
+                    charges.push(1);
+                }
+                charges.into_iter().map(move |z| {
+                    let charge =
+                        crate::system::f64::Charge::new::<crate::system::charge::e>(z as f64);
+                    (index, peak.experimental_mz * charge - proton * z as f64)
+                })
+            })
+            .collect_vec();
+        masses
+            .iter()
+            .tuple_combinations()
+            .filter(|((a, mass_a), (b, mass_b))| {
+                a != b && tolerance.within(&precursor, &(*mass_a + *mass_b))
+            })
+            .map(|((a, _), (b, _))| (*a, *b))
+            .unique()
+            .collect()
+    }
+
+    /// Write this spectrum as a spectrum entry in the mzSpecLib text format. Every peptidoform
+    /// ion is written as a separate analyte and every peak is annotated with all its fragments as
+    /// mzPAF interpretations, separated by commas. Peaks without annotation are written as `?`.
+    /// Ions that cannot be named in mzPAF are written as formula ions (`f{...}`). To build a full
+    /// library the `<mzSpecLib>` header has to be prepended.
+    pub fn to_mzspeclib(&self) -> String {
+        let mut output = String::from("<Spectrum=1>\n");
+        writeln!(output, "MS:1003061|library spectrum name={}", self.title).unwrap();
+        if let Some(charge) = self.charge {
+            writeln!(output, "MS:1000041|charge state={}", charge.value).unwrap();
+        }
+        let analytes = self.peptide.peptidoform_ions();
+        for (index, peptidoform_ion) in analytes.iter().enumerate() {
+            writeln!(output, "<Analyte={}>", index + 1).unwrap();
+            writeln!(
+                output,
+                "MS:1003169|proforma peptidoform sequence={peptidoform_ion}"
+            )
+            .unwrap();
+        }
+        output.push_str("<Peaks>\n");
+        for peak in &self.spectrum {
+            let interpretations = if peak.annotation.is_empty() {
+                "?".to_string()
+            } else {
+                peak.annotation
+                    .iter()
+                    .map(|fragment| {
+                        mzpaf_interpretation(fragment, peak.experimental_mz, analytes.len() > 1)
+                    })
+                    .join(",")
+            };
+            writeln!(
+                output,
+                "{}\t{}\t{interpretations}",
+                peak.experimental_mz.value, peak.intensity
+            )
+            .unwrap();
+        }
+        output
+    }
+
+    /// Compare the annotations of this spectrum with the annotations of another annotated
+    /// spectrum, for example to compare two fragmentation models on the same data. Both spectra
+    /// are assumed to be annotated from the same underlying spectrum, so peaks are matched on
+    /// their experimental m/z. The order of the annotations on a single peak is ignored.
+    pub fn diff(&self, other: &Self) -> AnnotationDiff {
+        let mut diff = AnnotationDiff::default();
+        for pair in self.spectrum.iter().merge_join_by(&other.spectrum, |a, b| {
+            a.experimental_mz.value.total_cmp(&b.experimental_mz.value)
+        }) {
+            match pair {
+                EitherOrBoth::Both(a, b) => {
+                    match (a.annotation.is_empty(), b.annotation.is_empty()) {
+                        (false, true) => diff.only_self.push(a.clone()),
+                        (true, false) => diff.only_other.push(b.clone()),
+                        (false, false)
+                            if a.annotation.len() != b.annotation.len()
+                                || a.annotation.iter().any(|f| !b.annotation.contains(f)) =>
+                        {
+                            diff.changed.push((a.clone(), b.clone()));
+                        }
+                        _ => (),
+                    }
+                }
+                EitherOrBoth::Left(a) if !a.annotation.is_empty() => {
+                    diff.only_self.push(a.clone());
+                }
+                EitherOrBoth::Right(b) if !b.annotation.is_empty() => {
+                    diff.only_other.push(b.clone());
+                }
+                _ => (),
+            }
+        }
+        diff
+    }
+}
+
+/// The differences between the annotations of two annotated spectra, see [`AnnotatedSpectrum::diff`]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AnnotationDiff {
+    /// The peaks that are only annotated in the first spectrum
+    pub only_self: Vec<AnnotatedPeak>,
+    /// The peaks that are only annotated in the second spectrum
+    pub only_other: Vec<AnnotatedPeak>,
+    /// The peaks that are annotated in both spectra but with different annotations, as the peak
+    /// from the first and the peak from the second spectrum
+    pub changed: Vec<(AnnotatedPeak, AnnotatedPeak)>,
+}
+
+impl AnnotationDiff {
+    /// Check if both spectra have identical annotations
+    pub fn is_empty(&self) -> bool {
+        self.only_self.is_empty() && self.only_other.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// Write a single neutral loss (or gain) as mzPAF suffix, using the conventional notation for
+/// common molecules (`-NH3`, `-H3PO4`) and Hill notation without counts of one otherwise.
+fn mzpaf_neutral_loss(loss: &NeutralLoss) -> String {
+    let (sign, formula) = match loss {
+        NeutralLoss::Loss(formula) => ('-', formula),
+        NeutralLoss::Gain(formula) => ('+', formula),
+    };
+    crate::mzpaf::mz_paf_conventional_molecules()
+        .iter()
+        .find(|(_, known)| known == formula)
+        .map_or_else(
+            || format!("{sign}{}", formula.hill_notation()),
+            |(name, _)| format!("{sign}{name}"),
+        )
+}
+
+/// Write a single fragment as mzPAF interpretation, the deviation is given as the experimental
+/// minus the theoretical m/z.
+#[allow(clippy::cast_possible_wrap)]
+fn mzpaf_interpretation(
+    fragment: &Fragment,
+    experimental_mz: MassOverCharge,
+    analyte_number: bool,
+) -> String {
+    let mut output = String::new();
+    if fragment.auxiliary {
+        output.push('&');
+    }
+    if analyte_number {
+        if let Some(index) = fragment.peptidoform_ion_index {
+            write!(output, "{}@", index + 1).unwrap();
+        }
+    }
+    let named = match &fragment.ion {
+        FragmentType::a(position)
+        | FragmentType::b(position)
+        | FragmentType::c(position)
+        | FragmentType::x(position)
+        | FragmentType::y(position)
+        | FragmentType::z(position) => Some(format!(
+            "{}{}",
+            fragment.ion.label(),
+            position.series_number
+        )),
+        FragmentType::Immonium(_, element) if element.modifications.is_empty() => {
+            Some(format!("I{}", element.aminoacid.char()))
+        }
+        FragmentType::Precursor => Some("p".to_string()),
+        FragmentType::Unknown(series) => Some(format!(
+            "?{}",
+            series.map_or(String::new(), |s| s.to_string())
+        )),
+        _ => None,
+    };
+    if let Some(named) = named {
+        output.push_str(&named);
+        for loss in &fragment.neutral_loss {
+            output.push_str(&mzpaf_neutral_loss(loss));
+        }
+    } else {
+        // Write the formula of the neutral fragment, all charge is assumed to be protons
+        let formula = fragment
+            .formula
+            .as_ref()
+            .map(|f| f.clone() - MolecularCharge::proton(fragment.charge.value as isize).formula());
+        match formula {
+            Some(formula) if *formula.additional_mass() == 0.0 => {
+                write!(output, "f{{{formula}}}").unwrap();
+            }
+            _ => output.push('?'),
+        }
+    }
+    if fragment.charge.value > 1 {
+        write!(output, "^{}", fragment.charge.value).unwrap();
+    }
+    if let Some(mz) = fragment.mz(MassMode::Monoisotopic) {
+        write!(output, "/{:.4}", (experimental_mz - mz).value).unwrap();
+    }
+    if let Some(confidence) = fragment.confidence {
+        write!(output, "*{confidence}").unwrap();
+    }
+    output
+}
+
 impl Extend<AnnotatedPeak> for AnnotatedSpectrum {
     fn extend<T: IntoIterator<Item = AnnotatedPeak>>(&mut self, iter: T) {
         self.spectrum.extend(iter);
@@ -123,6 +420,10 @@ pub struct AnnotatedPeak {
     pub annotation: Vec<Fragment>, // Could become Vec<(Fragment, Vec<MatchedIsotopeDistribution>)> when isotope matching is finally in place
     /// Any annotation as isotope from a given fragment
     pub isotope_annotation: Vec<(usize, usize)>,
+    /// The isotope (A+n) that was matched for the first annotation, only set when annotating
+    /// with [`MassMode::MostAbundant`]
+    #[serde(default)]
+    pub isotope_offset: Option<i8>,
 }
 
 impl AnnotatedPeak {
@@ -133,6 +434,7 @@ impl AnnotatedPeak {
             intensity: peak.intensity,
             annotation: vec![annotation],
             isotope_annotation: Vec::new(),
+            isotope_offset: None,
         }
     }
 
@@ -143,6 +445,7 @@ impl AnnotatedPeak {
             intensity: peak.intensity,
             annotation: Vec::new(),
             isotope_annotation: Vec::new(),
+            isotope_offset: None,
         }
     }
 }
@@ -175,3 +478,240 @@ impl PartialEq for AnnotatedPeak {
 }
 
 impl Eq for AnnotatedPeak {}
+
+#[cfg(test)]
+#[allow(clippy::missing_panics_doc)]
+mod tests {
+    use itertools::Itertools;
+
+    use crate::{
+        fragment::{Fragment, FragmentKind, FragmentType, PeptidePosition},
+        model::{ChargeRange, PrimaryIonSeries},
+        spectrum::{test_fragments, test_spectrum, RawPeak},
+        system::{da, e, usize::Charge},
+        AnnotatableSpectrum, CompoundPeptidoformIon, MassMode, Model, NeutralLoss,
+        SequencePosition, Tolerance,
+    };
+
+    #[test]
+    fn coverage_string() {
+        let (peptide, model, fragments) = test_fragments("PEPTIDE");
+        let spectrum = |kinds: &[FragmentKind]| {
+            let mut spectrum = test_spectrum(
+                fragments.iter().filter(|f| kinds.contains(&f.ion.kind())),
+                MassMode::Monoisotopic,
+            );
+            spectrum.extend([RawPeak {
+                mz: crate::system::MassOverCharge::new::<crate::system::mz>(50.0),
+                intensity: 1.0.into(),
+            }]);
+            spectrum.annotate(peptide.clone(), &fragments, &model, MassMode::Monoisotopic)
+        };
+        assert_eq!(
+            spectrum(&[FragmentKind::b, FragmentKind::y]).coverage_string(),
+            " ┐ ┐ ┐ ┐ ┐ ┐\nP E P T I D E\n └ └ └ └ └ └"
+        );
+        assert_eq!(
+            spectrum(&[FragmentKind::b]).coverage_string(),
+            " ┐ ┐ ┐ ┐ ┐ ┐\nP E P T I D E\n            "
+        );
+        assert_eq!(
+            spectrum(&[]).coverage_string(),
+            "            \nP E P T I D E\n            "
+        );
+    }
+
+    #[test]
+    fn complementary_pairs() {
+        let (peptide, model, fragments) = test_fragments("PEPTIDE");
+        let mut spectrum = crate::spectrum::RawSpectrum::default();
+        spectrum.mass = Some(peptide.formulas()[0].monoisotopic_mass());
+        spectrum.extend([RawPeak {
+            mz: crate::system::MassOverCharge::new::<crate::system::mz>(50.0),
+            intensity: 1.0.into(),
+        }]);
+        // Shift half of the y ions by 0.01 Da to simulate noise
+        spectrum.extend(fragments.iter().filter_map(|f| {
+            f.mz(MassMode::Monoisotopic).map(|mz| RawPeak {
+                mz: match &f.ion {
+                    FragmentType::y(position) if position.series_number % 2 == 0 => {
+                        mz + crate::system::MassOverCharge::new::<crate::system::mz>(0.01)
+                    }
+                    _ => mz,
+                },
+                intensity: 1.0.into(),
+            })
+        }));
+        let annotated = spectrum.annotate(peptide, &fragments, &model, MassMode::Monoisotopic);
+        let tight = annotated.complementary_pairs(Tolerance::new_ppm(1.0));
+        let loose = annotated.complementary_pairs(Tolerance::new_absolute(da(0.05)));
+        assert!(!tight.is_empty());
+        assert!(loose.len() > tight.len(), "{tight:?} {loose:?}");
+        assert!(tight.iter().all(|p| loose.contains(p)));
+        let mut unknown = annotated;
+        unknown.mass = None;
+        assert!(unknown
+            .complementary_pairs(Tolerance::new_absolute(da(0.05)))
+            .is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "isotopes")]
+    fn isotope_offset() {
+        let (peptide, model, fragments) = test_fragments("PEPTIDEPEPTIDEPEPTIDEPEPTIDE");
+        let spectrum = test_spectrum(&fragments, MassMode::MostAbundant);
+        let annotated =
+            spectrum.annotate(peptide.clone(), &fragments, &model, MassMode::MostAbundant);
+        let offsets = annotated
+            .spectrum
+            .iter()
+            .filter(|p| !p.annotation.is_empty())
+            .map(|p| p.isotope_offset)
+            .collect_vec();
+        assert!(!offsets.is_empty());
+        assert!(offsets.iter().all(Option::is_some));
+        assert!(offsets.contains(&Some(0)));
+        assert!(offsets.iter().any(|o| o.is_some_and(|o| o > 0)));
+        let monoisotopic = spectrum.annotate(peptide, &fragments, &model, MassMode::Monoisotopic);
+        assert!(monoisotopic
+            .spectrum
+            .iter()
+            .all(|p| p.isotope_offset.is_none()));
+    }
+
+    #[test]
+    fn to_mzspeclib() {
+        let peptide = CompoundPeptidoformIon::pro_forma("PEPTIDE/2", None).unwrap();
+        let model = Model::none()
+            .b(PrimaryIonSeries::default()
+                .neutral_losses(vec![NeutralLoss::Loss(molecular_formula!(H 2 O 1))]))
+            .y(PrimaryIonSeries::default())
+            .d(PrimaryIonSeries::default())
+            .immonium((true, ChargeRange::ONE));
+        let fragments = peptide.generate_theoretical_fragments(Charge::new::<e>(2), &model);
+        let mut spectrum = test_spectrum(&fragments, MassMode::Monoisotopic);
+        spectrum.title = "test".to_string();
+        spectrum.charge = Some(Charge::new::<e>(2));
+        spectrum.extend([RawPeak {
+            mz: crate::system::MassOverCharge::new::<crate::system::mz>(2000.0),
+            intensity: 1.0.into(),
+        }]);
+        let annotated = spectrum.annotate(peptide, &fragments, &model, MassMode::Monoisotopic);
+        let library = annotated.to_mzspeclib();
+        let mut lines = library.lines();
+        assert_eq!(lines.next(), Some("<Spectrum=1>"));
+        assert!(library.contains("MS:1003061|library spectrum name=test\n"));
+        assert!(library.contains("MS:1000041|charge state=2\n"));
+        assert!(library.contains("<Analyte=1>\nMS:1003169|proforma peptidoform sequence=PEPTIDE"));
+        let peaks = lines
+            .skip_while(|l| *l != "<Peaks>")
+            .skip(1)
+            .map(|l| l.split('\t').collect_vec())
+            .collect_vec();
+        assert_eq!(peaks.len(), annotated.spectrum.len());
+        assert!(peaks.contains(&vec!["2000", "1", "?"]));
+        for (peak, line) in annotated
+            .spectrum
+            .iter()
+            .zip(&peaks)
+            .filter(|(p, _)| !p.annotation.is_empty())
+        {
+            let interpretations = crate::mzpaf::parse_mzpaf(line[2]).unwrap();
+            assert_eq!(interpretations.len(), peak.annotation.len(), "{}", line[2]);
+        }
+        let interpretations = peaks.iter().map(|l| l[2]).join(",");
+        for expected in ["b2", "b2-H2O", "y3^2", "IP/", "f{"] {
+            assert!(interpretations.contains(expected), "{expected}");
+        }
+    }
+
+    #[test]
+    fn to_mzspeclib_secondary_series() {
+        let peptide = CompoundPeptidoformIon::pro_forma("PEPTIDE", None).unwrap();
+        let model = Model::none()
+            .b(PrimaryIonSeries::default())
+            .c(PrimaryIonSeries::default().secondary(Some(0.1)));
+        let fragments = peptide.generate_theoretical_fragments(Charge::new::<e>(1), &model);
+        let annotated = test_spectrum(&fragments, MassMode::Monoisotopic).annotate(
+            peptide,
+            &fragments,
+            &model,
+            MassMode::Monoisotopic,
+        );
+        assert!(annotated
+            .spectrum
+            .iter()
+            .flat_map(|p| &p.annotation)
+            .any(|f| f.secondary_weight.is_some()));
+        let library = annotated.to_mzspeclib();
+        let interpretations = library
+            .lines()
+            .skip_while(|l| *l != "<Peaks>")
+            .skip(1)
+            .map(|l| l.split('\t').nth(2).unwrap())
+            .collect_vec();
+        assert!(interpretations.iter().any(|i| i.contains("c2/")));
+        for interpretation in interpretations {
+            assert!(
+                !interpretation.contains('&') && !interpretation.contains('*'),
+                "{interpretation}"
+            );
+            crate::mzpaf::parse_mzpaf(interpretation).unwrap();
+        }
+    }
+
+    #[test]
+    fn mzpaf_multiple_neutral_losses() {
+        let fragment = Fragment::new(
+            molecular_formula!(C 20 H 35 N 5 O 8),
+            Charge::new::<e>(1),
+            0,
+            0,
+            FragmentType::y(PeptidePosition::c(SequencePosition::Index(2), 7)),
+        )
+        .with_neutral_loss(&NeutralLoss::Loss(molecular_formula!(H 2 O 1)))
+        .with_neutral_loss(&NeutralLoss::Loss(molecular_formula!(N 1 H 3)));
+        let mz = fragment.mz(MassMode::Monoisotopic).unwrap();
+        let label = super::mzpaf_interpretation(&fragment, mz, false);
+        assert_eq!(label, "y5-H2O-NH3/0.0000");
+        assert_eq!(crate::mzpaf::parse_mzpaf(&label).unwrap().len(), 1);
+        let losses = fragment
+            .neutral_loss
+            .iter()
+            .map(ToString::to_string)
+            .collect_vec();
+        assert_eq!(losses, ["-H2O1", "-H3N1"]);
+    }
+
+    #[test]
+    fn diff() {
+        let peptide = CompoundPeptidoformIon::pro_forma("PEPTIDE/2", None).unwrap();
+        let cid = Model::cid_hcd();
+        let etd = Model::etd();
+        let spectrum = test_spectrum(
+            &[&cid, &etd]
+                .iter()
+                .flat_map(|model| {
+                    peptide.generate_theoretical_fragments(Charge::new::<e>(2), model)
+                })
+                .collect_vec(),
+            MassMode::Monoisotopic,
+        );
+        let annotate = |model: &Model| {
+            let fragments = peptide.generate_theoretical_fragments(Charge::new::<e>(2), model);
+            spectrum.annotate(peptide.clone(), &fragments, model, MassMode::Monoisotopic)
+        };
+        let cid_annotated = annotate(&cid);
+        let etd_annotated = annotate(&etd);
+        assert!(cid_annotated.diff(&cid_annotated).is_empty());
+        let diff = cid_annotated.diff(&etd_annotated);
+        assert!(!diff.is_empty());
+        assert!(!diff.only_self.is_empty());
+        assert!(!diff.only_other.is_empty());
+        assert!(diff.only_self.iter().all(|p| !p.annotation.is_empty()
+            && etd_annotated
+                .spectrum
+                .iter()
+                .any(|o| o.experimental_mz == p.experimental_mz && o.annotation.is_empty())));
+    }
+}
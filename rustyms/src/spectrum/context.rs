@@ -0,0 +1,222 @@
+//! Reusable annotation context for a single spectrum
+
+use crate::{
+    spectrum::{AnnotatableSpectrum, AnnotatedPeak, PeakSpectrum},
+    system::{f64::MassOverCharge, usize::Charge},
+    AnnotatedSpectrum, CompoundPeptidoformIon, MassMode, Model, RawSpectrum, Tolerance,
+    WithinTolerance,
+};
+
+/// The width of a single bin in the peak index of an [`AnnotationContext`] in Th
+const BIN_WIDTH: f64 = 1.0;
+
+/// A spectrum prepared for repeated annotation, for example in interactive tools where the same
+/// spectrum is annotated with many different peptidoforms. The peak index and the blank annotated
+/// peaks are built once when the context is created and reused for every annotation.
+///
+/// The peak index splits the mz range of the spectrum in bins of 1 Th, so searching for a
+/// theoretical fragment only has to look at the peaks in the bins covered by the tolerance window.
+#[derive(Clone, Debug)]
+pub struct AnnotationContext {
+    /// The spectrum this context belongs to
+    spectrum: RawSpectrum,
+    /// The mz value at the start of the first bin
+    start: f64,
+    /// For every bin the index of the first peak in or above this bin, followed by the total
+    /// number of peaks, so the peaks in bin `i` are `bins[i]..bins[i + 1]`
+    bins: Vec<usize>,
+    /// The blank annotated peaks
+    background: Vec<AnnotatedPeak>,
+}
+
+impl AnnotationContext {
+    /// Prepare the given spectrum for annotation.
+    pub fn new(spectrum: RawSpectrum) -> Self {
+        let start = spectrum
+            .spectrum()
+            .next()
+            .map_or(0.0, |p| (p.mz.value / BIN_WIDTH).floor() * BIN_WIDTH);
+        let number_of_bins = spectrum
+            .spectrum()
+            .last()
+            .map_or(0, |p| ((p.mz.value - start) / BIN_WIDTH) as usize + 1);
+        let number_of_peaks = spectrum.spectrum().len();
+        let mut bins = Vec::with_capacity(number_of_bins + 1);
+        let mut peak = 0;
+        for bin in 0..number_of_bins {
+            let bin_start = (bin as f64).mul_add(BIN_WIDTH, start);
+            while peak < number_of_peaks && spectrum[peak].mz.value < bin_start {
+                peak += 1;
+            }
+            bins.push(peak);
+        }
+        bins.push(number_of_peaks);
+        let background = spectrum.spectrum().map(AnnotatedPeak::background).collect();
+        Self {
+            spectrum,
+            start,
+            bins,
+            background,
+        }
+    }
+
+    /// Get the bin for the given mz value, values below the first bin are placed in the first bin
+    fn bin(&self, mz: f64) -> usize {
+        ((mz - self.start) / BIN_WIDTH).max(0.0) as usize
+    }
+
+    /// The spectrum this context belongs to.
+    pub const fn spectrum(&self) -> &RawSpectrum {
+        &self.spectrum
+    }
+
+    /// Get back the spectrum this context belongs to.
+    pub fn into_spectrum(self) -> RawSpectrum {
+        self.spectrum
+    }
+
+    /// Annotate the spectrum with the given peptidoform. The theoretical fragments are generated
+    /// up to the charge of the spectrum (or 1 if this is unknown). This gives the same result as
+    /// generating the fragments and calling [`AnnotatableSpectrum::annotate`] on the spectrum.
+    pub fn annotate(
+        &self,
+        peptide: CompoundPeptidoformIon,
+        model: &Model,
+        mode: MassMode,
+    ) -> AnnotatedSpectrum {
+        let fragments = peptide.generate_theoretical_fragments(
+            self.spectrum
+                .charge
+                .unwrap_or_else(|| Charge::new::<crate::system::e>(1)),
+            model,
+        );
+        AnnotatableSpectrum::annotate(self, peptide, &fragments, model, mode)
+    }
+}
+
+impl From<RawSpectrum> for AnnotationContext {
+    fn from(value: RawSpectrum) -> Self {
+        Self::new(value)
+    }
+}
+
+impl AnnotatableSpectrum for AnnotationContext {
+    type Tolerance = Tolerance<MassOverCharge>;
+
+    fn empty_annotated(&self, peptide: CompoundPeptidoformIon) -> AnnotatedSpectrum {
+        AnnotatedSpectrum {
+            title: self.spectrum.title.clone(),
+            num_scans: self.spectrum.num_scans,
+            rt: self.spectrum.rt,
+            charge: self.spectrum.charge,
+            mass: self.spectrum.mass,
+            peptide,
+            spectrum: self.background.clone(),
+        }
+    }
+
+    fn search(&self, query: MassOverCharge, tolerance: Self::Tolerance) -> Option<usize> {
+        let (low, high) = tolerance.bounds(query);
+        if self.background.is_empty() || high.value < self.start {
+            return None;
+        }
+        let first = self.bin(low.value);
+        let last = self.bin(high.value).min(self.bins.len() - 2);
+        if first > last {
+            return None;
+        }
+
+        // Find the closest peak within tolerance out of all peaks in the bins covering the window
+        (self.bins[first]..self.bins[last + 1])
+            .filter(|i| tolerance.within(&self.spectrum[*i].mz, &query))
+            .min_by(|a, b| {
+                self.spectrum[*a]
+                    .ppm(query)
+                    .value
+                    .total_cmp(&self.spectrum[*b].ppm(query).value)
+            })
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::missing_panics_doc)]
+mod tests {
+    use super::*;
+    use crate::{
+        spectrum::RawPeak,
+        system::{e, mz},
+    };
+    use ordered_float::OrderedFloat;
+
+    #[test]
+    fn repeated_annotation() {
+        let model = Model::all();
+        let charge = Charge::new::<e>(2);
+        let target = CompoundPeptidoformIon::pro_forma("PEPTIDE", None).unwrap();
+        let mut spectrum = RawSpectrum::default();
+        spectrum.charge = Some(charge);
+        spectrum.extend(
+            target
+                .generate_theoretical_fragments(charge, &model)
+                .iter()
+                .filter_map(|f| f.mz(MassMode::Monoisotopic))
+                .step_by(3)
+                .map(|value| RawPeak {
+                    mz: value,
+                    intensity: OrderedFloat(1.0),
+                }),
+        );
+        spectrum.extend([RawPeak {
+            mz: MassOverCharge::new::<mz>(1234.5),
+            intensity: OrderedFloat(1.0),
+        }]);
+
+        let context = AnnotationContext::new(spectrum.clone());
+        for sequence in ["PEPTIDE", "PEPTLDE", "PEM[Oxidation]TIDE", "ELVISLIVER"] {
+            let peptide = CompoundPeptidoformIon::pro_forma(sequence, None).unwrap();
+            let fragments = peptide.generate_theoretical_fragments(charge, &model);
+            let expected =
+                spectrum.annotate(peptide.clone(), &fragments, &model, MassMode::Monoisotopic);
+            let first = context.annotate(peptide.clone(), &model, MassMode::Monoisotopic);
+            let second = context.annotate(peptide, &model, MassMode::Monoisotopic);
+            assert_eq!(first, expected, "{sequence}");
+            assert_eq!(second, expected, "{sequence}");
+        }
+        assert!(AnnotationContext::new(RawSpectrum::default())
+            .annotate(target, &model, MassMode::Monoisotopic)
+            .spectrum()
+            .next()
+            .is_none());
+    }
+
+    #[test]
+    fn search_matches_spectrum() {
+        let mut spectrum = RawSpectrum::default();
+        spectrum.extend(
+            [100.0, 100.4, 100.9, 101.0, 104.5, 250.25, 250.26, 999.999]
+                .iter()
+                .map(|value| RawPeak {
+                    mz: MassOverCharge::new::<mz>(*value),
+                    intensity: OrderedFloat(1.0),
+                }),
+        );
+        let context = AnnotationContext::new(spectrum.clone());
+        for tolerance in [
+            Tolerance::new_ppm(20.0),
+            Tolerance::new_absolute(MassOverCharge::new::<mz>(0.3)),
+            Tolerance::new_absolute(MassOverCharge::new::<mz>(2.0)),
+        ] {
+            for query in [
+                50.0, 99.9, 100.0, 100.2, 100.95, 102.0, 103.0, 250.255, 999.0, 1000.0, 1001.5,
+            ] {
+                let query = MassOverCharge::new::<mz>(query);
+                assert_eq!(
+                    context.search(query, tolerance),
+                    spectrum.search(query, tolerance),
+                    "{} {tolerance:?}",
+                    query.value
+                );
+            }
+        }
+    }
+}
@@ -25,6 +25,7 @@ impl AnnotatedSpectrum {
     ) -> (Fdr, Vec<Vec<Fdr>>) {
         let mzs = fragments
             .iter()
+            .filter(|f| model.heavy_enough(f, mass_mode))
             .filter_map(|f| {
                 f.mz(mass_mode)
                     .map(|mz| (mz, f.peptidoform_ion_index, f.peptidoform_index))
@@ -67,6 +68,23 @@ impl AnnotatedSpectrum {
         )
     }
 
+    /// Estimate the local false discovery rate of this annotation by comparing it to the
+    /// annotation of the same spectrum with a decoy peptide, for example generated with
+    /// [`CompoundPeptidoformIon::decoy`](crate::CompoundPeptidoformIon::decoy). Both annotations
+    /// should be made with the same model. See [`local_fdr`] for the estimation itself, the number
+    /// of annotated peaks is used as the number of matches.
+    pub fn local_fdr(&self, decoy: &Self) -> f64 {
+        local_fdr(self.annotated_peaks(), decoy.annotated_peaks())
+    }
+
+    /// The number of peaks with at least one annotation
+    fn annotated_peaks(&self) -> usize {
+        self.spectrum
+            .iter()
+            .filter(|p| !p.annotation.is_empty())
+            .count()
+    }
+
     fn internal_fdr(&self, mzs: &[MassOverCharge], model: &Model) -> Fdr {
         let mut results = Vec::with_capacity(51);
         let total_intensity = self.spectrum.iter().map(|s| s.intensity.0).sum::<f64>();
@@ -149,6 +167,18 @@ impl AnnotatedSpectrum {
     }
 }
 
+/// Estimate the local false discovery rate (as a fraction) from the number of matches of a target
+/// and a decoy against the same spectrum. The decoy matches are taken as the estimate of the
+/// number of random matches within the target matches, giving `decoy / target` capped at 1.0. If
+/// the target has no matches at all the FDR is 1.0.
+pub fn local_fdr(target_matches: usize, decoy_matches: usize) -> f64 {
+    if target_matches == 0 {
+        1.0
+    } else {
+        (decoy_matches as f64 / target_matches as f64).min(1.0)
+    }
+}
+
 /// A false discovery rate for an annotation to a spectrum
 #[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
 pub struct Fdr {
@@ -200,3 +230,36 @@ impl Fdr {
         self.intensity_sigma().log2()
     }
 }
+
+#[cfg(test)]
+#[allow(clippy::missing_panics_doc)]
+mod tests {
+    use super::*;
+    use crate::{
+        spectrum::{test_fragments, test_spectrum},
+        system::{e, usize::Charge},
+        AnnotatableSpectrum, DecoyMethod,
+    };
+
+    #[test]
+    fn local_fdr_counts() {
+        assert!((local_fdr(10, 2) - 0.2).abs() < f64::EPSILON);
+        assert!((local_fdr(10, 0)).abs() < f64::EPSILON);
+        assert!((local_fdr(5, 10) - 1.0).abs() < f64::EPSILON);
+        assert!((local_fdr(0, 0) - 1.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn local_fdr_decoy() {
+        let (peptide, model, fragments) = test_fragments("PEPTIDEK");
+        let decoy = peptide.decoy(DecoyMethod::PseudoReverse);
+        let decoy_fragments = decoy.generate_theoretical_fragments(Charge::new::<e>(1), &model);
+        let spectrum = test_spectrum(&fragments, MassMode::Monoisotopic);
+        let target = spectrum.annotate(peptide, &fragments, &model, MassMode::Monoisotopic);
+        let decoy = spectrum.annotate(decoy, &decoy_fragments, &model, MassMode::Monoisotopic);
+        let fdr = target.local_fdr(&decoy);
+        // Only y1, b7 (same composition), and the precursor are shared with the decoy EDITPEPK
+        assert!(fdr > 0.0 && fdr < 0.5, "{fdr}");
+        assert!((target.local_fdr(&target) - 1.0).abs() < f64::EPSILON);
+    }
+}
@@ -21,7 +21,9 @@ pub trait AnnotatableSpectrum {
     fn search(&self, query: MassOverCharge, tolerance: Self::Tolerance) -> Option<usize>;
 
     /// Annotate this spectrum with the given peptidoform and given fragments see
-    /// [`crate::CompoundPeptidoform::generate_theoretical_fragments`].
+    /// [`crate::CompoundPeptidoform::generate_theoretical_fragments`]. When annotating with
+    /// [`MassMode::MostAbundant`] the matched isotope is stored on every annotated peak, see
+    /// [`AnnotatedPeak::isotope_offset`](crate::spectrum::AnnotatedPeak::isotope_offset).
     fn annotate(
         &self,
         peptide: CompoundPeptidoformIon,
@@ -35,13 +37,22 @@ pub trait AnnotatableSpectrum {
         for fragment in theoretical_fragments {
             // Determine fragment mz and see if it is within the model range.
             if let Some(mz) = fragment.mz(mode) {
-                if !model.mz_range.contains(&mz) {
+                if !model.mz_range.contains(&mz) || !model.heavy_enough(fragment, mode) {
                     continue;
                 }
 
                 // Get the index of the element closest to this value
                 if let Some(index) = Self::search(self, mz, tolerance) {
-                    annotated.spectrum[index].annotation.push(fragment.clone());
+                    let peak = &mut annotated.spectrum[index];
+                    #[cfg(feature = "isotopes")]
+                    if mode == MassMode::MostAbundant && peak.annotation.is_empty() {
+                        peak.isotope_offset = fragment.formula.as_ref().map(|formula| {
+                            (formula.most_abundant_mass() - formula.monoisotopic_mass())
+                                .value
+                                .round() as i8
+                        });
+                    }
+                    peak.annotation.push(fragment.clone());
                 }
             }
         }
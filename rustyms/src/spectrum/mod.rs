@@ -1,6 +1,7 @@
 //! Spectrum related code
 
 mod annotated;
+mod context;
 mod fdr;
 mod fragmentation;
 #[cfg(feature = "mzdata")]
@@ -10,6 +11,7 @@ mod raw;
 mod scores;
 
 pub use annotated::*;
+pub use context::*;
 pub use fdr::*;
 pub use fragmentation::*;
 pub use peaks::*;
@@ -59,6 +59,15 @@ impl From<crate::Tolerance<MassOverCharge>> for Tolerance {
             crate::Tolerance::Relative(value) => {
                 Self::PPM(value.get::<crate::system::ratio::ppm>())
             }
+            // mzdata does not support combined tolerances, the absolute part is the minimal window
+            crate::Tolerance::Combined(_, value) => Self::Da(value.get::<crate::system::mz>()),
+            // mzdata does not support asymmetric tolerances, the narrowest side is used
+            crate::Tolerance::Asymmetric(lower, upper) => Self::PPM(
+                lower
+                    .get::<crate::system::ratio::ppm>()
+                    .abs()
+                    .min(upper.get::<crate::system::ratio::ppm>().abs()),
+            ),
         }
     }
 }
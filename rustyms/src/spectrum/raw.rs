@@ -1,18 +1,20 @@
 //! Raw spectra (not annotated)
 
-use std::cmp::Ordering;
+use std::{cmp::Ordering, ops::RangeInclusive};
 
 use itertools::Itertools;
 use ordered_float::OrderedFloat;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    spectrum::{AnnotatableSpectrum, AnnotatedPeak, PeakSpectrum},
+    spectrum::{AnnotatableSpectrum, AnnotatedPeak, PeakSpectrum, Score, Scores},
     system::{
+        da,
         f64::{Mass, MassOverCharge, Ratio, Time},
         usize::Charge,
     },
-    AnnotatedSpectrum, CompoundPeptidoformIon, Tolerance, WithinTolerance,
+    AnnotatedSpectrum, Chemical, CompoundPeptidoformIon, DecoyMethod, MassMode, Model,
+    MolecularCharge, Tolerance, WithinTolerance,
 };
 
 /// A raw spectrum (meaning not annotated yet)
@@ -78,41 +80,440 @@ impl RawSpectrum {
     }
 
     /// Filter a spectrum by dividing it in windows and within each window only retain the `top` number of peaks.
-    #[allow(clippy::missing_panics_doc)] // Cannot panic as it checks with peek first
+    /// See [`Self::filter_window`] for a variant that returns a new spectrum.
     pub fn top_x_filter(&mut self, window_size: f64, top: usize) {
-        let mut new_spectrum = Vec::with_capacity(
-            self.spectrum
-                .last()
-                .and_then(|l| self.spectrum.first().map(|f| (f, l)))
-                .map(|(f, l)| ((l.mz.value - f.mz.value) / window_size).round() as usize * top)
-                .unwrap_or_default(),
-        );
-        let mut spectrum = self.spectrum.iter().cloned().peekable();
-        let mut window = 1;
-        let mut peaks = Vec::new();
-
-        while spectrum.peek().is_some() {
-            while let Some(peek) = spectrum.peek() {
-                if peek.mz.value <= f64::from(window) * window_size {
-                    peaks.push(spectrum.next().unwrap());
-                } else {
-                    break;
+        self.spectrum = self
+            .filter_window(MassOverCharge::new::<crate::system::mz>(window_size), top)
+            .spectrum;
+    }
+
+    /// Get a new spectrum with only the `n` most intense peaks, all other properties of the
+    /// spectrum (eg the precursor) are retained.
+    #[must_use]
+    pub fn top_n_peaks(&self, n: usize) -> Self {
+        let mut peaks = self
+            .spectrum
+            .iter()
+            .cloned()
+            .k_largest_by(n, |a, b| a.intensity.cmp(&b.intensity))
+            .collect_vec();
+        peaks.sort_unstable();
+        Self {
+            spectrum: peaks,
+            ..self.clone()
+        }
+    }
+
+    /// Get a new spectrum by dividing the m/z axis in consecutive windows of the given size
+    /// (starting at 0) and within each window only retaining the `top_per_window` most intense
+    /// peaks. All other properties of the spectrum (eg the precursor) are retained.
+    #[must_use]
+    pub fn filter_window(&self, window: MassOverCharge, top_per_window: usize) -> Self {
+        let mut peaks = self
+            .spectrum
+            .iter()
+            .chunk_by(|p| (p.mz / window).value.floor() as usize)
+            .into_iter()
+            .flat_map(|(_, peaks)| {
+                peaks
+                    .cloned()
+                    .k_largest_by(top_per_window, |a, b| a.intensity.cmp(&b.intensity))
+                    .collect_vec()
+            })
+            .collect_vec();
+        peaks.sort_unstable();
+        Self {
+            spectrum: peaks,
+            ..self.clone()
+        }
+    }
+
+    /// Sort the peaks on ascending m/z and merge all peaks that are within the given tolerance of
+    /// each other into a single peak. The merged peak has the summed intensity and the intensity
+    /// weighted average m/z of the original peaks. All matching of fragments relies on the spectrum
+    /// being sorted, which is guaranteed by all methods that add peaks, but this can be
+    /// used to clean up spectra with duplicate peaks.
+    pub fn sort_and_dedup(&mut self, tolerance: Tolerance<MassOverCharge>) {
+        self.spectrum.sort_unstable();
+        let mut merged: Vec<RawPeak> = Vec::with_capacity(self.spectrum.len());
+        for peak in self.spectrum.drain(..) {
+            if let Some(last) = merged
+                .last_mut()
+                .filter(|last| tolerance.within(&last.mz, &peak.mz))
+            {
+                let intensity = *last.intensity + *peak.intensity;
+                if intensity != 0.0 {
+                    last.mz = (last.mz * *last.intensity + peak.mz * *peak.intensity) / intensity;
                 }
+                last.intensity = OrderedFloat(intensity);
+            } else {
+                merged.push(peak);
             }
-            new_spectrum.extend(
-                peaks
+        }
+        self.spectrum = merged;
+    }
+
+    /// The total ion current (TIC) of this spectrum, the sum of the intensities of all peaks.
+    pub fn total_ion_current(&self) -> f64 {
+        self.spectrum.iter().map(|p| *p.intensity).sum()
+    }
+
+    /// The base peak of this spectrum, the most intense peak. If multiple peaks share the highest
+    /// intensity the one with the lowest m/z is returned. Returns None for an empty spectrum.
+    pub fn base_peak(&self) -> Option<RawPeak> {
+        self.spectrum
+            .iter()
+            .rev()
+            .max_by_key(|p| p.intensity)
+            .cloned()
+    }
+
+    /// The intensity of the base peak of this spectrum, or None for an empty spectrum.
+    pub fn base_peak_intensity(&self) -> Option<f64> {
+        self.spectrum.iter().map(|p| p.intensity).max().map(|i| *i)
+    }
+
+    /// Determine the precursor purity of this spectrum. This is the fraction of the total intensity
+    /// within the isolation window in the given MS1 spectrum that belongs to the isotopic envelope of
+    /// the precursor. The MS1 spectrum should be the precursor scan for this spectrum. The precursor
+    /// m/z is calculated from the precursor mass and charge of this spectrum, assuming protons as
+    /// charge carriers. The isolation window is the full width of the window centred on the
+    /// precursor m/z.
+    ///
+    /// If the precursor mass or charge is not known, or if there is no intensity in the isolation
+    /// window this returns 0.0.
+    #[allow(clippy::cast_possible_wrap)]
+    pub fn precursor_purity(
+        &self,
+        ms1: &Self,
+        isolation_window: MassOverCharge,
+        tolerance: Tolerance<MassOverCharge>,
+    ) -> f64 {
+        let (Some(mass), Some(charge)) = (self.mass, self.charge) else {
+            return 0.0;
+        };
+        if charge.value == 0 {
+            return 0.0;
+        }
+        let z = crate::system::f64::Charge::new::<crate::system::charge::e>(charge.value as f64);
+        let precursor = (mass
+            + MolecularCharge::proton(charge.value as isize)
+                .formula()
+                .monoisotopic_mass())
+            / z;
+        let spacing = da(ISOTOPE_SPACING) / z;
+        let low = precursor - isolation_window / 2.0;
+        let high = precursor + isolation_window / 2.0;
+
+        let (total, target) = ms1
+            .binary_search(low, high)
+            .iter()
+            .filter(|p| p.mz >= low && p.mz <= high)
+            .fold((0.0, 0.0), |(total, target), peak| {
+                let isotope = ((peak.mz - precursor) / spacing).value.round();
+                let is_target =
+                    isotope >= 0.0 && tolerance.within(&peak.mz, &(precursor + spacing * isotope));
+                (
+                    total + *peak.intensity,
+                    if is_target {
+                        target + *peak.intensity
+                    } else {
+                        target
+                    },
+                )
+            });
+
+        if total == 0.0 {
+            0.0
+        } else {
+            target / total
+        }
+    }
+
+    /// Determine the precursor charge of this spectrum for the given peptide, for spectra where
+    /// the charge is not known. The precursor mass of this spectrum is interpreted as the precursor
+    /// m/z (as given by PEPMASS in MGF files). For every charge in the given range the neutral
+    /// precursor mass is calculated (assuming protons as charge carriers) and compared to all
+    /// formulas of the peptide, allowing for the given isotope errors (where 1 means the second
+    /// isotope was picked as precursor). The charge with the lowest mass error within the
+    /// tolerance is returned. The peptide should not have a charge state defined.
+    ///
+    /// If the precursor mass is not known, or no charge matches within the tolerance this
+    /// returns `None`.
+    #[allow(clippy::cast_possible_wrap, clippy::cast_precision_loss)]
+    pub fn infer_charge(
+        &self,
+        peptide: &CompoundPeptidoformIon,
+        charges: RangeInclusive<usize>,
+        tolerance: Tolerance<Mass>,
+        isotope_errors: RangeInclusive<isize>,
+    ) -> Option<Charge> {
+        let mz = self.mass?;
+        let formulas = peptide.formulas();
+        charges
+            .filter(|z| *z > 0)
+            .flat_map(|z| {
+                let experimental = mz * z as f64
+                    - MolecularCharge::proton(z as isize)
+                        .formula()
+                        .monoisotopic_mass();
+                formulas
                     .iter()
-                    .cloned()
-                    .k_largest_by(top, |a, b| a.mz.value.total_cmp(&b.mz.value)),
-            );
-            peaks.clear();
-            window += 1;
+                    .cartesian_product(isotope_errors.clone())
+                    .map(move |(formula, isotope)| {
+                        let theoretical =
+                            formula.monoisotopic_mass() + da(ISOTOPE_SPACING * isotope as f64);
+                        (z, experimental, theoretical)
+                    })
+            })
+            .filter(|(_, experimental, theoretical)| tolerance.within(experimental, theoretical))
+            .min_by(|(_, e1, t1), (_, e2, t2)| {
+                (*e1 - *t1).value.abs().total_cmp(&(*e2 - *t2).value.abs())
+            })
+            .map(|(z, _, _)| Charge::new::<crate::system::charge::e>(z))
+    }
+
+    /// Deisotope and charge deconvolute this spectrum. The peaks are grouped into isotope
+    /// envelopes with a charge up to `max_charge`, where every consecutive peak in an envelope
+    /// has to be within the tolerance of the expected isotope spacing and the intensities of the
+    /// envelope have to fit the isotopic distribution of an averagine peptide of that mass (see
+    /// [`MolecularFormula::averagine`](crate::MolecularFormula::averagine)). Every envelope is collapsed into a single peak at the
+    /// singly charged (protonated) m/z of its monoisotopic peak with the summed intensity of the
+    /// whole envelope, so the result can be annotated with singly charged fragments.
+    ///
+    /// The envelopes are detected greedily from low to high m/z, for every starting peak the
+    /// charge resulting in the longest fitting envelope is picked. Peaks that are already part
+    /// of an envelope cannot be used again, but interleaved envelopes of different charges are
+    /// detected as long as they do not share peaks. Peaks that do not fit any envelope are
+    /// retained as is, so assumed to be singly charged.
+    #[cfg(feature = "isotopes")]
+    #[allow(clippy::cast_precision_loss, clippy::cast_possible_wrap)]
+    #[must_use]
+    pub fn deisotope(&self, tolerance: Tolerance<MassOverCharge>, max_charge: Charge) -> Self {
+        /// The minimal cosine similarity between the envelope and the averagine distribution
+        const MIN_SIMILARITY: f64 = 0.9;
+        let proton = MolecularCharge::proton(1).formula().monoisotopic_mass();
+        let unit = crate::system::f64::Charge::new::<crate::system::charge::e>(1.0);
+        let mut used = vec![false; self.spectrum.len()];
+        let mut peaks = Vec::with_capacity(self.spectrum.len());
+
+        for index in 0..self.spectrum.len() {
+            if used[index] {
+                continue;
+            }
+            let start = &self.spectrum[index];
+            let mut best: Option<(Mass, Vec<usize>)> = None;
+            for z in (1..=max_charge.value).rev() {
+                let charge = crate::system::f64::Charge::new::<crate::system::charge::e>(z as f64);
+                let spacing = da(ISOTOPE_SPACING) / charge;
+                let mass = (start.mz - proton / unit) * charge;
+                let expected = crate::MolecularFormula::averagine(mass).isotopic_distribution(0.01);
+                let mut envelope = vec![index];
+                while envelope.len() < expected.len() {
+                    let next = start.mz + spacing * envelope.len() as f64;
+                    let found = self
+                        .search(next, tolerance)
+                        .filter(|i| !used[*i] && !envelope.contains(i));
+                    match found {
+                        Some(i) => envelope.push(i),
+                        None => break,
+                    }
+                }
+                if envelope.len() < 2 {
+                    continue;
+                }
+                let observed = envelope
+                    .iter()
+                    .map(|i| *self.spectrum[*i].intensity)
+                    .collect_vec();
+                let dot: f64 = observed.iter().zip(&expected).map(|(o, e)| o * e).sum();
+                let norm = observed.iter().map(|o| o * o).sum::<f64>().sqrt()
+                    * expected
+                        .iter()
+                        .take(observed.len())
+                        .map(|e| e * e)
+                        .sum::<f64>()
+                        .sqrt();
+                if norm > 0.0
+                    && dot / norm >= MIN_SIMILARITY
+                    && best.as_ref().map_or(true, |b| envelope.len() > b.1.len())
+                {
+                    best = Some((mass, envelope));
+                }
+            }
+            if let Some((mass, envelope)) = best {
+                let intensity: f64 = envelope.iter().map(|i| *self.spectrum[*i].intensity).sum();
+                for i in &envelope {
+                    used[*i] = true;
+                }
+                peaks.push(RawPeak {
+                    mz: (mass + proton) / unit,
+                    intensity: OrderedFloat(intensity),
+                });
+            } else {
+                used[index] = true;
+                peaks.push(start.clone());
+            }
+        }
+
+        let mut result = Self {
+            spectrum: Vec::new(),
+            ..self.clone()
+        };
+        result.extend(peaks);
+        result
+    }
+
+    /// The spectral entropy of this spectrum, this is the Shannon entropy (using the natural
+    /// logarithm) of the peak intensities normalised to sum to one. An empty spectrum has an
+    /// entropy of 0.0.
+    pub fn spectral_entropy(&self) -> f64 {
+        entropy(self.spectrum.iter().map(|p| *p.intensity))
+    }
+
+    /// The (unweighted) spectral entropy similarity between this and the other spectrum, as
+    /// defined by Li et al. (2021, Nature Methods). Every peak is matched to the closest unmatched
+    /// peak in the other spectrum within the tolerance. The similarity is 1.0 for identical spectra
+    /// and 0.0 for spectra without any matching peaks, or if either spectrum is empty.
+    pub fn spectral_entropy_similarity(
+        &self,
+        other: &Self,
+        tolerance: Tolerance<MassOverCharge>,
+    ) -> f64 {
+        let total_self: f64 = self.spectrum.iter().map(|p| *p.intensity).sum();
+        let total_other: f64 = other.spectrum.iter().map(|p| *p.intensity).sum();
+        if total_self <= 0.0 || total_other <= 0.0 {
+            return 0.0;
+        }
+
+        let mut used = vec![false; other.spectrum.len()];
+        let mut merged = Vec::with_capacity(self.spectrum.len() + other.spectrum.len());
+        for peak in &self.spectrum {
+            let (low, high) = tolerance.bounds(peak.mz);
+            let start = other.spectrum.partition_point(|p| p.mz < low);
+            let closest = other.spectrum[start..]
+                .iter()
+                .enumerate()
+                .take_while(|(_, p)| p.mz <= high)
+                .filter(|(i, _)| !used[start + i])
+                .min_by(|(_, a), (_, b)| {
+                    (a.mz - peak.mz)
+                        .value
+                        .abs()
+                        .total_cmp(&(b.mz - peak.mz).value.abs())
+                })
+                .map(|(i, p)| (start + i, *p.intensity));
+            let mut intensity = *peak.intensity / total_self;
+            if let Some((index, other_intensity)) = closest {
+                used[index] = true;
+                intensity += other_intensity / total_other;
+            }
+            merged.push(intensity / 2.0);
         }
+        merged.extend(
+            other
+                .spectrum
+                .iter()
+                .zip(used)
+                .filter(|(_, used)| !used)
+                .map(|(p, _)| *p.intensity / total_other / 2.0),
+        );
 
-        self.spectrum = new_spectrum;
+        let similarity = 1.0
+            - 2.0f64.mul_add(
+                entropy(merged.into_iter()),
+                -self.spectral_entropy() - other.spectral_entropy(),
+            ) / 4.0_f64.ln();
+        similarity.clamp(0.0, 1.0)
     }
 }
 
+/// The Shannon entropy of the given intensities after normalising them to sum to one.
+fn entropy(intensities: impl Iterator<Item = f64> + Clone) -> f64 {
+    let total: f64 = intensities.clone().sum();
+    if total <= 0.0 {
+        return 0.0;
+    }
+    -intensities
+        .filter(|i| *i > 0.0)
+        .map(|i| i / total * (i / total).ln())
+        .sum::<f64>()
+}
+
+impl RawSpectrum {
+    /// Annotate this spectrum with the given peptidoform and with its decoy (generated with the
+    /// given method) in one go, this can be used to calculate the per spectrum difference in score
+    /// between target and decoy. The theoretical fragments are generated up to the charge of the
+    /// spectrum (or 1 if this is unknown). Returns the target and decoy annotation in that order.
+    pub fn annotate_target_decoy(
+        &self,
+        peptidoform: CompoundPeptidoformIon,
+        decoy_method: DecoyMethod,
+        model: &Model,
+        mode: MassMode,
+    ) -> (AnnotatedSpectrum, AnnotatedSpectrum) {
+        let charge = self
+            .charge
+            .unwrap_or_else(|| Charge::new::<crate::system::e>(1));
+        let decoy = peptidoform.decoy(decoy_method);
+        let target_fragments = peptidoform.generate_theoretical_fragments(charge, model);
+        let decoy_fragments = decoy.generate_theoretical_fragments(charge, model);
+        (
+            self.annotate(peptidoform, &target_fragments, model, mode),
+            self.annotate(decoy, &decoy_fragments, model, mode),
+        )
+    }
+
+    /// Rank the given peptidoforms on how well they explain this spectrum. Each peptidoform is
+    /// annotated and scored, the combined score is the fraction of annotated intensity plus the
+    /// average fraction of positions covered for all individual peptides. The theoretical
+    /// fragments are generated up to the charge of the spectrum (or 1 if this is unknown). Returns
+    /// the index of every peptidoform with its combined [`Scores`], sorted from best to worst.
+    pub fn rank_peptides(
+        &self,
+        peptidoforms: &[CompoundPeptidoformIon],
+        model: &Model,
+        mode: MassMode,
+    ) -> Vec<(usize, Scores)> {
+        let charge = self
+            .charge
+            .unwrap_or_else(|| Charge::new::<crate::system::e>(1));
+        peptidoforms
+            .iter()
+            .enumerate()
+            .map(|(index, peptidoform)| {
+                let fragments = peptidoform.generate_theoretical_fragments(charge, model);
+                let (combined, individual) = self
+                    .annotate(peptidoform.clone(), &fragments, model, mode)
+                    .scores(&fragments, model, mode);
+                let coverage = individual
+                    .iter()
+                    .flatten()
+                    .map(|s| match s.score {
+                        Score::Position {
+                            theoretical_positions,
+                            ..
+                        } => theoretical_positions.fraction(),
+                        Score::UniqueFormulas {
+                            unique_formulas, ..
+                        } => unique_formulas.fraction(),
+                    })
+                    .collect_vec();
+                // Fractions with a total of zero are NaN, f64::max ignores NaN
+                let score = combined.combined(1.0).max(0.0)
+                    + coverage.iter().map(|c| c.max(0.0)).sum::<f64>()
+                        / coverage.len().max(1) as f64;
+                (index, combined, score)
+            })
+            .sorted_by(|a, b| b.2.total_cmp(&a.2))
+            .map(|(index, scores, _)| (index, scores))
+            .collect()
+    }
+}
+
+/// The mass difference between the 13C and 12C isotopes, used as the isotopic spacing for peptides
+const ISOTOPE_SPACING: f64 = 1.003_354_835;
+
 impl AnnotatableSpectrum for RawSpectrum {
     type Tolerance = Tolerance<MassOverCharge>;
 
@@ -133,23 +534,17 @@ impl AnnotatableSpectrum for RawSpectrum {
     }
 
     fn search(&self, query: MassOverCharge, tolerance: Self::Tolerance) -> Option<usize> {
-        let index = self
-            .spectrum
-            .binary_search_by(|p| p.mz.value.total_cmp(&query.value))
-            .unwrap_or_else(|i| i);
-
-        // Check index-1, index and index+1 (if existing) to find the one with the lowest ppm
-        let mut closest = (0, f64::INFINITY);
-        for i in if index == 0 { 0 } else { index - 1 }..=(index + 1).min(self.spectrum.len() - 1) {
-            let ppm = self.spectrum[i].ppm(query).value;
-            if ppm < closest.1 {
-                closest = (i, ppm);
-            }
-        }
-
-        tolerance
-            .within(&self.spectrum[closest.0].mz, &query)
-            .then_some(closest.0)
+        // Find the closest peak within tolerance out of all peaks in the window, for asymmetric
+        // tolerances the closest peak is not necessarily within the window
+        let (low, high) = tolerance.bounds(query);
+        let start = self.spectrum.partition_point(|p| p.mz < low);
+        self.spectrum[start..]
+            .iter()
+            .enumerate()
+            .take_while(|(_, p)| p.mz <= high)
+            .filter(|(_, p)| tolerance.within(&p.mz, &query))
+            .min_by(|(_, a), (_, b)| a.ppm(query).value.total_cmp(&b.ppm(query).value))
+            .map(|(i, _)| start + i)
     }
 }
 
@@ -259,3 +654,328 @@ impl RawPeak {
         self.mz.ppm(mz)
     }
 }
+
+/// Create a spectrum with a peak with an intensity of 1.0 at the m/z of every given fragment, to
+/// test the annotation of spectra.
+#[cfg(test)]
+pub fn test_spectrum<'a>(
+    fragments: impl IntoIterator<Item = &'a crate::Fragment>,
+    mode: MassMode,
+) -> RawSpectrum {
+    let mut spectrum = RawSpectrum::default();
+    spectrum.extend(fragments.into_iter().filter_map(|f| {
+        f.mz(mode).map(|mz| RawPeak {
+            mz,
+            intensity: OrderedFloat(1.0),
+        })
+    }));
+    spectrum
+}
+
+/// Parse the given peptide and generate its singly charged b and y fragments, to test the
+/// annotation of spectra.
+#[allow(clippy::missing_panics_doc)]
+#[cfg(test)]
+pub fn test_fragments(sequence: &str) -> (CompoundPeptidoformIon, Model, Vec<crate::Fragment>) {
+    let peptide = CompoundPeptidoformIon::pro_forma(sequence, None).unwrap();
+    let model = Model::none()
+        .b(crate::model::PrimaryIonSeries::default())
+        .y(crate::model::PrimaryIonSeries::default());
+    let fragments =
+        peptide.generate_theoretical_fragments(Charge::new::<crate::system::e>(1), &model);
+    (peptide, model, fragments)
+}
+
+#[cfg(test)]
+#[allow(clippy::missing_panics_doc)]
+mod tests {
+    use super::*;
+    use crate::system::{e, mz};
+
+    fn spectrum(peaks: &[(f64, f64)]) -> RawSpectrum {
+        let mut spectrum = RawSpectrum::default();
+        spectrum.extend(peaks.iter().map(|(m, i)| RawPeak {
+            mz: MassOverCharge::new::<mz>(*m),
+            intensity: OrderedFloat(*i),
+        }));
+        spectrum
+    }
+
+    #[test]
+    fn sort_and_dedup() {
+        let mut spectrum = RawSpectrum {
+            spectrum: [(300.0, 1.0), (100.0, 2.0), (200.0, 1.0), (100.000_01, 2.0)]
+                .iter()
+                .map(|(m, i)| RawPeak {
+                    mz: MassOverCharge::new::<mz>(*m),
+                    intensity: OrderedFloat(*i),
+                })
+                .collect(),
+            ..RawSpectrum::default()
+        };
+        spectrum.sort_and_dedup(Tolerance::new_ppm(1.0));
+        let peaks = spectrum
+            .spectrum()
+            .map(|p| (p.mz.value, *p.intensity))
+            .collect_vec();
+        assert_eq!(peaks.len(), 3);
+        assert!((peaks[0].0 - 100.000_005).abs() < 1e-9);
+        assert!((peaks[0].1 - 4.0).abs() < f64::EPSILON);
+        assert!(peaks.windows(2).all(|w| w[0].0 < w[1].0));
+        assert_eq!(
+            spectrum.search(MassOverCharge::new::<mz>(200.0), Tolerance::new_ppm(1.0)),
+            Some(1)
+        );
+    }
+
+    #[test]
+    fn search_asymmetric() {
+        // 3 ppm below and 8 ppm above the query of 1000
+        let raw = spectrum(&[(999.997, 1.0), (1000.008, 1.0)]);
+        let query = MassOverCharge::new::<mz>(1000.0);
+        // The closest peak is outside of the window but the other peak is inside
+        assert_eq!(
+            raw.search(query, Tolerance::new_asymmetric(-2.0, 10.0)),
+            Some(1)
+        );
+        assert_eq!(
+            raw.search(query, Tolerance::new_asymmetric(-5.0, 10.0)),
+            Some(0)
+        );
+        assert_eq!(
+            raw.search(query, Tolerance::new_asymmetric(-2.0, 2.0)),
+            None
+        );
+        assert_eq!(
+            RawSpectrum::default().search(query, Tolerance::new_ppm(10.0)),
+            None
+        );
+    }
+
+    #[test]
+    fn peak_filters() {
+        let mut raw = spectrum(&[
+            (100.0, 5.0),
+            (120.0, 1.0),
+            (150.0, 3.0),
+            (210.0, 2.0),
+            (250.0, 4.0),
+            (260.0, 6.0),
+        ]);
+        raw.title = "Spectrum 1".to_string();
+        raw.charge = Some(Charge::new::<e>(2));
+        let top = raw.top_n_peaks(3);
+        assert_eq!(
+            top.spectrum().map(|p| p.mz.value).collect_vec(),
+            [100.0, 250.0, 260.0]
+        );
+        assert_eq!(top.title, raw.title);
+        assert_eq!(top.charge, raw.charge);
+        assert_eq!(raw.top_n_peaks(10).spectrum().count(), 6);
+        let window = raw.filter_window(MassOverCharge::new::<mz>(100.0), 2);
+        assert_eq!(
+            window.spectrum().map(|p| p.mz.value).collect_vec(),
+            [100.0, 150.0, 250.0, 260.0]
+        );
+        assert_eq!(window.charge, raw.charge);
+        raw.top_x_filter(100.0, 1);
+        assert_eq!(
+            raw.spectrum().map(|p| p.mz.value).collect_vec(),
+            [100.0, 260.0]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "isotopes")]
+    fn deisotope() {
+        let proton = MolecularCharge::proton(1)
+            .formula()
+            .monoisotopic_mass()
+            .value;
+        let envelope = |formula: crate::MolecularFormula, z: f64, scale: f64| {
+            let mass = formula.monoisotopic_mass().value;
+            formula
+                .isotopic_distribution(0.01)
+                .iter()
+                .enumerate()
+                .map(|(i, p)| {
+                    (
+                        (ISOTOPE_SPACING.mul_add(i as f64, mass) + proton * z) / z,
+                        p * scale,
+                    )
+                })
+                .collect_vec()
+        };
+        let doubly = crate::MolecularFormula::averagine(da(1500.0));
+        let singly = crate::MolecularFormula::averagine(da(700.0));
+        let mut peaks = envelope(doubly.clone(), 2.0, 100.0);
+        peaks.extend(envelope(singly.clone(), 1.0, 50.0));
+        peaks.push((400.0, 10.0));
+        let deisotoped = spectrum(&peaks).deisotope(Tolerance::new_ppm(10.0), Charge::new::<e>(3));
+        let result = deisotoped
+            .spectrum()
+            .map(|p| (p.mz.value, *p.intensity))
+            .collect_vec();
+        assert_eq!(result.len(), 3, "{result:?}");
+        assert!((result[0].0 - 400.0).abs() < 1e-9);
+        assert!((result[0].1 - 10.0).abs() < 1e-9);
+        assert!((result[1].0 - (singly.monoisotopic_mass().value + proton)).abs() < 1e-6);
+        assert!((result[2].0 - (doubly.monoisotopic_mass().value + proton)).abs() < 1e-6);
+        let total: f64 = envelope(doubly, 2.0, 100.0).iter().map(|p| p.1).sum();
+        assert!((result[2].1 - total).abs() < 1e-9);
+    }
+
+    #[test]
+    fn rank_peptides() {
+        let (target, model, fragments) = test_fragments("VAEINPSNGGTTFNEK");
+        let decoy = CompoundPeptidoformIon::pro_forma("WQRHLKMYCDSGRAYW", None).unwrap();
+        let spectrum = test_spectrum(&fragments, MassMode::Monoisotopic);
+        let ranked = spectrum.rank_peptides(&[decoy, target], &model, MassMode::Monoisotopic);
+        assert_eq!(ranked.len(), 2);
+        assert_eq!(ranked[0].0, 1);
+        assert_eq!(ranked[1].0, 0);
+    }
+
+    #[test]
+    fn infer_charge() {
+        let peptide = CompoundPeptidoformIon::pro_forma("VAEINPSNGGTTFNEK", None).unwrap();
+        let mass = peptide.formulas()[0].monoisotopic_mass();
+        let proton = MolecularCharge::proton(1).formula().monoisotopic_mass();
+        let tolerance = Tolerance::new_ppm(10.0);
+        let mut spectrum = RawSpectrum::default();
+        assert_eq!(
+            spectrum.infer_charge(&peptide, 1..=4, tolerance, 0..=0),
+            None
+        );
+
+        spectrum.mass = Some((mass + proton * 3.0) / 3.0);
+        assert_eq!(
+            spectrum.infer_charge(&peptide, 1..=6, tolerance, 0..=0),
+            Some(Charge::new::<e>(3))
+        );
+        assert_eq!(
+            spectrum.infer_charge(&peptide, 1..=2, tolerance, 0..=0),
+            None
+        );
+
+        // The second isotope was selected as precursor
+        spectrum.mass = Some((mass + da(ISOTOPE_SPACING) + proton * 2.0) / 2.0);
+        assert_eq!(
+            spectrum.infer_charge(&peptide, 1..=6, tolerance, 0..=0),
+            None
+        );
+        assert_eq!(
+            spectrum.infer_charge(&peptide, 1..=6, tolerance, 0..=1),
+            Some(Charge::new::<e>(2))
+        );
+    }
+
+    #[test]
+    fn base_peak_and_tic() {
+        let spectrum = spectrum(&[(100.0, 5.0), (200.0, 20.0), (300.0, 1.5), (400.0, 20.0)]);
+        assert!((spectrum.total_ion_current() - 46.5).abs() < f64::EPSILON);
+        let base = spectrum.base_peak().unwrap();
+        assert!((base.mz.value - 200.0).abs() < f64::EPSILON);
+        assert!((*base.intensity - 20.0).abs() < f64::EPSILON);
+        assert_eq!(spectrum.base_peak_intensity(), Some(20.0));
+        assert!(RawSpectrum::default().total_ion_current().abs() < f64::EPSILON);
+        assert_eq!(RawSpectrum::default().base_peak(), None);
+        assert_eq!(RawSpectrum::default().base_peak_intensity(), None);
+    }
+
+    #[test]
+    fn spectral_entropy() {
+        let single = spectrum(&[(500.0, 10.0)]);
+        let uniform = spectrum(&[(100.0, 5.0), (200.0, 5.0), (300.0, 5.0), (400.0, 5.0)]);
+        assert!(single.spectral_entropy().abs() < f64::EPSILON);
+        assert!(uniform.spectral_entropy() > single.spectral_entropy());
+        assert!((uniform.spectral_entropy() - 4.0_f64.ln()).abs() < 1e-10);
+        assert!(RawSpectrum::default().spectral_entropy().abs() < f64::EPSILON);
+
+        let tolerance = Tolerance::new_ppm(10.0);
+        let shifted = spectrum(&[(100.0005, 5.0), (200.0, 5.0), (300.0, 5.0), (400.0, 5.0)]);
+        let other = spectrum(&[(150.0, 5.0), (250.0, 5.0)]);
+        assert!((uniform.spectral_entropy_similarity(&uniform, tolerance) - 1.0).abs() < 1e-10);
+        assert!((uniform.spectral_entropy_similarity(&shifted, tolerance) - 1.0).abs() < 1e-10);
+        assert!(uniform.spectral_entropy_similarity(&other, tolerance).abs() < 1e-10);
+        let overlap = uniform
+            .spectral_entropy_similarity(&spectrum(&[(100.0, 5.0), (200.0, 5.0)]), tolerance);
+        assert!(overlap > 0.0 && overlap < 1.0, "{overlap}");
+        assert!(
+            uniform
+                .spectral_entropy_similarity(&RawSpectrum::default(), tolerance)
+                .abs()
+                < f64::EPSILON
+        );
+    }
+
+    #[test]
+    fn precursor_purity() {
+        let ms2 = RawSpectrum {
+            mass: Some(da(998.985_447)),
+            charge: Some(Charge::new::<e>(2)),
+            ..Default::default()
+        };
+        // Precursor at 500.5 m/z with charge 2
+        let clean = spectrum(&[
+            (499.0, 10.0),
+            (500.5, 100.0),
+            (501.001_677, 50.0),
+            (503.0, 10.0),
+        ]);
+        let purity = ms2.precursor_purity(
+            &clean,
+            MassOverCharge::new::<mz>(2.0),
+            Tolerance::new_ppm(10.0),
+        );
+        assert!((purity - 1.0).abs() < 1e-6, "{purity}");
+        let interfered = spectrum(&[(500.5, 100.0), (500.8, 100.0), (501.001_677, 50.0)]);
+        let purity = ms2.precursor_purity(
+            &interfered,
+            MassOverCharge::new::<mz>(2.0),
+            Tolerance::new_ppm(10.0),
+        );
+        assert!((purity - 0.6).abs() < 1e-6, "{purity}");
+        assert!(
+            RawSpectrum::default().precursor_purity(
+                &clean,
+                MassOverCharge::new::<mz>(2.0),
+                Tolerance::new_ppm(10.0),
+            ) == 0.0
+        );
+    }
+
+    #[test]
+    fn annotate_target_decoy() {
+        let model = Model::all();
+        let charge = Charge::new::<e>(2);
+        let target = CompoundPeptidoformIon::pro_forma("VAEINPSNGGTTFNEK", None).unwrap();
+        let mut spectrum = test_spectrum(
+            &target.generate_theoretical_fragments(charge, &model),
+            MassMode::Monoisotopic,
+        );
+        spectrum.charge = Some(charge);
+
+        for method in [DecoyMethod::Reverse, DecoyMethod::PseudoReverse] {
+            let (annotated_target, annotated_decoy) = spectrum.annotate_target_decoy(
+                target.clone(),
+                method,
+                &model,
+                MassMode::Monoisotopic,
+            );
+            let annotated_intensity = |spectrum: &AnnotatedSpectrum| {
+                spectrum
+                    .spectrum()
+                    .filter(|p| !p.annotation.is_empty())
+                    .map(|p| *p.intensity)
+                    .sum::<f64>()
+            };
+            assert_eq!(annotated_target.peptide, target);
+            assert_eq!(annotated_decoy.peptide, target.decoy(method));
+            assert!(
+                annotated_intensity(&annotated_target) > annotated_intensity(&annotated_decoy),
+                "{method:?}"
+            );
+        }
+    }
+}
@@ -1,11 +1,14 @@
 //! Scoring of annotated spectra
 
+use std::collections::BTreeMap;
+
 use itertools::Itertools;
 use serde::{Deserialize, Serialize};
 
 use crate::{
     fragment::{Fragment, FragmentKind},
     peptidoform::UnAmbiguous,
+    system::{ratio::ppm, Ratio},
     AnnotatedSpectrum, MassMode, Model, Peptidoform,
 };
 
@@ -24,6 +27,7 @@ impl AnnotatedSpectrum {
             .filter(|f| {
                 f.mz(mass_mode)
                     .is_some_and(|mz| model.mz_range.contains(&mz))
+                    && model.heavy_enough(f, mass_mode)
             })
             .collect_vec();
         let total_intensity: f64 = self.spectrum.iter().map(|p| *p.intensity).sum();
@@ -90,6 +94,94 @@ impl AnnotatedSpectrum {
         )
     }
 
+    /// Get a compact quality control summary of this annotated spectrum. This contains the number
+    /// of matched peaks and the fraction of the annotated intensity (from [`Self::scores`]), the
+    /// ladder completeness (see [`Self::series_completeness`]) for every ion series present in
+    /// the given fragments, and the precursor mass error.
+    pub fn summary(
+        &self,
+        fragments: &[Fragment],
+        model: &Model,
+        mass_mode: MassMode,
+    ) -> AnnotationSummary {
+        let (scores, _) = self.scores(fragments, model, mass_mode);
+        let (peaks, intensity) = match scores.score {
+            Score::Position {
+                peaks, intensity, ..
+            }
+            | Score::UniqueFormulas {
+                peaks, intensity, ..
+            } => (peaks, intensity),
+        };
+        let series_coverage = scores
+            .ions
+            .iter()
+            .map(|(kind, _)| *kind)
+            .filter(|kind| {
+                matches!(
+                    kind,
+                    FragmentKind::a
+                        | FragmentKind::b
+                        | FragmentKind::c
+                        | FragmentKind::d
+                        | FragmentKind::v
+                        | FragmentKind::w
+                        | FragmentKind::x
+                        | FragmentKind::y
+                        | FragmentKind::z
+                )
+            })
+            .map(|kind| (kind, self.series_completeness(kind)))
+            .collect();
+        let precursor_mass_error = self.mass.and_then(|experimental| {
+            self.peptide
+                .formulas()
+                .iter()
+                .map(|f| f.mass(mass_mode))
+                .map(|theoretical| {
+                    Ratio::new::<ppm>(((experimental - theoretical) / theoretical).value * 1e6)
+                })
+                .min_by(|a, b| a.value.abs().total_cmp(&b.value.abs()))
+        });
+        AnnotationSummary {
+            matched_peaks: peaks,
+            intensity,
+            series_coverage,
+            precursor_mass_error,
+        }
+    }
+
+    /// Get the completeness of the ladder of the given ion series, this is the fraction of the
+    /// n−1 possible cleavage positions (summed over all peptidoforms) for which at least one
+    /// fragment of this series is annotated. Returns 0.0 if there are no cleavage positions.
+    pub fn series_completeness(&self, series: FragmentKind) -> f64 {
+        let total: usize = self
+            .peptide
+            .peptidoforms()
+            .map(|p| p.len().saturating_sub(1))
+            .sum();
+        if total == 0 {
+            return 0.0;
+        }
+        let found = self
+            .spectrum
+            .iter()
+            .flat_map(|p| p.annotation.iter())
+            .filter(|a| a.ion.kind() == series)
+            .filter_map(|a| {
+                a.ion.position().and_then(|pos| {
+                    (pos.series_number < pos.sequence_length).then_some((
+                        a.peptidoform_ion_index,
+                        a.peptidoform_index,
+                        pos.series_number,
+                    ))
+                })
+            })
+            .unique()
+            .count();
+        found as f64 / total as f64
+    }
+
     /// Get the base score of this spectrum
     /// (Fragments, peaks, intensity)
     fn filtered_base_score(
@@ -319,6 +411,70 @@ pub struct Scores {
     pub ions: Vec<(FragmentKind, Score)>,
 }
 
+impl Scores {
+    /// Get a single score blending the fraction of annotated intensity and the fraction of
+    /// annotated peaks. An `intensity_weight` of 1.0 gives the intensity fraction, 0.0 gives the
+    /// peaks fraction, and anything in between a weighted average of both.
+    pub fn combined(&self, intensity_weight: f64) -> f64 {
+        let (intensity, peaks) = match &self.score {
+            Score::Position {
+                intensity, peaks, ..
+            }
+            | Score::UniqueFormulas {
+                intensity, peaks, ..
+            } => (intensity.fraction(), peaks.fraction()),
+        };
+        intensity_weight.mul_add(intensity, (1.0 - intensity_weight) * peaks)
+    }
+
+    /// Get these scores as columns for a CSV row, using the same naming as the multi-annotator.
+    /// The columns are `ion_combined`, `intensity_combined`, and `total_ion_current` for the
+    /// combined score, and `ion_{kind}` and `intensity_{kind}` for every fragment kind. The ion
+    /// columns contain the fraction of the positions (or unique formulas) that are found. All
+    /// column names are prefixed with the given prefix.
+    pub fn to_columns(&self, prefix: &str) -> BTreeMap<String, String> {
+        let mut columns = BTreeMap::new();
+        columns.insert(
+            format!("{prefix}ion_combined"),
+            self.score.coverage().fraction().to_string(),
+        );
+        columns.insert(
+            format!("{prefix}intensity_combined"),
+            self.score.intensity().fraction().to_string(),
+        );
+        columns.insert(
+            format!("{prefix}total_ion_current"),
+            self.score.intensity().total.to_string(),
+        );
+        for (ion, score) in &self.ions {
+            columns.insert(
+                format!("{prefix}ion_{ion}"),
+                score.coverage().fraction().to_string(),
+            );
+            columns.insert(
+                format!("{prefix}intensity_{ion}"),
+                score.intensity().fraction().to_string(),
+            );
+        }
+        columns
+    }
+}
+
+/// A compact quality control summary of an annotated spectrum, see [`AnnotatedSpectrum::summary`]
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct AnnotationSummary {
+    /// The number of peaks with at least one annotation out of all peaks
+    pub matched_peaks: Recovered<u32>,
+    /// The annotated fraction of the total ion current
+    pub intensity: Recovered<f64>,
+    /// The ladder completeness for every ion series in the theoretical fragments
+    pub series_coverage: Vec<(FragmentKind, f64)>,
+    /// The signed error of the precursor mass relative to the closest theoretical mass (in ppm),
+    /// or `None` if the precursor mass is unknown
+    pub precursor_mass_error: Option<Ratio>,
+}
+
 /// The scores for a single fragment series for a single peptide in an annotated spectrum
 #[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
 pub enum Score {
@@ -347,6 +503,28 @@ pub enum Score {
         unique_formulas: Recovered<u32>,
     },
 }
+
+impl Score {
+    /// The recovered intensity
+    const fn intensity(&self) -> Recovered<f64> {
+        match self {
+            Self::Position { intensity, .. } | Self::UniqueFormulas { intensity, .. } => *intensity,
+        }
+    }
+
+    /// The recovered theoretical positions or unique formulas, depending on the kind of score
+    const fn coverage(&self) -> Recovered<u32> {
+        match self {
+            Self::Position {
+                theoretical_positions,
+                ..
+            } => *theoretical_positions,
+            Self::UniqueFormulas {
+                unique_formulas, ..
+            } => *unique_formulas,
+        }
+    }
+}
 /// A single statistic that has a total number and a subset of that found
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize, Deserialize)]
 #[non_exhaustive]
@@ -377,3 +555,121 @@ where
         f64::from(self.found) / f64::from(self.total)
     }
 }
+
+#[cfg(test)]
+#[allow(clippy::missing_panics_doc)]
+mod tests {
+    use super::*;
+    use crate::{
+        spectrum::{test_fragments, test_spectrum, RawPeak},
+        AnnotatableSpectrum,
+    };
+
+    #[test]
+    fn series_completeness() {
+        let (peptide, model, fragments) = test_fragments("PEPTIDE");
+        let annotated = test_spectrum(&fragments, MassMode::Monoisotopic).annotate(
+            peptide,
+            &fragments,
+            &model,
+            MassMode::Monoisotopic,
+        );
+        assert!((annotated.series_completeness(FragmentKind::b) - 1.0).abs() < f64::EPSILON);
+        assert!((annotated.series_completeness(FragmentKind::y) - 1.0).abs() < f64::EPSILON);
+        assert!(annotated.series_completeness(FragmentKind::c).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn combined_score() {
+        let (peptide, model, fragments) = test_fragments("PEPTIDE");
+        let mut spectrum = test_spectrum(
+            fragments.iter().filter(|f| f.ion.kind() == FragmentKind::b),
+            MassMode::Monoisotopic,
+        );
+        spectrum.extend([
+            RawPeak {
+                mz: crate::system::MassOverCharge::new::<crate::system::mz>(50.0),
+                intensity: 0.1.into(),
+            },
+            RawPeak {
+                mz: crate::system::MassOverCharge::new::<crate::system::mz>(1500.0),
+                intensity: 0.1.into(),
+            },
+        ]);
+        let annotated = spectrum.annotate(peptide, &fragments, &model, MassMode::Monoisotopic);
+        let (scores, _) = annotated.scores(&fragments, &model, MassMode::Monoisotopic);
+        let Score::UniqueFormulas {
+            intensity, peaks, ..
+        } = scores.score
+        else {
+            panic!("Combined scores should be based on unique formulas")
+        };
+        assert!((scores.combined(1.0) - intensity.fraction()).abs() < f64::EPSILON);
+        assert!((scores.combined(0.0) - peaks.fraction()).abs() < f64::EPSILON);
+        assert!(scores.combined(1.0) > scores.combined(0.0));
+        assert!(
+            (scores.combined(0.5) - (intensity.fraction() + peaks.fraction()) / 2.0).abs()
+                < f64::EPSILON
+        );
+    }
+
+    #[test]
+    fn columns() {
+        let (peptide, model, fragments) = test_fragments("PEPTIDE");
+        let mut spectrum = test_spectrum(
+            fragments.iter().filter(|f| f.ion.kind() == FragmentKind::b),
+            MassMode::Monoisotopic,
+        );
+        let annotated = spectrum.annotate(peptide, &fragments, &model, MassMode::Monoisotopic);
+        let (_, scores) = annotated.scores(&fragments, &model, MassMode::Monoisotopic);
+        let columns = scores[0][0].to_columns("");
+        for key in [
+            "ion_combined",
+            "intensity_combined",
+            "total_ion_current",
+            "ion_b",
+            "intensity_b",
+            "ion_y",
+            "intensity_y",
+        ] {
+            assert!(columns.contains_key(key), "Missing column {key}");
+        }
+        assert!(columns["ion_b"].parse::<f64>().unwrap() > 0.0);
+        assert_eq!(columns["ion_y"], "0");
+        assert!(scores[0][0]
+            .to_columns("x_")
+            .keys()
+            .all(|k| k.starts_with("x_")));
+    }
+
+    #[test]
+    fn summary() {
+        let (peptide, model, fragments) = test_fragments("PEPTIDE");
+        let mut spectrum = test_spectrum(
+            fragments.iter().filter(|f| f.ion.kind() == FragmentKind::b),
+            MassMode::Monoisotopic,
+        );
+        spectrum.mass = Some(
+            peptide.formulas()[0].monoisotopic_mass()
+                + crate::system::Mass::new::<crate::system::dalton>(0.001),
+        );
+        let annotated = spectrum.annotate(peptide, &fragments, &model, MassMode::Monoisotopic);
+        let summary = annotated.summary(&fragments, &model, MassMode::Monoisotopic);
+        assert!(summary.matched_peaks.found > 0);
+        assert!(summary.matched_peaks.found <= summary.matched_peaks.total);
+        assert!((0.0..=1.0).contains(&summary.intensity.fraction()));
+        assert_eq!(summary.series_coverage.len(), 2);
+        for (kind, coverage) in &summary.series_coverage {
+            match kind {
+                FragmentKind::b => assert!((coverage - 1.0).abs() < f64::EPSILON),
+                FragmentKind::y => assert!(coverage.abs() < f64::EPSILON),
+                _ => panic!("Unexpected series {kind}"),
+            }
+        }
+        let error = summary.precursor_mass_error.unwrap().get::<ppm>();
+        assert!(
+            error > 0.0 && error < 10.0,
+            "Unexpected precursor error {error}"
+        );
+    }
+}
@@ -16,6 +16,14 @@ pub enum Tolerance<T> {
     Relative(OrderedRatio),
     /// An absolute tolerance defined by a constant offset from the unit (bounds are unit - tolerance, unit + tolerance)
     Absolute(T),
+    /// A combined relative and absolute tolerance, the window at any given value is the wider of
+    /// the two. This is useful as small values suffer more from absolute errors and big values
+    /// from relative errors.
+    Combined(OrderedRatio, T),
+    /// An asymmetric relative tolerance, given as the signed lower and upper bound on the
+    /// relative error of a value compared to the reference value, eg -5 ppm and +10 ppm. This is
+    /// useful for instruments that are not centred on the true value.
+    Asymmetric(OrderedRatio, OrderedRatio),
 }
 
 impl<T> Tolerance<T> {
@@ -34,11 +42,31 @@ impl<T> Tolerance<T> {
         Self::Absolute(value.into())
     }
 
+    /// Create a new combined value, with the relative part in ppm, the window is the wider of the
+    /// two tolerances
+    pub fn new_combined(ppm: f64, absolute: impl Into<T>) -> Self {
+        Self::Combined(
+            Ratio::new::<crate::system::ratio::ppm>(ppm).into(),
+            absolute.into(),
+        )
+    }
+
+    /// Create a new asymmetric relative value, with the signed lower and upper bounds in ppm, so
+    /// `new_asymmetric(-5.0, 10.0)` accepts values from 5 ppm below to 10 ppm above the reference
+    pub fn new_asymmetric(lower: f64, upper: f64) -> Self {
+        Self::Asymmetric(
+            Ratio::new::<crate::system::ratio::ppm>(lower).into(),
+            Ratio::new::<crate::system::ratio::ppm>(upper).into(),
+        )
+    }
+
     /// Convert this tolerance into another absolute type.
     pub fn convert<O: From<T>>(self) -> Tolerance<O> {
         match self {
             Self::Relative(r) => Tolerance::Relative(r),
             Self::Absolute(a) => Tolerance::Absolute(a.into()),
+            Self::Combined(r, a) => Tolerance::Combined(r, a.into()),
+            Self::Asymmetric(l, u) => Tolerance::Asymmetric(l, u),
         }
     }
 }
@@ -48,9 +76,13 @@ where
     T: std::ops::Mul<Ratio, Output = T>
         + std::ops::Sub<T, Output = T>
         + std::ops::Add<T, Output = T>
+        + PartialOrd
         + Copy,
 {
-    /// Find the bounds around a given value for this tolerance
+    /// Find the bounds around a given reference (e.g. theoretical) value for this tolerance, so
+    /// the range of experimental values that are within the tolerance of this reference value.
+    /// To get the range of reference values that match a given experimental value use
+    /// [`Self::bounds_for_experimental`], which only differs for asymmetric tolerances.
     pub fn bounds(&self, value: impl Into<T>) -> (T, T) {
         let value = value.into();
         match self {
@@ -61,6 +93,79 @@ where
                     * (Ratio::new::<crate::system::ratio::fraction>(1.0) + tolerance.into_inner()),
             ),
             Self::Absolute(tolerance) => (value - *tolerance, value + *tolerance),
+            Self::Combined(relative, absolute) => {
+                let (relative_low, relative_high) = Self::Relative(*relative).bounds(value);
+                let (absolute_low, absolute_high) = Self::Absolute(*absolute).bounds(value);
+                (
+                    if relative_low < absolute_low {
+                        relative_low
+                    } else {
+                        absolute_low
+                    },
+                    if relative_high > absolute_high {
+                        relative_high
+                    } else {
+                        absolute_high
+                    },
+                )
+            }
+            Self::Asymmetric(lower, upper) => (
+                value * (Ratio::new::<crate::system::ratio::fraction>(1.0) + lower.into_inner()),
+                value * (Ratio::new::<crate::system::ratio::fraction>(1.0) + upper.into_inner()),
+            ),
+        }
+    }
+
+    /// Find the bounds around a given experimental value for this tolerance, so the range of
+    /// reference (e.g. theoretical) values for which this experimental value is within the
+    /// tolerance. For asymmetric tolerances this is the inverted window, so with `-5ppm+10ppm` the
+    /// reference can be from about 10 ppm below to 5 ppm above the experimental value. For all
+    /// other tolerances this is identical to [`Self::bounds`].
+    pub fn bounds_for_experimental(&self, value: impl Into<T>) -> (T, T) {
+        let value = value.into();
+        match self {
+            Self::Asymmetric(lower, upper) => (
+                value
+                    * Ratio::new::<crate::system::ratio::fraction>(
+                        1.0 / (1.0 + upper.get::<crate::system::ratio::fraction>()),
+                    ),
+                value
+                    * Ratio::new::<crate::system::ratio::fraction>(
+                        1.0 / (1.0 + lower.get::<crate::system::ratio::fraction>()),
+                    ),
+            ),
+            _ => self.bounds(value),
+        }
+    }
+
+    /// Scale this tolerance by the given factor, for example 0.5 on a 10 ppm tolerance gives a 5 ppm tolerance
+    #[must_use]
+    pub fn scale(self, factor: f64) -> Self {
+        let factor = Ratio::new::<crate::system::ratio::fraction>(factor);
+        match self {
+            Self::Relative(tolerance) => Self::Relative((tolerance.into_inner() * factor).into()),
+            Self::Absolute(tolerance) => Self::Absolute(tolerance * factor),
+            Self::Combined(relative, absolute) => {
+                Self::Combined((relative.into_inner() * factor).into(), absolute * factor)
+            }
+            Self::Asymmetric(lower, upper) => Self::Asymmetric(
+                (lower.into_inner() * factor).into(),
+                (upper.into_inner() * factor).into(),
+            ),
+        }
+    }
+
+    /// Get the tolerance that gives the smallest window around the given reference value.
+    /// This is needed to compare relative and absolute tolerances, if both windows are equally
+    /// wide `a` is returned.
+    pub fn tighter_of(a: Self, b: Self, reference: impl Into<T>) -> Self {
+        let reference = reference.into();
+        let (a_low, a_high) = a.bounds(reference);
+        let (b_low, b_high) = b.bounds(reference);
+        if b_high - b_low < a_high - a_low {
+            b
+        } else {
+            a
         }
     }
 }
@@ -73,6 +178,10 @@ impl<T: Display> Display for Tolerance<T> {
             match self {
                 Self::Absolute(value) => format!("{value} abs"),
                 Self::Relative(tolerance) => format!("{} rel", tolerance.value),
+                Self::Combined(relative, absolute) =>
+                    format!("{} rel|{absolute} abs", relative.value),
+                Self::Asymmetric(lower, upper) =>
+                    format!("{:+} rel{:+} rel", lower.value, upper.value),
             }
         )
     }
@@ -93,6 +202,18 @@ impl Display for Tolerance<Mass> {
                     tolerance
                         .into_format_args(crate::system::ratio::ppm, DisplayStyle::Abbreviation)
                 ),
+                Self::Combined(relative, absolute) => format!(
+                    "{}|{}",
+                    relative
+                        .into_format_args(crate::system::ratio::ppm, DisplayStyle::Abbreviation),
+                    absolute
+                        .into_format_args(crate::system::mass::dalton, DisplayStyle::Abbreviation)
+                ),
+                Self::Asymmetric(lower, upper) => format!(
+                    "{:+}ppm{:+}ppm",
+                    lower.get::<crate::system::ratio::ppm>(),
+                    upper.get::<crate::system::ratio::ppm>()
+                ),
             }
         )
     }
@@ -100,7 +221,31 @@ impl Display for Tolerance<Mass> {
 
 impl FromStr for Tolerance<Mass> {
     type Err = ();
+    /// Parse a tolerance as `<x>ppm`, `<x>da`, a combination of both separated by a pipe for a
+    /// combined tolerance, eg `5ppm|0.01da`, or two signed relative bounds for an asymmetric
+    /// tolerance, eg `-5ppm+10ppm`.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some((a, b)) = s.split_once('|') {
+            return match (a.parse()?, b.parse()?) {
+                (Self::Relative(relative), Self::Absolute(absolute))
+                | (Self::Absolute(absolute), Self::Relative(relative)) => {
+                    Ok(Self::Combined(relative, absolute))
+                }
+                _ => Err(()),
+            };
+        }
+        if let Some(split) = s
+            .find("ppm")
+            .map(|index| index + 3)
+            .filter(|index| s[*index..].trim_start().starts_with(['+', '-']))
+        {
+            return match (s[..split].parse()?, s[split..].trim_start().parse()?) {
+                (Self::Relative(lower), Self::Relative(upper)) if lower <= upper => {
+                    Ok(Self::Asymmetric(lower, upper))
+                }
+                _ => Err(()),
+            };
+        }
         let num_str = String::from_utf8(
             s.bytes()
                 .take_while(|c| {
@@ -137,15 +282,28 @@ where
 
 /// Check if two values are within the specified tolerance from each other.
 pub trait WithinTolerance<A, B> {
-    /// Check if two values are within the specified tolerance from each other.
+    /// Check if two values are within the specified tolerance from each other. The arguments are
+    /// given as (experimental, reference), for asymmetric tolerances the window is placed around
+    /// the reference value `b` (see [`Tolerance::bounds`]), so `a` is the experimental value that
+    /// is checked.
     fn within(&self, a: &A, b: &B) -> bool;
 }
 
+/// Check if `a` falls within the asymmetric relative window around `b`
+fn within_asymmetric(lower: OrderedRatio, upper: OrderedRatio, a: f64, b: f64) -> bool {
+    let error = Ratio::new::<crate::system::ratio::fraction>((a - b) / b.abs());
+    lower.into_inner() <= error && error <= upper.into_inner()
+}
+
 impl WithinTolerance<MassOverCharge, MassOverCharge> for Tolerance<MassOverCharge> {
     fn within(&self, a: &MassOverCharge, b: &MassOverCharge) -> bool {
         match self {
             Self::Absolute(tol) => (a.value - b.value).abs() <= tol.value,
             Self::Relative(tolerance) => a.ppm(*b) <= tolerance.into_inner(),
+            Self::Combined(relative, absolute) => {
+                (a.value - b.value).abs() <= absolute.value || a.ppm(*b) <= relative.into_inner()
+            }
+            Self::Asymmetric(lower, upper) => within_asymmetric(*lower, *upper, a.value, b.value),
         }
     }
 }
@@ -155,6 +313,10 @@ impl WithinTolerance<Mass, Mass> for Tolerance<Mass> {
         match self {
             Self::Absolute(tol) => (a.value - b.value).abs() <= tol.value,
             Self::Relative(tolerance) => a.ppm(*b) <= tolerance.into_inner(),
+            Self::Combined(relative, absolute) => {
+                (a.value - b.value).abs() <= absolute.value || a.ppm(*b) <= relative.into_inner()
+            }
+            Self::Asymmetric(lower, upper) => within_asymmetric(*lower, *upper, a.value, b.value),
         }
     }
 }
@@ -184,6 +346,10 @@ impl WithinTolerance<Mass, Mass> for Tolerance<OrderedMass> {
         match self {
             Self::Absolute(tol) => (a.value - b.value).abs() <= tol.value,
             Self::Relative(tolerance) => a.ppm(*b) <= tolerance.into_inner(),
+            Self::Combined(relative, absolute) => {
+                (a.value - b.value).abs() <= absolute.value || a.ppm(*b) <= relative.into_inner()
+            }
+            Self::Asymmetric(lower, upper) => within_asymmetric(*lower, *upper, a.value, b.value),
         }
     }
 }
@@ -207,3 +373,97 @@ impl WithinTolerance<Mass, Multi<Mass>> for Tolerance<OrderedMass> {
         b.iter().any(|b| self.within(a, b))
     }
 }
+
+#[cfg(test)]
+#[allow(clippy::missing_panics_doc)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scale() {
+        assert_eq!(
+            Tolerance::<Mass>::new_ppm(10.0).scale(0.5),
+            Tolerance::new_ppm(5.0)
+        );
+        assert_eq!(
+            Tolerance::<Mass>::new_absolute(da(0.02)).scale(0.5),
+            Tolerance::new_absolute(da(0.01))
+        );
+    }
+
+    #[test]
+    fn combined() {
+        let combined = Tolerance::<Mass>::new_combined(5.0, da(0.01));
+        assert_eq!("5ppm|0.01da".parse(), Ok(combined));
+        assert_eq!("0.01da|5ppm".parse(), Ok(combined));
+        assert!("5ppm|10ppm".parse::<Tolerance<Mass>>().is_err());
+        // At 500 Da 5 ppm is a window of ±0.0025 Da so the absolute part is wider
+        assert!(combined.within(&da(500.0), &da(500.009)));
+        assert!(!combined.within(&da(500.0), &da(500.011)));
+        // At 4000 Da 5 ppm is a window of ±0.02 Da so the relative part is wider
+        assert!(combined.within(&da(4000.0), &da(4000.019)));
+        assert!(!combined.within(&da(4000.0), &da(4000.021)));
+        let (low, high) = combined.bounds(da(4000.0));
+        assert!((low.value - 3999.98).abs() < 1e-9);
+        assert!((high.value - 4000.02).abs() < 1e-9);
+        let (low, high) = combined.bounds(da(500.0));
+        assert!((low.value - 499.99).abs() < 1e-9);
+        assert!((high.value - 500.01).abs() < 1e-9);
+    }
+
+    #[test]
+    fn asymmetric() {
+        let asymmetric = Tolerance::<Mass>::new_asymmetric(-5.0, 10.0);
+        assert_eq!("-5ppm+10ppm".parse(), Ok(asymmetric));
+        assert_eq!("-5 ppm +10 ppm".parse(), Ok(asymmetric));
+        assert_eq!(asymmetric.to_string().parse(), Ok(asymmetric));
+        assert_eq!(asymmetric.to_string(), "-5ppm+10ppm");
+        assert!("+10ppm-5ppm".parse::<Tolerance<Mass>>().is_err());
+        assert!("-5ppm+0.01da".parse::<Tolerance<Mass>>().is_err());
+        // 8 ppm above the reference is accepted, 8 ppm below is not
+        assert!(asymmetric.within(&da(1000.008), &da(1000.0)));
+        assert!(!asymmetric.within(&da(999.992), &da(1000.0)));
+        assert!(asymmetric.within(&da(999.996), &da(1000.0)));
+        assert!(!asymmetric.within(&da(1000.011), &da(1000.0)));
+        let (low, high) = asymmetric.bounds(da(1000.0));
+        assert!((low.value - 999.995).abs() < 1e-9);
+        assert!((high.value - 1000.01).abs() < 1e-9);
+        // The inverted window gives all references that accept this experimental value
+        let (low, high) = asymmetric.bounds_for_experimental(da(1000.0));
+        assert!(low.value < 999.991 && low.value > 999.99);
+        assert!(high.value > 1000.005 && high.value < 1000.006);
+        assert!(asymmetric.within(&da(1000.0), &(low + da(1e-7))));
+        assert!(asymmetric.within(&da(1000.0), &(high - da(1e-7))));
+        assert!(!asymmetric.within(&da(1000.0), &(low - da(0.0001))));
+        assert!(!asymmetric.within(&da(1000.0), &(high + da(0.0001))));
+        let symmetric = Tolerance::<Mass>::new_ppm(10.0);
+        assert_eq!(
+            symmetric.bounds_for_experimental(da(1000.0)),
+            symmetric.bounds(da(1000.0))
+        );
+        assert_eq!(
+            asymmetric.scale(2.0),
+            Tolerance::new_asymmetric(-10.0, 20.0)
+        );
+    }
+
+    #[test]
+    fn tighter_of() {
+        let relative = Tolerance::<Mass>::new_ppm(10.0);
+        let absolute = Tolerance::<Mass>::new_absolute(da(0.01));
+        // At 500 Da 10 ppm is a window of ±0.005 Da
+        assert_eq!(
+            Tolerance::tighter_of(relative, absolute, da(500.0)),
+            relative
+        );
+        // At 2000 Da 10 ppm is a window of ±0.02 Da
+        assert_eq!(
+            Tolerance::tighter_of(relative, absolute, da(2000.0)),
+            absolute
+        );
+        assert_eq!(
+            Tolerance::tighter_of(relative, relative.scale(0.5), da(2000.0)),
+            Tolerance::new_ppm(5.0)
+        );
+    }
+}